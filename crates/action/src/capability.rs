@@ -14,7 +14,8 @@ use std::{
 use nebula_core::{
     CoreError, CredentialKey, ResourceKey,
     accessor::{
-        CredentialAccessor, EventEmitter, LogLevel, Logger, MetricsEmitter, ResourceAccessor,
+        CredentialAccessor, EventEmitter, LogLevel, Logger, MetricsEmitter, RateLimitAccessor,
+        ResourceAccessor,
     },
     id::ExecutionId,
 };
@@ -301,6 +302,16 @@ impl EventEmitter for NoopEventEmitter {
     fn emit(&self, _topic: &str, _payload: serde_json::Value) {}
 }
 
+/// No-op rate limit accessor — every key is unlimited.
+#[derive(Debug, Default)]
+pub struct NoopRateLimitAccessor;
+
+impl RateLimitAccessor for NoopRateLimitAccessor {
+    fn acquire<'a>(&'a self, _key: &'a str) -> BoxFut<'a, Result<(), CoreError>> {
+        Box::pin(async { Ok(()) })
+    }
+}
+
 /// Default resource accessor — [`NoopResourceAccessor`].
 #[must_use]
 pub fn default_resource_accessor() -> Arc<dyn ResourceAccessor> {
@@ -331,6 +342,12 @@ pub fn default_event_emitter() -> Arc<dyn EventEmitter> {
     Arc::new(NoopEventEmitter)
 }
 
+/// Default rate limit accessor — [`NoopRateLimitAccessor`].
+#[must_use]
+pub fn default_rate_limit_accessor() -> Arc<dyn RateLimitAccessor> {
+    Arc::new(NoopRateLimitAccessor)
+}
+
 /// Default trigger scheduler — [`NoopTriggerScheduler`].
 #[must_use]
 pub fn default_trigger_scheduler() -> Arc<dyn TriggerScheduler> {
@@ -15,10 +15,14 @@
 use std::{any::Any, fmt, future::Future, pin::Pin, sync::Arc};
 
 use nebula_core::{
-    AttemptId, BaseContext, CredentialKey, NodeKey, ResourceKey,
-    accessor::{Clock, CredentialAccessor, EventEmitter, Logger, MetricsEmitter, ResourceAccessor},
+    AttemptId, BaseContext, CancellationReason, CredentialKey, NodeKey, ResourceKey,
+    accessor::{
+        Clock, CredentialAccessor, EventEmitter, Logger, MetricsEmitter, RateLimitAccessor,
+        ResourceAccessor,
+    },
     context::{
-        Context as CoreContext, HasCredentials, HasEventBus, HasLogger, HasMetrics, HasResources,
+        Context as CoreContext, HasCredentials, HasEventBus, HasLogger, HasMetrics, HasRateLimits,
+        HasResources,
     },
     id::{ExecutionId, WorkflowId},
     obs::{SpanId, TraceId},
@@ -32,9 +36,11 @@ use crate::{
     capability::{
         ExecutionEmitter, TriggerHealth, TriggerScheduler, default_action_logger,
         default_credential_accessor, default_event_emitter, default_execution_emitter,
-        default_metrics_emitter, default_resource_accessor, default_trigger_scheduler,
+        default_metrics_emitter, default_rate_limit_accessor, default_resource_accessor,
+        default_trigger_scheduler,
     },
     error::ActionError,
+    extension::Extensions,
 };
 
 // ── Action-specific capability traits ──────────────────────────────────────
@@ -57,6 +63,15 @@ pub trait HasNodeIdentity: CoreContext {
     fn attempt_id(&self) -> &AttemptId;
 }
 
+/// Capability: typed host services (HTTP client, clock, feature flags, ...).
+///
+/// Action-specific — the runtime fills the [`Extensions`] map per dispatch.
+/// Actions read it through [`ExtensionContextExt::get_extension`].
+pub trait HasExtensions: CoreContext {
+    /// Host-provided services, keyed by type.
+    fn extensions(&self) -> &Extensions;
+}
+
 /// Capability: trigger scheduling + execution emission.
 pub trait HasTriggerScheduling: CoreContext {
     /// Scheduler used by triggers for delayed re-runs.
@@ -76,10 +91,18 @@ pub trait HasWebhookEndpoint: CoreContext {
 /// Umbrella trait for execution-time action contexts.
 #[diagnostic::on_unimplemented(
     message = "`{Self}` does not implement ActionContext",
-    note = "ActionContext requires core::Context + resources + credentials + logger + metrics + event bus + node identity"
+    note = "ActionContext requires core::Context + resources + credentials + logger + metrics + event bus + rate limits + node identity + extensions"
 )]
 pub trait ActionContext:
-    CoreContext + HasResources + HasCredentials + HasLogger + HasMetrics + HasEventBus + HasNodeIdentity
+    CoreContext
+    + HasResources
+    + HasCredentials
+    + HasLogger
+    + HasMetrics
+    + HasEventBus
+    + HasRateLimits
+    + HasNodeIdentity
+    + HasExtensions
 {
 }
 
@@ -90,7 +113,9 @@ impl<T> ActionContext for T where
         + HasLogger
         + HasMetrics
         + HasEventBus
+        + HasRateLimits
         + HasNodeIdentity
+        + HasExtensions
         + ?Sized
 {
 }
@@ -151,6 +176,8 @@ pub struct ActionRuntimeContext {
     logger: Arc<dyn Logger>,
     metrics: Arc<dyn MetricsEmitter>,
     eventbus: Arc<dyn EventEmitter>,
+    rate_limits: Arc<dyn RateLimitAccessor>,
+    extensions: Arc<Extensions>,
 }
 
 impl ActionRuntimeContext {
@@ -185,6 +212,8 @@ impl ActionRuntimeContext {
             logger: default_action_logger(),
             metrics: default_metrics_emitter(),
             eventbus: default_event_emitter(),
+            rate_limits: default_rate_limit_accessor(),
+            extensions: Arc::new(Extensions::new()),
         }
     }
 
@@ -223,6 +252,28 @@ impl ActionRuntimeContext {
         self
     }
 
+    /// Inject a rate limit accessor capability, usually shared by every node
+    /// of the execution.
+    #[must_use]
+    pub fn with_rate_limits(mut self, rate_limits: Arc<dyn RateLimitAccessor>) -> Self {
+        self.rate_limits = rate_limits;
+        self
+    }
+
+    /// Replace the host-provided extensions, usually shared by every node
+    /// the runtime dispatches.
+    #[must_use]
+    pub fn with_extensions(mut self, extensions: Arc<Extensions>) -> Self {
+        self.extensions = extensions;
+        self
+    }
+
+    /// Add one host-provided service, replacing any earlier value of the
+    /// same type. Other contexts sharing the same extensions are unaffected.
+    pub fn insert_extension<T: Send + Sync + 'static>(&mut self, value: T) {
+        Arc::make_mut(&mut self.extensions).insert(value);
+    }
+
     /// Acquire a resource by string key through the configured accessor.
     ///
     /// Invalid keys surface as fatal [`ActionError`].
@@ -322,6 +373,10 @@ impl CoreContext for ActionRuntimeContext {
         self.base.cancellation()
     }
 
+    fn cancellation_reason(&self) -> Option<CancellationReason> {
+        self.base.cancellation_reason()
+    }
+
     fn clock(&self) -> &dyn Clock {
         self.base.clock()
     }
@@ -365,6 +420,18 @@ impl HasEventBus for ActionRuntimeContext {
     }
 }
 
+impl HasRateLimits for ActionRuntimeContext {
+    fn rate_limits(&self) -> &dyn RateLimitAccessor {
+        &*self.rate_limits
+    }
+}
+
+impl HasExtensions for ActionRuntimeContext {
+    fn extensions(&self) -> &Extensions {
+        &self.extensions
+    }
+}
+
 impl HasNodeIdentity for ActionRuntimeContext {
     fn node_key(&self) -> &NodeKey {
         &self.node_key
@@ -385,6 +452,8 @@ impl fmt::Debug for ActionRuntimeContext {
             .field("logger", &"<dyn Logger>")
             .field("metrics", &"<dyn MetricsEmitter>")
             .field("eventbus", &"<dyn EventEmitter>")
+            .field("rate_limits", &"<dyn RateLimitAccessor>")
+            .field("extensions", &self.extensions)
             .finish()
     }
 }
@@ -547,6 +616,10 @@ impl CoreContext for TriggerRuntimeContext {
         self.base.cancellation()
     }
 
+    fn cancellation_reason(&self) -> Option<CancellationReason> {
+        self.base.cancellation_reason()
+    }
+
     fn clock(&self) -> &dyn Clock {
         self.base.clock()
     }
@@ -715,6 +788,68 @@ pub trait CredentialContextExt: HasCredentials {
 /// Blanket impl — any type carrying `HasCredentials` gets the helpers.
 impl<T: ?Sized + HasCredentials> CredentialContextExt for T {}
 
+// ── ExtensionContextExt ────────────────────────────────────────────────────
+
+/// Typed lookup of host-provided services for any context that carries a
+/// [`HasExtensions`] capability.
+///
+/// A separate trait because the generic method would make [`HasExtensions`]
+/// unusable as `dyn`. Bring it into scope via
+/// `use nebula_action::ExtensionContextExt;` or via the prelude.
+pub trait ExtensionContextExt: HasExtensions {
+    /// Borrow the service of type `T` that the host inserted.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ActionError::Fatal`] if the host did not provide a `T`.
+    /// Retrying cannot help: the extensions are fixed for the dispatch.
+    fn get_extension<T: Send + Sync + 'static>(&self) -> Result<&T, ActionError> {
+        self.extensions().get::<T>().ok_or_else(|| {
+            ActionError::fatal(format!(
+                "extension `{}` is not provided by the host",
+                std::any::type_name::<T>()
+            ))
+        })
+    }
+}
+
+/// Blanket impl — any type carrying `HasExtensions` gets the helpers.
+impl<T: ?Sized + HasExtensions> ExtensionContextExt for T {}
+
+// ── RateLimitContextExt ────────────────────────────────────────────────────
+
+/// Rate-limit permits as [`ActionError`]s for any context that carries a
+/// [`HasRateLimits`] capability.
+///
+/// Bring it into scope via `use nebula_action::RateLimitContextExt;` or via
+/// the prelude.
+pub trait RateLimitContextExt: HasRateLimits {
+    /// Wait for a permit under rate-limit `key`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ActionError::Cancelled`] with the context's
+    /// [`cancellation_reason`](CoreContext::cancellation_reason) if the
+    /// execution is cancelled before a permit is granted. Propagate it with
+    /// `?` rather than making the rate-limited call.
+    fn acquire_rate_limit<'a>(
+        &'a self,
+        key: &'a str,
+    ) -> Pin<Box<dyn Future<Output = Result<(), ActionError>> + Send + 'a>> {
+        Box::pin(async move {
+            self.rate_limits().acquire(key).await.map_err(|e| match e {
+                nebula_core::CoreError::RateLimitCancelled { .. } => {
+                    ActionError::cancelled(self.cancellation_reason())
+                },
+                other => ActionError::from(other),
+            })
+        })
+    }
+}
+
+/// Blanket impl — any type carrying `HasRateLimits` gets the helper.
+impl<T: ?Sized + HasRateLimits> RateLimitContextExt for T {}
+
 // ── ActionContextExt — typed slot acquisition (Phase 3 / Session 2) ────────
 
 /// Typed slot-acquisition helpers used by `#[derive(Action)]` factories.
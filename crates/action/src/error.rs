@@ -1,5 +1,6 @@
 use std::{sync::Arc, time::Duration};
 
+use nebula_core::CancellationReason;
 use serde::{Deserialize, Serialize};
 
 /// Retry-strategy hint attached by the action body to a failing
@@ -222,8 +223,11 @@ pub enum ActionError {
     },
 
     /// Execution cancelled via cancellation token.
-    #[error("cancelled")]
-    Cancelled,
+    #[error("cancelled{}", reason.map_or_else(String::new, |r| format!(" ({r})")))]
+    Cancelled {
+        /// Why the token was cancelled, when the canceller recorded one.
+        reason: Option<CancellationReason>,
+    },
 
     /// Output exceeds the configured data limit.
     #[error("data limit exceeded: {actual_bytes} bytes > {limit_bytes} bytes limit")]
@@ -272,7 +276,7 @@ impl nebula_error::Classify for ActionError {
             Self::Fatal { .. } => nebula_error::ErrorCategory::Internal,
             Self::Validation { .. } => nebula_error::ErrorCategory::Validation,
             Self::CapabilityViolation { .. } => nebula_error::ErrorCategory::Authorization,
-            Self::Cancelled => nebula_error::ErrorCategory::Cancelled,
+            Self::Cancelled { .. } => nebula_error::ErrorCategory::Cancelled,
             Self::DataLimitExceeded { .. } => nebula_error::ErrorCategory::Exhausted,
             // Credential store trouble is an external dependency issue,
             // not an internal bug — route it through the External bucket
@@ -287,7 +291,7 @@ impl nebula_error::Classify for ActionError {
             Self::Fatal { .. } => "ACTION:FATAL",
             Self::Validation { .. } => "ACTION:VALIDATION",
             Self::CapabilityViolation { .. } => "ACTION:CAPABILITY_VIOLATION",
-            Self::Cancelled => "ACTION:CANCELLED",
+            Self::Cancelled { .. } => "ACTION:CANCELLED",
             Self::DataLimitExceeded { .. } => "ACTION:DATA_LIMIT",
             Self::CredentialRefreshFailed { .. } => "ACTION:CREDENTIAL_REFRESH_FAILED",
         })
@@ -345,6 +349,7 @@ impl From<nebula_core::CoreError> for ActionError {
                 retryable: false,
                 ..
             } => ActionError::fatal(format!("{key}: {detail}")),
+            nebula_core::CoreError::RateLimitCancelled { .. } => ActionError::cancelled(None),
             other => ActionError::fatal_from(other),
         }
     }
@@ -522,6 +527,25 @@ impl ActionError {
         }
     }
 
+    /// Create a [`Self::Cancelled`] error carrying the cancellation reason.
+    ///
+    /// Pass [`Context::cancellation_reason`](nebula_core::Context::cancellation_reason)
+    /// so callers can tell a shutdown apart from a user cancel.
+    #[must_use]
+    pub fn cancelled(reason: Option<CancellationReason>) -> Self {
+        Self::Cancelled { reason }
+    }
+
+    /// The cancellation reason, if this is a [`Self::Cancelled`] error that
+    /// carries one.
+    #[must_use]
+    pub fn cancellation_reason(&self) -> Option<CancellationReason> {
+        match self {
+            Self::Cancelled { reason } => *reason,
+            _ => None,
+        }
+    }
+
     /// Returns `true` if the engine should consider retrying this error.
     ///
     /// Includes both the explicit [`Self::Retryable`] variant and
@@ -721,12 +745,24 @@ mod tests {
 
     #[test]
     fn cancelled_is_neither_retryable_nor_fatal() {
-        let err = ActionError::Cancelled;
+        let err = ActionError::cancelled(None);
         assert!(!err.is_retryable());
         // Cancelled is special — not retryable, not "fatal" in the business sense
         assert!(!err.is_fatal());
     }
 
+    #[test]
+    fn cancelled_carries_reason() {
+        let err = ActionError::cancelled(Some(CancellationReason::Shutdown));
+        assert_eq!(
+            err.cancellation_reason(),
+            Some(CancellationReason::Shutdown)
+        );
+        assert_eq!(err.to_string(), "cancelled (shutdown)");
+        assert_eq!(ActionError::cancelled(None).to_string(), "cancelled");
+        assert_eq!(ActionError::fatal("x").cancellation_reason(), None);
+    }
+
     #[test]
     fn data_limit_exceeded_is_fatal() {
         let err = ActionError::DataLimitExceeded {
@@ -785,7 +821,7 @@ mod tests {
             "validation (malformed_json): field `body` — expected object"
         );
 
-        let err = ActionError::Cancelled;
+        let err = ActionError::cancelled(None);
         assert_eq!(err.to_string(), "cancelled");
     }
 
@@ -833,7 +869,7 @@ mod tests {
                 .retry_hint_code()
                 .is_none()
         );
-        assert!(ActionError::Cancelled.retry_hint_code().is_none());
+        assert!(ActionError::cancelled(None).retry_hint_code().is_none());
         assert!(
             ActionError::DataLimitExceeded {
                 limit_bytes: 1,
@@ -0,0 +1,125 @@
+//! Type-keyed host services carried by action contexts.
+//!
+//! Credentials and resources are looked up by key and declared in action
+//! metadata. Some services are neither: an HTTP client, a clock, a
+//! feature-flag reader that the host wants every action to share. The host
+//! puts one value per type into an [`Extensions`] map, the runtime hands it
+//! to each action through [`HasExtensions`](crate::context::HasExtensions),
+//! and actions read it back with
+//! [`get_extension`](crate::context::ExtensionContextExt::get_extension).
+
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    fmt,
+    sync::Arc,
+};
+
+/// A map holding at most one value per type.
+///
+/// Values are stored behind `Arc`, so cloning the map is cheap and the same
+/// service instance is shared by every clone.
+///
+/// # Examples
+///
+/// ```rust
+/// use nebula_action::Extensions;
+///
+/// struct FeatureFlags {
+///     beta: bool,
+/// }
+///
+/// let mut extensions = Extensions::new();
+/// extensions.insert(FeatureFlags { beta: true });
+///
+/// assert!(extensions.get::<FeatureFlags>().is_some_and(|flags| flags.beta));
+/// assert!(extensions.get::<String>().is_none());
+/// ```
+#[derive(Clone, Default)]
+pub struct Extensions {
+    map: HashMap<TypeId, Arc<dyn Any + Send + Sync>>,
+}
+
+impl Extensions {
+    /// Create an empty map.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Insert `value`, replacing any earlier value of the same type.
+    ///
+    /// Returns `true` if a value of type `T` was replaced.
+    pub fn insert<T: Send + Sync + 'static>(&mut self, value: T) -> bool {
+        self.map
+            .insert(TypeId::of::<T>(), Arc::new(value))
+            .is_some()
+    }
+
+    /// Borrow the value of type `T`, if one was inserted.
+    #[must_use]
+    pub fn get<T: Send + Sync + 'static>(&self) -> Option<&T> {
+        self.map
+            .get(&TypeId::of::<T>())
+            .and_then(|value| value.downcast_ref::<T>())
+    }
+
+    /// Whether a value of type `T` was inserted.
+    #[must_use]
+    pub fn contains<T: Send + Sync + 'static>(&self) -> bool {
+        self.map.contains_key(&TypeId::of::<T>())
+    }
+
+    /// Number of values in the map.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Whether the map is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+}
+
+impl fmt::Debug for Extensions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Extensions")
+            .field("len", &self.map.len())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_replaces_value_of_same_type() {
+        let mut extensions = Extensions::new();
+        assert!(!extensions.insert(1u32));
+        assert!(extensions.insert(2u32));
+        assert_eq!(extensions.get::<u32>(), Some(&2));
+        assert_eq!(extensions.len(), 1);
+    }
+
+    #[test]
+    fn values_are_keyed_by_exact_type() {
+        let mut extensions = Extensions::new();
+        extensions.insert(7u32);
+        assert!(extensions.contains::<u32>());
+        assert!(!extensions.contains::<u64>());
+        assert_eq!(extensions.get::<u64>(), None);
+    }
+
+    #[test]
+    fn clones_share_values() {
+        let mut extensions = Extensions::new();
+        extensions.insert(String::from("client"));
+        let clone = extensions.clone();
+        let original: *const String = extensions.get::<String>().unwrap();
+        let cloned: *const String = clone.get::<String>().unwrap();
+        assert!(std::ptr::eq(original, cloned));
+    }
+}
@@ -50,6 +50,8 @@ pub mod context;
 pub mod control;
 /// Error types distinguishing retryable from fatal failures.
 pub mod error;
+/// [`Extensions`] — type-keyed host services carried by action contexts.
+pub mod extension;
 /// `ActionFactory` — engine-side per-execution factory that produces an
 /// `ActionHandle` from a workflow node + context.
 pub mod factory;
@@ -110,13 +112,15 @@ pub use action::Action;
 pub use agent::{AgentAction, AgentActionAdapter};
 pub use capability::{ExecutionEmitter, TriggerHealth, TriggerHealthSnapshot, TriggerScheduler};
 pub use context::{
-    ActionContext, ActionContextExt, ActionRuntimeContext, CredentialContextExt, HasNodeIdentity,
-    HasTriggerScheduling, HasWebhookEndpoint, TriggerContext, TriggerRuntimeContext,
+    ActionContext, ActionContextExt, ActionRuntimeContext, CredentialContextExt,
+    ExtensionContextExt, HasExtensions, HasNodeIdentity, HasTriggerScheduling, HasWebhookEndpoint,
+    RateLimitContextExt, TriggerContext, TriggerRuntimeContext,
 };
 pub use control::{ControlAction, ControlActionAdapter, ControlInput, ControlOutcome};
 pub use error::{
     ActionError, ActionErrorExt, MAX_VALIDATION_DETAIL, RetryHintCode, ValidationReason,
 };
+pub use extension::Extensions;
 pub use factory::{
     ActionFactory, GenericAgentFactory, GenericControlFactory, GenericResourceFactory,
     GenericStatefulFactory, GenericStatelessFactory, GenericStreamFactory, GenericTriggerFactory,
@@ -135,8 +139,10 @@ pub use nebula_action_macros::{Action, action_phantom};
 pub use nebula_core::{BranchKey, KeyValidationError, KeyValidationErrorKind, PortKey};
 pub use nebula_core::{
     Context, Dependencies,
-    accessor::{EventEmitter, LogLevel, Logger, MetricsEmitter, ResourceAccessor},
-    context::{HasCredentials, HasEventBus, HasLogger, HasMetrics, HasResources},
+    accessor::{
+        EventEmitter, LogLevel, Logger, MetricsEmitter, RateLimitAccessor, ResourceAccessor,
+    },
+    context::{HasCredentials, HasEventBus, HasLogger, HasMetrics, HasRateLimits, HasResources},
 };
 pub use nebula_credential::{CredentialGuard, CredentialRef};
 pub use nebula_resource::ResourceRef;
@@ -175,16 +175,16 @@ macro_rules! assert_validation_error {
     };
 }
 
-/// Assert that the result is `Err(ActionError::Cancelled)`.
+/// Assert that the result is `Err(ActionError::Cancelled { .. })`.
 ///
 /// # Panics
 ///
-/// Panics if the result is not `Err(ActionError::Cancelled)`.
+/// Panics if the result is not `Err(ActionError::Cancelled { .. })`.
 #[macro_export]
 macro_rules! assert_cancelled {
     ($result:expr) => {
         match &$result {
-            Err($crate::ActionError::Cancelled) => {},
+            Err($crate::ActionError::Cancelled { .. }) => {},
             other => panic!("expected ActionError::Cancelled, got {:?}", other),
         }
     };
@@ -16,8 +16,10 @@
 
 pub use nebula_core::{
     Context, Dependencies,
-    accessor::{EventEmitter, LogLevel, Logger, MetricsEmitter, ResourceAccessor},
-    context::{HasCredentials, HasEventBus, HasLogger, HasMetrics, HasResources},
+    accessor::{
+        EventEmitter, LogLevel, Logger, MetricsEmitter, RateLimitAccessor, ResourceAccessor,
+    },
+    context::{HasCredentials, HasEventBus, HasLogger, HasMetrics, HasRateLimits, HasResources},
 };
 pub use nebula_credential::CredentialGuard;
 pub use nebula_schema::{Field, Schema, ValidSchema, field_key};
@@ -27,11 +29,13 @@ pub use crate::{
     agent::AgentAction,
     capability::{ExecutionEmitter, TriggerScheduler},
     context::{
-        ActionContext, ActionRuntimeContext, CredentialContextExt, HasNodeIdentity,
-        HasTriggerScheduling, TriggerContext, TriggerRuntimeContext,
+        ActionContext, ActionRuntimeContext, CredentialContextExt, ExtensionContextExt,
+        HasExtensions, HasNodeIdentity, HasTriggerScheduling, RateLimitContextExt, TriggerContext,
+        TriggerRuntimeContext,
     },
     control::{ControlAction, ControlActionAdapter, ControlInput, ControlOutcome},
     error::{ActionError, ActionErrorExt, RetryHintCode, ValidationReason},
+    extension::Extensions,
     idempotency::IdempotencyKey,
     metadata::{ActionMetadata, MetadataCompatibilityError},
     output::{
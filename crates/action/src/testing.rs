@@ -22,6 +22,7 @@ use tokio_util::sync::CancellationToken;
 use crate::{
     capability::{ExecutionEmitter, TriggerScheduler},
     error::ActionError,
+    extension::Extensions,
     result::ActionResult,
     stateful::StatefulAction,
     trigger::TriggerAction,
@@ -44,6 +45,7 @@ pub struct TestContextBuilder {
     resources: HashMap<String, ResourceFactory>,
     input: Option<serde_json::Value>,
     logs: Arc<SpyLogger>,
+    extensions: Extensions,
 }
 
 impl TestContextBuilder {
@@ -55,6 +57,7 @@ impl TestContextBuilder {
             resources: HashMap::new(),
             input: None,
             logs: Arc::new(SpyLogger::new()),
+            extensions: Extensions::new(),
         }
     }
 
@@ -99,6 +102,12 @@ impl TestContextBuilder {
         self
     }
 
+    #[must_use]
+    pub fn with_extension<T: Send + Sync + 'static>(mut self, value: T) -> Self {
+        self.extensions.insert(value);
+        self
+    }
+
     #[must_use]
     pub fn with_input(mut self, input: serde_json::Value) -> Self {
         self.input = Some(input);
@@ -139,6 +148,7 @@ impl TestContextBuilder {
             typed_credentials: self.typed_credentials,
         }))
         .with_logger(self.logs)
+        .with_extensions(Arc::new(self.extensions))
     }
 
     #[must_use]
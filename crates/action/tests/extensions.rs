@@ -0,0 +1,109 @@
+//! Integration tests for typed host extensions on the action context.
+//!
+//! The host inserts a service by type; the action retrieves it with
+//! `get_extension::<T>()` and gets a fatal error when the host did not
+//! provide one.
+
+use std::sync::{
+    Arc, OnceLock,
+    atomic::{AtomicU32, Ordering},
+};
+
+use nebula_action::{
+    Action, ActionError, ActionMetadata, ActionResult, ExtensionContextExt, StatelessAction,
+    testing::TestContextBuilder,
+};
+use nebula_core::{Dependencies, action_key};
+
+fn empty_deps() -> &'static Dependencies {
+    static D: OnceLock<Dependencies> = OnceLock::new();
+    D.get_or_init(Dependencies::new)
+}
+
+/// Host service the action expects: a counter standing in for an HTTP client.
+#[derive(Default)]
+struct MockHttpClient {
+    requests: AtomicU32,
+}
+
+impl MockHttpClient {
+    fn get(&self) -> u32 {
+        self.requests.fetch_add(1, Ordering::SeqCst) + 1
+    }
+}
+
+struct FetchAction;
+
+impl Action for FetchAction {
+    type Input = serde_json::Value;
+    type Output = serde_json::Value;
+
+    fn metadata() -> ActionMetadata {
+        ActionMetadata::new(action_key!("test.fetch"), "Fetch", "Calls the host client")
+    }
+
+    fn dependencies() -> &'static Dependencies {
+        empty_deps()
+    }
+}
+
+impl StatelessAction for FetchAction {
+    async fn execute(
+        &self,
+        _input: <Self as Action>::Input,
+        ctx: &(impl nebula_action::ActionContext + ?Sized),
+    ) -> Result<ActionResult<<Self as Action>::Output>, ActionError> {
+        let client = ctx.get_extension::<Arc<MockHttpClient>>()?;
+        Ok(ActionResult::success(serde_json::json!({
+            "request": client.get()
+        })))
+    }
+}
+
+#[tokio::test]
+async fn action_retrieves_host_extension_by_type() {
+    let client = Arc::new(MockHttpClient::default());
+    let ctx = TestContextBuilder::new()
+        .with_extension(Arc::clone(&client))
+        .build();
+
+    let result = FetchAction
+        .execute(serde_json::json!(null), &ctx)
+        .await
+        .unwrap();
+
+    let ActionResult::Success { output } = &result else {
+        panic!("expected Success, got {result:?}");
+    };
+    assert_eq!(
+        output.as_value().expect("value"),
+        &serde_json::json!({ "request": 1 })
+    );
+    assert_eq!(client.requests.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn missing_extension_is_fatal() {
+    let ctx = TestContextBuilder::new().build();
+
+    let err = FetchAction
+        .execute(serde_json::json!(null), &ctx)
+        .await
+        .unwrap_err();
+
+    assert!(err.is_fatal(), "expected fatal error, got {err:?}");
+    assert!(err.to_string().contains("MockHttpClient"), "{err}");
+}
+
+#[test]
+fn inserted_extension_does_not_leak_into_shared_contexts() {
+    let shared = TestContextBuilder::new().with_extension(1u32).build();
+    let mut own = shared.clone();
+    own.insert_extension(2u32);
+    own.insert_extension(String::from("flags"));
+
+    assert_eq!(shared.get_extension::<u32>().unwrap(), &1);
+    assert!(shared.get_extension::<String>().is_err());
+    assert_eq!(own.get_extension::<u32>().unwrap(), &2);
+    assert_eq!(own.get_extension::<String>().unwrap(), "flags");
+}
@@ -907,7 +907,7 @@ pub(crate) mod engine_seam {
                     Ok(nebula_action::result::ActionResult::success(input))
                 }
                 () = ctx.cancellation().cancelled() => {
-                    Err(nebula_action::ActionError::Cancelled)
+                    Err(nebula_action::ActionError::cancelled(None))
                 }
             }
         }
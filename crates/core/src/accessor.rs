@@ -7,7 +7,7 @@ use std::{future::Future, pin::Pin, time::Instant};
 use chrono::{DateTime, Utc};
 
 /// Type alias for dyn-safe async return.
-type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
 
 /// Dyn-safe resource accessor. Impl in nebula-engine.
 pub trait ResourceAccessor: Send + Sync {
@@ -80,6 +80,23 @@ pub trait EventEmitter: Send + Sync {
     fn emit(&self, topic: &str, payload: serde_json::Value);
 }
 
+/// Rate limits shared by every node of an execution. Impl in nebula-engine.
+///
+/// Nodes that call the same downstream acquire under the same key, so they
+/// draw from one budget instead of each overrunning it separately.
+pub trait RateLimitAccessor: Send + Sync {
+    /// Wait until the limiter registered under `key` grants a permit.
+    ///
+    /// Keys without a registered limiter are unlimited and return immediately.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CoreError::RateLimitCancelled`](crate::CoreError::RateLimitCancelled)
+    /// if the execution is cancelled before a permit is granted. The caller
+    /// holds no permit and must not proceed.
+    fn acquire<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Result<(), crate::CoreError>>;
+}
+
 /// Clock abstraction for deterministic testing.
 pub trait Clock: Send + Sync {
     /// Current wall-clock time.
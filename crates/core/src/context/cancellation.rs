@@ -0,0 +1,123 @@
+//! Structured cancellation -- a [`CancellationToken`] paired with the reason
+//! it was cancelled.
+
+use std::{
+    fmt,
+    sync::{Arc, OnceLock},
+};
+
+use serde::{Deserialize, Serialize};
+use tokio_util::sync::CancellationToken;
+
+/// Why a context was cancelled.
+///
+/// Lets actions and the runtime react differently to cancellation — e.g.
+/// re-queue work interrupted by a shutdown but not work a user cancelled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum CancellationReason {
+    /// A user (or API caller) cancelled the execution.
+    UserRequested,
+    /// The execution ran past its time budget.
+    Timeout,
+    /// The process is shutting down.
+    Shutdown,
+    /// An upstream node or sibling branch failed.
+    UpstreamFailure,
+}
+
+impl fmt::Display for CancellationReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::UserRequested => "user requested",
+            Self::Timeout => "timeout",
+            Self::Shutdown => "shutdown",
+            Self::UpstreamFailure => "upstream failure",
+        })
+    }
+}
+
+/// A [`CancellationToken`] that remembers why it was cancelled.
+///
+/// Clones share both the token and the reason. The first reason recorded
+/// wins; cancelling the token directly (without a reason) leaves
+/// [`reason`](Self::reason) as `None`.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationSignal {
+    token: CancellationToken,
+    reason: Arc<OnceLock<CancellationReason>>,
+}
+
+impl CancellationSignal {
+    /// Create a fresh, uncancelled signal.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Wrap an existing token. The reason starts unset.
+    #[must_use]
+    pub fn from_token(token: CancellationToken) -> Self {
+        Self {
+            token,
+            reason: Arc::default(),
+        }
+    }
+
+    /// The underlying cancellation token.
+    #[must_use]
+    pub fn token(&self) -> &CancellationToken {
+        &self.token
+    }
+
+    /// Record `reason` (unless one is already set) and cancel the token.
+    pub fn cancel(&self, reason: CancellationReason) {
+        let _ = self.reason.set(reason);
+        self.token.cancel();
+    }
+
+    /// Why the signal was cancelled, if a reason was recorded.
+    #[must_use]
+    pub fn reason(&self) -> Option<CancellationReason> {
+        self.reason.get().copied()
+    }
+
+    /// Whether the underlying token has been cancelled.
+    #[must_use]
+    pub fn is_cancelled(&self) -> bool {
+        self.token.is_cancelled()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cancel_records_reason_and_cancels_token() {
+        let signal = CancellationSignal::new();
+        let clone = signal.clone();
+        assert_eq!(signal.reason(), None);
+
+        clone.cancel(CancellationReason::Shutdown);
+        assert!(signal.is_cancelled());
+        assert_eq!(signal.reason(), Some(CancellationReason::Shutdown));
+    }
+
+    #[test]
+    fn first_reason_wins() {
+        let signal = CancellationSignal::new();
+        signal.cancel(CancellationReason::Timeout);
+        signal.cancel(CancellationReason::UserRequested);
+        assert_eq!(signal.reason(), Some(CancellationReason::Timeout));
+    }
+
+    #[test]
+    fn bare_token_cancel_has_no_reason() {
+        let signal = CancellationSignal::new();
+        signal.token().cancel();
+        assert!(signal.is_cancelled());
+        assert_eq!(signal.reason(), None);
+    }
+}
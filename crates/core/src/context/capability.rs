@@ -1,6 +1,8 @@
 //! Capability traits for context composition (spec 23).
 
-use crate::accessor::{CredentialAccessor, EventEmitter, Logger, MetricsEmitter, ResourceAccessor};
+use crate::accessor::{
+    CredentialAccessor, EventEmitter, Logger, MetricsEmitter, RateLimitAccessor, ResourceAccessor,
+};
 
 /// Capability: access to managed resources.
 pub trait HasResources: crate::context::Context {
@@ -36,3 +38,9 @@ pub trait HasEventBus: crate::context::Context {
     /// Get the event emitter.
     fn eventbus(&self) -> &dyn EventEmitter;
 }
+
+/// Capability: rate limits shared across an execution.
+pub trait HasRateLimits: crate::context::Context {
+    /// Get the rate limit accessor.
+    fn rate_limits(&self) -> &dyn RateLimitAccessor;
+}
@@ -1,7 +1,9 @@
 //! Context system -- base trait + capabilities (spec 23).
 
+pub mod cancellation;
 pub mod capability;
 
+pub use cancellation::{CancellationReason, CancellationSignal};
 pub use capability::*;
 use tokio_util::sync::CancellationToken;
 
@@ -19,6 +21,10 @@ pub trait Context: Send + Sync {
     fn principal(&self) -> &Principal;
     /// Get the cancellation token.
     fn cancellation(&self) -> &CancellationToken;
+    /// Why the context was cancelled, if it was cancelled with a reason.
+    fn cancellation_reason(&self) -> Option<CancellationReason> {
+        None
+    }
     /// Get the clock.
     fn clock(&self) -> &dyn Clock;
     /// Get the trace ID, if available.
@@ -39,7 +45,7 @@ pub trait Context: Send + Sync {
 pub struct BaseContext {
     scope: Scope,
     principal: Principal,
-    cancellation: CancellationToken,
+    cancellation: CancellationSignal,
     clock: Box<dyn Clock>,
     trace_id: Option<TraceId>,
     span_id: Option<SpanId>,
@@ -77,7 +83,10 @@ impl Context for BaseContext {
         &self.principal
     }
     fn cancellation(&self) -> &CancellationToken {
-        &self.cancellation
+        self.cancellation.token()
+    }
+    fn cancellation_reason(&self) -> Option<CancellationReason> {
+        self.cancellation.reason()
     }
     fn clock(&self) -> &dyn Clock {
         &*self.clock
@@ -99,7 +108,7 @@ impl Context for BaseContext {
 pub struct BaseContextBuilder {
     scope: Scope,
     principal: Option<Principal>,
-    cancellation: Option<CancellationToken>,
+    cancellation: Option<CancellationSignal>,
     clock: Option<Box<dyn Clock>>,
     trace_id: Option<TraceId>,
     span_id: Option<SpanId>,
@@ -118,7 +127,16 @@ impl BaseContextBuilder {
     ///
     /// Defaults to a fresh [`CancellationToken`] if not provided.
     pub fn cancellation(mut self, t: CancellationToken) -> Self {
-        self.cancellation = Some(t);
+        self.cancellation = Some(CancellationSignal::from_token(t));
+        self
+    }
+
+    /// Set the cancellation signal, so the context reports the reason it
+    /// was cancelled with via [`Context::cancellation_reason`].
+    ///
+    /// Replaces any token set with [`cancellation`](Self::cancellation).
+    pub fn cancellation_signal(mut self, s: CancellationSignal) -> Self {
+        self.cancellation = Some(s);
         self
     }
 
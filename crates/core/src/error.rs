@@ -94,6 +94,16 @@ pub enum CoreError {
         retry_after: Option<Duration>,
     },
 
+    /// Waiting for a rate-limit permit was cut short by cancellation.
+    ///
+    /// No permit was granted; the caller must not proceed with the
+    /// rate-limited call.
+    #[error("rate limit wait cancelled: {key}")]
+    RateLimitCancelled {
+        /// Rate-limit key that was being acquired.
+        key: String,
+    },
+
     /// An internal registry invariant was violated (e.g., duplicate registration
     /// or a lookup that contradicts a prior guarantee).
     ///
@@ -142,6 +152,11 @@ impl CoreError {
         Self::CredentialNotFound { key }
     }
 
+    /// Create a cancelled rate-limit wait error for `key`.
+    pub fn rate_limit_cancelled(key: impl Into<String>) -> Self {
+        Self::RateLimitCancelled { key: key.into() }
+    }
+
     /// Create a resource-acquire failure surfaced through the accessor seam.
     pub fn resource_unavailable(
         key: impl Into<String>,
@@ -176,6 +191,7 @@ impl nebula_error::Classify for CoreError {
             },
             Self::CredentialAccessDenied { .. } => nebula_error::ErrorCategory::Authorization,
             Self::ResourceUnavailable { .. } => nebula_error::ErrorCategory::Unavailable,
+            Self::RateLimitCancelled { .. } => nebula_error::ErrorCategory::Cancelled,
             Self::RegistryInvariant(_) => nebula_error::ErrorCategory::Internal,
         }
     }
@@ -191,6 +207,7 @@ impl nebula_error::Classify for CoreError {
             Self::CredentialNotFound { .. } => "CORE:CREDENTIAL_NOT_FOUND",
             Self::CredentialAccessDenied { .. } => "CORE:CREDENTIAL_ACCESS_DENIED",
             Self::ResourceUnavailable { .. } => "CORE:RESOURCE_UNAVAILABLE",
+            Self::RateLimitCancelled { .. } => "CORE:RATE_LIMIT_CANCELLED",
             Self::RegistryInvariant(_) => "CORE:REGISTRY_INVARIANT",
         })
     }
@@ -236,6 +253,7 @@ mod tests {
             CoreError::scope_violation("a", "b"),
             CoreError::dependency_cycle(vec!["a", "b"]),
             CoreError::dependency_missing("x", "y"),
+            CoreError::rate_limit_cancelled("api"),
         ];
         for e in &errors {
             assert!(!e.is_retryable());
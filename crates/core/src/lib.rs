@@ -24,9 +24,10 @@
 //!   — normalized string keys with validation.
 //! - **Scope** — `ScopeLevel`, `Scope`, `Principal`, `ScopeResolver` (Global → Organization → Workspace → Workflow → Execution).
 //! - **Context** — `Context` trait, `BaseContext`, `BaseContextBuilder`, capability traits
-//!   (`HasCredentials`, `HasResources`, `HasMetrics`, `HasEventBus`, `HasLogger`).
+//!   (`HasCredentials`, `HasResources`, `HasMetrics`, `HasEventBus`, `HasLogger`,
+//!   `HasRateLimits`).
 //! - **Accessors** — `ResourceAccessor`, `CredentialAccessor`, `Logger`, `MetricsEmitter`,
-//!   `EventEmitter`, `Clock`.
+//!   `EventEmitter`, `RateLimitAccessor`, `Clock`.
 //! - **Guards** — `Guard`, `TypedGuard` RAII guard traits for scoped resource and credential
 //!   lifecycle.
 //! - **Auth** — `AuthScheme` trait, `AuthPattern` enum (module `auth`).
@@ -90,8 +91,8 @@ mod keys;
 pub use auth::{AuthPattern, AuthScheme};
 pub use branch_key::BranchKey;
 pub use context::{
-    BaseContext, BaseContextBuilder, Context, HasCredentials, HasEventBus, HasLogger, HasMetrics,
-    HasResources,
+    BaseContext, BaseContextBuilder, CancellationReason, CancellationSignal, Context,
+    HasCredentials, HasEventBus, HasLogger, HasMetrics, HasRateLimits, HasResources,
 };
 pub use dependencies::*;
 pub use error::*;
@@ -114,7 +114,7 @@ impl ScopeLevel {
     }
 
     /// Strict containment check that verifies ID ownership via a resolver.
-    pub fn is_contained_in_strict<R: ScopeResolver>(
+    pub fn is_contained_in_strict<R: ScopeResolver + ?Sized>(
         &self,
         other: &ScopeLevel,
         resolver: &R,
@@ -71,9 +71,10 @@ pub struct AuditEvent {
 /// flowing through `AuditLayer`. Variants prefixed `RefreshCoord*`
 /// describe events emitted by the engine's two-tier refresh coordinator
 /// (sub-spec `docs/INTEGRATION_MODEL.md` (credential refresh coordinator)
-/// §6) and carry their structured payload as enum fields. The same
-/// [`AuditSink`] receives both families so operators reuse one sink
-/// implementation.
+/// §6) and carry their structured payload as enum fields.
+/// [`ExecutionAccess`](Self::ExecutionAccess) is emitted by the engine when
+/// a workflow node resolves a credential. The same [`AuditSink`] receives
+/// every family so operators reuse one sink implementation.
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[non_exhaustive]
 pub enum AuditOperation {
@@ -116,6 +117,17 @@ pub enum AuditOperation {
         /// arm's stable identifier).
         reason: String,
     },
+    /// A workflow node resolved a credential during an execution.
+    ///
+    /// Records who (`node_id`) and which run (`execution_id`) touched the
+    /// credential; `AuditEvent::timestamp` records when. Denied requests
+    /// are recorded too, with an [`AuditResult::Error`] outcome.
+    ExecutionAccess {
+        /// Execution the accessing node belongs to.
+        execution_id: String,
+        /// Workflow node that requested the credential.
+        node_id: String,
+    },
 }
 
 /// Outcome of an audited operation.
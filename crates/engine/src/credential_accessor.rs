@@ -33,10 +33,21 @@
 //! "fail-open" escape hatch.
 //!
 //! [`WorkflowEngine::with_action_credentials`]: crate::WorkflowEngine::with_action_credentials
+//!
+//! # Access audit
+//!
+//! When built [`with_audit`](EngineCredentialAccessor::with_audit), every
+//! `resolve_any` / `try_resolve_any` call records an
+//! [`AuditOperation::ExecutionAccess`] event carrying the execution and node
+//! ids. Only the credential id and the outcome are recorded — never the
+//! resolved value. As with the other credential audit emitters, a sink
+//! failure is logged and never fails the access.
 
 use std::{collections::HashSet, fmt, future::Future, pin::Pin, sync::Arc};
 
 use nebula_core::{CoreError, CredentialKey};
+use nebula_credential::{AuditEvent, AuditOperation, AuditResult, AuditSink};
+use nebula_error::Classify;
 
 /// Type alias for dyn-safe async return.
 type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
@@ -96,6 +107,50 @@ pub struct EngineCredentialAccessor {
     resolve_fn: ResolveFn,
     /// Identity of the action this accessor is scoped to, for security attribution.
     action_id: String,
+    /// Optional access-audit emitter. See [`with_audit`](Self::with_audit).
+    audit: Option<AccessAudit>,
+}
+
+/// Audit sink plus the execution context every access event is tagged with.
+struct AccessAudit {
+    sink: Arc<dyn AuditSink>,
+    execution_id: String,
+    node_id: String,
+}
+
+impl AccessAudit {
+    /// Records one access of `credential_id`. Sink failures are logged, not
+    /// propagated.
+    fn record(&self, credential_id: &str, result: AuditResult) {
+        let event = AuditEvent {
+            timestamp: chrono::Utc::now(),
+            credential_id: credential_id.to_owned(),
+            operation: AuditOperation::ExecutionAccess {
+                execution_id: self.execution_id.clone(),
+                node_id: self.node_id.clone(),
+            },
+            result,
+        };
+        if let Err(e) = self.sink.record(&event) {
+            tracing::warn!(
+                ?e,
+                cred = %credential_id,
+                execution_id = %self.execution_id,
+                node_id = %self.node_id,
+                "credential access audit sink failed"
+            );
+        }
+    }
+
+    /// Maps a resolution outcome to an audit result. Errors are reduced to
+    /// their stable code so no resolver-supplied text reaches the sink.
+    fn outcome<T>(result: &Result<T, CoreError>) -> AuditResult {
+        match result {
+            Ok(_) => AuditResult::Success,
+            Err(CoreError::CredentialNotFound { .. }) => AuditResult::NotFound,
+            Err(e) => AuditResult::Error(e.code().to_string()),
+        }
+    }
 }
 
 impl EngineCredentialAccessor {
@@ -133,6 +188,34 @@ impl EngineCredentialAccessor {
                     >
             }),
             action_id,
+            audit: None,
+        }
+    }
+
+    /// Records every credential access to `sink`, tagged with the execution
+    /// and node it was made from.
+    ///
+    /// Denied requests are recorded as well, so the trail shows attempted
+    /// access and not only successful access.
+    #[must_use = "builder methods must be chained or built"]
+    pub fn with_audit(
+        mut self,
+        sink: Arc<dyn AuditSink>,
+        execution_id: String,
+        node_id: String,
+    ) -> Self {
+        self.audit = Some(AccessAudit {
+            sink,
+            execution_id,
+            node_id,
+        });
+        self
+    }
+
+    /// Records `result` for `credential_id` if auditing is enabled.
+    fn audit<T>(&self, credential_id: &str, result: &Result<T, CoreError>) {
+        if let Some(audit) = &self.audit {
+            audit.record(credential_id, AccessAudit::outcome(result));
         }
     }
 
@@ -151,6 +234,7 @@ impl fmt::Debug for EngineCredentialAccessor {
             .field("allowed_keys", &self.allowed_keys)
             .field("resolve_fn", &"<fn>")
             .field("action_id", &self.action_id)
+            .field("audited", &self.audit.is_some())
             .finish()
     }
 }
@@ -178,25 +262,27 @@ impl nebula_core::accessor::CredentialAccessor for EngineCredentialAccessor {
     /// # Cancel safety
     ///
     /// This method is cancel-safe. If the future is dropped before completion,
-    /// no state is modified.
+    /// no state is modified and no access is audited.
     fn resolve_any(
         &self,
         key: &CredentialKey,
     ) -> BoxFuture<'_, Result<Box<dyn std::any::Any + Send + Sync>, CoreError>> {
         let key_str = key.as_str();
         if !self.is_allowed(key_str) {
-            let capability = format!("credential:{key_str}");
-            let action_id = self.action_id.clone();
-            return Box::pin(async move {
-                Err(CoreError::CredentialAccessDenied {
-                    capability,
-                    action_id,
-                })
+            let denied = Err(CoreError::CredentialAccessDenied {
+                capability: format!("credential:{key_str}"),
+                action_id: self.action_id.clone(),
             });
+            self.audit(key_str, &denied);
+            return Box::pin(async move { denied });
         }
         let key_owned = key_str.to_owned();
         let resolve_fn = Arc::clone(&self.resolve_fn);
-        Box::pin(async move { (resolve_fn)(&key_owned).await })
+        Box::pin(async move {
+            let result = (resolve_fn)(&key_owned).await;
+            self.audit(&key_owned, &result);
+            result
+        })
     }
 
     /// Try to resolve a credential by key, returning `None` if not in allowlist.
@@ -213,7 +299,11 @@ impl nebula_core::accessor::CredentialAccessor for EngineCredentialAccessor {
         }
         let key_owned = key_str.to_owned();
         let resolve_fn = Arc::clone(&self.resolve_fn);
-        Box::pin(async move { (resolve_fn)(&key_owned).await.map(Some) })
+        Box::pin(async move {
+            let result = (resolve_fn)(&key_owned).await;
+            self.audit(&key_owned, &result);
+            result.map(Some)
+        })
     }
 }
 
@@ -386,4 +476,99 @@ mod tests {
             "resolver must not run when denied"
         );
     }
+
+    /// Sink that keeps every event it receives.
+    #[derive(Default)]
+    struct RecordingSink(std::sync::Mutex<Vec<AuditEvent>>);
+
+    impl AuditSink for RecordingSink {
+        fn record(
+            &self,
+            event: &AuditEvent,
+        ) -> Result<(), nebula_storage_port::CredentialPersistenceError> {
+            self.0.lock().unwrap().push(event.clone());
+            Ok(())
+        }
+    }
+
+    fn audited(
+        accessor: EngineCredentialAccessor,
+        sink: &Arc<RecordingSink>,
+    ) -> EngineCredentialAccessor {
+        accessor.with_audit(
+            Arc::clone(sink) as Arc<dyn AuditSink>,
+            "exe_1".to_owned(),
+            "fetch".to_owned(),
+        )
+    }
+
+    #[tokio::test]
+    async fn audit_records_execution_and_node_without_secret() {
+        let sink = Arc::new(RecordingSink::default());
+        let accessor = audited(
+            EngineCredentialAccessor::new(
+                HashSet::from(["api_key".to_owned()]),
+                |_id: &str| async {
+                    Ok::<Box<dyn std::any::Any + Send + Sync>, CoreError>(Box::new(
+                        "s3cr3t-value".to_owned(),
+                    ))
+                },
+                "test_action".to_owned(),
+            ),
+            &sink,
+        );
+
+        accessor
+            .resolve_any(&credential_key!("api_key"))
+            .await
+            .expect("declared key resolves");
+
+        let events = sink.0.lock().unwrap();
+        assert_eq!(events.len(), 1);
+        let event = &events[0];
+        assert_eq!(event.credential_id, "api_key");
+        assert_eq!(
+            event.operation,
+            AuditOperation::ExecutionAccess {
+                execution_id: "exe_1".to_owned(),
+                node_id: "fetch".to_owned(),
+            }
+        );
+        assert_eq!(event.result, AuditResult::Success);
+        assert!(!format!("{event:?}").contains("s3cr3t-value"));
+    }
+
+    #[tokio::test]
+    async fn audit_records_denied_and_failed_access() {
+        let sink = Arc::new(RecordingSink::default());
+        let accessor = audited(
+            make_failing_accessor(
+                ["my_key"],
+                CoreError::CredentialNotConfigured("vault at 10.0.0.7 unreachable".to_owned()),
+            ),
+            &sink,
+        );
+
+        let _ = accessor.resolve_any(&credential_key!("other_key")).await;
+        let _ = accessor.try_resolve_any(&credential_key!("my_key")).await;
+
+        let events = sink.0.lock().unwrap();
+        let results: Vec<_> = events
+            .iter()
+            .map(|e| (e.credential_id.as_str(), e.result.clone()))
+            .collect();
+        assert_eq!(
+            results,
+            [
+                (
+                    "other_key",
+                    AuditResult::Error("CORE:CREDENTIAL_ACCESS_DENIED".to_owned())
+                ),
+                (
+                    "my_key",
+                    AuditResult::Error("CORE:CREDENTIAL_NOT_CONFIGURED".to_owned())
+                ),
+            ]
+        );
+    }
 }
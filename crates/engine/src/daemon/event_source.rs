@@ -327,7 +327,7 @@ fn classify_resource_error(res_err: nebula_resource::Error) -> ActionError {
         },
         ResourceErrorKind::Cancelled => {
             tracing::info!(error = %res_err, "event_source: subscribe cancelled");
-            ActionError::Cancelled
+            ActionError::cancelled(None)
         },
         // Permanent caller/wiring faults. `Ambiguous` is a client conflict
         // (multi-tenant `(key, scope)` with no resolved slot identity) and
@@ -51,6 +51,8 @@ impl WorkflowEngine {
         initial_resolved: HashMap<NodeKey, usize>,
     ) -> Option<(NodeKey, String)> {
         let total_output_bytes = Arc::new(AtomicU64::new(0));
+        // One budget per execution, shared by every node spawned below.
+        let rate_limits = self.new_execution_rate_limits(cancel_token);
         // Precompute how many incoming edges each node has
         let required_count: HashMap<NodeKey, usize> = node_map
             .keys()
@@ -500,6 +502,7 @@ impl WorkflowEngine {
                     workflow_id,
                     input,
                     &activated_edges,
+                    rate_limits.as_ref(),
                     &mut join_set,
                     &mut task_nodes,
                 );
@@ -1681,9 +1684,9 @@ impl WorkflowEngine {
                         && matches!(
                             err,
                             EngineError::Cancelled
-                                | EngineError::Action(ActionError::Cancelled)
+                                | EngineError::Action(ActionError::Cancelled { .. })
                                 | EngineError::Runtime(crate::runtime::RuntimeError::ActionError(
-                                    ActionError::Cancelled,
+                                    ActionError::Cancelled { .. },
                                 ),)
                         )
                     {
@@ -1989,6 +1992,7 @@ impl WorkflowEngine {
         workflow_id: WorkflowId,
         input: &serde_json::Value,
         activated_edges: &HashMap<NodeKey, HashSet<NodeKey>>,
+        rate_limits: Option<&Arc<dyn RateLimitAccessor>>,
         join_set: &mut JoinSet<(
             NodeKey,
             Result<ActionResult<serde_json::Value>, EngineError>,
@@ -2073,7 +2077,7 @@ impl WorkflowEngine {
             &self.credential_resolver
         {
             let resolver_fn = Arc::clone(resolver_fn);
-            Arc::new(EngineCredentialAccessor::new(
+            let accessor = EngineCredentialAccessor::new(
                 allowed_keys,
                 move |id: &str| {
                     let resolver_fn = Arc::clone(&resolver_fn);
@@ -2097,7 +2101,15 @@ impl WorkflowEngine {
                     }
                 },
                 node_def.action_key.as_str().to_owned(),
-            ))
+            );
+            match &self.credential_audit_sink {
+                Some(sink) => Arc::new(accessor.with_audit(
+                    Arc::clone(sink),
+                    execution_id.to_string(),
+                    node_key.to_string(),
+                )),
+                None => Arc::new(accessor),
+            }
         } else {
             default_credential_accessor()
         };
@@ -2172,6 +2184,8 @@ impl WorkflowEngine {
                 resources,
                 credential_refresh,
                 rate_limiter,
+                rate_limits: rate_limits.cloned(),
+                extensions: Arc::clone(&self.action_extensions),
             }
             .run(),
         );
@@ -20,11 +20,12 @@ use std::{
 use chrono::{DateTime, Utc};
 use dashmap::DashMap;
 use nebula_action::{
-    ActionError, ActionResult, capability::default_resource_accessor, result::WaitCondition,
+    ActionError, ActionResult, Extensions, capability::default_resource_accessor,
+    result::WaitCondition,
 };
 use nebula_core::{
     ActionKey, CredentialKey, NodeKey, PortKey, ResourceKey,
-    accessor::{Clock, CredentialAccessor, ResourceAccessor, SystemClock},
+    accessor::{Clock, CredentialAccessor, RateLimitAccessor, ResourceAccessor, SystemClock},
     id::{ExecutionId, InstanceId, WorkflowId},
     node_key,
 };
@@ -70,6 +71,7 @@ use crate::{
     credential_accessor::EngineCredentialAccessor,
     error::EngineError,
     event::{ExecutionEvent, NodeFailedDetails},
+    rate_limit_accessor::ExecutionRateLimits,
     resolver::ParamResolver,
     resource::ResourceActivatorRegistry,
     resource_accessor::EngineResourceAccessor,
@@ -308,6 +310,16 @@ pub struct WorkflowEngine {
     /// See product canon (operational honesty — no false capabilities; secrets and auth).
     /// Populated via [`WorkflowEngine::with_action_credentials`].
     action_credentials: HashMap<ActionKey, HashSet<String>>,
+    /// Optional sink receiving one audit event per credential access made
+    /// by a node. Populated via [`WorkflowEngine::with_credential_audit_sink`].
+    credential_audit_sink: Option<Arc<dyn nebula_credential::AuditSink>>,
+    /// Rate limit quotas `(key, max_requests, per)` instantiated once per
+    /// execution and shared by all of its nodes.
+    /// Populated via [`WorkflowEngine::with_execution_rate_limit`].
+    execution_rate_limits: Vec<(String, u32, Duration)>,
+    /// Typed host services injected into every action context.
+    /// Populated via [`WorkflowEngine::with_action_extension`].
+    action_extensions: Arc<Extensions>,
     /// Optional event sender for real-time execution monitoring (TUI, logging).
     event_bus: Option<EventBus>,
     /// Injectable clock for deterministic durable-timing paths (retry
@@ -552,6 +564,9 @@ impl WorkflowEngine {
             credential_resolver: None,
             credential_refresh: None,
             action_credentials: HashMap::new(),
+            credential_audit_sink: None,
+            execution_rate_limits: Vec::new(),
+            action_extensions: Arc::new(Extensions::new()),
             event_bus: None,
             clock: Arc::new(SystemClock),
             instance_id,
@@ -1362,6 +1377,87 @@ impl WorkflowEngine {
         self
     }
 
+    /// Record every credential access made by a node to `sink`.
+    ///
+    /// Each `resolve` through the node's credential accessor — allowed or
+    /// denied — emits an
+    /// [`AuditOperation::ExecutionAccess`](nebula_credential::AuditOperation::ExecutionAccess)
+    /// event carrying the execution id, the node key, and the credential id.
+    /// Secret material is never recorded. A failing sink is logged and does
+    /// not fail the node.
+    #[must_use = "builder methods must be chained or built"]
+    pub fn with_credential_audit_sink(
+        mut self,
+        sink: Arc<dyn nebula_credential::AuditSink>,
+    ) -> Self {
+        self.credential_audit_sink = Some(sink);
+        self
+    }
+
+    /// Limit acquisitions of rate-limit `key` to `max_requests` per `per`,
+    /// shared by every node of an execution.
+    ///
+    /// Each execution gets its own budget; within it, actions draw permits
+    /// through [`HasRateLimits::rate_limits`](nebula_core::HasRateLimits::rate_limits),
+    /// so several nodes calling the same downstream cannot collectively
+    /// overrun it. Keys without a limit are unlimited.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the quota cannot be enforced (see
+    /// [`ExecutionRateLimits::check_limit`]), e.g. zero `max_requests` or
+    /// one request per hour. A bad quota is a configuration bug and must not
+    /// turn into no limit at all.
+    #[must_use = "builder methods must be chained or built"]
+    pub fn with_execution_rate_limit(
+        mut self,
+        key: impl Into<String>,
+        max_requests: u32,
+        per: Duration,
+    ) -> Self {
+        let key = key.into();
+        if let Err(e) = ExecutionRateLimits::check_limit(max_requests, per) {
+            panic!("invalid execution rate limit for `{key}`: {e}");
+        }
+        self.execution_rate_limits.push((key, max_requests, per));
+        self
+    }
+
+    /// Provide a typed host service (HTTP client, clock, feature flags, ...)
+    /// to every action this engine dispatches.
+    ///
+    /// Actions read it with
+    /// [`ExtensionContextExt::get_extension`](nebula_action::ExtensionContextExt::get_extension).
+    /// One value is kept per type; a later call with the same type replaces it.
+    #[must_use = "builder methods must be chained or built"]
+    pub fn with_action_extension<T: Send + Sync + 'static>(mut self, value: T) -> Self {
+        Arc::make_mut(&mut self.action_extensions).insert(value);
+        self
+    }
+
+    /// Fresh per-execution rate limits, or `None` when no limit is configured.
+    ///
+    /// Permit waits end when `cancel` (the execution's token) fires.
+    fn new_execution_rate_limits(
+        &self,
+        cancel: &CancellationToken,
+    ) -> Option<Arc<dyn RateLimitAccessor>> {
+        if self.execution_rate_limits.is_empty() {
+            return None;
+        }
+        let limits = self
+            .execution_rate_limits
+            .iter()
+            .try_fold(
+                ExecutionRateLimits::new().with_cancellation(cancel.clone()),
+                |limits, (key, max_requests, per)| {
+                    limits.with_limit(key.clone(), *max_requests, *per)
+                },
+            )
+            .expect("quotas are checked by with_execution_rate_limit");
+        Some(Arc::new(limits))
+    }
+
     /// Set the spec-16 storage-port bundle for persistent execution state.
     ///
     /// When set, the engine persists execution state after creation and
@@ -2451,6 +2547,10 @@ struct NodeTask {
     credential_refresh: Option<CredentialRefreshFn>,
     /// Optional rate limiter shared with other nodes using the same ActionKey.
     rate_limiter: Option<Arc<nebula_resilience::rate_limiter::TokenBucket>>,
+    /// Execution-wide rate limits injected into the action context.
+    rate_limits: Option<Arc<dyn RateLimitAccessor>>,
+    /// Typed host services injected into the action context.
+    extensions: Arc<Extensions>,
 }
 
 impl NodeTask {
@@ -2522,14 +2622,18 @@ impl NodeTask {
                 );
             },
         };
-        let action_ctx = nebula_action::ActionRuntimeContext::new(
+        let mut action_ctx = nebula_action::ActionRuntimeContext::new(
             base,
             self.execution_id,
             self.node_key.clone(),
             self.workflow_id,
         )
         .with_credentials(self.credentials.clone())
-        .with_resources(self.resources.clone());
+        .with_resources(self.resources.clone())
+        .with_extensions(Arc::clone(&self.extensions));
+        if let Some(rate_limits) = self.rate_limits.clone() {
+            action_ctx = action_ctx.with_rate_limits(rate_limits);
+        }
 
         // Acquire rate limit permit if configured. If the limiter rejects the
         // request, fail the node so ErrorStrategy decides abort/continue.
@@ -105,11 +105,84 @@ impl StatelessAction for SlowHandler {
     ) -> Result<ActionResult<<Self as Action>::Output>, ActionError> {
         tokio::select! {
             () = tokio::time::sleep(self.delay) => Ok(ActionResult::success(input)),
-            () = ctx.cancellation().cancelled() => Err(ActionError::Cancelled),
+            () = ctx.cancellation().cancelled() => Err(ActionError::cancelled(None)),
         }
     }
 }
 
+/// Acquires the `api` rate limit `permits` times, then echoes its input.
+struct RateLimitedHandler {
+    permits: u32,
+}
+
+impl Action for RateLimitedHandler {
+    type Input = serde_json::Value;
+    type Output = serde_json::Value;
+
+    fn metadata() -> ActionMetadata {
+        ActionMetadata::new(
+            action_key!("test.rate_limited.static"),
+            "RateLimited",
+            "acquires rate limit permits",
+        )
+    }
+    fn dependencies() -> &'static Dependencies {
+        static D: OnceLock<Dependencies> = OnceLock::new();
+        D.get_or_init(Dependencies::new)
+    }
+}
+
+impl StatelessAction for RateLimitedHandler {
+    async fn execute(
+        &self,
+        input: <Self as Action>::Input,
+        ctx: &(impl nebula_action::ActionContext + ?Sized),
+    ) -> Result<ActionResult<<Self as Action>::Output>, ActionError> {
+        use nebula_action::RateLimitContextExt;
+
+        for _ in 0..self.permits {
+            ctx.acquire_rate_limit("api").await?;
+        }
+        Ok(ActionResult::success(input))
+    }
+}
+
+/// Host-provided service read by [`ExtensionHandler`].
+struct Greeting(&'static str);
+
+/// Outputs the [`Greeting`] extension the host provided.
+struct ExtensionHandler;
+
+impl Action for ExtensionHandler {
+    type Input = serde_json::Value;
+    type Output = serde_json::Value;
+
+    fn metadata() -> ActionMetadata {
+        ActionMetadata::new(
+            action_key!("test.extension.static"),
+            "Extension",
+            "reads a host extension",
+        )
+    }
+    fn dependencies() -> &'static Dependencies {
+        static D: OnceLock<Dependencies> = OnceLock::new();
+        D.get_or_init(Dependencies::new)
+    }
+}
+
+impl StatelessAction for ExtensionHandler {
+    async fn execute(
+        &self,
+        _input: <Self as Action>::Input,
+        ctx: &(impl nebula_action::ActionContext + ?Sized),
+    ) -> Result<ActionResult<<Self as Action>::Output>, ActionError> {
+        use nebula_action::ExtensionContextExt;
+
+        let greeting = ctx.get_extension::<Greeting>()?;
+        Ok(ActionResult::success(serde_json::json!(greeting.0)))
+    }
+}
+
 // -- Helpers --
 
 fn make_workflow(nodes: Vec<NodeDefinition>, connections: Vec<Connection>) -> WorkflowDefinition {
@@ -508,6 +581,81 @@ async fn diamond_workflow() {
     assert!(d_output.is_object());
 }
 
+#[tokio::test]
+async fn nodes_share_execution_rate_limit() {
+    let registry = Arc::new(ActionRegistry::new());
+    registry.register_stateless_instance(
+        ActionMetadata::new(action_key!("limited"), "Limited", "rate limited"),
+        RateLimitedHandler { permits: 6 },
+    );
+
+    let (engine, _) = make_engine(registry);
+    let engine = engine.with_execution_rate_limit("api", 4, Duration::from_millis(100));
+
+    let wf = make_workflow(
+        vec![
+            NodeDefinition::new(node_key!("a"), "A", "core", "limited").unwrap(),
+            NodeDefinition::new(node_key!("b"), "B", "core", "limited").unwrap(),
+        ],
+        vec![],
+    );
+
+    let started = Instant::now();
+    let result = engine
+        .execute_workflow(
+            &crate::store_seam::single_tenant_scope(),
+            &wf,
+            serde_json::json!("start"),
+            ExecutionBudget::default(),
+        )
+        .await
+        .unwrap();
+
+    assert!(result.is_success());
+    // 12 permits against a 4-permit burst refilling at 40/s need ~200ms.
+    // Separate budgets would let each node finish after ~50ms.
+    let elapsed = started.elapsed();
+    assert!(elapsed >= Duration::from_millis(150), "elapsed {elapsed:?}");
+}
+
+#[test]
+#[should_panic(expected = "invalid execution rate limit for `api`")]
+fn unenforceable_execution_rate_limit_panics() {
+    let (engine, _) = make_engine(Arc::new(ActionRegistry::new()));
+    let _ = engine.with_execution_rate_limit("api", 1, Duration::from_hours(1));
+}
+
+#[tokio::test]
+async fn engine_injects_action_extensions() {
+    let registry = Arc::new(ActionRegistry::new());
+    registry.register_stateless_instance(
+        ActionMetadata::new(action_key!("greet"), "Greet", "reads an extension"),
+        ExtensionHandler,
+    );
+
+    let (engine, _) = make_engine(registry);
+    let engine = engine.with_action_extension(Greeting("hello"));
+
+    let n = node_key!("n");
+    let wf = make_workflow(
+        vec![NodeDefinition::new(n.clone(), "N", "core", "greet").unwrap()],
+        vec![],
+    );
+
+    let result = engine
+        .execute_workflow(
+            &crate::store_seam::single_tenant_scope(),
+            &wf,
+            serde_json::json!(null),
+            ExecutionBudget::default(),
+        )
+        .await
+        .unwrap();
+
+    assert!(result.is_success());
+    assert_eq!(result.node_output(&n), Some(&serde_json::json!("hello")));
+}
+
 #[tokio::test]
 async fn failing_node_stops_execution() {
     let registry = Arc::new(ActionRegistry::new());
@@ -3511,6 +3659,66 @@ async fn credential_access_allowed_with_declaration() {
     );
 }
 
+/// Audit: a credential access made by a node is recorded with the execution
+/// and node that made it, and the recorded event carries no secret material.
+#[tokio::test]
+async fn credential_access_is_audited_with_execution_context() {
+    #[derive(Default)]
+    struct RecordingSink(std::sync::Mutex<Vec<nebula_credential::AuditEvent>>);
+
+    impl nebula_credential::AuditSink for RecordingSink {
+        fn record(
+            &self,
+            event: &nebula_credential::AuditEvent,
+        ) -> Result<(), nebula_storage_port::CredentialPersistenceError> {
+            self.0.lock().unwrap().push(event.clone());
+            Ok(())
+        }
+    }
+
+    let registry = Arc::new(ActionRegistry::new());
+    register_probe(&registry, action_key!("probe"), "Probe");
+
+    let sink = Arc::new(RecordingSink::default());
+    let (engine, _) = make_engine(registry);
+    let engine = engine
+        .with_credential_resolver(|id: &str| {
+            let id = id.to_owned();
+            async move { Ok(dummy_snapshot(&id)) }
+        })
+        .with_action_credentials(action_key!("probe"), ["api_key"])
+        .with_credential_audit_sink(sink.clone());
+
+    let wf = probe_workflow("probe", "api_key");
+    let result = engine
+        .execute_workflow(
+            &crate::store_seam::single_tenant_scope(),
+            &wf,
+            serde_json::json!(null),
+            ExecutionBudget::default(),
+        )
+        .await
+        .expect("engine returns Ok(ExecutionResult)");
+    assert!(result.is_success(), "errors: {:?}", result.node_errors);
+
+    let events = sink.0.lock().unwrap();
+    assert_eq!(events.len(), 1, "one access, one audit event: {events:?}");
+    let event = &events[0];
+    assert_eq!(event.credential_id, "api_key");
+    assert_eq!(
+        event.operation,
+        nebula_credential::AuditOperation::ExecutionAccess {
+            execution_id: result.execution_id.to_string(),
+            node_id: "probe".to_owned(),
+        }
+    );
+    assert_eq!(event.result, nebula_credential::AuditResult::Success);
+    assert!(
+        !format!("{event:?}").contains("test-value"),
+        "audit event must not carry the secret: {event:?}"
+    );
+}
+
 /// Mismatched: an action that declares credential `A` still cannot acquire
 /// credential `B`. Per-key enforcement, not per-action blanket allow.
 #[tokio::test]
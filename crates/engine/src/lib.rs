@@ -36,6 +36,8 @@
 //! - `ExecutionEvent` — broadcast event type for `nebula-eventbus`.
 //! - `EngineCredentialAccessor` / `EngineResourceAccessor` — scoped accessors injected into action
 //!   contexts.
+//! - `ExecutionRateLimits` — per-execution rate limits shared by every node through
+//!   `HasRateLimits`; configured with `WorkflowEngine::with_execution_rate_limit`.
 //! - `LayeredResourceAccessor` / `ScopedResourceMap` — Phase 6 (M6.1) precedence wiring. `scoped →
 //!   global` lookup; closest-ancestor wins.
 //! - `DashScopedResourceMap` / `BranchId` / `ScopedResourceGuard` — Phase 7 (M6.2) per-branch
@@ -71,6 +73,7 @@ pub mod error;
 pub mod event;
 pub mod node_output;
 pub(crate) mod plugin_wiring;
+pub mod rate_limit_accessor;
 pub(crate) mod resolver;
 pub mod resource;
 pub mod resource_accessor;
@@ -104,6 +107,7 @@ pub use error::EngineError;
 pub use event::{ExecutionEvent, NodeFailedDetails};
 pub use nebula_storage_port::dto::ResumeTarget;
 pub use plugin_wiring::PluginWiringError;
+pub use rate_limit_accessor::ExecutionRateLimits;
 // Re-export plugin types for convenience.
 pub use nebula_plugin::{Plugin, PluginKey, PluginManifest, PluginRegistry, ResolvedPlugin};
 pub use node_output::NodeOutput;
@@ -118,9 +122,11 @@ pub use resource_status::{
 pub use result::ExecutionResult;
 pub use runtime::{
     ActionExecutor, ActionRegistry, ActionRunContext, ActionRunner, ActionRuntime, BlobRef,
-    BlobStorage, BoundedStreamBuffer, DataPassingPolicy, InProcessRunner, LargeDataStrategy,
-    MemoryQueue, PushOutcome, QueueError, RuntimeError, StatefulCheckpoint, StatefulCheckpointSink,
-    TaskQueue,
+    BlobStorage, BoundedStreamBuffer, CallerProvidedKey, ContentHashKey, DataPassingPolicy,
+    DeadLetterEntry, DeadLetterQueue, IdempotencyClaim, IdempotencyGuard, IdempotencyKeyStrategy,
+    IdempotencyManager, InProcessRunner, LargeDataStrategy, MemoryDeadLetterQueue, MemoryQueue,
+    PushOutcome, QueueError, QueueMetrics, QueuePressure, RuntimeError, StatefulCheckpoint,
+    StatefulCheckpointSink, TaskPriority, TaskQueue,
 };
 pub use scoped_resources::{
     BranchId, CleanupOutcome, DEFAULT_CLEANUP_TIMEOUT, DashScopedResourceMap,
@@ -0,0 +1,224 @@
+//! Engine-side [`RateLimitAccessor`] implementation.
+//!
+//! [`ExecutionRateLimits`] holds one token bucket per key. The engine builds
+//! a fresh set per execution from the quotas registered through
+//! [`WorkflowEngine::with_execution_rate_limit`](crate::WorkflowEngine::with_execution_rate_limit)
+//! and hands the same instance to every node, so nodes calling the same
+//! downstream share one budget. Waiting for a permit stops as soon as the
+//! execution is cancelled.
+
+use std::{collections::HashMap, fmt, time::Duration};
+
+use nebula_core::{
+    CoreError,
+    accessor::{BoxFuture, RateLimitAccessor},
+};
+use nebula_resilience::{
+    ConfigError,
+    rate_limiter::{RateLimiter, TokenBucket},
+};
+use tokio_util::sync::CancellationToken;
+
+/// Sleep used when the limiter rejects without a retry-after hint.
+const FALLBACK_RETRY_DELAY: Duration = Duration::from_millis(10);
+
+/// Per-key rate limits shared by every node of one execution.
+#[derive(Default)]
+pub struct ExecutionRateLimits {
+    limiters: HashMap<String, TokenBucket>,
+    cancel: CancellationToken,
+}
+
+impl ExecutionRateLimits {
+    /// Create a set without limits — every key is unlimited.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allow at most `max_requests` acquisitions of `key` per `per`.
+    ///
+    /// The budget refills continuously and starts full, so up to
+    /// `max_requests` acquisitions succeed immediately.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError`] if the quota cannot be enforced; see
+    /// [`check_limit`](Self::check_limit). The key is never left unlimited.
+    pub fn with_limit(
+        mut self,
+        key: impl Into<String>,
+        max_requests: u32,
+        per: Duration,
+    ) -> Result<Self, ConfigError> {
+        let bucket = token_bucket(max_requests, per)?;
+        self.limiters.insert(key.into(), bucket);
+        Ok(self)
+    }
+
+    /// Check that `max_requests` per `per` can be enforced.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError`] if `max_requests` or `per` is zero, or if the
+    /// refill rate `max_requests / per` falls outside the 0.001..=10,000
+    /// permits per second a [`TokenBucket`] supports (one per hour is too
+    /// slow, for example).
+    pub fn check_limit(max_requests: u32, per: Duration) -> Result<(), ConfigError> {
+        token_bucket(max_requests, per).map(drop)
+    }
+
+    /// Stop waiting for permits once `cancel` fires.
+    ///
+    /// A cancelled `acquire` returns [`CoreError::RateLimitCancelled`]
+    /// without a permit.
+    #[must_use]
+    pub fn with_cancellation(mut self, cancel: CancellationToken) -> Self {
+        self.cancel = cancel;
+        self
+    }
+
+    /// Whether no key is limited.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.limiters.is_empty()
+    }
+}
+
+/// Token bucket starting full with `max_requests` and refilling them over `per`.
+fn token_bucket(max_requests: u32, per: Duration) -> Result<TokenBucket, ConfigError> {
+    if max_requests == 0 {
+        return Err(ConfigError::new("max_requests", "must be >= 1"));
+    }
+    if per.is_zero() {
+        return Err(ConfigError::new("per", "must be > 0"));
+    }
+    let refill_rate = f64::from(max_requests) / per.as_secs_f64();
+    TokenBucket::new(max_requests as usize, refill_rate)
+}
+
+impl RateLimitAccessor for ExecutionRateLimits {
+    fn acquire<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Result<(), CoreError>> {
+        Box::pin(async move {
+            let Some(limiter) = self.limiters.get(key) else {
+                return Ok(());
+            };
+            while let Err(e) = limiter.acquire().await {
+                let delay = e.retry_after().unwrap_or(FALLBACK_RETRY_DELAY);
+                tokio::select! {
+                    () = tokio::time::sleep(delay) => {},
+                    () = self.cancel.cancelled() => return Err(CoreError::rate_limit_cancelled(key)),
+                }
+            }
+            Ok(())
+        })
+    }
+}
+
+impl fmt::Debug for ExecutionRateLimits {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExecutionRateLimits")
+            .field("keys", &self.limiters.keys().collect::<Vec<_>>())
+            .field("cancelled", &self.cancel.is_cancelled())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::Arc, time::Instant};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn unlimited_key_returns_immediately() {
+        let limits = ExecutionRateLimits::new()
+            .with_limit("api", 1, Duration::from_mins(1))
+            .unwrap();
+        let started = Instant::now();
+        for _ in 0..100 {
+            limits.acquire("other").await.unwrap();
+        }
+        assert!(started.elapsed() < Duration::from_millis(50));
+    }
+
+    #[tokio::test]
+    async fn concurrent_callers_share_one_budget() {
+        let limits = Arc::new(
+            ExecutionRateLimits::new()
+                .with_limit("api", 4, Duration::from_millis(100))
+                .unwrap(),
+        );
+        let started = Instant::now();
+        let callers: Vec<_> = (0..2)
+            .map(|_| {
+                let limits = Arc::clone(&limits);
+                tokio::spawn(async move {
+                    for _ in 0..6 {
+                        limits.acquire("api").await.unwrap();
+                    }
+                })
+            })
+            .collect();
+        for caller in callers {
+            caller.await.unwrap();
+        }
+
+        // 12 permits: 4 from the initial burst, 8 refilled at 40/s = 200ms.
+        // Each caller alone would need only 2 refills (50ms).
+        assert!(
+            started.elapsed() >= Duration::from_millis(150),
+            "elapsed {:?}",
+            started.elapsed()
+        );
+    }
+
+    #[test]
+    fn unenforceable_limits_are_rejected() {
+        for (max_requests, per) in [
+            (0, Duration::from_secs(1)),
+            (1, Duration::ZERO),
+            (1, Duration::from_hours(1)),
+            (u32::MAX, Duration::from_secs(1)),
+        ] {
+            assert!(
+                ExecutionRateLimits::new()
+                    .with_limit("api", max_requests, per)
+                    .is_err(),
+                "{max_requests} per {per:?} should be rejected"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn cancellation_ends_a_pending_acquire() {
+        let cancel = CancellationToken::new();
+        let limits = Arc::new(
+            ExecutionRateLimits::new()
+                .with_limit("api", 1, Duration::from_mins(1))
+                .unwrap()
+                .with_cancellation(cancel.clone()),
+        );
+        limits.acquire("api").await.unwrap();
+
+        let waiter = {
+            let limits = Arc::clone(&limits);
+            tokio::spawn(async move { limits.acquire("api").await })
+        };
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert!(
+            !waiter.is_finished(),
+            "second acquire should wait for a refill"
+        );
+
+        cancel.cancel();
+        let result = tokio::time::timeout(Duration::from_secs(1), waiter)
+            .await
+            .expect("cancelled acquire should return promptly")
+            .unwrap();
+        assert!(
+            matches!(result, Err(CoreError::RateLimitCancelled { ref key }) if key == "api"),
+            "cancelled wait must not look like a granted permit: {result:?}"
+        );
+    }
+}
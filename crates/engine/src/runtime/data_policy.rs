@@ -15,6 +15,10 @@ pub struct DataPassingPolicy {
     pub max_total_execution_bytes: u64,
     /// What to do when data exceeds limits.
     pub large_data_strategy: LargeDataStrategy,
+    /// Validate each action output against the action's declared output
+    /// schema (default: `false`). Costs one schema walk per output value.
+    #[serde(default)]
+    pub validate_output_schema: bool,
 }
 
 impl Default for DataPassingPolicy {
@@ -23,6 +27,7 @@ impl Default for DataPassingPolicy {
             max_node_output_bytes: 10 * 1024 * 1024,      // 10 MB
             max_total_execution_bytes: 100 * 1024 * 1024, // 100 MB
             large_data_strategy: LargeDataStrategy::Reject,
+            validate_output_schema: false,
         }
     }
 }
@@ -61,6 +66,7 @@ mod tests {
         assert_eq!(policy.max_node_output_bytes, 10 * 1024 * 1024);
         assert_eq!(policy.max_total_execution_bytes, 100 * 1024 * 1024);
         assert_eq!(policy.large_data_strategy, LargeDataStrategy::Reject);
+        assert!(!policy.validate_output_schema);
     }
 
     #[test]
@@ -0,0 +1,219 @@
+//! Dead-letter queue for tasks that failed permanently.
+//!
+//! Fatally-failed work, and tasks that a [`MemoryQueue`](super::MemoryQueue)
+//! gave up redelivering, is parked here instead of being dropped, so operators
+//! can inspect it, re-drive it into the main [`TaskQueue`] once the cause is
+//! fixed, or purge it.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+
+use super::queue::{QueueError, TaskQueue};
+
+/// A task parked in a [`DeadLetterQueue`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct DeadLetterEntry {
+    /// Dead-letter ID, distinct from the ID the task gets when replayed.
+    pub id: String,
+    /// Payload re-enqueued by [`DeadLetterQueue::replay`].
+    pub payload: serde_json::Value,
+    /// Human-readable failure reason (usually the final error message).
+    pub reason: String,
+    /// Deliveries made before the task was dead-lettered; 1 for a task that
+    /// failed fatally on its first run.
+    #[serde(default = "first_attempt")]
+    pub attempts: u32,
+    /// When the last delivery failed and the task was dead-lettered.
+    pub failed_at: DateTime<Utc>,
+}
+
+const fn first_attempt() -> u32 {
+    1
+}
+
+impl DeadLetterEntry {
+    /// Build an entry with a fresh ID, stamped with the current time.
+    #[must_use]
+    pub fn new(payload: serde_json::Value, reason: impl Into<String>) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            payload,
+            reason: reason.into(),
+            attempts: first_attempt(),
+            failed_at: Utc::now(),
+        }
+    }
+
+    /// Record how many deliveries failed before the task was dead-lettered.
+    #[must_use]
+    pub fn with_attempts(mut self, attempts: u32) -> Self {
+        self.attempts = attempts;
+        self
+    }
+}
+
+/// Storage for permanently failed tasks with operator re-drive.
+///
+/// Object-safe so [`ActionRuntime`](super::ActionRuntime) can hold it as
+/// `Arc<dyn DeadLetterQueue>`.
+///
+/// # Errors
+///
+/// Methods taking an `id` return [`QueueError::NotFound`] for unknown entries;
+/// `replay` also surfaces the main queue's enqueue error.
+#[async_trait]
+pub trait DeadLetterQueue: Send + Sync {
+    /// Park a failed task. Returns the entry ID.
+    async fn push(&self, entry: DeadLetterEntry) -> Result<String, QueueError>;
+
+    /// All parked entries, oldest first.
+    async fn list(&self) -> Result<Vec<DeadLetterEntry>, QueueError>;
+
+    /// Re-enqueue an entry's payload into the main queue and remove it.
+    ///
+    /// Returns the task ID assigned by the main queue. The entry is kept if
+    /// the enqueue fails, so nothing is lost.
+    async fn replay(&self, id: &str) -> Result<String, QueueError>;
+
+    /// Drop an entry without replaying it.
+    async fn purge(&self, id: &str) -> Result<(), QueueError>;
+}
+
+/// In-memory [`DeadLetterQueue`] that replays into a [`TaskQueue`].
+///
+/// Not durable: entries are lost on restart, like [`MemoryQueue`](super::MemoryQueue).
+pub struct MemoryDeadLetterQueue<Q> {
+    main: Arc<Q>,
+    entries: Mutex<Vec<DeadLetterEntry>>,
+}
+
+impl<Q: TaskQueue> MemoryDeadLetterQueue<Q> {
+    /// Create an empty dead-letter queue that replays into `main`.
+    #[must_use]
+    pub fn new(main: Arc<Q>) -> Self {
+        Self {
+            main,
+            entries: Mutex::new(Vec::new()),
+        }
+    }
+
+    fn take(&self, id: &str) -> Result<DeadLetterEntry, QueueError> {
+        let mut entries = self.entries.lock();
+        let index = entries
+            .iter()
+            .position(|entry| entry.id == id)
+            .ok_or_else(|| QueueError::not_found("DeadLetter", id))?;
+        Ok(entries.remove(index))
+    }
+}
+
+#[async_trait]
+impl<Q: TaskQueue + 'static> DeadLetterQueue for MemoryDeadLetterQueue<Q> {
+    async fn push(&self, entry: DeadLetterEntry) -> Result<String, QueueError> {
+        let id = entry.id.clone();
+        self.entries.lock().push(entry);
+        Ok(id)
+    }
+
+    async fn list(&self) -> Result<Vec<DeadLetterEntry>, QueueError> {
+        Ok(self.entries.lock().clone())
+    }
+
+    async fn replay(&self, id: &str) -> Result<String, QueueError> {
+        // Removing before the enqueue keeps two concurrent replays of the same
+        // entry from both re-driving it.
+        let entry = self.take(id)?;
+        match self.main.enqueue(entry.payload.clone()).await {
+            Ok(task_id) => Ok(task_id),
+            Err(e) => {
+                // Put it back where it was so `list` stays oldest-first.
+                let mut entries = self.entries.lock();
+                let index = entries.partition_point(|other| other.failed_at <= entry.failed_at);
+                entries.insert(index, entry);
+                Err(e)
+            },
+        }
+    }
+
+    async fn purge(&self, id: &str) -> Result<(), QueueError> {
+        self.take(id).map(drop)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::runtime::queue::{DequeueResult, MemoryQueue};
+
+    #[tokio::test]
+    async fn dead_lettered_task_can_be_listed_and_replayed() {
+        let main = Arc::new(MemoryQueue::new(4));
+        let dlq = MemoryDeadLetterQueue::new(Arc::clone(&main));
+
+        let id = dlq
+            .push(DeadLetterEntry::new(
+                serde_json::json!({"task": "x"}),
+                "boom",
+            ))
+            .await
+            .unwrap();
+        let listed = dlq.list().await.unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].id, id);
+        assert_eq!(listed[0].reason, "boom");
+
+        let task_id = dlq.replay(&id).await.unwrap();
+        assert!(dlq.list().await.unwrap().is_empty());
+        assert_eq!(
+            main.dequeue(Duration::from_millis(10)).await.unwrap(),
+            DequeueResult::Item {
+                task_id,
+                payload: serde_json::json!({"task": "x"}),
+                attempt: 1,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn purge_removes_entry_without_replay() {
+        let main = Arc::new(MemoryQueue::new(4));
+        let dlq = MemoryDeadLetterQueue::new(Arc::clone(&main));
+        let id = dlq
+            .push(DeadLetterEntry::new(serde_json::json!(1), "boom"))
+            .await
+            .unwrap();
+
+        dlq.purge(&id).await.unwrap();
+        assert!(dlq.list().await.unwrap().is_empty());
+        assert!(main.is_empty().await.unwrap());
+        assert!(matches!(
+            dlq.purge(&id).await,
+            Err(QueueError::NotFound { .. })
+        ));
+        assert!(matches!(
+            dlq.replay(&id).await,
+            Err(QueueError::NotFound { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn failed_replay_keeps_entry() {
+        let main = Arc::new(MemoryQueue::new(1));
+        main.drain(false).await;
+        let dlq = MemoryDeadLetterQueue::new(Arc::clone(&main));
+        let id = dlq
+            .push(DeadLetterEntry::new(serde_json::json!(1), "boom"))
+            .await
+            .unwrap();
+
+        assert!(matches!(dlq.replay(&id).await, Err(QueueError::Closed)));
+        assert_eq!(dlq.list().await.unwrap()[0].id, id);
+    }
+}
@@ -51,6 +51,24 @@ pub enum RuntimeError {
         actual_bytes: u64,
     },
 
+    /// An action output does not match the action's declared output schema.
+    ///
+    /// Only raised when
+    /// [`DataPassingPolicy::validate_output_schema`](super::DataPassingPolicy::validate_output_schema)
+    /// is enabled.
+    #[classify(
+        category = "validation",
+        code = "RUNTIME:OUTPUT_SCHEMA_VIOLATION",
+        retryable = false
+    )]
+    #[error("output of action '{key}' violates its declared output schema:\n{report}")]
+    OutputSchemaViolation {
+        /// The action key whose output was rejected.
+        key: String,
+        /// The validation issues, each with the path of the offending value.
+        report: nebula_schema::ValidationReport,
+    },
+
     /// A `StatefulAction` returned `Continue` without mutating its state —
     /// the author's iteration is stuck (forgot to advance a cursor, reset
     /// an accumulator to the same value, etc.). The runtime converts this
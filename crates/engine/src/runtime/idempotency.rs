@@ -0,0 +1,416 @@
+//! Idempotency keys for runtime dispatches.
+//!
+//! An [`IdempotencyKeyStrategy`] derives a key for each dispatch; the
+//! [`IdempotencyManager`] reserves the key while the dispatch runs and
+//! remembers which keys completed, so a retried or overlapping dispatch of the
+//! same logical work returns the recorded result instead of running the action
+//! again.
+
+use std::{collections::VecDeque, fmt::Write as _, time::Duration};
+
+use dashmap::{DashMap, mapref::entry::Entry};
+use nebula_action::{ActionResult, IdempotencyKey};
+use parking_lot::Mutex;
+use sha2::{Digest, Sha256};
+use tokio::{sync::watch, time::Instant};
+
+/// Derives the idempotency key for a dispatch.
+///
+/// Returning `None` runs the dispatch without deduplication.
+pub trait IdempotencyKeyStrategy: Send + Sync {
+    /// Key for dispatching `action_key` with `input`.
+    fn key(&self, action_key: &str, input: &serde_json::Value) -> Option<IdempotencyKey>;
+}
+
+/// Keys a dispatch by a SHA-256 hash of the action key and its input.
+///
+/// Identical inputs to the same action always produce the same key, across
+/// processes and restarts. Object keys are hashed in sorted order, so field
+/// order in the input does not matter.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ContentHashKey;
+
+impl IdempotencyKeyStrategy for ContentHashKey {
+    fn key(&self, action_key: &str, input: &serde_json::Value) -> Option<IdempotencyKey> {
+        let input = serde_json::to_vec(input).ok()?;
+        let mut hasher = Sha256::new();
+        // Length-prefix the action key so `("a", "bc")` and `("ab", "c")`
+        // cannot hash the same bytes.
+        hasher.update((action_key.len() as u64).to_le_bytes());
+        hasher.update(action_key.as_bytes());
+        hasher.update(&input);
+        let mut key = format!("{action_key}:");
+        for byte in hasher.finalize() {
+            // Writing to a String is infallible.
+            let _ = write!(key, "{byte:02x}");
+        }
+        Some(IdempotencyKey::new(key))
+    }
+}
+
+/// Keys a dispatch by a caller-supplied value inside the input.
+///
+/// `pointer` is a JSON pointer (for example `/request_id`). Inputs without a
+/// string or number at that location are not deduplicated. The action key is
+/// part of the idempotency key, so two actions can share a caller key.
+#[derive(Debug, Clone)]
+pub struct CallerProvidedKey {
+    pointer: String,
+}
+
+impl CallerProvidedKey {
+    /// Read the key from the input value at `pointer`.
+    #[must_use]
+    pub fn new(pointer: impl Into<String>) -> Self {
+        Self {
+            pointer: pointer.into(),
+        }
+    }
+}
+
+impl IdempotencyKeyStrategy for CallerProvidedKey {
+    fn key(&self, action_key: &str, input: &serde_json::Value) -> Option<IdempotencyKey> {
+        let caller_key = match input.pointer(&self.pointer)? {
+            serde_json::Value::String(s) if !s.is_empty() => s.clone(),
+            serde_json::Value::Number(n) => n.to_string(),
+            _ => return None,
+        };
+        Some(IdempotencyKey::new(format!("{action_key}:{caller_key}")))
+    }
+}
+
+/// In-memory record of dispatches that completed successfully.
+///
+/// A dispatch first [`acquire`](Self::acquire)s its key. The first caller
+/// reserves it and runs the action; concurrent callers with the same key wait
+/// for that run and then get its recorded result instead of running the action
+/// themselves. Failed or abandoned runs are not recorded, so the next caller
+/// runs the action again.
+///
+/// Records expire after a TTL and the oldest are evicted once more than
+/// `max_entries` are held, so a long-running engine does not grow without
+/// bound. Not durable: records are lost on restart.
+#[derive(Debug)]
+pub struct IdempotencyManager {
+    slots: DashMap<IdempotencyKey, Slot>,
+    /// Completed records in the order they were written, for eviction.
+    order: Mutex<VecDeque<(IdempotencyKey, Instant)>>,
+    ttl: Duration,
+    max_entries: usize,
+}
+
+#[derive(Debug)]
+enum Slot {
+    /// A dispatch holds the key. The sender lives in its [`IdempotencyGuard`];
+    /// waiters wake when it is dropped.
+    InFlight(watch::Receiver<()>),
+    Completed {
+        result: Box<ActionResult<serde_json::Value>>,
+        recorded_at: Instant,
+    },
+}
+
+/// Outcome of [`IdempotencyManager::acquire`].
+#[derive(Debug)]
+pub enum IdempotencyClaim<'a> {
+    /// The key already completed; this is its recorded result.
+    Completed(ActionResult<serde_json::Value>),
+    /// The caller holds the key and should run the dispatch.
+    Reserved(IdempotencyGuard<'a>),
+}
+
+/// Reservation of an idempotency key for one running dispatch.
+///
+/// Call [`complete`](Self::complete) with the result on success. Dropping the
+/// guard without completing — on failure or cancellation — releases the key,
+/// and the next waiter runs the dispatch itself.
+#[derive(Debug)]
+pub struct IdempotencyGuard<'a> {
+    manager: &'a IdempotencyManager,
+    key: IdempotencyKey,
+    completed: bool,
+    // Dropped after `Drop::drop` updates the slot, which wakes the waiters.
+    _done: watch::Sender<()>,
+}
+
+impl IdempotencyGuard<'_> {
+    /// Record `result` for the key and wake waiting dispatches.
+    pub fn complete(mut self, result: ActionResult<serde_json::Value>) {
+        self.manager.record(self.key.clone(), result);
+        self.completed = true;
+    }
+}
+
+impl Drop for IdempotencyGuard<'_> {
+    fn drop(&mut self) {
+        if !self.completed {
+            self.manager
+                .slots
+                .remove_if(&self.key, |_, slot| matches!(slot, Slot::InFlight(_)));
+        }
+    }
+}
+
+impl Default for IdempotencyManager {
+    fn default() -> Self {
+        Self {
+            slots: DashMap::new(),
+            order: Mutex::new(VecDeque::new()),
+            ttl: Self::DEFAULT_TTL,
+            max_entries: Self::DEFAULT_MAX_ENTRIES,
+        }
+    }
+}
+
+impl IdempotencyManager {
+    /// How long a record is kept by default: one hour.
+    pub const DEFAULT_TTL: Duration = Duration::from_hours(1);
+    /// How many records are kept by default.
+    pub const DEFAULT_MAX_ENTRIES: usize = 10_000;
+
+    /// Create an empty manager with the default TTL and capacity.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keep records for `ttl`; after that the key runs again.
+    #[must_use]
+    pub const fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Keep at most `max_entries` records, evicting the oldest first.
+    #[must_use]
+    pub const fn with_max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = max_entries;
+        self
+    }
+
+    fn is_expired(&self, recorded_at: Instant) -> bool {
+        recorded_at.elapsed() >= self.ttl
+    }
+
+    /// Claim `key` for a dispatch.
+    ///
+    /// Returns the recorded result if the key already completed. Otherwise
+    /// reserves the key atomically, so exactly one of several overlapping
+    /// callers gets [`IdempotencyClaim::Reserved`]; the others wait until that
+    /// dispatch completes or gives up, then return its result or try again.
+    pub async fn acquire(&self, key: &IdempotencyKey) -> IdempotencyClaim<'_> {
+        loop {
+            let mut in_flight = match self.slots.entry(key.clone()) {
+                Entry::Occupied(mut entry) => {
+                    let waiting = match entry.get() {
+                        Slot::Completed {
+                            result,
+                            recorded_at,
+                        } if !self.is_expired(*recorded_at) => {
+                            return IdempotencyClaim::Completed((**result).clone());
+                        },
+                        Slot::InFlight(done) => Some(done.clone()),
+                        Slot::Completed { .. } => None,
+                    };
+                    if let Some(done) = waiting {
+                        done
+                    } else {
+                        let (tx, rx) = watch::channel(());
+                        entry.insert(Slot::InFlight(rx));
+                        return IdempotencyClaim::Reserved(self.guard(key, tx));
+                    }
+                },
+                Entry::Vacant(entry) => {
+                    let (tx, rx) = watch::channel(());
+                    entry.insert(Slot::InFlight(rx));
+                    return IdempotencyClaim::Reserved(self.guard(key, tx));
+                },
+            };
+            // Nothing is ever sent: this resolves once the holder's guard drops.
+            let _ = in_flight.changed().await;
+        }
+    }
+
+    fn guard(&self, key: &IdempotencyKey, done: watch::Sender<()>) -> IdempotencyGuard<'_> {
+        IdempotencyGuard {
+            manager: self,
+            key: key.clone(),
+            completed: false,
+            _done: done,
+        }
+    }
+
+    /// The result recorded for `key`, if that dispatch already completed and
+    /// the record has not expired.
+    #[must_use]
+    pub fn completed(&self, key: &IdempotencyKey) -> Option<ActionResult<serde_json::Value>> {
+        match self.slots.get(key)?.value() {
+            Slot::Completed {
+                result,
+                recorded_at,
+            } if !self.is_expired(*recorded_at) => Some((**result).clone()),
+            _ => None,
+        }
+    }
+
+    /// Record the result of a completed dispatch, evicting expired records and,
+    /// past `max_entries`, the oldest ones.
+    pub fn record(&self, key: IdempotencyKey, result: ActionResult<serde_json::Value>) {
+        let now = Instant::now();
+        self.slots.insert(
+            key.clone(),
+            Slot::Completed {
+                result: Box::new(result),
+                recorded_at: now,
+            },
+        );
+        let mut order = self.order.lock();
+        order.push_back((key, now));
+        while let Some((_, oldest)) = order.front() {
+            if order.len() <= self.max_entries && !self.is_expired(*oldest) {
+                break;
+            }
+            if let Some((key, at)) = order.pop_front() {
+                // A newer record for the same key stays.
+                self.slots.remove_if(&key, |_, slot| {
+                    matches!(slot, Slot::Completed { recorded_at, .. } if *recorded_at == at)
+                });
+            }
+        }
+    }
+
+    /// Drop the record for `key`, so the next dispatch runs again.
+    pub fn forget(&self, key: &IdempotencyKey) {
+        self.slots
+            .remove_if(key, |_, slot| matches!(slot, Slot::Completed { .. }));
+    }
+
+    /// Number of recorded dispatches, including expired ones not yet evicted.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.slots
+            .iter()
+            .filter(|slot| matches!(slot.value(), Slot::Completed { .. }))
+            .count()
+    }
+
+    /// Whether no dispatch has been recorded.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn content_hash_ignores_field_order_but_not_values() {
+        let a = ContentHashKey.key("http.request", &serde_json::json!({"x": 1, "y": 2}));
+        let b = ContentHashKey.key("http.request", &serde_json::json!({"y": 2, "x": 1}));
+        let c = ContentHashKey.key("http.request", &serde_json::json!({"x": 1, "y": 3}));
+        let d = ContentHashKey.key("http.other", &serde_json::json!({"x": 1, "y": 2}));
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        assert_ne!(a, d);
+    }
+
+    #[test]
+    fn caller_provided_key_reads_pointer() {
+        let strategy = CallerProvidedKey::new("/request_id");
+        assert_eq!(
+            strategy.key(
+                "pay",
+                &serde_json::json!({"request_id": "r-1", "amount": 5})
+            ),
+            Some(IdempotencyKey::new("pay:r-1"))
+        );
+        assert_eq!(
+            strategy.key("pay", &serde_json::json!({"request_id": 7})),
+            Some(IdempotencyKey::new("pay:7"))
+        );
+        assert_eq!(strategy.key("pay", &serde_json::json!({"amount": 5})), None);
+        assert_eq!(
+            strategy.key("pay", &serde_json::json!({"request_id": ""})),
+            None
+        );
+    }
+
+    fn key(name: &str) -> IdempotencyKey {
+        IdempotencyKey::new(name)
+    }
+
+    fn result(n: u32) -> ActionResult<serde_json::Value> {
+        ActionResult::success(serde_json::json!(n))
+    }
+
+    async fn reserve<'a>(
+        manager: &'a IdempotencyManager,
+        key: &IdempotencyKey,
+    ) -> IdempotencyGuard<'a> {
+        match manager.acquire(key).await {
+            IdempotencyClaim::Reserved(guard) => guard,
+            IdempotencyClaim::Completed(_) => panic!("key should not be completed yet"),
+        }
+    }
+
+    #[tokio::test]
+    async fn waiter_gets_result_of_in_flight_dispatch() {
+        let manager = IdempotencyManager::new();
+        let k = key("a");
+        let guard = reserve(&manager, &k).await;
+
+        let (claim, ()) = tokio::join!(manager.acquire(&k), async {
+            tokio::task::yield_now().await;
+            guard.complete(result(1));
+        });
+        assert!(matches!(claim, IdempotencyClaim::Completed(_)));
+        assert_eq!(manager.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn abandoned_reservation_passes_to_waiter() {
+        let manager = IdempotencyManager::new();
+        let k = key("a");
+        let guard = reserve(&manager, &k).await;
+
+        let (claim, ()) = tokio::join!(manager.acquire(&k), async {
+            tokio::task::yield_now().await;
+            drop(guard);
+        });
+        assert!(matches!(claim, IdempotencyClaim::Reserved(_)));
+        assert!(manager.is_empty());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn records_expire_after_ttl() {
+        let manager = IdempotencyManager::new().with_ttl(Duration::from_mins(1));
+        let k = key("a");
+        manager.record(k.clone(), result(1));
+        assert!(manager.completed(&k).is_some());
+
+        tokio::time::advance(Duration::from_mins(1)).await;
+        assert!(manager.completed(&k).is_none());
+        assert!(matches!(
+            manager.acquire(&k).await,
+            IdempotencyClaim::Reserved(_)
+        ));
+
+        // The next record sweeps the expired one.
+        manager.record(key("b"), result(2));
+        assert_eq!(manager.len(), 1);
+    }
+
+    #[test]
+    fn oldest_records_are_evicted_past_capacity() {
+        let manager = IdempotencyManager::new().with_max_entries(2);
+        for (i, name) in ["a", "b", "c"].into_iter().enumerate() {
+            manager.record(key(name), result(i as u32));
+        }
+        assert_eq!(manager.len(), 2);
+        assert!(manager.completed(&key("a")).is_none());
+        assert!(manager.completed(&key("b")).is_some());
+        assert!(manager.completed(&key("c")).is_some());
+    }
+}
@@ -10,8 +10,11 @@
 //! - [`ActionRuntime`] — executes a resolved action through the runner with data limits.
 //! - [`ActionRegistry`] — registers and looks up action handlers by key.
 //! - [`DataPassingPolicy`], [`LargeDataStrategy`] — output size enforcement.
-//! - [`MemoryQueue`], [`TaskQueue`] — in-memory task queueing (not durable; durable control signals
-//!   live in `execution_control_queue`).
+//! - [`MemoryQueue`], [`TaskQueue`], [`TaskPriority`] — in-memory task queueing with priority
+//!   lanes (not durable; durable control signals live in `execution_control_queue`).
+//! - [`DeadLetterQueue`], [`MemoryDeadLetterQueue`] — parking and replay of fatally-failed tasks
+//!   and of tasks a [`MemoryQueue`] stopped redelivering.
+//! - [`IdempotencyKeyStrategy`], [`IdempotencyManager`] — dispatch dedup by idempotency key.
 //! - [`BlobRef`], [`BlobStorage`] — side-channel for large payloads.
 //! - [`StatefulCheckpoint`], [`StatefulCheckpointSink`] — checkpoint boundaries for
 //!   `StatefulAction` types.
@@ -27,7 +30,9 @@
 
 pub mod blob;
 pub mod data_policy;
+pub mod dead_letter;
 pub mod error;
+pub mod idempotency;
 pub mod queue;
 pub mod registry;
 pub mod runner;
@@ -41,8 +46,13 @@ pub mod stream_backpressure;
 
 pub use blob::{BlobRef, BlobStorage};
 pub use data_policy::{DataPassingPolicy, LargeDataStrategy};
+pub use dead_letter::{DeadLetterEntry, DeadLetterQueue, MemoryDeadLetterQueue};
 pub use error::RuntimeError;
-pub use queue::{MemoryQueue, QueueError, TaskQueue};
+pub use idempotency::{
+    CallerProvidedKey, ContentHashKey, IdempotencyClaim, IdempotencyGuard, IdempotencyKeyStrategy,
+    IdempotencyManager,
+};
+pub use queue::{MemoryQueue, QueueError, QueueMetrics, QueuePressure, TaskPriority, TaskQueue};
 pub use registry::ActionRegistry;
 pub use runner::{ActionExecutor, ActionRunContext, ActionRunner, InProcessRunner};
 pub use runtime::{ActionRuntime, StatefulCheckpoint, StatefulCheckpointSink};
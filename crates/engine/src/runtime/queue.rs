@@ -3,18 +3,25 @@
 //! Used to distribute work to workers; at-least-once delivery with ack/nack.
 
 use std::{
-    collections::HashMap,
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
     future::Future,
+    pin::pin,
     sync::{
         Arc,
-        atomic::{AtomicUsize, Ordering},
+        atomic::{AtomicU64, AtomicUsize, Ordering},
     },
     time::Duration,
 };
 
-use async_channel::{Receiver, Sender};
+use serde::Serialize;
 use thiserror::Error;
-use tokio::{sync::Mutex, time::Instant};
+use tokio::{
+    sync::{Mutex, Notify},
+    time::Instant,
+};
+
+use super::dead_letter::{DeadLetterEntry, DeadLetterQueue};
 
 /// Errors returned by queue operations.
 #[derive(Debug, Error)]
@@ -29,7 +36,23 @@ pub enum QueueError {
         id: String,
     },
 
-    /// Internal queue failure (full, closed, etc.).
+    /// The task's lease expired before this ack/nack arrived, and the task was
+    /// already returned to the queue for redelivery.
+    #[error("lease expired for task {id}; it was requeued for redelivery")]
+    LeaseExpired {
+        /// Task whose lease expired.
+        id: String,
+    },
+
+    /// The queue was closed (for example by [`MemoryQueue::drain`]).
+    #[error("queue is closed")]
+    Closed,
+
+    /// The queue is at capacity and cannot accept new work right now.
+    #[error("queue is full")]
+    Full,
+
+    /// Internal queue failure.
     #[error("internal error: {0}")]
     Internal(String),
 }
@@ -55,11 +78,28 @@ impl QueueError {
 /// owned/cloned inputs to satisfy the spawned future's `'static` requirement.
 pub trait TaskQueue: Send + Sync {
     /// Enqueue a task. Returns a task ID.
+    ///
+    /// Fails with [`QueueError::Full`] instead of waiting when the queue is at
+    /// capacity.
     fn enqueue(
         &self,
         payload: serde_json::Value,
     ) -> impl Future<Output = Result<String, QueueError>> + Send;
 
+    /// Enqueue a task with a delivery priority. Returns a task ID.
+    ///
+    /// Queues that support priorities deliver higher-priority tasks first and
+    /// keep FIFO order within a priority. The default implementation ignores
+    /// `priority` and calls [`enqueue`](Self::enqueue).
+    fn enqueue_with_priority(
+        &self,
+        payload: serde_json::Value,
+        priority: TaskPriority,
+    ) -> impl Future<Output = Result<String, QueueError>> + Send {
+        let _ = priority;
+        self.enqueue(payload)
+    }
+
     /// Dequeue the next available task.
     ///
     /// Distinguishes timeout from a closed queue so callers can react
@@ -69,29 +109,167 @@ pub trait TaskQueue: Send + Sync {
         timeout: Duration,
     ) -> impl Future<Output = Result<DequeueResult, QueueError>> + Send;
 
-    /// Acknowledge successful processing.
-    fn ack(&self, task_id: &str) -> impl Future<Output = Result<(), QueueError>> + Send;
+    /// Dequeue up to `max` tasks at once.
+    ///
+    /// Waits up to `timeout` for the first task, then takes whatever else is
+    /// ready without waiting further. Every element is a
+    /// [`DequeueResult::Item`] with its own lease, acked or nacked
+    /// individually. An empty batch means the timeout elapsed; fails with
+    /// [`QueueError::Closed`] once the queue is closed and has nothing left to
+    /// deliver. The default implementation calls [`dequeue`](Self::dequeue)
+    /// in a loop.
+    fn dequeue_batch(
+        &self,
+        max: usize,
+        timeout: Duration,
+    ) -> impl Future<Output = Result<Vec<DequeueResult>, QueueError>> + Send {
+        async move {
+            let mut batch = Vec::new();
+            let mut wait = timeout;
+            while batch.len() < max {
+                match self.dequeue(wait).await? {
+                    item @ DequeueResult::Item { .. } => batch.push(item),
+                    DequeueResult::Closed if batch.is_empty() => return Err(QueueError::Closed),
+                    _ => break,
+                }
+                wait = Duration::ZERO;
+            }
+            Ok(batch)
+        }
+    }
+
+    /// Acknowledge successful processing of delivery `attempt` of `task_id`.
+    ///
+    /// `attempt` is the value from [`DequeueResult::Item`]; it ties the ack to
+    /// one delivery. Fails with [`QueueError::LeaseExpired`] if that lease ran
+    /// out and the task is waiting to be, or already was, redelivered; a
+    /// newer delivery's lease is left untouched.
+    fn ack(
+        &self,
+        task_id: &str,
+        attempt: u32,
+    ) -> impl Future<Output = Result<(), QueueError>> + Send;
+
+    /// Enqueue a task that becomes visible to `dequeue` only after `delay`.
+    ///
+    /// Returns a task ID. Scheduled tasks are delivered approximately in
+    /// ready-time order, not insertion order. They count against capacity
+    /// like queued tasks, so this fails with [`QueueError::Full`] the same
+    /// way as [`enqueue`](Self::enqueue).
+    fn enqueue_delayed(
+        &self,
+        payload: serde_json::Value,
+        delay: Duration,
+    ) -> impl Future<Output = Result<String, QueueError>> + Send;
 
-    /// Negative-acknowledge — requeue for retry.
-    fn nack(&self, task_id: &str) -> impl Future<Output = Result<(), QueueError>> + Send;
+    /// Negative-acknowledge delivery `attempt` of `task_id` — requeue for retry.
+    ///
+    /// The task was already accepted, so a full queue makes this wait for
+    /// capacity rather than fail. Fails like [`ack`](Self::ack) when `attempt`
+    /// is not the live delivery.
+    fn nack(
+        &self,
+        task_id: &str,
+        attempt: u32,
+    ) -> impl Future<Output = Result<(), QueueError>> + Send;
+
+    /// Negative-acknowledge with backoff — the task is requeued but stays
+    /// invisible to `dequeue` until `delay` has passed. Waits for capacity
+    /// like [`nack`](Self::nack).
+    fn nack_delayed(
+        &self,
+        task_id: &str,
+        attempt: u32,
+        delay: Duration,
+    ) -> impl Future<Output = Result<(), QueueError>> + Send;
 
-    /// Total number of tasks tracked by the queue: queued + in-flight.
+    /// Total number of tasks tracked by the queue: scheduled + queued + in-flight.
     ///
-    /// This is a workload cardinality view, not just channel depth.
+    /// This is a workload cardinality view, not just queue depth.
     fn len(&self) -> impl Future<Output = Result<usize, QueueError>> + Send;
 
-    /// Number of tasks currently waiting in the queue channel.
+    /// Number of tasks currently waiting to be dequeued.
     fn queued_len(&self) -> impl Future<Output = Result<usize, QueueError>> + Send;
 
+    /// Number of delayed tasks whose ready time has not been reached yet.
+    fn scheduled_len(&self) -> impl Future<Output = Result<usize, QueueError>> + Send;
+
     /// Number of tasks currently leased to workers and awaiting ack/nack.
     fn in_flight_len(&self) -> impl Future<Output = Result<usize, QueueError>> + Send;
 
+    /// Coarse occupancy level, so producers can slow down before the queue is full.
+    fn pressure(&self) -> impl Future<Output = Result<QueuePressure, QueueError>> + Send;
+
     /// Whether the queue is empty.
     fn is_empty(&self) -> impl Future<Output = Result<bool, QueueError>> + Send {
         async { Ok(self.len().await? == 0) }
     }
 }
 
+/// Delivery priority passed to [`TaskQueue::enqueue_with_priority`].
+///
+/// Ordered from lowest to highest, so `High > Normal > Low`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum TaskPriority {
+    /// Bulk or background work that can wait.
+    Low,
+    /// Default priority, used by [`TaskQueue::enqueue`].
+    #[default]
+    Normal,
+    /// Interactive work, e.g. a human-triggered execution.
+    High,
+}
+
+impl TaskPriority {
+    /// Number of priority levels.
+    const LEVELS: usize = 3;
+
+    /// Lane index in [`ReadyQueue`]; higher is served first.
+    const fn level(self) -> usize {
+        self as usize
+    }
+}
+
+/// Backpressure level reported by [`TaskQueue::pressure`].
+///
+/// Ordered, so producers can compare against a level (`pressure >= Medium`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum QueuePressure {
+    /// Plenty of room; enqueue at full rate.
+    Low,
+    /// Filling up; producers should start throttling.
+    Medium,
+    /// Near capacity; enqueues are likely to fail soon.
+    High,
+}
+
+impl QueuePressure {
+    /// Occupancy (percent) at which pressure becomes [`Medium`](Self::Medium).
+    pub const MEDIUM_THRESHOLD_PERCENT: usize = 50;
+    /// Occupancy (percent) at which pressure becomes [`High`](Self::High).
+    pub const HIGH_THRESHOLD_PERCENT: usize = 80;
+
+    /// Classify `depth` items in a queue holding at most `capacity`.
+    ///
+    /// A zero capacity is always [`High`](Self::High).
+    #[must_use]
+    pub fn from_occupancy(depth: usize, capacity: usize) -> Self {
+        if capacity == 0 {
+            return Self::High;
+        }
+        let percent = depth.saturating_mul(100) / capacity;
+        if percent >= Self::HIGH_THRESHOLD_PERCENT {
+            Self::High
+        } else if percent >= Self::MEDIUM_THRESHOLD_PERCENT {
+            Self::Medium
+        } else {
+            Self::Low
+        }
+    }
+}
+
 /// Outcome of a dequeue attempt.
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[non_exhaustive]
@@ -102,6 +280,10 @@ pub enum DequeueResult {
         task_id: String,
         /// Opaque payload for worker execution.
         payload: serde_json::Value,
+        /// Delivery attempt, starting at 1. Higher values mean the task was
+        /// redelivered after a nack or an expired lease. Pass it back to
+        /// [`TaskQueue::ack`] / [`TaskQueue::nack`] to identify this delivery.
+        attempt: u32,
     },
     /// No item arrived before the timeout elapsed.
     Timeout,
@@ -109,32 +291,241 @@ pub enum DequeueResult {
     Closed,
 }
 
+/// Point-in-time counters for a [`MemoryQueue`].
+///
+/// Totals are cumulative since the queue was created. `avg_wait` averages the
+/// time items spent queued between (re)enqueue and dequeue, including the
+/// requeue of tasks whose lease expired.
+///
+/// Taking a snapshot does not requeue expired leases: they count as
+/// `in_flight` until the next `dequeue` or length query returns them to the
+/// queue.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[non_exhaustive]
+pub struct QueueMetrics {
+    /// Tasks accepted by `enqueue`.
+    pub enqueued_total: u64,
+    /// Tasks handed to a worker by `dequeue`, including redeliveries.
+    pub dequeued_total: u64,
+    /// Successful `ack` calls.
+    pub acked: u64,
+    /// Successful `nack` calls (tasks requeued).
+    pub nacked: u64,
+    /// Tasks currently waiting to be dequeued.
+    pub current_depth: usize,
+    /// Tasks leased to workers and awaiting ack/nack.
+    pub in_flight: usize,
+    /// Maximum number of tasks waiting to be dequeued.
+    pub capacity: usize,
+    /// Delayed tasks not yet ready for delivery.
+    pub scheduled_depth: usize,
+    /// Tasks handed to the dead-letter queue after exhausting their
+    /// delivery attempts.
+    pub dead_lettered: u64,
+    /// Mean time between enqueue and dequeue.
+    pub avg_wait: Duration,
+}
+
+#[derive(Debug, Default)]
+struct QueueCounters {
+    enqueued: AtomicU64,
+    dequeued: AtomicU64,
+    acked: AtomicU64,
+    nacked: AtomicU64,
+    dead_lettered: AtomicU64,
+    waits: AtomicU64,
+    wait_nanos: AtomicU64,
+    /// Size of the in-flight map, updated under its lock after each change
+    /// so `metrics` can read it without awaiting the lock.
+    in_flight: AtomicUsize,
+}
+
+impl QueueCounters {
+    fn set_in_flight(&self, in_flight: &HashMap<String, InFlightEntry>) {
+        self.in_flight.store(in_flight.len(), Ordering::Relaxed);
+    }
+
+    fn record_wait(&self, wait: Duration) {
+        let nanos = u64::try_from(wait.as_nanos()).unwrap_or(u64::MAX);
+        self.wait_nanos.fetch_add(nanos, Ordering::Relaxed);
+        self.waits.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn avg_wait(&self) -> Duration {
+        let waits = self.waits.load(Ordering::Relaxed);
+        if waits == 0 {
+            return Duration::ZERO;
+        }
+        Duration::from_nanos(self.wait_nanos.load(Ordering::Relaxed) / waits)
+    }
+}
+
 #[derive(Debug, Clone)]
 struct QueueItem {
     id: String,
     payload: serde_json::Value,
+    enqueued_at: Instant,
+    /// Number of times the task has been leased so far.
+    attempts: u32,
+    priority: TaskPriority,
+}
+
+/// A delayed task waiting for its ready time.
+///
+/// Ordered by `(ready_at, seq)` so the heap pops the earliest deadline first
+/// and ties keep insertion order.
+#[derive(Debug)]
+struct ScheduledItem {
+    ready_at: Instant,
+    seq: u64,
+    item: QueueItem,
+}
+
+impl PartialEq for ScheduledItem {
+    fn eq(&self, other: &Self) -> bool {
+        (self.ready_at, self.seq) == (other.ready_at, other.seq)
+    }
+}
+
+impl Eq for ScheduledItem {}
+
+impl PartialOrd for ScheduledItem {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ScheduledItem {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        (self.ready_at, self.seq).cmp(&(other.ready_at, other.seq))
+    }
+}
+
+#[derive(Debug, Default)]
+struct Schedule {
+    heap: BinaryHeap<Reverse<ScheduledItem>>,
+    next_seq: u64,
+}
+
+impl Schedule {
+    fn push(&mut self, item: QueueItem, ready_at: Instant) {
+        let seq = self.next_seq;
+        self.next_seq += 1;
+        self.heap.push(Reverse(ScheduledItem {
+            ready_at,
+            seq,
+            item,
+        }));
+    }
+
+    fn next_ready_at(&self) -> Option<Instant> {
+        self.heap.peek().map(|Reverse(entry)| entry.ready_at)
+    }
+}
+
+/// Tasks ready for delivery: one FIFO lane per [`TaskPriority`].
+#[derive(Debug, Default)]
+struct ReadyQueue {
+    lanes: [VecDeque<QueueItem>; TaskPriority::LEVELS],
+    closed: bool,
+}
+
+impl ReadyQueue {
+    fn len(&self) -> usize {
+        self.lanes.iter().map(VecDeque::len).sum()
+    }
+
+    /// Take the next task: the head of the highest non-empty lane.
+    ///
+    /// With `aging`, each head first gains one level per `aging` it has
+    /// waited; ties go to the lane with the higher base priority.
+    fn pop(&mut self, aging: Option<Duration>, now: Instant) -> Option<QueueItem> {
+        let mut best: Option<(usize, u128)> = None;
+        for (level, lane) in self.lanes.iter().enumerate().rev() {
+            let Some(head) = lane.front() else {
+                continue;
+            };
+            let boost = aging.map_or(0, |aging| {
+                now.saturating_duration_since(head.enqueued_at).as_nanos() / aging.as_nanos().max(1)
+            });
+            let effective = boost.saturating_add(level as u128);
+            if best.is_none_or(|(_, top)| effective > top) {
+                best = Some((level, effective));
+            }
+        }
+        best.and_then(|(level, _)| self.lanes[level].pop_front())
+    }
+}
+
+/// Why a task could not be added to the queue; the task is handed back.
+enum PushError {
+    Full(QueueItem),
+    Closed(QueueItem),
+}
+
+impl From<PushError> for QueueError {
+    fn from(e: PushError) -> Self {
+        match e {
+            PushError::Full(_) => Self::Full,
+            PushError::Closed(_) => Self::Closed,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
 struct InFlightEntry {
     item: QueueItem,
     lease_deadline: Instant,
+    /// Set while `nack` waits for capacity, so the lease reaper does not
+    /// requeue the same task a second time.
+    requeuing: bool,
 }
 
 /// In-memory bounded task queue.
 ///
 /// Tasks: Queued → In-flight (dequeued) → Done (acked) or requeued (nacked).
+/// With [`with_max_delivery_attempts`](Self::with_max_delivery_attempts) and
+/// a [dead-letter queue](Self::set_dead_letter_queue), a task that is nacked
+/// or loses its lease on its last allowed delivery is parked there instead of
+/// requeued.
+/// Delayed tasks start in a ready-time min-heap and are promoted into the
+/// ready lanes lazily by `dequeue` once their deadline passes. Leases past
+/// their [visibility timeout](Self::with_visibility_timeout) are requeued the
+/// same way, by `dequeue` and by the length queries; no background task is
+/// spawned, and a parked `dequeue` wakes up when the earliest lease expires.
+///
+/// Ready tasks sit in one FIFO lane per [`TaskPriority`]; `dequeue` serves
+/// the highest non-empty lane first. Under a steady stream of high-priority
+/// work, [`with_priority_aging`](Self::with_priority_aging) keeps lower lanes
+/// from starving.
 ///
-/// The receiver is multi-consumer (`async_channel`), so multiple concurrent
-/// `dequeue` callers may park inside `recv()` simultaneously. A previous
+/// Capacity bounds every task waiting for delivery, whether queued in any
+/// lane or scheduled. New work (`enqueue`, `enqueue_delayed`) fails with
+/// [`QueueError::Full`] at capacity; requeues (`nack`, `nack_delayed`) wait
+/// for space, keeping the task leased meanwhile so it is never lost.
+///
+/// Consumers park on a [`Notify`] rather than holding a lock, so multiple
+/// concurrent `dequeue` callers wait in parallel. A previous
 /// `Arc<Mutex<mpsc::Receiver>>` design forced workers to serialize on the
 /// mutex, capping effective parallelism at 1 (issue #279).
 pub struct MemoryQueue {
-    sender: Sender<QueueItem>,
-    receiver: Receiver<QueueItem>,
+    ready: parking_lot::Mutex<ReadyQueue>,
+    /// Signalled when a task becomes ready or the queue closes.
+    item_ready: Notify,
+    /// Signalled when a ready task is taken or the queue closes.
+    space_freed: Notify,
+    capacity: usize,
     in_flight: Arc<Mutex<HashMap<String, InFlightEntry>>>,
-    queued_count: AtomicUsize,
+    /// Tasks requeued after their lease expired and not yet re-leased, so a
+    /// late ack/nack can be told apart from an unknown ID.
+    expired: parking_lot::Mutex<HashSet<String>>,
+    scheduled: parking_lot::Mutex<Schedule>,
+    /// Where tasks that exhaust their delivery attempts are parked.
+    dead_letter_queue: parking_lot::Mutex<Option<Arc<dyn DeadLetterQueue>>>,
+    counters: QueueCounters,
     visibility_timeout: Duration,
+    max_delivery_attempts: Option<u32>,
+    priority_aging: Option<Duration>,
 }
 
 impl MemoryQueue {
@@ -152,120 +543,635 @@ impl MemoryQueue {
     /// is considered stale and can be redelivered by a later [`TaskQueue::dequeue`].
     #[must_use]
     pub fn new_with_visibility_timeout(capacity: usize, visibility_timeout: Duration) -> Self {
-        let (sender, receiver) = async_channel::bounded(capacity);
         Self {
-            sender,
-            receiver,
+            ready: parking_lot::Mutex::new(ReadyQueue::default()),
+            item_ready: Notify::new(),
+            space_freed: Notify::new(),
+            capacity,
             in_flight: Arc::new(Mutex::new(HashMap::new())),
-            queued_count: AtomicUsize::new(0),
+            expired: parking_lot::Mutex::new(HashSet::new()),
+            scheduled: parking_lot::Mutex::new(Schedule::default()),
+            dead_letter_queue: parking_lot::Mutex::new(None),
+            counters: QueueCounters::default(),
             visibility_timeout,
+            max_delivery_attempts: None,
+            priority_aging: None,
+        }
+    }
+
+    /// Requeue a dequeued task that is neither acked nor nacked within
+    /// `timeout` (default: 30s), e.g. because its worker crashed.
+    ///
+    /// The task is delivered again with the next attempt number; a late ack
+    /// or nack of the old lease fails with [`QueueError::LeaseExpired`].
+    #[must_use]
+    pub fn with_visibility_timeout(mut self, timeout: Duration) -> Self {
+        self.visibility_timeout = timeout;
+        self
+    }
+
+    /// Dead-letter tasks after `attempts` failed deliveries instead of
+    /// requeuing them forever. Unlimited by default.
+    ///
+    /// A delivery fails when it is nacked or its lease expires. Values below 1
+    /// are treated as 1. The limit only applies once a dead-letter queue is
+    /// attached with [`set_dead_letter_queue`](Self::set_dead_letter_queue);
+    /// without one, tasks keep being requeued rather than dropped.
+    #[must_use]
+    pub fn with_max_delivery_attempts(mut self, attempts: u32) -> Self {
+        self.max_delivery_attempts = Some(attempts.max(1));
+        self
+    }
+
+    /// Raise a waiting task by one priority level for every `interval` it has
+    /// been ready, so `Normal` work is not starved by a continuous stream of
+    /// `High` tasks. Off by default: priorities are strict.
+    ///
+    /// With an interval of 1s, a `Normal` task that has waited 1s ties with a
+    /// fresh `High` task and is served after it; once it has waited 2s it is
+    /// served first.
+    #[must_use]
+    pub fn with_priority_aging(mut self, interval: Duration) -> Self {
+        self.priority_aging = Some(interval);
+        self
+    }
+
+    /// Park tasks that exhaust their delivery attempts in `dlq`, replacing
+    /// any dead-letter queue attached earlier.
+    ///
+    /// Takes `&self` so `dlq` can replay into this queue, e.g. a
+    /// [`MemoryDeadLetterQueue`](super::MemoryDeadLetterQueue) built from the
+    /// same `Arc<MemoryQueue>`. The two then keep each other alive, which
+    /// suits queues that live as long as the process.
+    pub fn set_dead_letter_queue(&self, dlq: Arc<dyn DeadLetterQueue>) {
+        *self.dead_letter_queue.lock() = Some(dlq);
+    }
+
+    /// The dead-letter queue to park `item` in, if it used up its delivery
+    /// attempts and one is attached.
+    fn dead_letter_target(&self, item: &QueueItem) -> Option<Arc<dyn DeadLetterQueue>> {
+        if self
+            .max_delivery_attempts
+            .is_some_and(|max| item.attempts >= max)
+        {
+            self.dead_letter_queue.lock().clone()
+        } else {
+            None
+        }
+    }
+
+    /// Park `item` in `dlq`. Hands the item back if `dlq` rejects it, so the
+    /// caller can requeue it instead of losing it.
+    async fn dead_letter(
+        &self,
+        dlq: &dyn DeadLetterQueue,
+        item: QueueItem,
+    ) -> Result<(), QueueItem> {
+        let entry = DeadLetterEntry::new(
+            item.payload.clone(),
+            format!("task {} failed {} deliveries", item.id, item.attempts),
+        )
+        .with_attempts(item.attempts);
+        match dlq.push(entry).await {
+            Ok(_) => {
+                self.counters.dead_lettered.fetch_add(1, Ordering::Relaxed);
+                Ok(())
+            },
+            Err(e) => {
+                tracing::warn!(task_id = %item.id, error = %e, "dead-letter queue rejected task; requeuing it");
+                Err(item)
+            },
+        }
+    }
+
+    fn is_closed(&self) -> bool {
+        self.ready.lock().closed
+    }
+
+    /// Add `item` to its priority lane, or to the schedule if `ready_at` is
+    /// set, unless the queue is full or closed.
+    fn try_push(&self, item: QueueItem, ready_at: Option<Instant>) -> Result<(), PushError> {
+        // Same lock order as `promote_due_scheduled`: schedule, then lanes.
+        let mut schedule = self.scheduled.lock();
+        let mut ready = self.ready.lock();
+        if ready.closed {
+            return Err(PushError::Closed(item));
+        }
+        if ready.len() + schedule.heap.len() >= self.capacity {
+            return Err(PushError::Full(item));
+        }
+        match ready_at {
+            Some(ready_at) => schedule.push(item, ready_at),
+            None => ready.lanes[item.priority.level()].push_back(item),
+        }
+        drop(ready);
+        drop(schedule);
+        // A parked consumer also re-checks the earliest scheduled ready time.
+        self.item_ready.notify_one();
+        Ok(())
+    }
+
+    /// Like [`try_push`](Self::try_push), but waits for capacity if the queue
+    /// is full. Hands the item back if the queue is closed.
+    async fn push(&self, mut item: QueueItem, ready_at: Option<Instant>) -> Result<(), QueueItem> {
+        loop {
+            // Register before trying, so a slot freed in between is not missed.
+            let mut space_freed = pin!(self.space_freed.notified());
+            space_freed.as_mut().enable();
+            match self.try_push(item, ready_at) {
+                Ok(()) => return Ok(()),
+                Err(PushError::Closed(item)) => return Err(item),
+                Err(PushError::Full(returned)) => item = returned,
+            }
+            space_freed.await;
+        }
+    }
+
+    /// Take up to `max` ready tasks under a single lock. `Err` once the
+    /// queue is closed and empty.
+    fn pop_ready(&self, max: usize) -> Result<Vec<QueueItem>, ()> {
+        let mut ready = self.ready.lock();
+        let now = Instant::now();
+        let items: Vec<QueueItem> = std::iter::from_fn(|| ready.pop(self.priority_aging, now))
+            .take(max)
+            .collect();
+        if items.is_empty() && ready.closed {
+            return Err(());
+        }
+        drop(ready);
+        for _ in &items {
+            self.space_freed.notify_one();
+        }
+        Ok(items)
+    }
+
+    /// Wait up to `timeout` for ready tasks and take up to `max` of them.
+    ///
+    /// Returns an empty batch on timeout and `Err` once the queue is closed
+    /// and empty.
+    async fn take_ready(&self, max: usize, timeout: Duration) -> Result<Vec<QueueItem>, ()> {
+        // `None` only when `timeout` is too large to represent as an instant,
+        // which is treated as "wait forever".
+        let deadline = Instant::now().checked_add(timeout);
+        loop {
+            // Wake up early when a scheduled task becomes due or a lease
+            // expires, so the task is picked up within this call instead of
+            // waiting for the next one.
+            let next_expiry = self.requeue_expired_leases().await;
+            let next_ready = self.promote_due_scheduled();
+            let wake_at = [next_expiry, next_ready, deadline]
+                .into_iter()
+                .flatten()
+                .min();
+
+            // Register before looking, so a task pushed in between still wakes
+            // this consumer. No lock is held while parked, so concurrent
+            // workers wait in parallel.
+            let mut item_ready = pin!(self.item_ready.notified());
+            item_ready.as_mut().enable();
+            let items = self.pop_ready(max)?;
+            if !items.is_empty() {
+                for item in &items {
+                    self.counters.record_wait(item.enqueued_at.elapsed());
+                }
+                return Ok(items);
+            }
+
+            if let Some(wake_at) = wake_at {
+                let timed_out = tokio::time::timeout_at(wake_at, item_ready).await.is_err();
+                if timed_out && Some(wake_at) == deadline {
+                    return Ok(Vec::new());
+                }
+            } else {
+                item_ready.await;
+            }
         }
     }
 
-    async fn try_reclaim_stale_in_flight(&self) -> Option<QueueItem> {
+    /// Return every in-flight task whose lease expired to the queue, and the
+    /// deadline of the earliest lease still running.
+    ///
+    /// Tasks a concurrent `nack` is already requeuing are skipped. If the
+    /// queue is full the remaining tasks stay leased and are retried on the
+    /// next call, as are exhausted tasks the dead-letter queue rejects.
+    async fn requeue_expired_leases(&self) -> Option<Instant> {
         let now = Instant::now();
         let mut in_flight = self.in_flight.lock().await;
-        let stale_task_id = in_flight
+        let expired: Vec<String> = in_flight
             .iter()
-            .find(|(_, entry)| entry.lease_deadline <= now)
-            .map(|(task_id, _)| task_id.clone())?;
-        in_flight.remove(&stale_task_id).map(|entry| entry.item)
+            .filter(|(_, entry)| !entry.requeuing && entry.lease_deadline <= now)
+            .map(|(task_id, _)| task_id.clone())
+            .collect();
+        let mut exhausted = Vec::new();
+        for task_id in expired {
+            let Some(mut entry) = in_flight.remove(&task_id) else {
+                continue;
+            };
+            if let Some(dlq) = self.dead_letter_target(&entry.item) {
+                exhausted.push((dlq, entry));
+                continue;
+            }
+            entry.item.enqueued_at = now;
+            match self.try_push(entry.item, None) {
+                Ok(()) => {
+                    self.expired.lock().insert(task_id);
+                },
+                Err(PushError::Full(item) | PushError::Closed(item)) => {
+                    entry.item = item;
+                    in_flight.insert(task_id, entry);
+                    break;
+                },
+            }
+        }
+        self.counters.set_in_flight(&in_flight);
+        let next_expiry = in_flight
+            .values()
+            .filter(|entry| !entry.requeuing && entry.lease_deadline > now)
+            .map(|entry| entry.lease_deadline)
+            .min();
+        drop(in_flight);
+
+        // Park outside the lock so a slow dead-letter queue does not stall
+        // ack/nack.
+        for (dlq, entry) in exhausted {
+            if let Err(item) = self.dead_letter(&*dlq, entry.item).await {
+                let mut in_flight = self.in_flight.lock().await;
+                in_flight.insert(item.id.clone(), InFlightEntry { item, ..entry });
+                self.counters.set_in_flight(&in_flight);
+            }
+        }
+        next_expiry
+    }
+
+    /// Check that `entry`, the in-flight entry of `task_id`, is the lease of
+    /// delivery `attempt`.
+    fn check_lease(
+        &self,
+        entry: Option<&InFlightEntry>,
+        task_id: &str,
+        attempt: u32,
+    ) -> Result<(), QueueError> {
+        match entry {
+            Some(entry) if entry.item.attempts == attempt => Ok(()),
+            // The task was redelivered; the caller's lease is gone.
+            Some(_) => Err(QueueError::LeaseExpired {
+                id: task_id.to_owned(),
+            }),
+            None => Err(self.missing_lease(task_id)),
+        }
+    }
+
+    /// Drop the lease of delivery `attempt` of `task_id`, leaving a newer
+    /// delivery's lease in place.
+    async fn release_lease(&self, task_id: &str, attempt: u32) {
+        let mut in_flight = self.in_flight.lock().await;
+        if in_flight
+            .get(task_id)
+            .is_some_and(|entry| entry.item.attempts == attempt)
+        {
+            in_flight.remove(task_id);
+            self.counters.set_in_flight(&in_flight);
+        }
+    }
+
+    /// Error for an ack/nack whose task is not in flight.
+    fn missing_lease(&self, task_id: &str) -> QueueError {
+        if self.expired.lock().contains(task_id) {
+            QueueError::LeaseExpired {
+                id: task_id.to_owned(),
+            }
+        } else {
+            QueueError::not_found("Task", task_id)
+        }
+    }
+
+    /// Move every due scheduled task into the ready lanes.
+    ///
+    /// Scheduled tasks already count against capacity, so promotion never
+    /// waits for space. Returns the ready time of the earliest task still
+    /// scheduled.
+    fn promote_due_scheduled(&self) -> Option<Instant> {
+        let now = Instant::now();
+        let mut schedule = self.scheduled.lock();
+        let mut ready = self.ready.lock();
+        let mut promoted = 0;
+        while !ready.closed
+            && schedule
+                .next_ready_at()
+                .is_some_and(|ready_at| ready_at <= now)
+        {
+            let Some(Reverse(mut entry)) = schedule.heap.pop() else {
+                break;
+            };
+            // Wait time is measured from when the task became ready.
+            entry.item.enqueued_at = entry.ready_at;
+            ready.lanes[entry.item.priority.level()].push_back(entry.item);
+            promoted += 1;
+        }
+        drop(ready);
+        let next_ready_at = schedule.next_ready_at();
+        drop(schedule);
+        for _ in 0..promoted {
+            self.item_ready.notify_one();
+        }
+        next_ready_at
     }
 
-    async fn lease_item(&self, item: QueueItem) -> (String, serde_json::Value) {
-        let id = item.id.clone();
-        let payload = item.payload.clone();
+    /// Lease every item under one in-flight lock.
+    async fn lease_items(&self, items: Vec<QueueItem>) -> Vec<DequeueResult> {
         let lease_deadline = Instant::now() + self.visibility_timeout;
-        self.in_flight.lock().await.insert(
-            id.clone(),
-            InFlightEntry {
-                item,
-                lease_deadline,
-            },
+        let mut in_flight = self.in_flight.lock().await;
+        let leased: Vec<DequeueResult> = items
+            .into_iter()
+            .map(|mut item| {
+                item.attempts = item.attempts.saturating_add(1);
+                let task_id = item.id.clone();
+                let payload = item.payload.clone();
+                let attempt = item.attempts;
+                in_flight.insert(
+                    task_id.clone(),
+                    InFlightEntry {
+                        item,
+                        lease_deadline,
+                        requeuing: false,
+                    },
+                );
+                // The lease now belongs to the new delivery.
+                self.expired.lock().remove(&task_id);
+                DequeueResult::Item {
+                    task_id,
+                    payload,
+                    attempt,
+                }
+            })
+            .collect();
+        self.counters.set_in_flight(&in_flight);
+        drop(in_flight);
+        self.counters
+            .dequeued
+            .fetch_add(leased.len() as u64, Ordering::Relaxed);
+        leased
+    }
+
+    /// Close the queue and hand back the work it still holds.
+    ///
+    /// Returns every queued task (highest priority first) and every scheduled
+    /// task, plus every in-flight (leased, unacked) task when
+    /// `include_in_flight` is set, as `(task_id, payload)` pairs. After this call
+    /// `enqueue` and `nack` fail with [`QueueError::Closed`] and `dequeue` reports
+    /// [`DequeueResult::Closed`]. Intended for graceful shutdown, so a supervisor
+    /// can persist the remaining work.
+    pub async fn drain(&self, include_in_flight: bool) -> Vec<(String, serde_json::Value)> {
+        let lanes = {
+            let mut ready = self.ready.lock();
+            ready.closed = true;
+            std::mem::take(&mut ready.lanes)
+        };
+        self.item_ready.notify_waiters();
+        self.space_freed.notify_waiters();
+        let mut drained: Vec<_> = lanes
+            .into_iter()
+            .rev()
+            .flatten()
+            .map(|item| (item.id, item.payload))
+            .collect();
+        let scheduled = std::mem::take(&mut self.scheduled.lock().heap);
+        drained.extend(
+            scheduled
+                .into_sorted_vec()
+                .into_iter()
+                .rev()
+                .map(|Reverse(entry)| (entry.item.id, entry.item.payload)),
         );
-        (id, payload)
+        if include_in_flight {
+            let mut in_flight = self.in_flight.lock().await;
+            drained.extend(
+                in_flight
+                    .drain()
+                    .map(|(id, entry)| (id, entry.item.payload)),
+            );
+            self.counters.set_in_flight(&in_flight);
+        }
+        self.expired.lock().clear();
+        drained
+    }
+
+    /// Snapshot of queue counters and average wait time.
+    #[must_use]
+    pub fn metrics(&self) -> QueueMetrics {
+        QueueMetrics {
+            enqueued_total: self.counters.enqueued.load(Ordering::Relaxed),
+            dequeued_total: self.counters.dequeued.load(Ordering::Relaxed),
+            acked: self.counters.acked.load(Ordering::Relaxed),
+            nacked: self.counters.nacked.load(Ordering::Relaxed),
+            current_depth: self.queued_count(),
+            in_flight: self.counters.in_flight.load(Ordering::Relaxed),
+            capacity: self.capacity,
+            scheduled_depth: self.scheduled_count(),
+            dead_lettered: self.counters.dead_lettered.load(Ordering::Relaxed),
+            avg_wait: self.counters.avg_wait(),
+        }
     }
 }
 
 impl TaskQueue for MemoryQueue {
     async fn enqueue(&self, payload: serde_json::Value) -> Result<String, QueueError> {
+        self.enqueue_with_priority(payload, TaskPriority::Normal)
+            .await
+    }
+
+    async fn enqueue_with_priority(
+        &self,
+        payload: serde_json::Value,
+        priority: TaskPriority,
+    ) -> Result<String, QueueError> {
         let id = uuid::Uuid::new_v4().to_string();
         let item = QueueItem {
             id: id.clone(),
             payload,
+            enqueued_at: Instant::now(),
+            attempts: 0,
+            priority,
         };
-        self.sender
-            .try_send(item)
-            .map_err(|e| QueueError::Internal(format!("queue full or closed: {e}")))?;
-        self.queued_count.fetch_add(1, Ordering::Relaxed);
+        self.try_push(item, None)?;
+        self.counters.enqueued.fetch_add(1, Ordering::Relaxed);
         Ok(id)
     }
 
     async fn dequeue(&self, timeout: Duration) -> Result<DequeueResult, QueueError> {
-        if let Some(item) = self.try_reclaim_stale_in_flight().await {
-            let (task_id, payload) = self.lease_item(item).await;
-            return Ok(DequeueResult::Item { task_id, payload });
-        }
-
-        // No mutex around `recv()` — `async_channel::Receiver` is multi-consumer
-        // and `recv()` takes `&self`, so concurrent workers register independent
-        // wakers and park in parallel.
-        let result = tokio::time::timeout(timeout, self.receiver.recv()).await;
-        match result {
-            Ok(Ok(item)) => {
-                self.queued_count.fetch_sub(1, Ordering::Relaxed);
-                let (task_id, payload) = self.lease_item(item).await;
-                Ok(DequeueResult::Item { task_id, payload })
-            },
-            Ok(Err(_)) => Ok(DequeueResult::Closed),
-            Err(_) => Ok(DequeueResult::Timeout),
+        let Ok(items) = self.take_ready(1, timeout).await else {
+            return Ok(DequeueResult::Closed);
+        };
+        Ok(self
+            .lease_items(items)
+            .await
+            .pop()
+            .unwrap_or(DequeueResult::Timeout))
+    }
+
+    /// Takes the whole batch under one lock on the ready lanes and leases it
+    /// under one lock on the in-flight map.
+    async fn dequeue_batch(
+        &self,
+        max: usize,
+        timeout: Duration,
+    ) -> Result<Vec<DequeueResult>, QueueError> {
+        if max == 0 {
+            return Ok(Vec::new());
         }
+        let items = self
+            .take_ready(max, timeout)
+            .await
+            .map_err(|()| QueueError::Closed)?;
+        Ok(self.lease_items(items).await)
     }
 
-    async fn ack(&self, task_id: &str) -> Result<(), QueueError> {
-        let removed = self.in_flight.lock().await.remove(task_id);
-        if removed.is_none() {
-            return Err(QueueError::not_found("Task", task_id));
+    async fn enqueue_delayed(
+        &self,
+        payload: serde_json::Value,
+        delay: Duration,
+    ) -> Result<String, QueueError> {
+        if delay.is_zero() {
+            return self.enqueue(payload).await;
         }
+        let id = uuid::Uuid::new_v4().to_string();
+        let now = Instant::now();
+        let item = QueueItem {
+            id: id.clone(),
+            payload,
+            enqueued_at: now,
+            attempts: 0,
+            priority: TaskPriority::Normal,
+        };
+        self.try_push(item, Some(now + delay))?;
+        self.counters.enqueued.fetch_add(1, Ordering::Relaxed);
+        Ok(id)
+    }
+
+    async fn ack(&self, task_id: &str, attempt: u32) -> Result<(), QueueError> {
+        let mut in_flight = self.in_flight.lock().await;
+        self.check_lease(in_flight.get(task_id), task_id, attempt)?;
+        in_flight.remove(task_id);
+        self.counters.set_in_flight(&in_flight);
+        drop(in_flight);
+        self.counters.acked.fetch_add(1, Ordering::Relaxed);
         Ok(())
     }
 
-    async fn nack(&self, task_id: &str) -> Result<(), QueueError> {
-        // Keep the item in-flight until requeue succeeds to preserve
-        // at-least-once guarantees when the queue is saturated.
-        let item = {
-            let in_flight = self.in_flight.lock().await;
-            in_flight.get(task_id).map(|entry| entry.item.clone())
-        };
-        let Some(item) = item else {
-            return Err(QueueError::not_found("Task", task_id));
-        };
+    async fn nack(&self, task_id: &str, attempt: u32) -> Result<(), QueueError> {
+        self.requeue(task_id, attempt, None).await
+    }
 
-        self.sender
-            .send(item)
+    async fn nack_delayed(
+        &self,
+        task_id: &str,
+        attempt: u32,
+        delay: Duration,
+    ) -> Result<(), QueueError> {
+        self.requeue(task_id, attempt, Some(delay).filter(|d| !d.is_zero()))
             .await
-            .map_err(|e| QueueError::Internal(format!("requeue failed: {e}")))?;
-        self.queued_count.fetch_add(1, Ordering::Relaxed);
-        let _ = self.in_flight.lock().await.remove(task_id);
-        Ok(())
     }
 
     async fn len(&self) -> Result<usize, QueueError> {
-        Ok(self.queued_count() + self.in_flight_count().await)
+        self.requeue_expired_leases().await;
+        Ok(self.scheduled_count() + self.queued_count() + self.in_flight_count().await)
     }
 
     async fn queued_len(&self) -> Result<usize, QueueError> {
+        self.requeue_expired_leases().await;
         Ok(self.queued_count())
     }
 
+    async fn scheduled_len(&self) -> Result<usize, QueueError> {
+        Ok(self.scheduled_count())
+    }
+
     async fn in_flight_len(&self) -> Result<usize, QueueError> {
+        self.requeue_expired_leases().await;
         Ok(self.in_flight_count().await)
     }
+
+    /// Based on queued and scheduled (not in-flight) tasks relative to the
+    /// queue capacity.
+    async fn pressure(&self) -> Result<QueuePressure, QueueError> {
+        self.requeue_expired_leases().await;
+        Ok(QueuePressure::from_occupancy(
+            self.queued_count() + self.scheduled_count(),
+            self.capacity,
+        ))
+    }
 }
 
 impl MemoryQueue {
+    /// Requeue delivery `attempt` of `task_id`, invisible until `delay` has
+    /// passed if one is given, or park it in the dead-letter queue once it
+    /// used up its delivery attempts.
+    async fn requeue(
+        &self,
+        task_id: &str,
+        attempt: u32,
+        delay: Option<Duration>,
+    ) -> Result<(), QueueError> {
+        // Keep the item in-flight until requeue succeeds to preserve
+        // at-least-once guarantees when the queue is saturated.
+        if self.is_closed() {
+            return Err(QueueError::Closed);
+        }
+        let (mut item, dlq) = {
+            let mut in_flight = self.in_flight.lock().await;
+            self.check_lease(in_flight.get(task_id), task_id, attempt)?;
+            match in_flight.get_mut(task_id) {
+                Some(entry) if entry.requeuing => {
+                    return Err(QueueError::Internal(format!(
+                        "task {task_id} is already being requeued"
+                    )));
+                },
+                Some(entry) => {
+                    entry.requeuing = true;
+                    (entry.item.clone(), self.dead_letter_target(&entry.item))
+                },
+                None => {
+                    drop(in_flight);
+                    return Err(self.missing_lease(task_id));
+                },
+            }
+        };
+        if let Some(dlq) = dlq {
+            match self.dead_letter(&*dlq, item).await {
+                Ok(()) => {
+                    self.counters.nacked.fetch_add(1, Ordering::Relaxed);
+                    self.release_lease(task_id, attempt).await;
+                    return Ok(());
+                },
+                Err(returned) => item = returned,
+            }
+        }
+        let now = Instant::now();
+        item.enqueued_at = now;
+        if self
+            .push(item, delay.map(|delay| now + delay))
+            .await
+            .is_err()
+        {
+            if let Some(entry) = self.in_flight.lock().await.get_mut(task_id)
+                && entry.item.attempts == attempt
+            {
+                entry.requeuing = false;
+            }
+            return Err(QueueError::Closed);
+        }
+        self.counters.nacked.fetch_add(1, Ordering::Relaxed);
+        // Another worker may already hold the redelivered task; keep its lease.
+        self.release_lease(task_id, attempt).await;
+        Ok(())
+    }
+
     fn queued_count(&self) -> usize {
-        self.queued_count.load(Ordering::Relaxed)
+        self.ready.lock().len()
+    }
+
+    fn scheduled_count(&self) -> usize {
+        self.scheduled.lock().heap.len()
     }
 
     async fn in_flight_count(&self) -> usize {
@@ -275,7 +1181,10 @@ impl MemoryQueue {
 
 #[cfg(test)]
 mod tests {
+    use std::sync::atomic::AtomicUsize;
+
     use super::*;
+    use crate::runtime::MemoryDeadLetterQueue;
 
     #[tokio::test]
     async fn dequeue_reports_timeout_distinct_from_closed() {
@@ -285,11 +1194,63 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn len_includes_in_flight_work() {
-        let queue = MemoryQueue::new(2);
-        let id = queue
-            .enqueue(serde_json::json!({"task":"x"}))
-            .await
+    async fn dequeue_batch_leases_each_item() {
+        let queue = MemoryQueue::new(8);
+        let mut ids = Vec::new();
+        for i in 0..5 {
+            ids.push(queue.enqueue(serde_json::json!({ "i": i })).await.unwrap());
+        }
+
+        let first = queue
+            .dequeue_batch(3, Duration::from_millis(50))
+            .await
+            .unwrap();
+        let second = queue
+            .dequeue_batch(3, Duration::from_millis(50))
+            .await
+            .unwrap();
+        assert_eq!(first.len(), 3);
+        assert_eq!(second.len(), 2);
+        assert_eq!(queue.in_flight_len().await.unwrap(), 5);
+        assert_eq!(queue.metrics().dequeued_total, 5);
+
+        let leased: Vec<String> = first
+            .into_iter()
+            .chain(second)
+            .map(|got| match got {
+                DequeueResult::Item {
+                    task_id, attempt, ..
+                } => {
+                    assert_eq!(attempt, 1);
+                    task_id
+                },
+                other => panic!("expected an item, got {other:?}"),
+            })
+            .collect();
+        assert_eq!(leased, ids);
+        for id in &leased {
+            queue.ack(id, 1).await.unwrap();
+        }
+        assert_eq!(queue.len().await.unwrap(), 0);
+
+        let empty = queue
+            .dequeue_batch(3, Duration::from_millis(10))
+            .await
+            .unwrap();
+        assert!(empty.is_empty());
+        queue.drain(false).await;
+        assert!(matches!(
+            queue.dequeue_batch(3, Duration::from_millis(10)).await,
+            Err(QueueError::Closed)
+        ));
+    }
+
+    #[tokio::test]
+    async fn len_includes_in_flight_work() {
+        let queue = MemoryQueue::new(2);
+        let id = queue
+            .enqueue(serde_json::json!({"task":"x"}))
+            .await
             .unwrap();
         assert_eq!(queue.len().await.unwrap(), 1);
         assert_eq!(queue.queued_len().await.unwrap(), 1);
@@ -315,7 +1276,9 @@ mod tests {
             .await
             .unwrap();
         let (dequeued_id, _) = match queue.dequeue(Duration::from_millis(50)).await.unwrap() {
-            DequeueResult::Item { task_id, payload } => (task_id, payload),
+            DequeueResult::Item {
+                task_id, payload, ..
+            } => (task_id, payload),
             other => panic!("expected dequeued task, got {other:?}"),
         };
         assert_eq!(dequeued_id, first_id);
@@ -328,7 +1291,7 @@ mod tests {
 
         let queue_for_nack = Arc::clone(&queue);
         let id_for_nack = dequeued_id.clone();
-        let nack_task = tokio::spawn(async move { queue_for_nack.nack(&id_for_nack).await });
+        let nack_task = tokio::spawn(async move { queue_for_nack.nack(&id_for_nack, 1).await });
 
         tokio::time::sleep(Duration::from_millis(20)).await;
         assert!(
@@ -339,18 +1302,41 @@ mod tests {
         // Free one slot, then nack should complete and requeue original task.
         let (_filler_id, _filler_payload) =
             match queue.dequeue(Duration::from_millis(50)).await.unwrap() {
-                DequeueResult::Item { task_id, payload } => (task_id, payload),
+                DequeueResult::Item {
+                    task_id, payload, ..
+                } => (task_id, payload),
                 other => panic!("expected filler dequeue, got {other:?}"),
             };
         nack_task.await.unwrap().unwrap();
 
         let (requeued_id, _) = match queue.dequeue(Duration::from_millis(50)).await.unwrap() {
-            DequeueResult::Item { task_id, payload } => (task_id, payload),
+            DequeueResult::Item {
+                task_id, payload, ..
+            } => (task_id, payload),
             other => panic!("expected requeued task, got {other:?}"),
         };
         assert_eq!(requeued_id, dequeued_id);
     }
 
+    #[tokio::test]
+    async fn nack_waiting_for_capacity_reports_closed_when_queue_closes() {
+        let queue = Arc::new(MemoryQueue::new(1));
+        let leased = queue.enqueue(serde_json::json!({"i": 1})).await.unwrap();
+        queue.dequeue(Duration::from_millis(50)).await.unwrap();
+        queue.enqueue(serde_json::json!({"i": 2})).await.unwrap();
+
+        let queue_for_nack = Arc::clone(&queue);
+        let nack_task = tokio::spawn(async move { queue_for_nack.nack(&leased, 1).await });
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert!(
+            !nack_task.is_finished(),
+            "nack should block while queue is full"
+        );
+
+        queue.drain(false).await;
+        assert!(matches!(nack_task.await.unwrap(), Err(QueueError::Closed)));
+    }
+
     #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
     async fn dequeue_supports_concurrent_consumers() {
         // Regression for issue #279 — the previous `Arc<Mutex<Receiver>>`
@@ -366,10 +1352,11 @@ mod tests {
         }
 
         async fn drain(queue: Arc<MemoryQueue>, processed: Arc<AtomicUsize>) {
-            while let DequeueResult::Item { task_id, .. } =
-                queue.dequeue(Duration::from_millis(50)).await.unwrap()
+            while let DequeueResult::Item {
+                task_id, attempt, ..
+            } = queue.dequeue(Duration::from_millis(50)).await.unwrap()
             {
-                queue.ack(&task_id).await.unwrap();
+                queue.ack(&task_id, attempt).await.unwrap();
                 processed.fetch_add(1, Ordering::Relaxed);
             }
         }
@@ -436,7 +1423,9 @@ mod tests {
             .await
             .unwrap();
         let (first_delivery, _) = match queue.dequeue(Duration::from_millis(50)).await.unwrap() {
-            DequeueResult::Item { task_id, payload } => (task_id, payload),
+            DequeueResult::Item {
+                task_id, payload, ..
+            } => (task_id, payload),
             other => panic!("expected first delivery, got {other:?}"),
         };
         assert_eq!(first_delivery, id);
@@ -444,9 +1433,648 @@ mod tests {
         tokio::time::sleep(Duration::from_millis(30)).await;
 
         let (second_delivery, _) = match queue.dequeue(Duration::from_millis(50)).await.unwrap() {
-            DequeueResult::Item { task_id, payload } => (task_id, payload),
+            DequeueResult::Item {
+                task_id, payload, ..
+            } => (task_id, payload),
             other => panic!("expected stale redelivery, got {other:?}"),
         };
         assert_eq!(second_delivery, id);
     }
+
+    #[tokio::test(start_paused = true)]
+    async fn parked_dequeue_picks_up_expired_lease() {
+        let queue = MemoryQueue::new(4).with_visibility_timeout(Duration::from_secs(10));
+        let id = queue
+            .enqueue(serde_json::json!({"task": "crashed"}))
+            .await
+            .unwrap();
+        queue.dequeue(Duration::from_millis(10)).await.unwrap();
+        assert_eq!(queue.in_flight_len().await.unwrap(), 1);
+
+        // The worker never acks; a parked consumer gets the task once the
+        // lease expires, well before its own timeout.
+        let started = Instant::now();
+        let got = queue.dequeue(Duration::from_mins(1)).await.unwrap();
+        assert!(matches!(
+            got,
+            DequeueResult::Item { task_id, payload, attempt: 2 }
+                if task_id == id && payload == serde_json::json!({"task": "crashed"})
+        ));
+        assert_eq!(started.elapsed(), Duration::from_secs(10));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn lengths_count_expired_lease_as_queued() {
+        let queue = MemoryQueue::new(4).with_visibility_timeout(Duration::from_secs(10));
+        queue.enqueue(serde_json::json!({})).await.unwrap();
+        queue.dequeue(Duration::from_millis(10)).await.unwrap();
+        assert_eq!(queue.queued_len().await.unwrap(), 0);
+
+        tokio::time::sleep(Duration::from_secs(11)).await;
+        assert_eq!(queue.in_flight_len().await.unwrap(), 0);
+        assert_eq!(queue.queued_len().await.unwrap(), 1);
+        assert_eq!(queue.len().await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn metrics_track_operations_and_wait_time() {
+        let queue = MemoryQueue::new(4);
+        assert_eq!(
+            queue.metrics(),
+            QueueMetrics {
+                capacity: 4,
+                ..QueueMetrics::default()
+            }
+        );
+
+        let first = queue.enqueue(serde_json::json!({"i": 1})).await.unwrap();
+        queue.enqueue(serde_json::json!({"i": 2})).await.unwrap();
+        assert_eq!(queue.metrics().current_depth, 2);
+
+        tokio::time::sleep(Duration::from_millis(10)).await;
+
+        let DequeueResult::Item { task_id, .. } =
+            queue.dequeue(Duration::from_millis(50)).await.unwrap()
+        else {
+            panic!("expected first item");
+        };
+        assert_eq!(task_id, first);
+        queue.ack(&task_id, 1).await.unwrap();
+
+        let DequeueResult::Item { task_id, .. } =
+            queue.dequeue(Duration::from_millis(50)).await.unwrap()
+        else {
+            panic!("expected second item");
+        };
+        queue.nack(&task_id, 1).await.unwrap();
+        assert!(queue.ack("missing", 1).await.is_err());
+
+        let metrics = queue.metrics();
+        assert_eq!(metrics.enqueued_total, 2);
+        assert_eq!(metrics.dequeued_total, 2);
+        assert_eq!(metrics.acked, 1);
+        assert_eq!(metrics.nacked, 1);
+        assert_eq!(metrics.current_depth, 1);
+        assert!(metrics.avg_wait >= Duration::from_millis(10));
+    }
+
+    #[tokio::test]
+    async fn metrics_track_in_flight_and_cumulative_totals() {
+        async fn lease(queue: &MemoryQueue) -> String {
+            match queue.dequeue(Duration::from_millis(50)).await.unwrap() {
+                DequeueResult::Item { task_id, .. } => task_id,
+                other => panic!("expected an item, got {other:?}"),
+            }
+        }
+
+        let queue = MemoryQueue::new(8);
+        for i in 0..3 {
+            queue.enqueue(serde_json::json!({ "i": i })).await.unwrap();
+        }
+        let first = lease(&queue).await;
+        let second = lease(&queue).await;
+        let metrics = queue.metrics();
+        assert_eq!(metrics.current_depth, 1);
+        assert_eq!(metrics.in_flight, 2);
+
+        queue.ack(&first, 1).await.unwrap();
+        queue.nack(&second, 1).await.unwrap();
+        let third = lease(&queue).await;
+        queue
+            .nack_delayed(&third, 1, Duration::from_mins(1))
+            .await
+            .unwrap();
+        let redelivered = lease(&queue).await;
+        assert_eq!(redelivered, second);
+        queue.ack(&redelivered, 2).await.unwrap();
+
+        let metrics = queue.metrics();
+        assert_eq!(metrics.enqueued_total, 3);
+        assert_eq!(metrics.dequeued_total, 4);
+        assert_eq!(metrics.acked, 2);
+        assert_eq!(metrics.nacked, 2);
+        assert_eq!(metrics.current_depth, 0);
+        assert_eq!(metrics.scheduled_depth, 1);
+        assert_eq!(metrics.in_flight, 0);
+        assert_eq!(metrics.capacity, 8);
+
+        let json = serde_json::to_value(metrics).unwrap();
+        assert_eq!(json["in_flight"], 0);
+        assert_eq!(json["acked"], 2);
+    }
+
+    #[tokio::test]
+    async fn drain_returns_pending_work_and_closes_queue() {
+        let queue = MemoryQueue::new(4);
+        let leased = queue.enqueue(serde_json::json!({"i": 1})).await.unwrap();
+        let queued = queue.enqueue(serde_json::json!({"i": 2})).await.unwrap();
+        assert!(matches!(
+            queue.dequeue(Duration::from_millis(50)).await.unwrap(),
+            DequeueResult::Item { task_id, .. } if task_id == leased
+        ));
+
+        let drained = queue.drain(false).await;
+        assert_eq!(drained, vec![(queued, serde_json::json!({"i": 2}))]);
+        assert_eq!(queue.queued_len().await.unwrap(), 0);
+
+        assert!(matches!(
+            queue.enqueue(serde_json::json!({"i": 3})).await,
+            Err(QueueError::Closed)
+        ));
+        assert!(matches!(
+            queue.nack(&leased, 1).await,
+            Err(QueueError::Closed)
+        ));
+        assert_eq!(
+            queue.dequeue(Duration::from_millis(10)).await.unwrap(),
+            DequeueResult::Closed
+        );
+    }
+
+    #[tokio::test]
+    async fn drain_can_include_in_flight_work() {
+        let queue = MemoryQueue::new(4);
+        let leased = queue.enqueue(serde_json::json!({"i": 1})).await.unwrap();
+        let queued = queue.enqueue(serde_json::json!({"i": 2})).await.unwrap();
+        queue.dequeue(Duration::from_millis(50)).await.unwrap();
+
+        let mut ids: Vec<String> = queue
+            .drain(true)
+            .await
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect();
+        ids.sort();
+        let mut expected = vec![leased, queued];
+        expected.sort();
+        assert_eq!(ids, expected);
+        assert!(queue.is_empty().await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn pressure_rises_as_queue_fills() {
+        let queue = MemoryQueue::new(10);
+        assert_eq!(queue.pressure().await.unwrap(), QueuePressure::Low);
+
+        let mut seen = Vec::new();
+        for i in 0..10 {
+            queue.enqueue(serde_json::json!({ "i": i })).await.unwrap();
+            seen.push(queue.pressure().await.unwrap());
+        }
+        assert_eq!(seen[3], QueuePressure::Low);
+        assert_eq!(seen[4], QueuePressure::Medium);
+        assert_eq!(seen[6], QueuePressure::Medium);
+        assert_eq!(seen[7], QueuePressure::High);
+        assert!(seen.windows(2).all(|w| w[0] <= w[1]));
+
+        while let DequeueResult::Item {
+            task_id, attempt, ..
+        } = queue.dequeue(Duration::from_millis(10)).await.unwrap()
+        {
+            queue.ack(&task_id, attempt).await.unwrap();
+        }
+        assert_eq!(queue.pressure().await.unwrap(), QueuePressure::Low);
+    }
+
+    #[test]
+    fn zero_capacity_is_high_pressure() {
+        assert_eq!(QueuePressure::from_occupancy(0, 0), QueuePressure::High);
+    }
+
+    #[tokio::test]
+    async fn dequeue_reports_delivery_attempt() {
+        let queue = MemoryQueue::new(2);
+        let id = queue.enqueue(serde_json::json!({})).await.unwrap();
+
+        let got = queue.dequeue(Duration::from_millis(10)).await.unwrap();
+        assert!(matches!(got, DequeueResult::Item { attempt: 1, .. }));
+        queue.nack(&id, 1).await.unwrap();
+
+        let got = queue.dequeue(Duration::from_millis(10)).await.unwrap();
+        assert!(matches!(
+            got,
+            DequeueResult::Item { task_id, attempt: 2, .. } if task_id == id
+        ));
+    }
+
+    #[tokio::test]
+    async fn late_ack_after_lease_expiry_is_distinct_error() {
+        let queue = MemoryQueue::new_with_visibility_timeout(2, Duration::from_millis(20));
+        let stale = queue.enqueue(serde_json::json!({"i": 1})).await.unwrap();
+        queue.dequeue(Duration::from_millis(10)).await.unwrap();
+        let other = queue.enqueue(serde_json::json!({"i": 2})).await.unwrap();
+
+        tokio::time::sleep(Duration::from_millis(30)).await;
+
+        // This dequeue requeues the expired lease behind `other`.
+        let got = queue.dequeue(Duration::from_millis(10)).await.unwrap();
+        assert!(matches!(got, DequeueResult::Item { task_id, .. } if task_id == other));
+        assert!(matches!(
+            queue.ack(&stale, 1).await,
+            Err(QueueError::LeaseExpired { id }) if id == stale
+        ));
+        assert!(matches!(
+            queue.nack(&stale, 1).await,
+            Err(QueueError::LeaseExpired { .. })
+        ));
+        assert!(matches!(
+            queue.ack("unknown", 1).await,
+            Err(QueueError::NotFound { .. })
+        ));
+
+        // Once redelivered, the new lease can be acked normally.
+        let got = queue.dequeue(Duration::from_millis(10)).await.unwrap();
+        assert!(matches!(
+            got,
+            DequeueResult::Item { task_id, attempt: 2, .. } if task_id == stale
+        ));
+        queue.ack(&stale, 2).await.unwrap();
+        assert!(matches!(
+            queue.ack(&stale, 2).await,
+            Err(QueueError::NotFound { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn stale_ack_after_redelivery_keeps_new_lease() {
+        let queue = MemoryQueue::new_with_visibility_timeout(2, Duration::from_millis(20));
+        let id = queue.enqueue(serde_json::json!({})).await.unwrap();
+        queue.dequeue(Duration::from_millis(10)).await.unwrap();
+
+        tokio::time::sleep(Duration::from_millis(30)).await;
+
+        // The expired task is redelivered to a second worker...
+        let got = queue.dequeue(Duration::from_millis(10)).await.unwrap();
+        assert!(matches!(
+            got,
+            DequeueResult::Item { ref task_id, attempt: 2, .. } if *task_id == id
+        ));
+
+        // ...so the first worker's late ack and nack must not touch its lease.
+        assert!(matches!(
+            queue.ack(&id, 1).await,
+            Err(QueueError::LeaseExpired { .. })
+        ));
+        assert!(matches!(
+            queue.nack_delayed(&id, 1, Duration::from_secs(1)).await,
+            Err(QueueError::LeaseExpired { .. })
+        ));
+        assert_eq!(queue.in_flight_len().await.unwrap(), 1);
+        queue.ack(&id, 2).await.unwrap();
+        assert!(queue.is_empty().await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn reaper_skips_task_being_nacked() {
+        let queue = MemoryQueue::new_with_visibility_timeout(2, Duration::from_millis(20));
+        let id = queue.enqueue(serde_json::json!({})).await.unwrap();
+        queue.dequeue(Duration::from_millis(10)).await.unwrap();
+
+        // Simulate a nack that has claimed the task but not finished requeuing.
+        queue.in_flight.lock().await.get_mut(&id).unwrap().requeuing = true;
+        tokio::time::sleep(Duration::from_millis(30)).await;
+
+        queue.requeue_expired_leases().await;
+        assert_eq!(queue.queued_len().await.unwrap(), 0);
+        assert_eq!(queue.in_flight_len().await.unwrap(), 1);
+        assert!(matches!(
+            queue.nack(&id, 1).await,
+            Err(QueueError::Internal(_))
+        ));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn delayed_task_is_invisible_until_ready() {
+        let queue = MemoryQueue::new(4);
+        let id = queue
+            .enqueue_delayed(serde_json::json!({"retry": 1}), Duration::from_secs(30))
+            .await
+            .unwrap();
+
+        assert_eq!(queue.scheduled_len().await.unwrap(), 1);
+        assert_eq!(queue.queued_len().await.unwrap(), 0);
+        assert_eq!(queue.len().await.unwrap(), 1);
+        assert_eq!(
+            queue.dequeue(Duration::from_secs(10)).await.unwrap(),
+            DequeueResult::Timeout
+        );
+
+        // A parked dequeue wakes up when the task becomes due.
+        let got = queue.dequeue(Duration::from_mins(1)).await.unwrap();
+        assert!(matches!(got, DequeueResult::Item { task_id, .. } if task_id == id));
+        assert_eq!(queue.scheduled_len().await.unwrap(), 0);
+        assert_eq!(queue.metrics().enqueued_total, 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn delayed_tasks_are_delivered_by_ready_time() {
+        let queue = MemoryQueue::new(4);
+        let late = queue
+            .enqueue_delayed(serde_json::json!({"i": "late"}), Duration::from_secs(20))
+            .await
+            .unwrap();
+        let early = queue
+            .enqueue_delayed(serde_json::json!({"i": "early"}), Duration::from_secs(10))
+            .await
+            .unwrap();
+
+        tokio::time::advance(Duration::from_secs(25)).await;
+
+        let mut order = Vec::new();
+        while let DequeueResult::Item { task_id, .. } =
+            queue.dequeue(Duration::from_millis(10)).await.unwrap()
+        {
+            order.push(task_id);
+        }
+        assert_eq!(order, vec![early, late]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn delayed_enqueue_respects_capacity() {
+        let queue = MemoryQueue::new(1);
+        queue
+            .enqueue_delayed(serde_json::json!({}), Duration::from_secs(1))
+            .await
+            .unwrap();
+        assert!(matches!(
+            queue
+                .enqueue_delayed(serde_json::json!({}), Duration::from_secs(1))
+                .await,
+            Err(QueueError::Full)
+        ));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn enqueue_counts_scheduled_tasks_against_capacity() {
+        let queue = MemoryQueue::new(1);
+        queue
+            .enqueue_delayed(serde_json::json!({}), Duration::from_secs(1))
+            .await
+            .unwrap();
+        assert!(matches!(
+            queue.enqueue(serde_json::json!({})).await,
+            Err(QueueError::Full)
+        ));
+        assert_eq!(queue.pressure().await.unwrap(), QueuePressure::High);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn nack_delayed_waits_for_capacity() {
+        let queue = Arc::new(MemoryQueue::new(1));
+        let leased = queue.enqueue(serde_json::json!({})).await.unwrap();
+        queue.dequeue(Duration::from_millis(10)).await.unwrap();
+        let filler = queue
+            .enqueue_delayed(serde_json::json!({}), Duration::from_secs(1))
+            .await
+            .unwrap();
+
+        let queue_for_nack = Arc::clone(&queue);
+        let id_for_nack = leased.clone();
+        let nack_task = tokio::spawn(async move {
+            queue_for_nack
+                .nack_delayed(&id_for_nack, 1, Duration::from_secs(5))
+                .await
+        });
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert!(
+            !nack_task.is_finished(),
+            "nack_delayed should wait for space"
+        );
+        assert_eq!(queue.scheduled_len().await.unwrap(), 1);
+        assert_eq!(queue.in_flight_len().await.unwrap(), 1);
+
+        let got = queue.dequeue(Duration::from_secs(2)).await.unwrap();
+        assert!(matches!(got, DequeueResult::Item { task_id, .. } if task_id == filler));
+        nack_task.await.unwrap().unwrap();
+        assert_eq!(queue.scheduled_len().await.unwrap(), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn nack_delayed_requeues_with_backoff() {
+        let queue = MemoryQueue::new(4);
+        let id = queue
+            .enqueue(serde_json::json!({"task": "x"}))
+            .await
+            .unwrap();
+        queue.dequeue(Duration::from_millis(10)).await.unwrap();
+
+        queue
+            .nack_delayed(&id, 1, Duration::from_secs(5))
+            .await
+            .unwrap();
+        assert_eq!(queue.in_flight_len().await.unwrap(), 0);
+        assert_eq!(queue.scheduled_len().await.unwrap(), 1);
+        assert_eq!(queue.metrics().nacked, 1);
+        assert_eq!(
+            queue.dequeue(Duration::from_secs(1)).await.unwrap(),
+            DequeueResult::Timeout
+        );
+
+        let got = queue.dequeue(Duration::from_secs(10)).await.unwrap();
+        assert!(matches!(got, DequeueResult::Item { task_id, .. } if task_id == id));
+        assert!(matches!(
+            queue
+                .nack_delayed("missing", 1, Duration::from_secs(1))
+                .await,
+            Err(QueueError::NotFound { .. })
+        ));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn drain_returns_scheduled_work() {
+        let queue = MemoryQueue::new(4);
+        let queued = queue.enqueue(serde_json::json!({"i": 1})).await.unwrap();
+        let scheduled = queue
+            .enqueue_delayed(serde_json::json!({"i": 2}), Duration::from_mins(1))
+            .await
+            .unwrap();
+
+        let ids: Vec<String> = queue
+            .drain(false)
+            .await
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect();
+        assert_eq!(ids, vec![queued, scheduled]);
+        assert!(queue.is_empty().await.unwrap());
+        assert!(matches!(
+            queue
+                .enqueue_delayed(serde_json::json!({}), Duration::from_secs(1))
+                .await,
+            Err(QueueError::Closed)
+        ));
+    }
+
+    /// A queue whose exhausted tasks are parked in a dead-letter queue that
+    /// replays into it.
+    fn queue_with_dead_letters(
+        queue: MemoryQueue,
+    ) -> (Arc<MemoryQueue>, Arc<MemoryDeadLetterQueue<MemoryQueue>>) {
+        let queue = Arc::new(queue);
+        let dlq = Arc::new(MemoryDeadLetterQueue::new(Arc::clone(&queue)));
+        queue.set_dead_letter_queue(dlq.clone());
+        (queue, dlq)
+    }
+
+    #[tokio::test]
+    async fn nack_on_last_attempt_dead_letters_task() {
+        let (queue, dlq) =
+            queue_with_dead_letters(MemoryQueue::new(2).with_max_delivery_attempts(2));
+        let id = queue.enqueue(serde_json::json!({"i": 1})).await.unwrap();
+
+        queue.dequeue(Duration::from_millis(10)).await.unwrap();
+        queue.nack(&id, 1).await.unwrap();
+        let got = queue.dequeue(Duration::from_millis(10)).await.unwrap();
+        assert!(matches!(got, DequeueResult::Item { attempt: 2, .. }));
+        queue.nack(&id, 2).await.unwrap();
+
+        // Dead letters are not queued work.
+        assert_eq!(queue.len().await.unwrap(), 0);
+        assert_eq!(
+            queue.dequeue(Duration::from_millis(10)).await.unwrap(),
+            DequeueResult::Timeout
+        );
+        assert!(matches!(
+            queue.ack(&id, 2).await,
+            Err(QueueError::NotFound { .. })
+        ));
+
+        let dead = dlq.list().await.unwrap();
+        assert_eq!(dead.len(), 1);
+        assert_eq!(dead[0].payload, serde_json::json!({"i": 1}));
+        assert_eq!(dead[0].attempts, 2);
+        assert!(dead[0].reason.contains(&id));
+        assert_eq!(queue.metrics().dead_lettered, 1);
+    }
+
+    #[tokio::test]
+    async fn delivery_limit_needs_a_dead_letter_queue() {
+        let queue = MemoryQueue::new(4).with_max_delivery_attempts(1);
+        let id = queue.enqueue(serde_json::json!({"i": 1})).await.unwrap();
+
+        for attempt in 1..=3 {
+            let got = queue.dequeue(Duration::from_millis(10)).await.unwrap();
+            assert!(matches!(got, DequeueResult::Item { attempt: a, .. } if a == attempt));
+            queue.nack(&id, attempt).await.unwrap();
+        }
+        assert_eq!(queue.queued_len().await.unwrap(), 1);
+        assert_eq!(queue.metrics().dead_lettered, 0);
+    }
+
+    #[tokio::test]
+    async fn expired_lease_on_last_attempt_dead_letters_task() {
+        let (queue, dlq) = queue_with_dead_letters(
+            MemoryQueue::new_with_visibility_timeout(2, Duration::from_millis(20))
+                .with_max_delivery_attempts(1),
+        );
+        queue.enqueue(serde_json::json!({"i": 1})).await.unwrap();
+        queue.dequeue(Duration::from_millis(10)).await.unwrap();
+
+        tokio::time::sleep(Duration::from_millis(30)).await;
+
+        assert_eq!(
+            queue.dequeue(Duration::from_millis(10)).await.unwrap(),
+            DequeueResult::Timeout
+        );
+        assert!(queue.is_empty().await.unwrap());
+        assert_eq!(
+            dlq.list().await.unwrap()[0].payload,
+            serde_json::json!({"i": 1})
+        );
+    }
+
+    #[tokio::test]
+    async fn replayed_dead_letter_is_delivered_as_a_new_task() {
+        let (queue, dlq) =
+            queue_with_dead_letters(MemoryQueue::new(2).with_max_delivery_attempts(1));
+        let id = queue.enqueue(serde_json::json!({"i": 1})).await.unwrap();
+        queue.dequeue(Duration::from_millis(10)).await.unwrap();
+        queue
+            .nack_delayed(&id, 1, Duration::from_mins(1))
+            .await
+            .unwrap();
+        assert_eq!(queue.scheduled_len().await.unwrap(), 0);
+
+        let entry = dlq.list().await.unwrap().remove(0);
+        let replayed = dlq.replay(&entry.id).await.unwrap();
+        assert_ne!(replayed, id);
+        let got = queue.dequeue(Duration::from_millis(10)).await.unwrap();
+        assert!(matches!(
+            got,
+            DequeueResult::Item { task_id, attempt: 1, .. } if task_id == replayed
+        ));
+    }
+
+    async fn dequeue_ids(queue: &MemoryQueue) -> Vec<String> {
+        let mut ids = Vec::new();
+        while let DequeueResult::Item { task_id, .. } =
+            queue.dequeue(Duration::from_millis(10)).await.unwrap()
+        {
+            ids.push(task_id);
+        }
+        ids
+    }
+
+    #[tokio::test]
+    async fn higher_priority_is_dequeued_first_and_fifo_within_a_priority() {
+        let queue = MemoryQueue::new(8);
+        let normal_1 = queue.enqueue(serde_json::json!({})).await.unwrap();
+        let low = queue
+            .enqueue_with_priority(serde_json::json!({}), TaskPriority::Low)
+            .await
+            .unwrap();
+        let normal_2 = queue.enqueue(serde_json::json!({})).await.unwrap();
+        let high_1 = queue
+            .enqueue_with_priority(serde_json::json!({}), TaskPriority::High)
+            .await
+            .unwrap();
+        let high_2 = queue
+            .enqueue_with_priority(serde_json::json!({}), TaskPriority::High)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            dequeue_ids(&queue).await,
+            vec![high_1, high_2, normal_1, normal_2, low]
+        );
+    }
+
+    #[tokio::test]
+    async fn requeued_task_keeps_its_priority() {
+        let queue = MemoryQueue::new(4);
+        let high = queue
+            .enqueue_with_priority(serde_json::json!({}), TaskPriority::High)
+            .await
+            .unwrap();
+        queue.dequeue(Duration::from_millis(10)).await.unwrap();
+        let normal = queue.enqueue(serde_json::json!({})).await.unwrap();
+        queue.nack(&high, 1).await.unwrap();
+
+        assert_eq!(dequeue_ids(&queue).await, vec![high, normal]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn priority_aging_stops_high_priority_work_starving_normal() {
+        let queue = MemoryQueue::new(8).with_priority_aging(Duration::from_secs(1));
+        let normal = queue.enqueue(serde_json::json!({})).await.unwrap();
+
+        // A fresh high task outranks a normal task that has not aged yet.
+        tokio::time::advance(Duration::from_millis(500)).await;
+        let high = queue
+            .enqueue_with_priority(serde_json::json!({}), TaskPriority::High)
+            .await
+            .unwrap();
+        assert!(matches!(
+            queue.dequeue(Duration::from_millis(10)).await.unwrap(),
+            DequeueResult::Item { task_id, .. } if task_id == high
+        ));
+
+        // Two intervals later the normal task outranks every fresh high task.
+        tokio::time::advance(Duration::from_secs(2)).await;
+        let fresh_high = queue
+            .enqueue_with_priority(serde_json::json!({}), TaskPriority::High)
+            .await
+            .unwrap();
+        assert_eq!(dequeue_ids(&queue).await, vec![normal, fresh_high]);
+    }
 }
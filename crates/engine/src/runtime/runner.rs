@@ -37,7 +37,7 @@ impl ActionRunContext {
     /// Check whether execution has been cancelled.
     pub fn check_cancelled(&self) -> Result<(), ActionError> {
         if self.cancellation.is_cancelled() {
-            Err(ActionError::Cancelled)
+            Err(ActionError::cancelled(None))
         } else {
             Ok(())
         }
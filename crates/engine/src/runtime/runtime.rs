@@ -1,7 +1,7 @@
 //! Action runtime -- the main execution orchestrator.
 //!
 //! Resolves actions from the registry, executes them through the runner,
-//! enforces data limits, and records metrics.
+//! enforces data limits (and optionally output schemas), and records metrics.
 
 use std::{sync::Arc, time::Instant};
 
@@ -19,13 +19,16 @@ use nebula_metrics::naming::{
     NEBULA_ACTION_EXECUTIONS_TOTAL, NEBULA_ACTION_FAILURES_TOTAL, dispatch_reject_reason,
 };
 use nebula_metrics::{Counter, Histogram, MetricsError, MetricsRegistry};
+use nebula_schema::{SchemaKind, ValidationReport};
 use nebula_workflow::NodeDefinition;
 use serde::{Deserialize, Serialize};
 
 use super::{
     blob::BlobStorage,
     data_policy::{DataPassingPolicy, LargeDataStrategy},
+    dead_letter::{DeadLetterEntry, DeadLetterQueue},
     error::RuntimeError,
+    idempotency::{IdempotencyClaim, IdempotencyKeyStrategy, IdempotencyManager},
     registry::ActionRegistry,
     runner::{ActionRunContext, ActionRunner},
 };
@@ -136,6 +139,10 @@ pub struct ActionRuntime {
     action_duration_seconds: Histogram,
     action_executions_total: Counter,
     blob_storage: Option<Arc<dyn BlobStorage>>,
+    /// Receives executions that failed with a fatal [`ActionError`].
+    dead_letter: Option<Arc<dyn DeadLetterQueue>>,
+    /// Derives dispatch keys and dedupes dispatches that already completed.
+    idempotency: Option<(Arc<dyn IdempotencyKeyStrategy>, Arc<IdempotencyManager>)>,
     /// Sum of estimated output bytes per execution for
     /// [`DataPassingPolicy::max_total_execution_bytes`].
     execution_output_totals: Arc<DashMap<ExecutionId, u64>>,
@@ -167,6 +174,8 @@ impl ActionRuntime {
             action_duration_seconds,
             action_executions_total,
             blob_storage: None,
+            dead_letter: None,
+            idempotency: None,
             execution_output_totals: Arc::new(DashMap::new()),
         })
     }
@@ -194,6 +203,36 @@ impl ActionRuntime {
         self
     }
 
+    /// Set a dead-letter queue for fatally-failed executions.
+    ///
+    /// When an action fails with a fatal [`ActionError`] (one retry policy
+    /// will never re-dispatch), the runtime pushes a [`DeadLetterEntry`] whose
+    /// payload is `{"action_key", "execution_id", "input"}` before returning
+    /// the error. A failed push is logged and does not mask the action error.
+    #[must_use]
+    pub fn with_dead_letter_queue(mut self, queue: Arc<dyn DeadLetterQueue>) -> Self {
+        self.dead_letter = Some(queue);
+        self
+    }
+
+    /// Deduplicate dispatches by idempotency key.
+    ///
+    /// Before running an action the runtime asks `strategy` for a key. If
+    /// `manager` already holds a result for that key, the recorded result is
+    /// returned without running the action; otherwise the key is reserved and
+    /// a successful result is recorded under it. A dispatch that overlaps one
+    /// already running with the same key waits for it and returns its result.
+    /// Dispatches the strategy returns `None` for run as usual.
+    #[must_use]
+    pub fn with_idempotency(
+        mut self,
+        strategy: Arc<dyn IdempotencyKeyStrategy>,
+        manager: Arc<IdempotencyManager>,
+    ) -> Self {
+        self.idempotency = Some((strategy, manager));
+        self
+    }
+
     /// Access the data passing policy.
     pub fn data_policy(&self) -> &DataPassingPolicy {
         &self.data_policy
@@ -371,16 +410,42 @@ impl ActionRuntime {
         let (metadata, factory) = factory_lookup.ok_or_else(|| RuntimeError::ActionNotFound {
             key: action_key_str.to_owned(),
         })?;
-        self.run_factory(
-            action_key_str,
-            metadata,
-            factory,
-            node,
-            input,
-            context,
-            checkpoint,
-        )
-        .await
+
+        let idempotency = self.idempotency.as_ref().and_then(|(strategy, manager)| {
+            strategy
+                .key(action_key_str, &input)
+                .map(|key| (key, manager))
+        });
+        let reservation = match &idempotency {
+            Some((key, manager)) => match manager.acquire(key).await {
+                IdempotencyClaim::Completed(result) => {
+                    tracing::debug!(
+                        action_key = %action_key_str,
+                        idempotency_key = %key,
+                        "dispatch deduplicated by idempotency key"
+                    );
+                    return Ok(result);
+                },
+                IdempotencyClaim::Reserved(guard) => Some(guard),
+            },
+            None => None,
+        };
+
+        let result = self
+            .run_factory(
+                action_key_str,
+                metadata,
+                factory,
+                node,
+                input,
+                context,
+                checkpoint,
+            )
+            .await?;
+        if let Some(guard) = reservation {
+            guard.complete(result.clone());
+        }
+        Ok(result)
     }
 
     /// Dispatch through the factory path — instantiate a fresh
@@ -424,6 +489,10 @@ impl ActionRuntime {
 
         let started = Instant::now();
 
+        // Handlers consume the input, so keep a copy only when it may need to
+        // be dead-lettered.
+        let dead_letter_input = self.dead_letter.as_ref().map(|_| input.clone());
+
         // Instantiate the action via the factory. Slot-binding resolution
         // (and any FromWorkflowNode user code) runs here.
         let handle = match factory.instantiate(node, context).await {
@@ -432,6 +501,10 @@ impl ActionRuntime {
                 let result: Result<ActionResult<serde_json::Value>, RuntimeError> =
                     Err(RuntimeError::ActionError(e));
                 self.observe_dispatched(started, &result);
+                if let Err(err) = &result {
+                    self.dead_letter_if_fatal(action_key, execution_id, dead_letter_input, err)
+                        .await;
+                }
                 return result;
             },
         };
@@ -497,6 +570,12 @@ impl ActionRuntime {
 
         match result {
             Ok(mut action_result) => {
+                self.enforce_output_schema(
+                    action_key,
+                    &metadata,
+                    &mut action_result,
+                    error_counter,
+                )?;
                 self.enforce_data_limit(
                     action_key,
                     execution_id,
@@ -506,7 +585,49 @@ impl ActionRuntime {
                 .await?;
                 Ok(action_result)
             },
-            Err(runtime_err) => Err(runtime_err),
+            Err(runtime_err) => {
+                self.dead_letter_if_fatal(
+                    action_key,
+                    execution_id,
+                    dead_letter_input,
+                    &runtime_err,
+                )
+                .await;
+                Err(runtime_err)
+            },
+        }
+    }
+
+    /// Push a fatally-failed execution to the dead-letter queue, if one is
+    /// configured. `input` is `None` exactly when no queue is configured.
+    async fn dead_letter_if_fatal(
+        &self,
+        action_key: &str,
+        execution_id: ExecutionId,
+        input: Option<serde_json::Value>,
+        err: &RuntimeError,
+    ) {
+        let (Some(queue), Some(input)) = (self.dead_letter.as_deref(), input) else {
+            return;
+        };
+        if !err.as_action_error().is_some_and(ActionError::is_fatal) {
+            return;
+        }
+        let payload = serde_json::json!({
+            "action_key": action_key,
+            "execution_id": execution_id,
+            "input": input,
+        });
+        if let Err(push_err) = queue
+            .push(DeadLetterEntry::new(payload, err.to_string()))
+            .await
+        {
+            tracing::warn!(
+                action_key,
+                %execution_id,
+                error = %push_err,
+                "failed to dead-letter fatally-failed execution"
+            );
         }
     }
 
@@ -600,7 +721,7 @@ impl ActionRuntime {
         }
 
         if context.cancellation().is_cancelled() {
-            return Err(ActionError::Cancelled.into());
+            return Err(ActionError::cancelled(context.cancellation_reason()).into());
         }
 
         let (mut state, mut iteration) = match checkpoint.as_deref() {
@@ -634,7 +755,7 @@ impl ActionRuntime {
             }
 
             if context.cancellation().is_cancelled() {
-                return Err(ActionError::Cancelled.into());
+                return Err(ActionError::cancelled(context.cancellation_reason()).into());
             }
 
             let state_digest_before = stateful_state_digest(&state);
@@ -646,7 +767,7 @@ impl ActionRuntime {
                 tokio::select! {
                     biased;
                     () = context.cancellation().cancelled() => {
-                        return Err(ActionError::Cancelled.into());
+                        return Err(ActionError::cancelled(context.cancellation_reason()).into());
                     }
                     res = &mut exec_fut => res,
                 }
@@ -675,7 +796,7 @@ impl ActionRuntime {
                         tokio::select! {
                             () = tokio::time::sleep(d) => {}
                             () = context.cancellation().cancelled() => {
-                                return Err(ActionError::Cancelled.into());
+                                return Err(ActionError::cancelled(context.cancellation_reason()).into());
                             }
                         }
                     }
@@ -769,7 +890,7 @@ impl ActionRuntime {
         }
 
         if context.cancellation().is_cancelled() {
-            return Err(ActionError::Cancelled.into());
+            return Err(ActionError::cancelled(context.cancellation_reason()).into());
         }
 
         let mut turn_state = handle.init_turn(&input)?;
@@ -787,7 +908,7 @@ impl ActionRuntime {
             }
 
             if context.cancellation().is_cancelled() {
-                return Err(ActionError::Cancelled.into());
+                return Err(ActionError::cancelled(context.cancellation_reason()).into());
             }
 
             let step_result = {
@@ -799,7 +920,7 @@ impl ActionRuntime {
                         tokio::select! {
                             biased;
                             () = context.cancellation().cancelled() => {
-                                return Err(ActionError::Cancelled.into());
+                                return Err(ActionError::cancelled(context.cancellation_reason()).into());
                             }
                             timeout_result = tokio::time::timeout(deadline, &mut step_future) => {
                                 match timeout_result {
@@ -819,7 +940,7 @@ impl ActionRuntime {
                         tokio::select! {
                             biased;
                             () = context.cancellation().cancelled() => {
-                                return Err(ActionError::Cancelled.into());
+                                return Err(ActionError::cancelled(context.cancellation_reason()).into());
                             }
                             step_outcome = &mut step_future => step_outcome,
                         }
@@ -846,7 +967,7 @@ impl ActionRuntime {
                         tokio::select! {
                             () = tokio::time::sleep(d) => {}
                             () = context.cancellation().cancelled() => {
-                                return Err(ActionError::Cancelled.into());
+                                return Err(ActionError::cancelled(context.cancellation_reason()).into());
                             }
                         }
                     }
@@ -909,6 +1030,51 @@ impl ActionRuntime {
         }
     }
 
+    /// Validate every inline output value against the action's declared
+    /// output schema, when
+    /// [`DataPassingPolicy::validate_output_schema`] is enabled.
+    ///
+    /// Runs before [`enforce_data_limit`](Self::enforce_data_limit) so a
+    /// value is checked before it can be spilled to blob storage. A schema
+    /// without fields (the default for actions that declare no output
+    /// shape) and an `Any` schema accept every value.
+    fn enforce_output_schema(
+        &self,
+        action_key: &str,
+        metadata: &ActionMetadata,
+        action_result: &mut ActionResult<serde_json::Value>,
+        error_counter: &Counter,
+    ) -> Result<(), RuntimeError> {
+        let schema = metadata.output_schema();
+        if !self.data_policy.validate_output_schema
+            || schema.kind() == SchemaKind::Any
+            || schema.fields().is_empty()
+        {
+            return Ok(());
+        }
+
+        let mut slots: Vec<&mut ActionOutput<serde_json::Value>> = Vec::new();
+        collect_output_slots_mut(action_result, &mut slots);
+        for slot in slots {
+            let ActionOutput::Value(value) = &*slot else {
+                continue;
+            };
+            let report = match schema.values_from_wire(value.clone()) {
+                Ok(values) => match schema.validate(&values) {
+                    Ok(_) => continue,
+                    Err(report) => report,
+                },
+                Err(err) => ValidationReport::from(err),
+            };
+            error_counter.inc();
+            return Err(RuntimeError::OutputSchemaViolation {
+                key: action_key.to_owned(),
+                report,
+            });
+        }
+        Ok(())
+    }
+
     /// Check every downstream-visible output slot against the data-passing
     /// policy.
     ///
@@ -1290,6 +1456,31 @@ mod tests {
         }
     }
 
+    struct FatalAction;
+
+    impl Action for FatalAction {
+        type Input = serde_json::Value;
+        type Output = serde_json::Value;
+
+        fn metadata() -> ActionMetadata {
+            ActionMetadata::new(action_key!("test.fatal.static"), "Fatal", "fails fatally")
+        }
+        fn dependencies() -> &'static Dependencies {
+            static D: OnceLock<Dependencies> = OnceLock::new();
+            D.get_or_init(Dependencies::new)
+        }
+    }
+
+    impl StatelessAction for FatalAction {
+        async fn execute(
+            &self,
+            _input: <Self as Action>::Input,
+            _ctx: &(impl ActionContext + ?Sized),
+        ) -> Result<ActionResult<<Self as Action>::Output>, ActionError> {
+            Err(ActionError::fatal("bad schema"))
+        }
+    }
+
     fn test_context() -> ActionRuntimeContext {
         ActionRuntimeContext::new(
             Arc::new(
@@ -1409,6 +1600,132 @@ mod tests {
         rt.clear_execution_output_totals(eid);
     }
 
+    fn schema_checked_runtime(registry: Arc<ActionRegistry>) -> ActionRuntime {
+        let executor: ActionExecutor = Arc::new(|_ctx, _meta, input| {
+            Box::pin(async move { Ok(ActionResult::success(input)) })
+        });
+        let runner = Arc::new(InProcessRunner::new(executor));
+        ActionRuntime::try_new(
+            registry,
+            runner,
+            DataPassingPolicy {
+                validate_output_schema: true,
+                ..Default::default()
+            },
+            MetricsRegistry::new(),
+        )
+        .unwrap()
+    }
+
+    /// Output declaring a `user { name (required), age }` shape while
+    /// serializing whatever value the action produced, so a test can
+    /// return a mismatched shape.
+    #[derive(serde::Serialize)]
+    #[serde(transparent)]
+    struct UserOutput(serde_json::Value);
+
+    impl nebula_schema::HasSchema for UserOutput {
+        fn schema() -> nebula_schema::ValidSchema {
+            use nebula_schema::{Field, Schema, field_key};
+
+            Schema::builder()
+                .add(
+                    Field::object(field_key!("user"))
+                        .add(Field::string(field_key!("name")).required())
+                        .add(Field::integer(field_key!("age"))),
+                )
+                .build()
+                .expect("user output schema is valid")
+        }
+    }
+
+    struct UserEchoAction;
+
+    impl Action for UserEchoAction {
+        type Input = serde_json::Value;
+        type Output = UserOutput;
+
+        fn metadata() -> ActionMetadata {
+            ActionMetadata::new(action_key!("test.user_echo"), "User echo", "echoes input")
+        }
+        fn dependencies() -> &'static Dependencies {
+            static D: OnceLock<Dependencies> = OnceLock::new();
+            D.get_or_init(Dependencies::new)
+        }
+    }
+
+    impl StatelessAction for UserEchoAction {
+        async fn execute(
+            &self,
+            input: <Self as Action>::Input,
+            _ctx: &(impl ActionContext + ?Sized),
+        ) -> Result<ActionResult<<Self as Action>::Output>, ActionError> {
+            Ok(ActionResult::success(UserOutput(input)))
+        }
+    }
+
+    fn register_echo_with_output_schema(registry: &ActionRegistry) {
+        registry.register_stateless_instance(UserEchoAction::metadata(), UserEchoAction);
+    }
+
+    #[tokio::test]
+    async fn output_matching_schema_passes_validation() {
+        let registry = Arc::new(ActionRegistry::new());
+        register_echo_with_output_schema(&registry);
+        let rt = schema_checked_runtime(registry);
+
+        let output = serde_json::json!({"user": {"name": "Ada", "age": 36}});
+        let result = rt
+            .execute_action("test.user_echo", output.clone(), &test_context())
+            .await
+            .expect("output matches the declared schema");
+        assert!(matches!(
+            result,
+            ActionResult::Success { output: ActionOutput::Value(v) } if v == output
+        ));
+    }
+
+    #[tokio::test]
+    async fn output_schema_violation_reports_path() {
+        let registry = Arc::new(ActionRegistry::new());
+        register_echo_with_output_schema(&registry);
+        let rt = schema_checked_runtime(registry);
+
+        let err = rt
+            .execute_action(
+                "test.user_echo",
+                serde_json::json!({"user": {"age": 36}}),
+                &test_context(),
+            )
+            .await
+            .expect_err("user.name is required");
+        let RuntimeError::OutputSchemaViolation { key, report } = &err else {
+            panic!("expected OutputSchemaViolation, got {err:?}");
+        };
+        assert_eq!(key, "test.user_echo");
+        assert!(
+            report
+                .errors()
+                .any(|issue| issue.path.to_string() == "user.name"),
+            "report: {report}"
+        );
+    }
+
+    #[tokio::test]
+    async fn output_schema_not_checked_when_policy_disabled() {
+        let registry = Arc::new(ActionRegistry::new());
+        register_echo_with_output_schema(&registry);
+        let rt = make_runtime(registry);
+
+        rt.execute_action(
+            "test.user_echo",
+            serde_json::json!({"user": {"age": 36}}),
+            &test_context(),
+        )
+        .await
+        .expect("validation is opt-in");
+    }
+
     #[tokio::test]
     async fn execute_trusted_action() {
         let registry = Arc::new(ActionRegistry::new());
@@ -1457,6 +1774,194 @@ mod tests {
         assert!(result.unwrap_err().is_retryable());
     }
 
+    #[tokio::test]
+    async fn fatal_failure_is_dead_lettered_and_replayable() {
+        use crate::runtime::{
+            dead_letter::MemoryDeadLetterQueue,
+            queue::{DequeueResult, MemoryQueue, TaskQueue},
+        };
+
+        let registry = Arc::new(ActionRegistry::new());
+        registry.register_stateless_instance(
+            ActionMetadata::new(action_key!("test.fatal"), "Fatal", "fails fatally"),
+            FatalAction,
+        );
+        registry.register_stateless_instance(
+            ActionMetadata::new(action_key!("test.fail"), "Fail", "always fails"),
+            FailAction,
+        );
+        let main = Arc::new(MemoryQueue::new(4));
+        let dlq = Arc::new(MemoryDeadLetterQueue::new(Arc::clone(&main)));
+        let rt = make_runtime(registry).with_dead_letter_queue(dlq.clone());
+
+        // Retryable failures are left to retry policy, not dead-lettered.
+        rt.execute_action("test.fail", serde_json::json!(null), &test_context())
+            .await
+            .unwrap_err();
+        assert!(dlq.list().await.unwrap().is_empty());
+
+        let input = serde_json::json!({"order": 7});
+        let err = rt
+            .execute_action("test.fatal", input.clone(), &test_context())
+            .await
+            .unwrap_err();
+        assert!(!err.is_retryable());
+
+        let entries = dlq.list().await.unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].payload["action_key"], "test.fatal");
+        assert_eq!(entries[0].payload["input"], input);
+        assert!(entries[0].reason.contains("bad schema"));
+
+        let task_id = dlq.replay(&entries[0].id).await.unwrap();
+        let DequeueResult::Item {
+            task_id: dequeued,
+            payload,
+            ..
+        } = main
+            .dequeue(std::time::Duration::from_millis(10))
+            .await
+            .unwrap()
+        else {
+            panic!("replayed task should be queued");
+        };
+        assert_eq!(dequeued, task_id);
+        assert_eq!(payload["input"], input);
+        assert!(dlq.list().await.unwrap().is_empty());
+    }
+
+    /// Counts executions so dedup tests can tell whether the action ran.
+    struct CountingAction(Arc<AtomicU32>);
+
+    impl Action for CountingAction {
+        type Input = serde_json::Value;
+        type Output = serde_json::Value;
+
+        fn metadata() -> ActionMetadata {
+            ActionMetadata::new(
+                action_key!("test.counting.static"),
+                "Counting",
+                "counts runs",
+            )
+        }
+        fn dependencies() -> &'static Dependencies {
+            static D: OnceLock<Dependencies> = OnceLock::new();
+            D.get_or_init(Dependencies::new)
+        }
+    }
+
+    impl StatelessAction for CountingAction {
+        async fn execute(
+            &self,
+            _input: <Self as Action>::Input,
+            _ctx: &(impl ActionContext + ?Sized),
+        ) -> Result<ActionResult<<Self as Action>::Output>, ActionError> {
+            let run = self.0.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1;
+            // Long enough for overlapping dispatches to meet in the manager.
+            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+            Ok(ActionResult::success(serde_json::json!({ "run": run })))
+        }
+    }
+
+    #[tokio::test]
+    async fn content_hash_strategy_dedupes_identical_dispatches() {
+        use crate::runtime::idempotency::ContentHashKey;
+
+        let runs = Arc::new(AtomicU32::new(0));
+        let registry = Arc::new(ActionRegistry::new());
+        registry.register_stateless_instance(
+            ActionMetadata::new(action_key!("test.counting"), "Counting", "counts runs"),
+            CountingAction(Arc::clone(&runs)),
+        );
+        let manager = Arc::new(IdempotencyManager::new());
+        let rt =
+            make_runtime(registry).with_idempotency(Arc::new(ContentHashKey), Arc::clone(&manager));
+
+        let input = serde_json::json!({"order": 7, "sku": "a"});
+        let key = ContentHashKey.key("test.counting", &input).unwrap();
+        assert_eq!(
+            Some(key.clone()),
+            ContentHashKey.key(
+                "test.counting",
+                &serde_json::json!({"sku": "a", "order": 7})
+            )
+        );
+
+        let first = rt
+            .execute_action("test.counting", input.clone(), &test_context())
+            .await
+            .unwrap();
+        let second = rt
+            .execute_action("test.counting", input, &test_context())
+            .await
+            .unwrap();
+        assert_eq!(runs.load(std::sync::atomic::Ordering::SeqCst), 1);
+        assert_eq!(
+            format!("{:?}", first.into_primary_output()),
+            format!("{:?}", second.into_primary_output())
+        );
+        assert!(manager.completed(&key).is_some());
+
+        // Different input is different logical work.
+        rt.execute_action(
+            "test.counting",
+            serde_json::json!({"order": 8}),
+            &test_context(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(runs.load(std::sync::atomic::Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn overlapping_identical_dispatches_run_the_action_once() {
+        use crate::runtime::idempotency::ContentHashKey;
+
+        let runs = Arc::new(AtomicU32::new(0));
+        let registry = Arc::new(ActionRegistry::new());
+        registry.register_stateless_instance(
+            ActionMetadata::new(action_key!("test.counting"), "Counting", "counts runs"),
+            CountingAction(Arc::clone(&runs)),
+        );
+        let rt = make_runtime(registry).with_idempotency(
+            Arc::new(ContentHashKey),
+            Arc::new(IdempotencyManager::new()),
+        );
+
+        let input = serde_json::json!({"order": 7});
+        let ctx = test_context();
+        let (first, second) = tokio::join!(
+            rt.execute_action("test.counting", input.clone(), &ctx),
+            rt.execute_action("test.counting", input, &ctx),
+        );
+        assert_eq!(runs.load(std::sync::atomic::Ordering::SeqCst), 1);
+        assert_eq!(
+            format!("{:?}", first.unwrap().into_primary_output()),
+            format!("{:?}", second.unwrap().into_primary_output())
+        );
+    }
+
+    #[tokio::test]
+    async fn failed_dispatch_is_not_recorded_for_dedup() {
+        use crate::runtime::idempotency::ContentHashKey;
+
+        let registry = Arc::new(ActionRegistry::new());
+        registry.register_stateless_instance(
+            ActionMetadata::new(action_key!("test.fail"), "Fail", "always fails"),
+            FailAction,
+        );
+        let manager = Arc::new(IdempotencyManager::new());
+        let rt =
+            make_runtime(registry).with_idempotency(Arc::new(ContentHashKey), Arc::clone(&manager));
+
+        for _ in 0..2 {
+            rt.execute_action("test.fail", serde_json::json!(1), &test_context())
+                .await
+                .unwrap_err();
+        }
+        assert!(manager.is_empty());
+    }
+
     #[tokio::test]
     async fn data_limit_enforcement() {
         let registry = Arc::new(ActionRegistry::new());
@@ -2797,12 +3302,63 @@ mod tests {
         assert!(
             matches!(
                 result,
-                Err(RuntimeError::ActionError(ActionError::Cancelled))
+                Err(RuntimeError::ActionError(ActionError::Cancelled { .. }))
             ),
             "expected ActionError::Cancelled, got {result:?}"
         );
     }
 
+    /// The reason recorded on the context's cancellation signal surfaces on
+    /// the `Cancelled` error, so callers can retry a shutdown-interrupted
+    /// run but not a user cancel.
+    #[tokio::test(start_paused = true)]
+    async fn cancelled_error_carries_context_cancellation_reason() {
+        use nebula_core::{CancellationReason, CancellationSignal};
+
+        let registry = Arc::new(ActionRegistry::new());
+        registry.register_stateful_factory::<SleepyStateful>();
+        let rt = Arc::new(make_runtime(registry));
+
+        for reason in [
+            CancellationReason::UserRequested,
+            CancellationReason::Timeout,
+            CancellationReason::Shutdown,
+            CancellationReason::UpstreamFailure,
+        ] {
+            let signal = CancellationSignal::new();
+            let ctx = ActionRuntimeContext::new(
+                Arc::new(
+                    BaseContext::builder(Scope::default())
+                        .cancellation_signal(signal.clone())
+                        .build_with(Principal::System),
+                ),
+                ExecutionId::new(),
+                node_key!("test"),
+                WorkflowId::new(),
+            );
+
+            let rt_clone = Arc::clone(&rt);
+            let handle = tokio::spawn(async move {
+                rt_clone
+                    .execute_action("test.sleepy", serde_json::json!(null), &ctx)
+                    .await
+            });
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+            signal.cancel(reason);
+
+            let result = tokio::time::timeout(std::time::Duration::from_millis(500), handle)
+                .await
+                .expect("cancel must abort the handler")
+                .expect("task panicked");
+            match result {
+                Err(RuntimeError::ActionError(err @ ActionError::Cancelled { .. })) => {
+                    assert_eq!(err.cancellation_reason(), Some(reason));
+                },
+                other => panic!("expected ActionError::Cancelled, got {other:?}"),
+            }
+        }
+    }
+
     /// #308 regression: every iteration boundary is checkpointed.
     /// Counting 0→3 produces two `save()` calls (at iterations 1 and 2)
     /// and one `clear()` call on the terminal `Break` at iteration 3.
@@ -179,7 +179,7 @@ impl StatelessAction for SlowCancellableHandler {
         self.started.notify_one();
         tokio::select! {
             () = tokio::time::sleep(Duration::from_secs(30)) => Ok(ActionResult::success(input)),
-            () = ctx.cancellation().cancelled() => Err(ActionError::Cancelled),
+            () = ctx.cancellation().cancelled() => Err(ActionError::cancelled(None)),
         }
     }
 }
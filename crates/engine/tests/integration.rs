@@ -134,7 +134,7 @@ impl StatelessAction for SlowHandler {
     ) -> Result<ActionResult<<Self as Action>::Output>, ActionError> {
         tokio::select! {
             () = tokio::time::sleep(self.delay) => Ok(ActionResult::success(input)),
-            () = ctx.cancellation().cancelled() => Err(ActionError::Cancelled),
+            () = ctx.cancellation().cancelled() => Err(ActionError::cancelled(None)),
         }
     }
 }
@@ -239,7 +239,7 @@ impl StatelessAction for ParkHandler {
         self.invocations.fetch_add(1, Ordering::SeqCst);
         self.started.notify_one();
         ctx.cancellation().cancelled().await;
-        Err(ActionError::Cancelled)
+        Err(ActionError::cancelled(None))
     }
 }
 
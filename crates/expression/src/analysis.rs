@@ -0,0 +1,258 @@
+//! Static analysis of parsed expressions
+//!
+//! [`ExpressionEngine::analyze`](crate::ExpressionEngine::analyze) walks the
+//! AST of an expression without evaluating it and reports what the
+//! expression depends on, so callers (e.g. a workflow editor) can show
+//! node dependencies and catch misspelled function names at save time.
+
+use std::{collections::BTreeSet, sync::Arc};
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::{
+    ast::{BinaryOp, Expr},
+    builtins::BuiltinRegistry,
+    eval::{HIGHER_ORDER_FUNCTIONS, LEGACY_ACCUMULATOR},
+};
+
+/// What an expression references, gathered from its AST alone.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct ExpressionAnalysis {
+    /// Context variables read by the expression, as the longest static
+    /// access path written (`$node.fetch.data`, `$input.items[0]`,
+    /// `$node["HTTP Request"]`). A path stops at the first dynamic index,
+    /// so `$node[$input.name]` reports `$node` and `$input.name`. Lambda
+    /// parameters are not included.
+    pub variables: BTreeSet<String>,
+    /// Every function called, directly or through a pipeline.
+    pub functions: BTreeSet<String>,
+    /// Called functions that are neither registered builtins nor
+    /// higher-order functions; evaluating the expression would fail with
+    /// `FunctionNotFound` when the call is reached.
+    pub unknown_functions: BTreeSet<String>,
+    /// Whether the expression uses the `=~` regex match operator.
+    pub uses_regex: bool,
+    /// Depth of the deepest AST node; a lone literal has depth 1.
+    pub max_depth: usize,
+}
+
+impl ExpressionAnalysis {
+    /// Analyze a parsed expression, resolving function names against `builtins`.
+    pub fn of(expr: &Expr, builtins: &BuiltinRegistry) -> Self {
+        let mut walker = Walker {
+            builtins,
+            bound: Vec::new(),
+            analysis: Self::default(),
+        };
+        walker.visit(expr, 1, false);
+        walker.analysis
+    }
+}
+
+/// AST walker state. `bound` holds the lambda parameters in scope, which
+/// shadow context variables of the same name.
+struct Walker<'a> {
+    builtins: &'a BuiltinRegistry,
+    bound: Vec<Arc<str>>,
+    analysis: ExpressionAnalysis,
+}
+
+impl Walker<'_> {
+    /// Visit `expr` at `depth`. `in_path` is set while descending through an
+    /// access chain whose full path has already been recorded.
+    fn visit(&mut self, expr: &Expr, depth: usize, in_path: bool) {
+        self.analysis.max_depth = self.analysis.max_depth.max(depth);
+        let next = depth + 1;
+
+        match expr {
+            Expr::Literal(_) | Expr::Identifier(_) => {},
+
+            Expr::Variable(_) => {
+                if !in_path {
+                    self.record_path(expr);
+                }
+            },
+
+            Expr::PropertyAccess { object, .. } | Expr::OptionalPropertyAccess { object, .. } => {
+                let recorded = in_path || self.record_path(expr);
+                self.visit(object, next, recorded);
+            },
+
+            Expr::IndexAccess { object, index } => {
+                let recorded = in_path || self.record_path(expr);
+                self.visit(object, next, recorded);
+                self.visit(index, next, false);
+            },
+
+            Expr::Negate(inner) | Expr::Not(inner) => self.visit(inner, next, false),
+
+            Expr::Binary { left, op, right } => {
+                if *op == BinaryOp::RegexMatch {
+                    self.analysis.uses_regex = true;
+                }
+                self.visit(left, next, false);
+                self.visit(right, next, false);
+            },
+
+            Expr::FunctionCall { name, args } => self.visit_call(name, args, next),
+
+            Expr::Pipeline {
+                value,
+                function,
+                args,
+            } => {
+                self.visit(value, next, false);
+                self.visit_call(function, args, next);
+            },
+
+            Expr::Conditional {
+                condition,
+                then_expr,
+                else_expr,
+            } => {
+                self.visit(condition, next, false);
+                self.visit(then_expr, next, false);
+                self.visit(else_expr, next, false);
+            },
+
+            Expr::Lambda { params, body } => {
+                let scope = self.bound.len();
+                self.bound.extend(params.iter().cloned());
+                self.visit(body, next, false);
+                self.bound.truncate(scope);
+            },
+
+            Expr::Array(items) => {
+                for item in items {
+                    self.visit(item, next, false);
+                }
+            },
+
+            Expr::Object(entries) => {
+                for (_, value) in entries {
+                    self.visit(value, next, false);
+                }
+            },
+        }
+    }
+
+    fn visit_call(&mut self, name: &Arc<str>, args: &[Expr], depth: usize) {
+        self.analysis.functions.insert(name.to_string());
+        if !self.builtins.has_function(name) && !HIGHER_ORDER_FUNCTIONS.contains(&&**name) {
+            self.analysis.unknown_functions.insert(name.to_string());
+        }
+
+        for arg in args {
+            // The single-parameter `reduce` form binds `$acc` implicitly.
+            let legacy_acc = &**name == "reduce"
+                && matches!(arg, Expr::Lambda { params, .. } if params.len() < 2);
+            if legacy_acc {
+                self.bound.push(Arc::from(LEGACY_ACCUMULATOR));
+            }
+            self.visit(arg, depth, false);
+            if legacy_acc {
+                self.bound.pop();
+            }
+        }
+    }
+
+    /// Record the static access path of `expr` unless its root variable is a
+    /// lambda parameter. Returns whether `expr` is a static path at all.
+    fn record_path(&mut self, expr: &Expr) -> bool {
+        let Some((root, path)) = static_path(expr) else {
+            return false;
+        };
+        if !self.bound.iter().any(|param| **param == *root) {
+            self.analysis.variables.insert(path);
+        }
+        true
+    }
+}
+
+/// `(root variable, rendered path)` for a chain of property accesses and
+/// literal indexes ending in a variable, or `None` if any step is dynamic.
+fn static_path(expr: &Expr) -> Option<(&str, String)> {
+    match expr {
+        Expr::Variable(name) => Some((name, format!("${name}"))),
+        Expr::PropertyAccess { object, property }
+        | Expr::OptionalPropertyAccess { object, property } => {
+            static_path(object).map(|(root, path)| (root, format!("{path}.{property}")))
+        },
+        Expr::IndexAccess { object, index } => match index.as_literal() {
+            Some(key @ (Value::String(_) | Value::Number(_))) => {
+                static_path(object).map(|(root, path)| (root, format!("{path}[{key}]")))
+            },
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ExpressionEngine;
+
+    fn names(set: &std::collections::BTreeSet<String>) -> Vec<&str> {
+        set.iter().map(String::as_str).collect()
+    }
+
+    #[test]
+    fn reports_static_variable_paths() {
+        let engine = ExpressionEngine::new();
+        let analysis = engine
+            .analyze(r#"$node.fetch.data.items[0] + $node["HTTP Request"].status + $execution.id"#)
+            .unwrap();
+
+        assert_eq!(
+            names(&analysis.variables),
+            [
+                "$execution.id",
+                "$node.fetch.data.items[0]",
+                r#"$node["HTTP Request"].status"#,
+            ]
+        );
+        assert!(analysis.functions.is_empty());
+        assert!(!analysis.uses_regex);
+    }
+
+    #[test]
+    fn dynamic_index_splits_the_path() {
+        let engine = ExpressionEngine::new();
+        let analysis = engine.analyze("$node[$input.name].data").unwrap();
+
+        assert_eq!(names(&analysis.variables), ["$input.name", "$node"]);
+    }
+
+    #[test]
+    fn lambda_parameters_are_not_context_variables() {
+        let engine = ExpressionEngine::new();
+        let analysis = engine
+            .analyze("reduce(map($input.items, x => $x.price), 0, p => $acc + $p) | round()")
+            .unwrap();
+
+        assert_eq!(names(&analysis.variables), ["$input.items"]);
+        assert_eq!(names(&analysis.functions), ["map", "reduce", "round"]);
+        assert!(analysis.unknown_functions.is_empty());
+    }
+
+    #[test]
+    fn flags_unknown_functions_and_regex() {
+        let engine = ExpressionEngine::new();
+        let analysis = engine
+            .analyze(r#"uppercse($input.name) =~ "^A" && lenght($input.tags) > 0"#)
+            .unwrap();
+
+        assert_eq!(names(&analysis.unknown_functions), ["lenght", "uppercse"]);
+        assert!(analysis.uses_regex);
+    }
+
+    #[test]
+    fn max_depth_counts_nested_nodes() {
+        let engine = ExpressionEngine::new();
+
+        assert_eq!(engine.analyze("1").unwrap().max_depth, 1);
+        assert_eq!(engine.analyze("-($input.a + 2)").unwrap().max_depth, 4);
+        assert_eq!(engine.analyze("$input.a.b").unwrap().max_depth, 3);
+    }
+}
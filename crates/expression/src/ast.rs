@@ -42,6 +42,16 @@ pub enum Expr {
         property: Arc<str>,
     },
 
+    /// Optional property access (object?.property)
+    ///
+    /// Yields `null` when `object` is null or has no such property, instead
+    /// of failing like [`PropertyAccess`](Self::PropertyAccess). Guards only
+    /// its own step: write `a?.b?.c` to guard every level.
+    OptionalPropertyAccess {
+        object: Box<Expr>,
+        property: Arc<str>,
+    },
+
     /// Index access (array\[index\])
     IndexAccess { object: Box<Expr>, index: Box<Expr> },
 
@@ -66,8 +76,14 @@ pub enum Expr {
     },
 
     // Lambda
-    /// Lambda expression (param => body)
-    Lambda { param: Arc<str>, body: Box<Expr> },
+    /// Lambda expression (`x => body` or `(acc, x) => body`)
+    ///
+    /// Parameters bind positionally; which arguments a higher-order function
+    /// passes (element, index, accumulator) is documented on each function.
+    Lambda {
+        params: Vec<Arc<str>>,
+        body: Box<Expr>,
+    },
 
     // Array and Object literals
     /// Array literal ([expr1, expr2, ...])
@@ -100,6 +116,10 @@ pub enum BinaryOp {
     // Logical
     And,
     Or,
+
+    // Null handling
+    /// `left ?? right`: `left` unless it is null, else `right`.
+    NullCoalesce,
 }
 
 impl BinaryOp {
@@ -121,6 +141,7 @@ impl BinaryOp {
             BinaryOp::RegexMatch => "=~",
             BinaryOp::And => "&&",
             BinaryOp::Or => "||",
+            BinaryOp::NullCoalesce => "??",
         }
     }
 }
@@ -356,8 +356,110 @@ pub fn date_day_of_week(
     Ok(Value::Number((weekday as i64).into()))
 }
 
+/// Next fire time of a cron schedule, as a Unix timestamp.
+///
+/// Signature: `cron_next(expr, from, tz?)`
+/// - `expr`: 5-field (`minute hour day month weekday`) or 6-field (leading seconds) cron pattern;
+///   nicknames such as `@daily` are accepted too.
+/// - `from`: timestamp (integer) or date string; the result is strictly after it.
+/// - `tz`: optional IANA timezone the schedule's clock fields are read in (UTC by default). Naive
+///   date strings in `from` are interpreted as wall time in `tz`, as in `parse_date`.
+pub fn cron_next(
+    args: &[Value],
+    _view: BuiltinView<'_>,
+    _ctx: &EvaluationContext,
+) -> ExpressionResult<Value> {
+    check_cron_arg_count("cron_next", args)?;
+    let cron = parse_cron("cron_next", &args[0])?;
+    let (from, tz) = cron_instant("cron_next", args)?;
+
+    let next = cron
+        .find_next_occurrence(&from.with_timezone(&tz), false)
+        .map_err(|err| {
+            ExpressionError::expression_invalid_argument(
+                "cron_next",
+                format!("no fire time after {from} for cron expression '{cron}': {err}"),
+            )
+        })?;
+    Ok(Value::Number(next.timestamp().into()))
+}
+
+/// Whether a timestamp falls on a cron schedule.
+///
+/// Signature: `cron_matches(expr, timestamp, tz?)` with the same arguments as `cron_next`.
+/// Matching is to the second: a 5-field pattern only matches at second 0.
+pub fn cron_matches(
+    args: &[Value],
+    _view: BuiltinView<'_>,
+    _ctx: &EvaluationContext,
+) -> ExpressionResult<Value> {
+    check_cron_arg_count("cron_matches", args)?;
+    let cron = parse_cron("cron_matches", &args[0])?;
+    let (at, tz) = cron_instant("cron_matches", args)?;
+
+    let matches = cron
+        .is_time_matching(&at.with_timezone(&tz))
+        .map_err(|err| {
+            ExpressionError::expression_invalid_argument(
+                "cron_matches",
+                format!("invalid cron expression '{cron}': {err}"),
+            )
+        })?;
+    Ok(Value::Bool(matches))
+}
+
 // Helper functions
 
+/// Both cron builtins take `(expr, instant, tz?)`.
+fn check_cron_arg_count(function: &str, args: &[Value]) -> ExpressionResult<()> {
+    check_min_arg_count(function, args, 2)?;
+    if args.len() > 3 {
+        return Err(ExpressionError::expression_invalid_argument(
+            function,
+            format!("expected 2-3 arguments, got {}", args.len()),
+        ));
+    }
+    Ok(())
+}
+
+/// Parse a cron pattern argument, accepting only 5- or 6-field patterns
+/// (or an `@nickname`). The 7-field form with a trailing year that the
+/// underlying parser would also take is rejected so schedules stay
+/// portable to standard cron.
+fn parse_cron(function: &str, value: &Value) -> ExpressionResult<croner::Cron> {
+    let expr = value.as_str().ok_or_else(|| {
+        ExpressionError::expression_type_error("string", crate::value_utils::value_type_name(value))
+    })?;
+    let trimmed = expr.trim();
+    if !trimmed.starts_with('@') {
+        let fields = trimmed.split_whitespace().count();
+        if !matches!(fields, 5 | 6) {
+            return Err(ExpressionError::expression_invalid_argument(
+                function,
+                format!(
+                    "invalid cron expression '{expr}': expected 5 fields (minute hour day month \
+                     weekday) or 6 with leading seconds, got {fields}"
+                ),
+            ));
+        }
+    }
+    trimmed.parse::<croner::Cron>().map_err(|err| {
+        ExpressionError::expression_invalid_argument(
+            function,
+            format!("invalid cron expression '{expr}': {err}"),
+        )
+    })
+}
+
+/// Resolve the instant (`args[1]`) and optional timezone (`args[2]`) of a
+/// cron builtin call.
+fn cron_instant(function: &str, args: &[Value]) -> ExpressionResult<(DateTime<Utc>, Tz)> {
+    match optional_tz_arg(function, args, 2)? {
+        Some(tz) => Ok((parse_datetime_in_tz(&args[1], tz)?, tz)),
+        None => Ok((parse_datetime(&args[1])?, Tz::UTC)),
+    }
+}
+
 /// Format strings tried in order when no explicit format is given.
 /// Strings that fail RFC 3339 fall through to these naive (no offset)
 /// patterns; midnight is assumed for date-only forms.
@@ -0,0 +1,284 @@
+//! Binary-to-text encoding functions
+//!
+//! Every `*_encode` takes a string (encoded as its UTF-8 bytes) or an array
+//! of byte values `0..=255`. Every `*_decode` returns a string by default and
+//! fails if the decoded bytes are not UTF-8; pass `"bytes"` as the second
+//! argument to get an array of byte values instead.
+
+use base64::Engine as _;
+use serde_json::Value;
+
+use super::{check_min_arg_count, get_string_arg};
+use crate::{
+    ExpressionError,
+    context::EvaluationContext,
+    error::{ExpressionErrorExt, ExpressionResult},
+    eval::BuiltinView,
+};
+
+/// Bitcoin base58 alphabet: no `0`, `O`, `I` or `l`.
+const BASE58_ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+#[derive(Debug, Clone, Copy)]
+enum Encoding {
+    /// RFC 4648 base32. Encodes with `=` padding; decodes case-insensitively,
+    /// with or without padding.
+    Base32,
+    /// Bitcoin-alphabet base58. Leading zero bytes map to leading `1`s.
+    Base58,
+    /// RFC 4648 base64 with `+`, `/` and `=` padding.
+    Base64,
+    /// RFC 4648 URL-safe base64 (`-`, `_`). Encodes without padding; decodes
+    /// with or without it.
+    Base64Url,
+}
+
+impl Encoding {
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Base32 => "base32",
+            Self::Base58 => "base58",
+            Self::Base64 => "base64",
+            Self::Base64Url => "base64url",
+        }
+    }
+
+    fn encode(self, bytes: &[u8]) -> String {
+        match self {
+            Self::Base32 => data_encoding::BASE32.encode(bytes),
+            Self::Base58 => base58_encode_bytes(bytes),
+            Self::Base64 => base64::engine::general_purpose::STANDARD.encode(bytes),
+            Self::Base64Url => base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(bytes),
+        }
+    }
+
+    fn decode(self, text: &str) -> Result<Vec<u8>, String> {
+        match self {
+            Self::Base32 => data_encoding::BASE32_NOPAD
+                .decode(text.trim_end_matches('=').to_ascii_uppercase().as_bytes())
+                .map_err(|e| e.to_string()),
+            Self::Base58 => base58_decode_bytes(text),
+            Self::Base64 => base64::engine::general_purpose::STANDARD
+                .decode(text)
+                .map_err(|e| e.to_string()),
+            Self::Base64Url => base64::engine::general_purpose::URL_SAFE_NO_PAD
+                .decode(text.trim_end_matches('='))
+                .map_err(|e| e.to_string()),
+        }
+    }
+}
+
+fn base58_encode_bytes(bytes: &[u8]) -> String {
+    let zeros = bytes.iter().take_while(|&&b| b == 0).count();
+    // Base-58 digits of the big-endian number, least significant first.
+    let mut digits: Vec<u8> = Vec::with_capacity(bytes.len() * 138 / 100 + 1);
+    for &byte in &bytes[zeros..] {
+        let mut carry = u32::from(byte);
+        for digit in &mut digits {
+            carry += u32::from(*digit) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+    std::iter::repeat_n('1', zeros)
+        .chain(
+            digits
+                .iter()
+                .rev()
+                .map(|&d| char::from(BASE58_ALPHABET[usize::from(d)])),
+        )
+        .collect()
+}
+
+fn base58_decode_bytes(text: &str) -> Result<Vec<u8>, String> {
+    let zeros = text.bytes().take_while(|&c| c == b'1').count();
+    // Bytes of the big-endian number, least significant first.
+    let mut bytes: Vec<u8> = Vec::with_capacity(text.len() * 733 / 1000 + 1);
+    for (position, c) in text.char_indices().skip(zeros) {
+        let value = u8::try_from(c)
+            .ok()
+            .and_then(|c| BASE58_ALPHABET.iter().position(|&a| a == c))
+            .ok_or_else(|| format!("invalid symbol '{c}' at {position}"))?;
+        let mut carry = value as u32;
+        for byte in &mut bytes {
+            carry += u32::from(*byte) * 58;
+            *byte = (carry & 0xff) as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.push((carry & 0xff) as u8);
+            carry >>= 8;
+        }
+    }
+    bytes.extend(std::iter::repeat_n(0, zeros));
+    bytes.reverse();
+    Ok(bytes)
+}
+
+/// Bytes of the value to encode: a string's UTF-8 bytes or an array of
+/// byte values.
+fn input_bytes(func_name: &str, value: &Value) -> ExpressionResult<Vec<u8>> {
+    match value {
+        Value::String(s) => Ok(s.as_bytes().to_vec()),
+        Value::Array(items) => items
+            .iter()
+            .map(|item| {
+                item.as_u64()
+                    .and_then(|n| u8::try_from(n).ok())
+                    .ok_or_else(|| {
+                        ExpressionError::expression_invalid_argument(
+                            func_name,
+                            format!("Byte array elements must be integers 0-255, got {item}"),
+                        )
+                    })
+            })
+            .collect(),
+        other => Err(ExpressionError::expression_invalid_argument(
+            func_name,
+            format!(
+                "Argument 'value' must be a string or an array of bytes, got {}",
+                crate::value_utils::value_type_name(other)
+            ),
+        )),
+    }
+}
+
+fn check_arg_range(func_name: &str, args: &[Value], max: usize) -> ExpressionResult<()> {
+    check_min_arg_count(func_name, args, 1)?;
+    if args.len() > max {
+        return Err(ExpressionError::expression_invalid_argument(
+            func_name,
+            format!("Expected 1-{max} arguments, got {}", args.len()),
+        ));
+    }
+    Ok(())
+}
+
+fn encode_with(encoding: Encoding, func_name: &str, args: &[Value]) -> ExpressionResult<Value> {
+    check_arg_range(func_name, args, 1)?;
+    let bytes = input_bytes(func_name, &args[0])?;
+    Ok(Value::String(encoding.encode(&bytes)))
+}
+
+fn decode_with(encoding: Encoding, func_name: &str, args: &[Value]) -> ExpressionResult<Value> {
+    check_arg_range(func_name, args, 2)?;
+    let text = get_string_arg(func_name, args, 0, "value")?;
+    let as_bytes = match args.get(1).map(Value::as_str) {
+        None | Some(Some("string")) => false,
+        Some(Some("bytes")) => true,
+        Some(_) => {
+            return Err(ExpressionError::expression_invalid_argument(
+                func_name,
+                "Argument 'output' must be \"string\" or \"bytes\"",
+            ));
+        },
+    };
+
+    let bytes = encoding.decode(text).map_err(|reason| {
+        ExpressionError::expression_invalid_argument(
+            func_name,
+            format!("Invalid {} input: {reason}", encoding.name()),
+        )
+    })?;
+    if as_bytes {
+        return Ok(Value::Array(bytes.into_iter().map(Value::from).collect()));
+    }
+    String::from_utf8(bytes).map(Value::String).map_err(|_| {
+        ExpressionError::expression_invalid_argument(
+            func_name,
+            "Decoded bytes are not valid UTF-8; pass \"bytes\" as the second argument",
+        )
+    })
+}
+
+/// Encode as RFC 4648 base32 with padding.
+///
+/// Example: `base32_encode("hi")` returns `"NBUQ===="`
+pub fn base32_encode(
+    args: &[Value],
+    _view: BuiltinView<'_>,
+    _ctx: &EvaluationContext,
+) -> ExpressionResult<Value> {
+    encode_with(Encoding::Base32, "base32_encode", args)
+}
+
+/// Decode base32; case and trailing padding are ignored.
+///
+/// Example: `base32_decode("nbuq")` returns `"hi"`
+pub fn base32_decode(
+    args: &[Value],
+    _view: BuiltinView<'_>,
+    _ctx: &EvaluationContext,
+) -> ExpressionResult<Value> {
+    decode_with(Encoding::Base32, "base32_decode", args)
+}
+
+/// Encode as base58 (Bitcoin alphabet).
+///
+/// Example: `base58_encode("hello")` returns `"Cn8eVZg"`
+pub fn base58_encode(
+    args: &[Value],
+    _view: BuiltinView<'_>,
+    _ctx: &EvaluationContext,
+) -> ExpressionResult<Value> {
+    encode_with(Encoding::Base58, "base58_encode", args)
+}
+
+/// Decode base58 (Bitcoin alphabet).
+///
+/// Example: `base58_decode("Cn8eVZg")` returns `"hello"`
+pub fn base58_decode(
+    args: &[Value],
+    _view: BuiltinView<'_>,
+    _ctx: &EvaluationContext,
+) -> ExpressionResult<Value> {
+    decode_with(Encoding::Base58, "base58_decode", args)
+}
+
+/// Encode as standard base64 with padding.
+///
+/// Example: `base64_encode("hi?")` returns `"aGk/"`
+pub fn base64_encode(
+    args: &[Value],
+    _view: BuiltinView<'_>,
+    _ctx: &EvaluationContext,
+) -> ExpressionResult<Value> {
+    encode_with(Encoding::Base64, "base64_encode", args)
+}
+
+/// Decode standard base64; padding is required.
+///
+/// Example: `base64_decode("aGk/")` returns `"hi?"`
+pub fn base64_decode(
+    args: &[Value],
+    _view: BuiltinView<'_>,
+    _ctx: &EvaluationContext,
+) -> ExpressionResult<Value> {
+    decode_with(Encoding::Base64, "base64_decode", args)
+}
+
+/// Encode as URL-safe base64 without padding.
+///
+/// Example: `base64url_encode("hi?")` returns `"aGk_"`
+pub fn base64url_encode(
+    args: &[Value],
+    _view: BuiltinView<'_>,
+    _ctx: &EvaluationContext,
+) -> ExpressionResult<Value> {
+    encode_with(Encoding::Base64Url, "base64url_encode", args)
+}
+
+/// Decode URL-safe base64, with or without padding.
+///
+/// Example: `base64url_decode("aGk_")` returns `"hi?"`
+pub fn base64url_decode(
+    args: &[Value],
+    _view: BuiltinView<'_>,
+    _ctx: &EvaluationContext,
+) -> ExpressionResult<Value> {
+    decode_with(Encoding::Base64Url, "base64url_decode", args)
+}
@@ -0,0 +1,239 @@
+//! Deep equality and structural hashing
+//!
+//! `deep_equal` compares two values structurally, with opt-in relaxations for
+//! array order and extra object keys. `structural_hash` digests a value so
+//! that values equal under `==` hash alike, whatever order their object keys
+//! were inserted in.
+
+use serde_json::Value;
+
+use super::{check_arg_count, check_min_arg_count};
+use crate::{
+    ExpressionError,
+    context::EvaluationContext,
+    error::{ExpressionErrorExt, ExpressionResult},
+    eval::BuiltinView,
+};
+
+/// Relaxations accepted by `deep_equal`'s options object.
+#[derive(Debug, Clone, Copy, Default)]
+struct EqualOptions {
+    ignore_order: bool,
+    ignore_extra_keys: bool,
+}
+
+impl EqualOptions {
+    fn parse(value: &Value) -> ExpressionResult<Self> {
+        let map = value.as_object().ok_or_else(|| {
+            ExpressionError::expression_invalid_argument(
+                "deep_equal",
+                format!(
+                    "Argument 'options' must be an object, got {}",
+                    crate::value_utils::value_type_name(value)
+                ),
+            )
+        })?;
+
+        let mut options = Self::default();
+        for (key, flag) in map {
+            let slot = match key.as_str() {
+                "ignore_order" => &mut options.ignore_order,
+                "ignore_extra_keys" => &mut options.ignore_extra_keys,
+                _ => {
+                    return Err(ExpressionError::expression_invalid_argument(
+                        "deep_equal",
+                        format!(
+                            "Unknown option '{key}' (expected 'ignore_order' or 'ignore_extra_keys')"
+                        ),
+                    ));
+                },
+            };
+            *slot = flag.as_bool().ok_or_else(|| {
+                ExpressionError::expression_invalid_argument(
+                    "deep_equal",
+                    format!(
+                        "Option '{key}' must be a boolean, got {}",
+                        crate::value_utils::value_type_name(flag)
+                    ),
+                )
+            })?;
+        }
+        Ok(options)
+    }
+}
+
+/// Compare `expected` against `actual` under `options`.
+fn values_match(expected: &Value, actual: &Value, options: EqualOptions) -> bool {
+    match (expected, actual) {
+        (Value::Array(expected), Value::Array(actual)) => {
+            expected.len() == actual.len()
+                && if options.ignore_order {
+                    arrays_match_unordered(expected, actual, options)
+                } else {
+                    expected
+                        .iter()
+                        .zip(actual)
+                        .all(|(e, a)| values_match(e, a, options))
+                }
+        },
+        (Value::Object(expected), Value::Object(actual)) => {
+            (options.ignore_extra_keys || expected.len() == actual.len())
+                && expected
+                    .iter()
+                    .all(|(key, e)| actual.get(key).is_some_and(|a| values_match(e, a, options)))
+        },
+        _ => expected == actual,
+    }
+}
+
+/// Whether every element of `expected` can be paired with a distinct element
+/// of `actual`. With `ignore_extra_keys` the element relation is not
+/// symmetric, so a greedy first-fit pairing could miss a valid one; this
+/// finds a maximum bipartite matching instead.
+fn arrays_match_unordered(expected: &[Value], actual: &[Value], options: EqualOptions) -> bool {
+    let compatible: Vec<Vec<bool>> = expected
+        .iter()
+        .map(|e| actual.iter().map(|a| values_match(e, a, options)).collect())
+        .collect();
+    // `paired_with[j]` is the index in `expected` currently paired with `actual[j]`.
+    let mut paired_with = vec![None; actual.len()];
+    (0..expected.len()).all(|i| {
+        let mut visited = vec![false; actual.len()];
+        try_pair(i, &compatible, &mut paired_with, &mut visited)
+    })
+}
+
+/// Augmenting-path step of Kuhn's matching algorithm.
+fn try_pair(
+    i: usize,
+    compatible: &[Vec<bool>],
+    paired_with: &mut [Option<usize>],
+    visited: &mut [bool],
+) -> bool {
+    for j in 0..paired_with.len() {
+        if visited[j] || !compatible[i][j] {
+            continue;
+        }
+        visited[j] = true;
+        if paired_with[j].is_none_or(|k| try_pair(k, compatible, paired_with, visited)) {
+            paired_with[j] = Some(i);
+            return true;
+        }
+    }
+    false
+}
+
+/// Compare two values structurally.
+///
+/// Signature: `deep_equal(a, b, options?)`. Without options this agrees with
+/// `a == b`: arrays compare element by element in order, objects must have
+/// the same keys with equal values, and scalars compare as `==` does (so `1`
+/// and `1.0` differ). `options` is an object with these boolean fields, both
+/// `false` by default and applied at every nesting level:
+///
+/// - `ignore_order` — arrays of the same length are equal when their elements
+///   can be paired one-to-one, in any order (`[1, 1, 2]` equals `[1, 2, 1]`
+///   but not `[1, 2, 2]`). Costs a quadratic number of comparisons.
+/// - `ignore_extra_keys` — `a` is a pattern: every key of an object in `a`
+///   must be present in the matching object of `b` with an equal value, but
+///   `b` may have more keys. `deep_equal({x: 1}, {x: 1, y: 2}, {ignore_extra_keys: true})`
+///   is `true`; with the arguments swapped it is `false`.
+///
+/// Any other option key, or a non-boolean value, is an error.
+pub fn deep_equal(
+    args: &[Value],
+    _view: BuiltinView<'_>,
+    _ctx: &EvaluationContext,
+) -> ExpressionResult<Value> {
+    check_min_arg_count("deep_equal", args, 2)?;
+    if args.len() > 3 {
+        return Err(ExpressionError::expression_invalid_argument(
+            "deep_equal",
+            format!("Expected 2-3 arguments, got {}", args.len()),
+        ));
+    }
+    let options = args
+        .get(2)
+        .map(EqualOptions::parse)
+        .transpose()?
+        .unwrap_or_default();
+
+    Ok(Value::Bool(values_match(&args[0], &args[1], options)))
+}
+
+/// 64-bit FNV-1a, chosen because its output is fixed by its definition,
+/// unlike `std`'s hasher, whose algorithm may change between releases.
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= u64::from(byte);
+            self.0 = self.0.wrapping_mul(Self::PRIME);
+        }
+    }
+
+    fn write_len(&mut self, len: usize) {
+        self.write(&(len as u64).to_le_bytes());
+    }
+
+    fn write_str(&mut self, s: &str) {
+        self.write_len(s.len());
+        self.write(s.as_bytes());
+    }
+}
+
+/// Feed `value` into `hasher` with a type tag per node and length prefixes,
+/// visiting object entries in key order.
+fn hash_value(value: &Value, hasher: &mut Fnv1a) {
+    match value {
+        Value::Null => hasher.write(b"n"),
+        Value::Bool(b) => hasher.write(if *b { b"t" } else { b"f" }),
+        Value::Number(n) => {
+            hasher.write(b"d");
+            hasher.write_str(&n.to_string());
+        },
+        Value::String(s) => {
+            hasher.write(b"s");
+            hasher.write_str(s);
+        },
+        Value::Array(items) => {
+            hasher.write(b"a");
+            hasher.write_len(items.len());
+            for item in items {
+                hash_value(item, hasher);
+            }
+        },
+        Value::Object(map) => {
+            hasher.write(b"o");
+            hasher.write_len(map.len());
+            let mut entries: Vec<_> = map.iter().collect();
+            entries.sort_unstable_by(|a, b| a.0.cmp(b.0));
+            for (key, item) in entries {
+                hasher.write_str(key);
+                hash_value(item, hasher);
+            }
+        },
+    }
+}
+
+/// Hash a value by structure.
+///
+/// Signature: `structural_hash(value)` → a 16-digit lowercase hex string, the
+/// 64-bit FNV-1a digest of a canonical encoding of `value`. Object keys are
+/// hashed in sorted order, so the result does not depend on insertion order;
+/// array order does matter. Values equal under `==` hash alike, and the
+/// digest is the same across runs and releases. Not a cryptographic hash.
+pub fn structural_hash(
+    args: &[Value],
+    _view: BuiltinView<'_>,
+    _ctx: &EvaluationContext,
+) -> ExpressionResult<Value> {
+    check_arg_count("structural_hash", args, 1)?;
+    let mut hasher = Fnv1a(Fnv1a::OFFSET_BASIS);
+    hash_value(&args[0], &mut hasher);
+    Ok(Value::String(format!("{:016x}", hasher.0)))
+}
@@ -17,7 +17,7 @@ use crate::{
 ///
 /// Mirrors the `**` operator's finiteness guard so the function and operator
 /// forms agree.
-fn finite_result(fn_name: &str, value: f64) -> ExpressionResult<Value> {
+pub(crate) fn finite_result(fn_name: &str, value: f64) -> ExpressionResult<Value> {
     if value.is_finite() {
         Ok(serde_json::json!(value))
     } else {
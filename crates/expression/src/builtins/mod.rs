@@ -4,12 +4,16 @@ pub mod array;
 pub mod conversion;
 #[cfg(feature = "datetime")]
 pub mod datetime;
+pub mod encoding;
+pub mod equality;
 pub mod math;
 pub mod object;
+pub mod statistics;
 pub mod string;
+pub mod units;
 pub mod util;
 
-use std::collections::HashMap;
+use std::{collections::HashMap, sync::Arc};
 
 use serde_json::Value;
 
@@ -48,9 +52,13 @@ impl BuiltinRegistry {
         // Register all builtin functions
         registry.register_string_functions();
         registry.register_math_functions();
+        registry.register_statistics_functions();
         registry.register_array_functions();
         registry.register_object_functions();
         registry.register_conversion_functions();
+        registry.register_encoding_functions();
+        registry.register_equality_functions();
+        registry.register_unit_functions();
         registry.register_util_functions();
         #[cfg(feature = "datetime")]
         registry.register_datetime_functions();
@@ -120,6 +128,14 @@ impl BuiltinRegistry {
         self.register("pow", math::pow);
     }
 
+    fn register_statistics_functions(&mut self) {
+        self.register("sum", statistics::sum);
+        self.register("avg", statistics::avg);
+        self.register("median", statistics::median);
+        self.register("percentile", statistics::percentile);
+        self.register("stddev", statistics::stddev);
+    }
+
     fn register_array_functions(&mut self) {
         self.register("first", array::first);
         self.register("last", array::last);
@@ -157,6 +173,26 @@ impl BuiltinRegistry {
         self.register("parse_json", conversion::parse_json);
     }
 
+    fn register_encoding_functions(&mut self) {
+        self.register("base32_encode", encoding::base32_encode);
+        self.register("base32_decode", encoding::base32_decode);
+        self.register("base58_encode", encoding::base58_encode);
+        self.register("base58_decode", encoding::base58_decode);
+        self.register("base64_encode", encoding::base64_encode);
+        self.register("base64_decode", encoding::base64_decode);
+        self.register("base64url_encode", encoding::base64url_encode);
+        self.register("base64url_decode", encoding::base64url_decode);
+    }
+
+    fn register_equality_functions(&mut self) {
+        self.register("deep_equal", equality::deep_equal);
+        self.register("structural_hash", equality::structural_hash);
+    }
+
+    fn register_unit_functions(&mut self) {
+        self.register("convert", units::convert);
+    }
+
     fn register_util_functions(&mut self) {
         self.register("length", util::length); // Universal length for strings and arrays
         self.register("is_null", util::is_null);
@@ -192,6 +228,10 @@ impl BuiltinRegistry {
         self.register("date_minute", datetime::date_minute);
         self.register("date_second", datetime::date_second);
         self.register("date_day_of_week", datetime::date_day_of_week);
+
+        // Cron schedules
+        self.register("cron_next", datetime::cron_next);
+        self.register("cron_matches", datetime::cron_matches);
     }
 }
 
@@ -235,9 +275,9 @@ pub(crate) fn check_min_arg_count(
 
 /// Helper to extract a lambda expression from args
 #[expect(dead_code)]
-pub(crate) fn extract_lambda(arg: &Expr) -> ExpressionResult<(&str, &Expr)> {
+pub(crate) fn extract_lambda(arg: &Expr) -> ExpressionResult<(&[Arc<str>], &Expr)> {
     match arg {
-        Expr::Lambda { param, body } => Ok((param, body)),
+        Expr::Lambda { params, body } => Ok((params, body)),
         _ => Err(ExpressionError::expression_invalid_argument(
             "lambda",
             "Expected a lambda expression",
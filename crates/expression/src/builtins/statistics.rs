@@ -0,0 +1,194 @@
+//! Statistical aggregation over numeric arrays
+//!
+//! Every function takes an array as its first argument. Elements are read like
+//! any numeric argument: in strict mode each must be a number, otherwise
+//! numeric strings and booleans are coerced. An element that is not a number
+//! is an error naming its index; nothing is skipped silently.
+//!
+//! `sum` of an empty array is `0`; `avg`, `median`, `percentile` and `stddev`
+//! of an empty array are `null`.
+
+use serde_json::Value;
+
+use super::{
+    check_arg_count, check_min_arg_count, get_array_arg, get_number_arg_with_policy,
+    math::finite_result,
+};
+use crate::{
+    ExpressionError,
+    context::EvaluationContext,
+    error::{ExpressionErrorExt, ExpressionResult},
+    eval::BuiltinView,
+};
+
+/// Read every element of the array argument as `f64`.
+fn numbers(
+    func_name: &str,
+    args: &[Value],
+    view: BuiltinView<'_>,
+    ctx: &EvaluationContext,
+) -> ExpressionResult<Vec<f64>> {
+    get_array_arg(func_name, args, 0, "values")?
+        .iter()
+        .enumerate()
+        .map(|(i, item)| {
+            get_number_arg_with_policy(func_name, std::slice::from_ref(item), 0, "value", view, ctx)
+                .map_err(|_| {
+                    ExpressionError::expression_invalid_argument(
+                        func_name,
+                        format!(
+                            "Element at index {i} must be a number, got {}",
+                            crate::value_utils::value_type_name(item)
+                        ),
+                    )
+                })
+        })
+        .collect()
+}
+
+fn mean(values: &[f64]) -> f64 {
+    values.iter().sum::<f64>() / values.len() as f64
+}
+
+/// Value at `percent` (0–100) of an ascending, non-empty slice, interpolating
+/// linearly between the two nearest ranks.
+fn percentile_of_sorted(sorted: &[f64], percent: f64) -> f64 {
+    let rank = percent / 100.0 * (sorted.len() - 1) as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    (sorted[upper] - sorted[lower]).mul_add(rank - lower as f64, sorted[lower])
+}
+
+fn sorted(mut values: Vec<f64>) -> Vec<f64> {
+    values.sort_unstable_by(f64::total_cmp);
+    values
+}
+
+/// Sum of an array of numbers.
+///
+/// Stays an integer while every element is an integer and the total fits in
+/// 64 bits. Example: `sum([1, 2, 3])` returns `6`; `sum([])` returns `0`.
+pub fn sum(
+    args: &[Value],
+    view: BuiltinView<'_>,
+    ctx: &EvaluationContext,
+) -> ExpressionResult<Value> {
+    check_arg_count("sum", args, 1)?;
+    let values = numbers("sum", args, view, ctx)?;
+    let integer_total = get_array_arg("sum", args, 0, "values")?
+        .iter()
+        .try_fold(0_i64, |total, item| {
+            item.as_i64().and_then(|n| total.checked_add(n))
+        });
+    match integer_total {
+        Some(total) => Ok(Value::from(total)),
+        None => finite_result("sum", values.iter().sum()),
+    }
+}
+
+/// Arithmetic mean of an array of numbers, or `null` if it is empty.
+///
+/// Example: `avg([1, 2, 3, 4])` returns `2.5`
+pub fn avg(
+    args: &[Value],
+    view: BuiltinView<'_>,
+    ctx: &EvaluationContext,
+) -> ExpressionResult<Value> {
+    check_arg_count("avg", args, 1)?;
+    let values = numbers("avg", args, view, ctx)?;
+    if values.is_empty() {
+        return Ok(Value::Null);
+    }
+    finite_result("avg", mean(&values))
+}
+
+/// Median of an array of numbers, or `null` if it is empty. An even-length
+/// array yields the mean of its two middle values.
+///
+/// Example: `median([3, 1, 4, 1])` returns `2.0`
+pub fn median(
+    args: &[Value],
+    view: BuiltinView<'_>,
+    ctx: &EvaluationContext,
+) -> ExpressionResult<Value> {
+    check_arg_count("median", args, 1)?;
+    let values = numbers("median", args, view, ctx)?;
+    if values.is_empty() {
+        return Ok(Value::Null);
+    }
+    finite_result("median", percentile_of_sorted(&sorted(values), 50.0))
+}
+
+/// Percentile of an array of numbers, or `null` if it is empty.
+///
+/// Signature: `percentile(values, p)` with `p` from 0 to 100. Interpolates
+/// linearly between the nearest ranks, so `percentile(values, 50)` equals
+/// `median(values)`, `0` gives the minimum and `100` the maximum.
+///
+/// Example: `percentile([1, 2, 3, 4, 5], 90)` returns `4.6`
+pub fn percentile(
+    args: &[Value],
+    view: BuiltinView<'_>,
+    ctx: &EvaluationContext,
+) -> ExpressionResult<Value> {
+    check_arg_count("percentile", args, 2)?;
+    let percent = get_number_arg_with_policy("percentile", args, 1, "p", view, ctx)?;
+    if !(0.0..=100.0).contains(&percent) {
+        return Err(ExpressionError::expression_invalid_argument(
+            "percentile",
+            format!("Argument 'p' must be between 0 and 100, got {percent}"),
+        ));
+    }
+    let values = numbers("percentile", args, view, ctx)?;
+    if values.is_empty() {
+        return Ok(Value::Null);
+    }
+    finite_result("percentile", percentile_of_sorted(&sorted(values), percent))
+}
+
+/// Standard deviation of an array of numbers.
+///
+/// Signature: `stddev(values, sample?)`. By default this is the population
+/// standard deviation (divide by `n`); pass `true` for the sample standard
+/// deviation (divide by `n - 1`). Returns `null` for an empty array, and for
+/// a single element when `sample` is `true`.
+///
+/// Example: `stddev([2, 4, 4, 4, 5, 5, 7, 9])` returns `2.0`
+pub fn stddev(
+    args: &[Value],
+    view: BuiltinView<'_>,
+    ctx: &EvaluationContext,
+) -> ExpressionResult<Value> {
+    check_min_arg_count("stddev", args, 1)?;
+    if args.len() > 2 {
+        return Err(ExpressionError::expression_invalid_argument(
+            "stddev",
+            format!("Expected 1-2 arguments, got {}", args.len()),
+        ));
+    }
+    let sample = match args.get(1) {
+        None => false,
+        Some(Value::Bool(flag)) => *flag,
+        Some(other) => {
+            return Err(ExpressionError::expression_invalid_argument(
+                "stddev",
+                format!(
+                    "Argument 'sample' must be a boolean, got {}",
+                    crate::value_utils::value_type_name(other)
+                ),
+            ));
+        },
+    };
+    let values = numbers("stddev", args, view, ctx)?;
+    let divisor = if sample {
+        values.len().saturating_sub(1)
+    } else {
+        values.len()
+    };
+    if divisor == 0 {
+        return Ok(Value::Null);
+    }
+    let center = mean(&values);
+    let squares: f64 = values.iter().map(|v| (v - center).powi(2)).sum();
+    finite_result("stddev", (squares / divisor as f64).sqrt())
+}
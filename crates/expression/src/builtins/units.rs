@@ -0,0 +1,212 @@
+//! Unit conversion functions
+//!
+//! `convert(value, from_unit, to_unit)` looks both units up in [`UNITS`] and
+//! converts through the base unit of their shared [`Dimension`]. Supporting a
+//! new unit is one more row in the table.
+
+use serde_json::Value;
+
+use super::{check_arg_count, get_number_arg_with_policy, get_string_arg};
+use crate::{
+    ExpressionError,
+    context::EvaluationContext,
+    error::{ExpressionErrorExt, ExpressionResult},
+    eval::BuiltinView,
+};
+
+/// Kind of quantity a unit measures. Only units of the same dimension convert
+/// into each other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Dimension {
+    /// Base unit: byte.
+    Data,
+    /// Base unit: metre.
+    Length,
+    /// Base unit: kilogram.
+    Mass,
+    /// Base unit: second.
+    Time,
+    /// Base unit: kelvin.
+    Temperature,
+}
+
+impl Dimension {
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Data => "data size",
+            Self::Length => "length",
+            Self::Mass => "mass",
+            Self::Time => "time",
+            Self::Temperature => "temperature",
+        }
+    }
+}
+
+/// One supported unit: `base = value * scale + offset`.
+///
+/// `offset` is zero for every dimension except temperature.
+struct Unit {
+    /// Accepted spellings, matched case-sensitively (`MB` is a megabyte, `mb`
+    /// is not recognised).
+    names: &'static [&'static str],
+    dimension: Dimension,
+    scale: f64,
+    offset: f64,
+}
+
+const fn unit(names: &'static [&'static str], dimension: Dimension, scale: f64) -> Unit {
+    Unit {
+        names,
+        dimension,
+        scale,
+        offset: 0.0,
+    }
+}
+
+/// Every unit `convert` knows. Data sizes follow SI (`KB` = 1000 bytes) and
+/// IEC (`KiB` = 1024 bytes) prefixes.
+const UNITS: &[Unit] = &[
+    // Data
+    unit(&["bit", "bits"], Dimension::Data, 0.125),
+    unit(&["B", "byte", "bytes"], Dimension::Data, 1.0),
+    unit(&["KB", "kilobyte", "kilobytes"], Dimension::Data, 1e3),
+    unit(&["MB", "megabyte", "megabytes"], Dimension::Data, 1e6),
+    unit(&["GB", "gigabyte", "gigabytes"], Dimension::Data, 1e9),
+    unit(&["TB", "terabyte", "terabytes"], Dimension::Data, 1e12),
+    unit(&["KiB", "kibibyte", "kibibytes"], Dimension::Data, 1024.0),
+    unit(
+        &["MiB", "mebibyte", "mebibytes"],
+        Dimension::Data,
+        1_048_576.0,
+    ),
+    unit(
+        &["GiB", "gibibyte", "gibibytes"],
+        Dimension::Data,
+        1_073_741_824.0,
+    ),
+    unit(
+        &["TiB", "tebibyte", "tebibytes"],
+        Dimension::Data,
+        1_099_511_627_776.0,
+    ),
+    // Length
+    unit(
+        &["mm", "millimeter", "millimeters"],
+        Dimension::Length,
+        1e-3,
+    ),
+    unit(
+        &["cm", "centimeter", "centimeters"],
+        Dimension::Length,
+        1e-2,
+    ),
+    unit(
+        &["m", "meter", "meters", "metre", "metres"],
+        Dimension::Length,
+        1.0,
+    ),
+    unit(&["km", "kilometer", "kilometers"], Dimension::Length, 1e3),
+    unit(&["in", "inch", "inches"], Dimension::Length, 0.0254),
+    unit(&["ft", "foot", "feet"], Dimension::Length, 0.3048),
+    unit(&["yd", "yard", "yards"], Dimension::Length, 0.9144),
+    unit(&["mi", "mile", "miles"], Dimension::Length, 1609.344),
+    // Mass
+    unit(&["mg", "milligram", "milligrams"], Dimension::Mass, 1e-6),
+    unit(&["g", "gram", "grams"], Dimension::Mass, 1e-3),
+    unit(&["kg", "kilogram", "kilograms"], Dimension::Mass, 1.0),
+    unit(&["t", "tonne", "tonnes"], Dimension::Mass, 1e3),
+    unit(
+        &["oz", "ounce", "ounces"],
+        Dimension::Mass,
+        0.028_349_523_125,
+    ),
+    unit(
+        &["lb", "lbs", "pound", "pounds"],
+        Dimension::Mass,
+        0.453_592_37,
+    ),
+    // Time
+    unit(
+        &["ms", "millisecond", "milliseconds"],
+        Dimension::Time,
+        1e-3,
+    ),
+    unit(&["s", "second", "seconds"], Dimension::Time, 1.0),
+    unit(&["min", "minute", "minutes"], Dimension::Time, 60.0),
+    unit(&["h", "hour", "hours"], Dimension::Time, 3600.0),
+    unit(&["d", "day", "days"], Dimension::Time, 86_400.0),
+    unit(&["week", "weeks"], Dimension::Time, 604_800.0),
+    // Temperature
+    unit(&["K", "kelvin"], Dimension::Temperature, 1.0),
+    Unit {
+        names: &["C", "celsius"],
+        dimension: Dimension::Temperature,
+        scale: 1.0,
+        offset: 273.15,
+    },
+    Unit {
+        names: &["F", "fahrenheit"],
+        dimension: Dimension::Temperature,
+        scale: 5.0 / 9.0,
+        offset: 273.15 - 32.0 * 5.0 / 9.0,
+    },
+];
+
+fn lookup(name: &str) -> ExpressionResult<&'static Unit> {
+    UNITS
+        .iter()
+        .find(|unit| unit.names.contains(&name))
+        .ok_or_else(|| {
+            ExpressionError::expression_invalid_argument(
+                "convert",
+                format!("Unknown unit '{name}'"),
+            )
+        })
+}
+
+/// Round to 15 significant digits, dropping the binary floating-point noise
+/// the scale factors introduce (`3 ft` is `36 in`, not `36.00000000000001`).
+fn round_significant(value: f64) -> f64 {
+    format!("{value:.14e}").parse().unwrap_or(value)
+}
+
+/// Convert a number between units of the same dimension.
+///
+/// Signature: `convert(value, from_unit, to_unit)`, e.g.
+/// `convert(1536, "KiB", "MiB")` → `1.5`. Converting between dimensions
+/// (`convert(1, "kg", "m")`) is an error. Results are rounded to 15
+/// significant digits.
+pub fn convert(
+    args: &[Value],
+    view: BuiltinView<'_>,
+    ctx: &EvaluationContext,
+) -> ExpressionResult<Value> {
+    check_arg_count("convert", args, 3)?;
+    let value = get_number_arg_with_policy("convert", args, 0, "value", view, ctx)?;
+    let from_name = get_string_arg("convert", args, 1, "from_unit")?;
+    let to_name = get_string_arg("convert", args, 2, "to_unit")?;
+    let from = lookup(from_name)?;
+    let to = lookup(to_name)?;
+
+    if from.dimension != to.dimension {
+        return Err(ExpressionError::expression_invalid_argument(
+            "convert",
+            format!(
+                "Cannot convert {} '{from_name}' to {} '{to_name}'",
+                from.dimension.name(),
+                to.dimension.name()
+            ),
+        ));
+    }
+
+    let base = value.mul_add(from.scale, from.offset);
+    let converted = (base - to.offset) / to.scale;
+    if converted.is_finite() {
+        Ok(serde_json::json!(round_significant(converted)))
+    } else {
+        Err(ExpressionError::expression_invalid_argument(
+            "convert",
+            "result is not a finite number",
+        ))
+    }
+}
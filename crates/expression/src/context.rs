@@ -6,6 +6,7 @@
 use std::{collections::HashMap, sync::Arc};
 
 use chrono::Utc;
+use parking_lot::Mutex;
 use serde_json::{Map, Value};
 
 use crate::policy::EvaluationPolicy;
@@ -48,6 +49,10 @@ pub struct EvaluationContext {
     nodes_view: Arc<Value>,
     /// Pre-materialized `$execution` view (same rationale as `nodes_view`).
     execution_view: Arc<Value>,
+    /// Values cached by [`memoize`](Self::memoize). Shared, not copied, by
+    /// clones, so the per-iteration clones made by `map`/`filter`/`reduce`
+    /// reuse what the enclosing evaluation already computed.
+    memo: Arc<Mutex<HashMap<Arc<str>, Arc<Value>>>>,
 }
 
 #[inline]
@@ -81,6 +86,7 @@ impl EvaluationContext {
             policy: None,
             nodes_view: empty_object_arc(),
             execution_view: empty_object_arc(),
+            memo: Arc::default(),
         }
     }
 
@@ -150,6 +156,27 @@ impl EvaluationContext {
         self.policy.as_deref()
     }
 
+    /// Return the value cached under `key`, computing it with `compute` on
+    /// first use.
+    ///
+    /// Meant for builtins that derive an expensive value from the context:
+    /// every reference within one context's lifetime, across expressions and
+    /// template parts, reuses the first result. The cache is shared with
+    /// clones of this context and is never invalidated, so a value derived
+    /// from data changed later (e.g. by `set_node_data`) stays as computed.
+    ///
+    /// `compute` runs without the cache locked and may call `memoize` itself.
+    /// If two threads miss the same key at once, both compute and the first
+    /// stored value is kept.
+    pub fn memoize(&self, key: impl AsRef<str>, compute: impl FnOnce() -> Value) -> Arc<Value> {
+        let key = key.as_ref();
+        if let Some(value) = self.memo.lock().get(key) {
+            return Arc::clone(value);
+        }
+        let value = Arc::new(compute());
+        Arc::clone(self.memo.lock().entry(Arc::from(key)).or_insert(value))
+    }
+
     /// Resolve a variable by name.
     ///
     /// `$node` and `$execution` are served from pre-materialized views
@@ -260,6 +287,7 @@ impl EvaluationContextBuilder {
             policy: self.policy,
             nodes_view,
             execution_view,
+            memo: Arc::default(),
         }
     }
 }
@@ -389,6 +417,27 @@ mod tests {
         }
     }
 
+    #[test]
+    fn memoize_computes_once_and_is_shared_with_clones() {
+        let ctx = EvaluationContext::new();
+        let mut runs = 0;
+        let first = ctx.memoize("total", || {
+            runs += 1;
+            Value::from(42)
+        });
+        let cloned = ctx.clone();
+        let again = cloned.memoize("total", || unreachable!("cached"));
+        assert_eq!(runs, 1);
+        assert_eq!(*first, Value::from(42));
+        assert!(Arc::ptr_eq(&first, &again));
+
+        let nested = ctx.memoize("outer", || {
+            let inner = ctx.memoize("inner", || Value::from(1));
+            Value::from(inner.as_i64().unwrap() + 1)
+        });
+        assert_eq!(*nested, Value::from(2));
+    }
+
     #[test]
     fn clone_preserves_view_content() {
         // `EvaluationContext::Clone` is invoked per lambda iteration; the
@@ -14,8 +14,9 @@ use serde_json::Value;
 use tracing::instrument;
 
 use crate::{
-    ast::Expr, builtins::BuiltinRegistry, context::EvaluationContext, error::ExpressionResult,
-    eval::Evaluator, lexer::Lexer, parser::Parser, policy::EvaluationPolicy,
+    analysis::ExpressionAnalysis, ast::Expr, builtins::BuiltinRegistry, context::EvaluationContext,
+    error::ExpressionResult, eval::Evaluator, eval_trace::EvalTrace, fold::ConstantFolder,
+    lexer::Lexer, parser::Parser, policy::EvaluationPolicy, span::Span,
 };
 
 /// Cache hit/miss statistics snapshot.
@@ -164,6 +165,8 @@ pub struct ExpressionEngine {
     policy: Option<Arc<EvaluationPolicy>>,
     /// Evaluator
     evaluator: Evaluator,
+    /// Constant-folding pass run on every parsed AST
+    folder: ConstantFolder,
 }
 
 impl ExpressionEngine {
@@ -175,6 +178,7 @@ impl ExpressionEngine {
     ) -> Self {
         let builtins = Arc::new(BuiltinRegistry::new());
         let evaluator = Evaluator::with_policy(Arc::clone(&builtins), policy.clone());
+        let folder = ConstantFolder::new(Arc::clone(&builtins), policy.as_deref());
 
         Self {
             expr_cache,
@@ -182,6 +186,7 @@ impl ExpressionEngine {
             builtins,
             policy,
             evaluator,
+            folder,
         }
     }
 
@@ -189,11 +194,13 @@ impl ExpressionEngine {
     fn create(policy: Option<Arc<EvaluationPolicy>>) -> Self {
         let builtins = Arc::new(BuiltinRegistry::new());
         let evaluator = Evaluator::with_policy(Arc::clone(&builtins), policy.clone());
+        let folder = ConstantFolder::new(Arc::clone(&builtins), policy.as_deref());
 
         Self {
             builtins,
             policy,
             evaluator,
+            folder,
         }
     }
 
@@ -266,6 +273,7 @@ impl ExpressionEngine {
 
     fn rebuild_evaluator(&mut self) {
         self.evaluator = Evaluator::with_policy(Arc::clone(&self.builtins), self.policy.clone());
+        self.folder = ConstantFolder::new(Arc::clone(&self.builtins), self.policy.as_deref());
     }
 
     /// Register a custom builtin function.
@@ -291,23 +299,7 @@ impl ExpressionEngine {
     ) -> ExpressionResult<Value> {
         trace!(expression = expression, "Evaluating expression");
 
-        // Parse the expression (with caching if enabled)
-        #[cfg(feature = "cache")]
-        let ast = if let Some(cache) = &self.expr_cache {
-            let key: Arc<str> = Arc::from(expression);
-            if let Some(cached) = cache.get(&key) {
-                cached
-            } else {
-                let parsed = self.parse_expression(expression)?;
-                cache.insert(key, parsed.clone());
-                parsed
-            }
-        } else {
-            self.parse_expression(expression)?
-        };
-
-        #[cfg(not(feature = "cache"))]
-        let ast = self.parse_expression(expression)?;
+        let ast = self.parse_cached(expression)?;
 
         // Evaluate the AST
         let result = self.evaluator.eval(&ast, context)?;
@@ -316,6 +308,79 @@ impl ExpressionEngine {
         Ok(result)
     }
 
+    /// Evaluate an expression and deserialize the result into `T`.
+    ///
+    /// Shorthand for [`evaluate`](Self::evaluate) followed by
+    /// `serde_json::from_value`, e.g. `engine.evaluate_as::<Vec<i64>>("[1, 2]", &ctx)`.
+    ///
+    /// # Errors
+    ///
+    /// Any error from [`evaluate`](Self::evaluate), or
+    /// [`ExpressionError::TypeError`](crate::ExpressionError::TypeError) naming `T`
+    /// and the JSON type actually produced when the result does not
+    /// deserialize into `T`.
+    pub fn evaluate_as<T: serde::de::DeserializeOwned>(
+        &self,
+        expression: &str,
+        context: &EvaluationContext,
+    ) -> ExpressionResult<T> {
+        let value = self.evaluate(expression, context)?;
+        let actual = crate::value_utils::value_type_name(&value);
+        serde_json::from_value(value).map_err(|err| {
+            crate::ExpressionError::type_error(
+                std::any::type_name::<T>(),
+                format!("{actual} ({err})"),
+            )
+        })
+    }
+
+    /// Parse an expression without evaluating it and report what it references.
+    ///
+    /// The result lists the context variables read (`$node.fetch.data`,
+    /// `$execution.id`, …), the functions called, any of those that are not
+    /// known to this engine, whether the `=~` regex operator is used, and the
+    /// maximum AST depth. See [`ExpressionAnalysis`] for the exact rules.
+    ///
+    /// Parsing goes through the expression cache, so analyzing and then
+    /// evaluating the same string parses it only once.
+    ///
+    /// # Errors
+    ///
+    /// Returns the lexer or parser error if the expression is malformed.
+    pub fn analyze(&self, expression: &str) -> ExpressionResult<ExpressionAnalysis> {
+        let ast = self.parse_cached(expression)?;
+        Ok(ExpressionAnalysis::of(&ast, &self.builtins))
+    }
+
+    /// Evaluate an expression while recording every intermediate value.
+    ///
+    /// Returns the result [`evaluate`](Self::evaluate) would, together with
+    /// an [`EvalTrace`] of each sub-expression's span in `expression` and the
+    /// value it produced, for debuggers and editor tooling. The expression is
+    /// parsed afresh and without constant folding, so every sub-expression
+    /// written in the source shows up; use [`evaluate`](Self::evaluate) on
+    /// hot paths. If parsing fails the trace is empty; if evaluation fails it
+    /// holds the steps completed before the error.
+    pub fn evaluate_traced(
+        &self,
+        expression: &str,
+        context: &EvaluationContext,
+    ) -> (ExpressionResult<Value>, EvalTrace) {
+        let (offset, body) = strip_delimiters(expression);
+        let parsed = Lexer::new(body)
+            .tokenize()
+            .and_then(|tokens| Parser::new(tokens).parse_with_spans());
+        let (ast, spans) = match parsed {
+            Ok(parsed) => parsed,
+            Err(err) => return (Err(err), EvalTrace::default()),
+        };
+        let spans = spans
+            .into_iter()
+            .map(|span| Span::new(offset + span.start as usize, offset + span.end as usize))
+            .collect();
+        self.evaluator.eval_traced(&ast, context, spans)
+    }
+
     /// Parse a template from a string (with caching if enabled)
     ///
     /// If template caching is enabled, this will return a cached template
@@ -352,24 +417,33 @@ impl ExpressionEngine {
         template.render(self, context)
     }
 
+    /// Parse an expression through the expression cache, if enabled
+    fn parse_cached(&self, expression: &str) -> ExpressionResult<Expr> {
+        #[cfg(feature = "cache")]
+        if let Some(cache) = &self.expr_cache {
+            let key: Arc<str> = Arc::from(expression);
+            if let Some(cached) = cache.get(&key) {
+                return Ok(cached);
+            }
+            let parsed = self.parse_expression(expression)?;
+            cache.insert(key, parsed.clone());
+            return Ok(parsed);
+        }
+
+        self.parse_expression(expression)
+    }
+
     /// Parse an expression string into an AST (internal helper)
     fn parse_expression(&self, expression: &str) -> ExpressionResult<Expr> {
-        // Handle template delimiters
-        let expr_content =
-            if expression.trim().starts_with("{{") && expression.trim().ends_with("}}") {
-                let trimmed = expression.trim();
-                trimmed[2..trimmed.len() - 2].trim()
-            } else {
-                expression
-            };
+        let (_, expr_content) = strip_delimiters(expression);
 
         // Tokenize
         let mut lexer = Lexer::new(expr_content);
         let tokens = lexer.tokenize()?;
 
-        // Parse
+        // Parse, then fold constant sub-expressions
         let mut parser = Parser::new(tokens);
-        parser.parse()
+        self.folder.fold(parser.parse()?)
     }
 
     /// Clear all caches (expressions and templates)
@@ -497,11 +571,116 @@ impl Default for ExpressionEngine {
     }
 }
 
+/// Strip one pair of surrounding `{{ }}` delimiters, returning the byte
+/// offset of the expression body in `expression` along with the body.
+fn strip_delimiters(expression: &str) -> (usize, &str) {
+    let trimmed = expression.trim();
+    if !(trimmed.starts_with("{{") && trimmed.ends_with("}}")) {
+        return (0, expression);
+    }
+    let inner = &trimmed[2..trimmed.len() - 2];
+    let leading = expression.len() - expression.trim_start().len();
+    let offset = leading + 2 + (inner.len() - inner.trim_start().len());
+    (offset, inner.trim())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::EvaluationPolicy;
 
+    #[test]
+    #[cfg(feature = "cache")]
+    fn analyze_shares_the_parse_cache_with_evaluate() {
+        let engine = ExpressionEngine::with_cache_size(16);
+        let context = EvaluationContext::new();
+
+        let analysis = engine.analyze("max(1, 2) + 3").unwrap();
+        assert_eq!(analysis.functions.len(), 1);
+        assert_eq!(engine.evaluate("max(1, 2) + 3", &context).unwrap(), 5);
+
+        let overview = engine.cache_overview();
+        assert_eq!((overview.expr_misses, overview.expr_hits), (1, 1));
+    }
+
+    #[test]
+    fn evaluate_as_deserializes_result() {
+        let engine = ExpressionEngine::new();
+        let context = EvaluationContext::new();
+
+        assert_eq!(engine.evaluate_as::<i64>("1 + 1", &context).unwrap(), 2);
+        assert_eq!(
+            engine.evaluate_as::<Vec<i64>>("[1,2,3]", &context).unwrap(),
+            vec![1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn evaluate_as_reports_type_mismatch() {
+        let engine = ExpressionEngine::new();
+        let context = EvaluationContext::new();
+
+        let err = engine
+            .evaluate_as::<Vec<i64>>("\"not a list\"", &context)
+            .unwrap_err();
+        let crate::ExpressionError::TypeError { expected, actual } = &err else {
+            panic!("expected TypeError, got {err:?}");
+        };
+        assert!(expected.contains("Vec<i64>"), "{expected}");
+        assert!(actual.starts_with("string ("), "{actual}");
+    }
+
+    #[test]
+    fn evaluate_traced_records_intermediate_values_with_spans() {
+        let engine = ExpressionEngine::new();
+        let mut context = EvaluationContext::new();
+        context.set_input(serde_json::json!({"a": 2, "b": 3, "c": 4}));
+        let source = "$input.a + $input.b * $input.c";
+
+        let (result, trace) = engine.evaluate_traced(source, &context);
+        assert_eq!(result.unwrap(), 14);
+
+        let steps: Vec<(&str, &Value)> = trace
+            .steps()
+            .iter()
+            .map(|step| (step.span.slice(source), &step.value))
+            .filter(|(text, _)| *text != "$input")
+            .collect();
+        assert_eq!(
+            steps,
+            [
+                ("$input.a", &Value::from(2)),
+                ("$input.b", &Value::from(3)),
+                ("$input.c", &Value::from(4)),
+                ("$input.b * $input.c", &Value::from(12)),
+                (source, &Value::from(14)),
+            ]
+        );
+    }
+
+    #[test]
+    fn evaluate_traced_offsets_spans_past_delimiters_and_keeps_partial_steps() {
+        let engine = ExpressionEngine::new();
+        let context = EvaluationContext::new();
+        let source = "{{ (2 * 3) / 0 }}";
+
+        let (result, trace) = engine.evaluate_traced(source, &context);
+        assert!(result.is_err());
+        let steps: Vec<&str> = trace
+            .steps()
+            .iter()
+            .map(|step| step.span.slice(source))
+            .collect();
+        assert_eq!(
+            steps,
+            ["2", "3", "2 * 3", "0"],
+            "unfolded, up to the failure"
+        );
+
+        let (result, trace) = engine.evaluate_traced("1 +", &context);
+        assert!(result.is_err() && trace.is_empty());
+    }
+
     fn constant_one(
         _args: &[Value],
         _view: crate::eval::BuiltinView<'_>,
@@ -546,6 +725,58 @@ mod tests {
         assert_eq!(result.as_i64(), Some(5));
     }
 
+    #[test]
+    fn test_evaluate_null_coalesce() {
+        let engine = ExpressionEngine::new();
+        let mut context = EvaluationContext::new();
+        context.set_input(serde_json::json!({ "name": null, "count": 0 }));
+
+        let result = engine
+            .evaluate("$input.name ?? \"unknown\"", &context)
+            .unwrap();
+        assert_eq!(result.as_str(), Some("unknown"));
+
+        // Only null falls through; falsy values are kept.
+        let result = engine.evaluate("$input.count ?? 5", &context).unwrap();
+        assert_eq!(result.as_i64(), Some(0));
+
+        // The fallback is not evaluated when the left side is non-null.
+        let result = engine.evaluate("1 ?? no_such_fn()", &context).unwrap();
+        assert_eq!(result.as_i64(), Some(1));
+    }
+
+    #[test]
+    fn test_evaluate_optional_chaining() {
+        let engine = ExpressionEngine::new();
+        let mut context = EvaluationContext::new();
+        context.set_node_data("fetch", serde_json::json!({ "data": { "id": 7 } }));
+
+        let result = engine
+            .evaluate("$node.fetch.data?.user?.email", &context)
+            .unwrap();
+        assert!(result.is_null());
+
+        let result = engine
+            .evaluate("$node.fetch.data?.user?.email ?? \"none\"", &context)
+            .unwrap();
+        assert_eq!(result.as_str(), Some("none"));
+
+        let result = engine.evaluate("$node.fetch?.data?.id", &context).unwrap();
+        assert_eq!(result.as_i64(), Some(7));
+
+        // Plain `.` still fails on a missing property, with the same message.
+        let err = engine
+            .evaluate("$node.fetch.data.user.email", &context)
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("Property 'user' not found"),
+            "{err}"
+        );
+
+        // `?.` on a non-object, non-null value is still a type error.
+        assert!(engine.evaluate("$node.fetch.data.id?.x", &context).is_err());
+    }
+
     #[test]
     fn test_parse_template() {
         let engine = ExpressionEngine::new();
@@ -621,6 +852,41 @@ mod tests {
         assert_eq!(result.as_i64(), Some(1));
     }
 
+    static INPUT_SUM_RUNS: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+
+    /// Sum of the `$input` array, memoized on the context.
+    fn input_sum(
+        _args: &[Value],
+        _view: crate::eval::BuiltinView<'_>,
+        context: &EvaluationContext,
+    ) -> ExpressionResult<Value> {
+        let sum = context.memoize("input_sum", || {
+            INPUT_SUM_RUNS.fetch_add(1, Ordering::SeqCst);
+            let input = context.get_input();
+            let items = input.as_array().map_or(&[][..], Vec::as_slice);
+            Value::from(items.iter().filter_map(Value::as_i64).sum::<i64>())
+        });
+        Ok((*sum).clone())
+    }
+
+    #[test]
+    fn memoized_builtin_runs_once_per_template_render() {
+        let mut engine = ExpressionEngine::new();
+        engine.register_function("input_sum", input_sum);
+        let template = engine
+            .parse_template(
+                "{{ input_sum() }} / {{ input_sum() * 2 }} / {{ map([1, 2], x => input_sum() + x) }}",
+            )
+            .unwrap();
+
+        let mut context = EvaluationContext::new();
+        context.set_input(serde_json::json!([1, 2, 3]));
+        let rendered = engine.render_template(&template, &context).unwrap();
+
+        assert_eq!(rendered, "6 / 12 / [7,8]");
+        assert_eq!(INPUT_SUM_RUNS.load(Ordering::SeqCst), 1);
+    }
+
     #[test]
     fn test_function_allowlist_blocks_disallowed() {
         let engine = ExpressionEngine::new().restrict_to_functions(["length"]);
@@ -4,6 +4,8 @@
 
 use thiserror::Error;
 
+use crate::span::Span;
+
 // ============================================================================
 // Main Error Type
 // ============================================================================
@@ -22,6 +24,20 @@ pub enum ExpressionError {
     #[error("Expression parse error: {message}")]
     ParseError { message: String },
 
+    /// Template opening delimiter (`{{` or `{% raw %}`) with no closing one
+    ///
+    /// `span` covers the byte range from the opening delimiter to the end
+    /// of the template source; `line` and `column` (1-based) locate it.
+    #[classify(category = "validation", code = "EXPR:UNCLOSED_TEMPLATE")]
+    #[error("Unclosed '{opening}' at line {line}, column {column}: expected closing '{closing}'")]
+    UnclosedTemplate {
+        opening: &'static str,
+        closing: &'static str,
+        span: Span,
+        line: usize,
+        column: usize,
+    },
+
     /// Evaluation error
     #[classify(category = "internal", code = "EXPR:EVAL")]
     #[error("Expression evaluation error: {message}")]
@@ -14,7 +14,9 @@ use crate::{
     builtins::BuiltinRegistry,
     context::EvaluationContext,
     error::{ExpressionErrorExt, ExpressionResult},
+    eval_trace::{EvalTrace, Tracer},
     policy::EvaluationPolicy,
+    span::Span,
 };
 
 /// Maximum recursion depth for expression evaluation
@@ -28,6 +30,26 @@ const MAX_REGEX_PATTERN_LEN: usize = 1000;
 #[cfg(feature = "regex")]
 const MAX_REGEX_CACHE_SIZE: usize = 100;
 
+/// Lambda-variable name the legacy single-parameter `reduce` form binds the
+/// accumulator to; `$acc` in an expression resolves to it.
+pub(crate) const LEGACY_ACCUMULATOR: &str = "acc";
+
+/// Functions dispatched by [`Evaluator`] itself rather than the builtin
+/// registry, because they take lambda arguments (aliases included).
+pub(crate) const HIGHER_ORDER_FUNCTIONS: &[&str] = &[
+    "filter",
+    "map",
+    "reduce",
+    "find",
+    "find_index",
+    "every",
+    "all",
+    "some",
+    "any",
+    "group_by",
+    "flat_map",
+];
+
 /// Per-call evaluation frame that tracks recursion depth and the DoS
 /// step budget for a single top-level [`Evaluator::eval`] invocation.
 ///
@@ -47,6 +69,8 @@ pub(crate) struct EvalFrame {
     depth: usize,
     steps: usize,
     max_steps: Option<usize>,
+    /// Set only by [`Evaluator::eval_traced`]; `None` on the normal path.
+    tracer: Option<Tracer>,
 }
 
 impl EvalFrame {
@@ -58,6 +82,7 @@ impl EvalFrame {
             depth: 0,
             steps: 0,
             max_steps,
+            tracer: None,
         }
     }
 
@@ -254,6 +279,22 @@ impl Evaluator {
         self.eval_with_frame(expr, context, &mut frame)
     }
 
+    /// Evaluate like [`eval`](Self::eval), recording the value of every node
+    /// that has a span. `spans` are the node spans of `expr` in post-order,
+    /// as returned by `Parser::parse_with_spans`.
+    pub(crate) fn eval_traced(
+        &self,
+        expr: &Expr,
+        context: &EvaluationContext,
+        spans: Vec<Span>,
+    ) -> (ExpressionResult<Value>, EvalTrace) {
+        let mut frame = EvalFrame::new(self.resolve_max_steps(context));
+        frame.tracer = Some(Tracer::new(expr, spans));
+        let result = self.eval_with_frame(expr, context, &mut frame);
+        let trace = frame.tracer.map(Tracer::finish).unwrap_or_default();
+        (result, trace)
+    }
+
     /// Evaluate an expression using the caller's step/depth frame.
     ///
     /// Internal recursive paths MUST use this method — calling
@@ -271,6 +312,11 @@ impl Evaluator {
         frame.enter()?;
         let result = self.eval_node(expr, context, frame);
         frame.leave();
+        if let Some(tracer) = &mut frame.tracer
+            && let Ok(value) = &result
+        {
+            tracer.record(expr, value);
+        }
         result
     }
 
@@ -353,6 +399,15 @@ impl Evaluator {
                 self.access_property(&obj_val, property)
             },
 
+            Expr::OptionalPropertyAccess { object, property } => {
+                let obj_val = self.eval_with_frame(object, context, frame)?;
+                match obj_val {
+                    Value::Null => Ok(Value::Null),
+                    Value::Object(ref o) => Ok(o.get(&**property).cloned().unwrap_or(Value::Null)),
+                    _ => self.access_property(&obj_val, property),
+                }
+            },
+
             Expr::IndexAccess { object, index } => {
                 let obj_val = self.eval_with_frame(object, context, frame)?;
                 let index_val = self.eval_with_frame(index, context, frame)?;
@@ -470,6 +525,14 @@ impl Evaluator {
                 let right_val = self.eval_with_frame(right, context, frame)?;
                 Ok(Value::Bool(self.coerce_boolean(&right_val, context)?))
             },
+            BinaryOp::NullCoalesce => {
+                let left_val = self.eval_with_frame(left, context, frame)?;
+                if left_val.is_null() {
+                    // Only evaluate the fallback when it is needed
+                    return self.eval_with_frame(right, context, frame);
+                }
+                Ok(left_val)
+            },
             // For all other operators, evaluate both operands
             _ => {
                 let left_val = self.eval_with_frame(left, context, frame)?;
@@ -489,7 +552,8 @@ impl Evaluator {
                     BinaryOp::LessEqual => self.less_equal(&left_val, &right_val, context),
                     BinaryOp::GreaterEqual => self.greater_equal(&left_val, &right_val, context),
                     BinaryOp::RegexMatch => self.regex_match(&left_val, &right_val),
-                    BinaryOp::And | BinaryOp::Or => unreachable!(), // Handled above
+                    // Handled above
+                    BinaryOp::And | BinaryOp::Or | BinaryOp::NullCoalesce => unreachable!(),
                 }
             },
         }
@@ -1037,26 +1101,36 @@ impl Evaluator {
         self.builtins.call(name, args, self, context)
     }
 
-    /// Evaluate a lambda expression with a parameter value.
+    /// Evaluate a lambda body for one array element.
+    ///
+    /// Binds the lambda's first parameter to `value` and, when it declares a
+    /// second, binds that to the element's `index` — JavaScript callback
+    /// order, so `map(arr, (x, i) => ...)` sees each element's position.
     ///
     /// Visibility is `pub(crate)` — external callers cannot construct
     /// an [`EvalFrame`], and exposing a wrapper that would create a
     /// fresh frame would reopen the CO-C1-01 lambda DoS bypass.
     pub(crate) fn eval_lambda(
         &self,
-        param: &str,
+        params: &[Arc<str>],
         body: &Expr,
         value: &Value,
+        index: usize,
         context: &EvaluationContext,
         frame: &mut EvalFrame,
     ) -> ExpressionResult<Value> {
-        // Create a new context with the lambda parameter. Note: we
+        // Create a new context with the lambda parameters. Note: we
         // reuse the caller's `frame` so the step budget accumulates
         // across every lambda application. Do NOT switch this to
         // `self.eval(...)` — doing so would construct a fresh frame
         // and defeat the whole budget.
         let mut lambda_context = context.clone();
-        lambda_context.set_lambda_var(param, value.clone());
+        if let Some(param) = params.first() {
+            lambda_context.set_lambda_var(param, value.clone());
+        }
+        if let Some(param) = params.get(1) {
+            lambda_context.set_lambda_var(param, Value::from(index));
+        }
         self.eval_with_frame(body, &lambda_context, frame)
     }
 
@@ -1207,7 +1281,7 @@ impl Evaluator {
 
     /// Filter array elements using a lambda predicate
     ///
-    /// Usage: `filter(array, x => condition)`
+    /// Usage: `filter(array, x => condition)` or `filter(array, (x, i) => condition)`
     /// Example: `filter([1, 2, 3, 4, 5], x => x > 2)` returns `[3, 4, 5]`
     fn eval_filter(
         &self,
@@ -1231,21 +1305,12 @@ impl Evaluator {
             )
         })?;
 
-        // Extract the lambda
-        let (param, body) = match &args[1] {
-            Expr::Lambda { param, body } => (param.as_ref(), body.as_ref()),
-            _ => {
-                return Err(ExpressionError::expression_type_error(
-                    "lambda expression",
-                    "non-lambda",
-                ));
-            },
-        };
+        let (params, body) = lambda_arg("filter", &args[1], 2)?;
 
         // Filter the array
         let mut result = Vec::with_capacity(array.len());
-        for item in array {
-            let predicate_result = self.eval_lambda(param, body, item, context, frame)?;
+        for (index, item) in array.iter().enumerate() {
+            let predicate_result = self.eval_lambda(params, body, item, index, context, frame)?;
             if self.coerce_boolean(&predicate_result, context)? {
                 result.push(item.clone());
             }
@@ -1256,7 +1321,7 @@ impl Evaluator {
 
     /// Map over array elements using a lambda transformer
     ///
-    /// Usage: `map(array, x => transform)`
+    /// Usage: `map(array, x => transform)` or `map(array, (x, i) => transform)`
     /// Example: `map([1, 2, 3], x => x * 2)` returns `[2, 4, 6]`
     fn eval_map(
         &self,
@@ -1280,21 +1345,12 @@ impl Evaluator {
             )
         })?;
 
-        // Extract the lambda
-        let (param, body) = match &args[1] {
-            Expr::Lambda { param, body } => (param.as_ref(), body.as_ref()),
-            _ => {
-                return Err(ExpressionError::expression_type_error(
-                    "lambda expression",
-                    "non-lambda",
-                ));
-            },
-        };
+        let (params, body) = lambda_arg("map", &args[1], 2)?;
 
         // Map the array
         let mut result = Vec::with_capacity(array.len());
-        for item in array {
-            let transformed = self.eval_lambda(param, body, item, context, frame)?;
+        for (index, item) in array.iter().enumerate() {
+            let transformed = self.eval_lambda(params, body, item, index, context, frame)?;
             result.push(transformed);
         }
 
@@ -1303,11 +1359,15 @@ impl Evaluator {
 
     /// Reduce array elements using a lambda accumulator
     ///
-    /// Usage: `reduce(array, initial, (acc, x) => expression)`
-    /// Note: Since we only support single-parameter lambdas, we use a special syntax:
-    /// `reduce(array, initial, x => expression)` where `$acc` is available in context
+    /// Usage: `reduce(array, initial, (acc, x) => expression)`, optionally
+    /// `(acc, x, i) => ...` to also bind the element index.
     ///
-    /// Example: `reduce([1, 2, 3], 0, x => $acc + x)` returns `6`
+    /// Example: `reduce([1, 2, 3], 0, (acc, x) => acc + x)` returns `6`
+    ///
+    /// The legacy single-parameter form `reduce(array, initial, x => $acc + x)`
+    /// still works but is deprecated: it binds the accumulator to the lambda
+    /// name `acc`, shadowing any execution variable `$acc`. Multi-parameter
+    /// lambdas bind nothing implicitly.
     fn eval_reduce(
         &self,
         args: &[Expr],
@@ -1333,28 +1393,46 @@ impl Evaluator {
         // Evaluate the initial value
         let initial = self.eval_with_frame(&args[1], context, frame)?;
 
-        // Extract the lambda
-        let (param, body) = match &args[2] {
-            Expr::Lambda { param, body } => (param.as_ref(), body.as_ref()),
-            _ => {
-                return Err(ExpressionError::expression_type_error(
-                    "lambda expression",
-                    "non-lambda",
-                ));
-            },
-        };
+        let (params, body) = lambda_arg("reduce", &args[2], 3)?;
 
         // Reduce the array. Each iteration reuses the caller's frame
         // so the step budget is enforced across every element — the
         // previous `self.eval(body, ...)` pattern reset the counter on
         // every element and was the CO-C1-01 DoS bypass.
         let mut accumulator = initial;
-        for item in array {
-            // Create context with both accumulator and current item
+        if params.len() < 2 {
+            for item in array {
+                let mut reduce_context = context.clone();
+                reduce_context.set_lambda_var(LEGACY_ACCUMULATOR, accumulator);
+                if let Some(param) = params.first() {
+                    reduce_context.set_lambda_var(param, item.clone());
+                }
+                accumulator = self.eval_with_frame(body, &reduce_context, frame)?;
+            }
+            return Ok(accumulator);
+        }
+
+        for (index, item) in array.iter().enumerate() {
             let mut reduce_context = context.clone();
-            reduce_context.set_lambda_var("$acc", accumulator.clone());
-            reduce_context.set_lambda_var(param, item.clone());
-            accumulator = self.eval_with_frame(body, &reduce_context, frame)?;
+            reduce_context.set_lambda_var(&params[0], accumulator);
+            reduce_context.set_lambda_var(&params[1], item.clone());
+            if let Some(param) = params.get(2) {
+                reduce_context.set_lambda_var(param, Value::from(index));
+            }
+            accumulator = self
+                .eval_with_frame(body, &reduce_context, frame)
+                .map_err(|err| match err {
+                    ExpressionError::VariableNotFound { name } if name == LEGACY_ACCUMULATOR => {
+                        ExpressionError::expression_eval_error(format!(
+                            "Variable '${LEGACY_ACCUMULATOR}' not found: reduce binds no implicit \
+                             accumulator when its lambda declares parameters; refer to the \
+                             accumulator as '{}' instead (the '${LEGACY_ACCUMULATOR}' form is \
+                             deprecated)",
+                            params[0]
+                        ))
+                    },
+                    other => other,
+                })?;
         }
 
         Ok(accumulator)
@@ -1385,18 +1463,10 @@ impl Evaluator {
             )
         })?;
 
-        let (param, body) = match &args[1] {
-            Expr::Lambda { param, body } => (param.as_ref(), body.as_ref()),
-            _ => {
-                return Err(ExpressionError::expression_type_error(
-                    "lambda expression",
-                    "non-lambda",
-                ));
-            },
-        };
+        let (params, body) = lambda_arg("find", &args[1], 2)?;
 
-        for item in array {
-            let predicate_result = self.eval_lambda(param, body, item, context, frame)?;
+        for (index, item) in array.iter().enumerate() {
+            let predicate_result = self.eval_lambda(params, body, item, index, context, frame)?;
             if self.coerce_boolean(&predicate_result, context)? {
                 return Ok(item.clone());
             }
@@ -1430,18 +1500,10 @@ impl Evaluator {
             )
         })?;
 
-        let (param, body) = match &args[1] {
-            Expr::Lambda { param, body } => (param.as_ref(), body.as_ref()),
-            _ => {
-                return Err(ExpressionError::expression_type_error(
-                    "lambda expression",
-                    "non-lambda",
-                ));
-            },
-        };
+        let (params, body) = lambda_arg("every", &args[1], 2)?;
 
-        for item in array {
-            let predicate_result = self.eval_lambda(param, body, item, context, frame)?;
+        for (index, item) in array.iter().enumerate() {
+            let predicate_result = self.eval_lambda(params, body, item, index, context, frame)?;
             if !self.coerce_boolean(&predicate_result, context)? {
                 return Ok(Value::Bool(false));
             }
@@ -1475,18 +1537,10 @@ impl Evaluator {
             )
         })?;
 
-        let (param, body) = match &args[1] {
-            Expr::Lambda { param, body } => (param.as_ref(), body.as_ref()),
-            _ => {
-                return Err(ExpressionError::expression_type_error(
-                    "lambda expression",
-                    "non-lambda",
-                ));
-            },
-        };
+        let (params, body) = lambda_arg("some", &args[1], 2)?;
 
-        for item in array {
-            let predicate_result = self.eval_lambda(param, body, item, context, frame)?;
+        for (index, item) in array.iter().enumerate() {
+            let predicate_result = self.eval_lambda(params, body, item, index, context, frame)?;
             if self.coerce_boolean(&predicate_result, context)? {
                 return Ok(Value::Bool(true));
             }
@@ -1520,20 +1574,12 @@ impl Evaluator {
             )
         })?;
 
-        let (param, body) = match &args[1] {
-            Expr::Lambda { param, body } => (param.as_ref(), body.as_ref()),
-            _ => {
-                return Err(ExpressionError::expression_type_error(
-                    "lambda expression",
-                    "non-lambda",
-                ));
-            },
-        };
+        let (params, body) = lambda_arg("find_index", &args[1], 2)?;
 
-        for (i, item) in array.iter().enumerate() {
-            let predicate_result = self.eval_lambda(param, body, item, context, frame)?;
+        for (index, item) in array.iter().enumerate() {
+            let predicate_result = self.eval_lambda(params, body, item, index, context, frame)?;
             if self.coerce_boolean(&predicate_result, context)? {
-                return Ok(Value::Number((i as i64).into()));
+                return Ok(Value::Number((index as i64).into()));
             }
         }
 
@@ -1566,19 +1612,11 @@ impl Evaluator {
             )
         })?;
 
-        let (param, body) = match &args[1] {
-            Expr::Lambda { param, body } => (param.as_ref(), body.as_ref()),
-            _ => {
-                return Err(ExpressionError::expression_type_error(
-                    "lambda expression",
-                    "non-lambda",
-                ));
-            },
-        };
+        let (params, body) = lambda_arg("group_by", &args[1], 2)?;
 
         let mut groups = serde_json::Map::new();
-        for item in array {
-            let key_val = self.eval_lambda(param, body, item, context, frame)?;
+        for (index, item) in array.iter().enumerate() {
+            let key_val = self.eval_lambda(params, body, item, index, context, frame)?;
             let key = match &key_val {
                 Value::String(s) => s.clone(),
                 Value::Number(n) => n.to_string(),
@@ -1633,19 +1671,11 @@ impl Evaluator {
             )
         })?;
 
-        let (param, body) = match &args[1] {
-            Expr::Lambda { param, body } => (param.as_ref(), body.as_ref()),
-            _ => {
-                return Err(ExpressionError::expression_type_error(
-                    "lambda expression",
-                    "non-lambda",
-                ));
-            },
-        };
+        let (params, body) = lambda_arg("flat_map", &args[1], 2)?;
 
         let mut result = Vec::new();
-        for item in array {
-            let transformed = self.eval_lambda(param, body, item, context, frame)?;
+        for (index, item) in array.iter().enumerate() {
+            let transformed = self.eval_lambda(params, body, item, index, context, frame)?;
             match transformed {
                 Value::Array(inner) => result.extend(inner),
                 other => result.push(other),
@@ -1656,6 +1686,31 @@ impl Evaluator {
     }
 }
 
+/// Extract the lambda argument of a higher-order function, rejecting
+/// non-lambdas and lambdas declaring more than `max_params` parameters.
+fn lambda_arg<'e>(
+    function: &str,
+    arg: &'e Expr,
+    max_params: usize,
+) -> ExpressionResult<(&'e [Arc<str>], &'e Expr)> {
+    let Expr::Lambda { params, body } = arg else {
+        return Err(ExpressionError::expression_type_error(
+            "lambda expression",
+            "non-lambda",
+        ));
+    };
+    if params.len() > max_params {
+        return Err(ExpressionError::expression_invalid_argument(
+            function,
+            format!(
+                "lambda takes at most {max_params} parameters, got {}",
+                params.len()
+            ),
+        ));
+    }
+    Ok((params, body))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2029,7 +2084,7 @@ mod tests {
                     Expr::Literal(Value::Number(5.into())),
                 ]),
                 Expr::Lambda {
-                    param: Arc::from("x"),
+                    params: vec![Arc::from("x")],
                     body: Box::new(Expr::Binary {
                         left: Box::new(Expr::Variable(Arc::from("x"))),
                         op: BinaryOp::GreaterThan,
@@ -2062,7 +2117,7 @@ mod tests {
                     Expr::Literal(Value::Number(3.into())),
                 ]),
                 Expr::Lambda {
-                    param: Arc::from("x"),
+                    params: vec![Arc::from("x")],
                     body: Box::new(Expr::Binary {
                         left: Box::new(Expr::Variable(Arc::from("x"))),
                         op: BinaryOp::Multiply,
@@ -2096,9 +2151,9 @@ mod tests {
                 ]),
                 Expr::Literal(Value::Number(0.into())),
                 Expr::Lambda {
-                    param: Arc::from("x"),
+                    params: vec![Arc::from("x")],
                     body: Box::new(Expr::Binary {
-                        left: Box::new(Expr::Variable(Arc::from("$acc"))),
+                        left: Box::new(Expr::Variable(Arc::from("acc"))),
                         op: BinaryOp::Add,
                         right: Box::new(Expr::Variable(Arc::from("x"))),
                     }),
@@ -2126,7 +2181,7 @@ mod tests {
                     Expr::Literal(Value::Number(4.into())),
                 ]),
                 Expr::Lambda {
-                    param: Arc::from("x"),
+                    params: vec![Arc::from("x")],
                     body: Box::new(Expr::Binary {
                         left: Box::new(Expr::Variable(Arc::from("x"))),
                         op: BinaryOp::GreaterThan,
@@ -2155,7 +2210,7 @@ mod tests {
                     Expr::Literal(Value::Number(6.into())),
                 ]),
                 Expr::Lambda {
-                    param: Arc::from("x"),
+                    params: vec![Arc::from("x")],
                     body: Box::new(Expr::Binary {
                         left: Box::new(Expr::Binary {
                             left: Box::new(Expr::Variable(Arc::from("x"))),
@@ -2188,7 +2243,7 @@ mod tests {
                     Expr::Literal(Value::Number(3.into())),
                 ]),
                 Expr::Lambda {
-                    param: Arc::from("x"),
+                    params: vec![Arc::from("x")],
                     body: Box::new(Expr::Binary {
                         left: Box::new(Expr::Variable(Arc::from("x"))),
                         op: BinaryOp::GreaterThan,
@@ -2211,7 +2266,7 @@ mod tests {
                     Expr::Literal(Value::Number(3.into())),
                 ]),
                 Expr::Lambda {
-                    param: Arc::from("x"),
+                    params: vec![Arc::from("x")],
                     body: Box::new(Expr::Binary {
                         left: Box::new(Expr::Variable(Arc::from("x"))),
                         op: BinaryOp::GreaterThan,
@@ -2239,7 +2294,7 @@ mod tests {
                     Expr::Literal(Value::Number(6.into())),
                 ]),
                 Expr::Lambda {
-                    param: Arc::from("x"),
+                    params: vec![Arc::from("x")],
                     body: Box::new(Expr::Binary {
                         left: Box::new(Expr::Binary {
                             left: Box::new(Expr::Variable(Arc::from("x"))),
@@ -2284,7 +2339,7 @@ mod tests {
     /// out under higher-order traversal.
     fn increment_lambda() -> Expr {
         Expr::Lambda {
-            param: Arc::from("x"),
+            params: vec![Arc::from("x")],
             body: Box::new(Expr::Binary {
                 left: Box::new(Expr::Variable(Arc::from("x"))),
                 op: BinaryOp::Add,
@@ -2348,7 +2403,7 @@ mod tests {
             args: vec![
                 literal_array(20),
                 Expr::Lambda {
-                    param: Arc::from("y"),
+                    params: vec![Arc::from("y")],
                     body: Box::new(Expr::Binary {
                         left: Box::new(Expr::Variable(Arc::from("y"))),
                         op: BinaryOp::GreaterThan,
@@ -2362,7 +2417,7 @@ mod tests {
             args: vec![
                 literal_array(20),
                 Expr::Lambda {
-                    param: Arc::from("x"),
+                    params: vec![Arc::from("x")],
                     body: Box::new(inner_filter),
                 },
             ],
@@ -2386,9 +2441,9 @@ mod tests {
                 literal_array(100),
                 Expr::Literal(Value::Number(0.into())),
                 Expr::Lambda {
-                    param: Arc::from("x"),
+                    params: vec![Arc::from("x")],
                     body: Box::new(Expr::Binary {
-                        left: Box::new(Expr::Variable(Arc::from("$acc"))),
+                        left: Box::new(Expr::Variable(Arc::from("acc"))),
                         op: BinaryOp::Add,
                         right: Box::new(Expr::Variable(Arc::from("x"))),
                     }),
@@ -2543,9 +2598,9 @@ mod tests {
                 literal_array(10),
                 Expr::Literal(Value::Number(0.into())),
                 Expr::Lambda {
-                    param: Arc::from("y"),
+                    params: vec![Arc::from("y")],
                     body: Box::new(Expr::Binary {
-                        left: Box::new(Expr::Variable(Arc::from("$acc"))),
+                        left: Box::new(Expr::Variable(Arc::from("acc"))),
                         op: BinaryOp::Add,
                         right: Box::new(Expr::Variable(Arc::from("y"))),
                     }),
@@ -2557,7 +2612,7 @@ mod tests {
             args: vec![
                 literal_array(10),
                 Expr::Lambda {
-                    param: Arc::from("x"),
+                    params: vec![Arc::from("x")],
                     body: Box::new(inner_reduce),
                 },
             ],
@@ -0,0 +1,139 @@
+//! Step-by-step evaluation traces
+//!
+//! [`ExpressionEngine::evaluate_traced`](crate::ExpressionEngine::evaluate_traced)
+//! evaluates an expression while recording the value of every sub-expression
+//! it computes, so tooling can show how a result was reached. Plain
+//! [`evaluate`](crate::ExpressionEngine::evaluate) never records anything.
+
+use std::collections::HashMap;
+
+use serde_json::Value;
+
+use crate::{ast::Expr, span::Span};
+
+/// One evaluated sub-expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceStep {
+    /// Byte range of the sub-expression in the evaluated source.
+    pub span: Span,
+    /// The value it evaluated to.
+    pub value: Value,
+}
+
+/// Values computed while evaluating an expression, in completion order:
+/// operands before the operation that uses them, the whole expression last.
+///
+/// Only sub-expressions that evaluated successfully are recorded. Nodes
+/// inside a lambda appear once per invocation; nodes short-circuited away
+/// (`a || b` with `a` true, the branch of an `if` not taken) do not appear.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EvalTrace {
+    steps: Vec<TraceStep>,
+}
+
+impl EvalTrace {
+    /// Recorded steps, in completion order.
+    #[must_use]
+    pub fn steps(&self) -> &[TraceStep] {
+        &self.steps
+    }
+
+    /// Number of recorded steps.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// Whether nothing was recorded, e.g. because parsing failed.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+}
+
+/// Recorder carried in the evaluation frame of a traced evaluation.
+///
+/// Nodes are identified by address, so a node evaluated from a copy of the
+/// AST (the value of a pipeline into a higher-order function) is not found
+/// and not recorded.
+pub(crate) struct Tracer {
+    spans: HashMap<*const Expr, Span>,
+    steps: Vec<TraceStep>,
+}
+
+impl Tracer {
+    /// Pair each node of `expr` with its span, as produced in post-order by
+    /// [`Parser::parse_with_spans`](crate::parser::Parser::parse_with_spans).
+    pub(crate) fn new(expr: &Expr, spans: Vec<Span>) -> Self {
+        let mut nodes = Vec::with_capacity(spans.len());
+        post_order(expr, &mut nodes);
+        debug_assert_eq!(nodes.len(), spans.len(), "one span per AST node");
+        Self {
+            spans: nodes
+                .into_iter()
+                .map(std::ptr::from_ref)
+                .zip(spans)
+                .collect(),
+            steps: Vec::new(),
+        }
+    }
+
+    pub(crate) fn record(&mut self, expr: &Expr, value: &Value) {
+        if let Some(&span) = self.spans.get(&std::ptr::from_ref(expr)) {
+            self.steps.push(TraceStep {
+                span,
+                value: value.clone(),
+            });
+        }
+    }
+
+    pub(crate) fn finish(self) -> EvalTrace {
+        EvalTrace { steps: self.steps }
+    }
+}
+
+/// Push every node of `expr` in the order the parser completes them.
+fn post_order<'e>(expr: &'e Expr, nodes: &mut Vec<&'e Expr>) {
+    match expr {
+        Expr::Literal(_) | Expr::Variable(_) | Expr::Identifier(_) => {},
+        Expr::Negate(inner) | Expr::Not(inner) => post_order(inner, nodes),
+        Expr::Binary { left, right, .. } => {
+            post_order(left, nodes);
+            post_order(right, nodes);
+        },
+        Expr::PropertyAccess { object, .. } | Expr::OptionalPropertyAccess { object, .. } => {
+            post_order(object, nodes);
+        },
+        Expr::IndexAccess { object, index } => {
+            post_order(object, nodes);
+            post_order(index, nodes);
+        },
+        Expr::FunctionCall { args, .. } | Expr::Array(args) => {
+            for arg in args {
+                post_order(arg, nodes);
+            }
+        },
+        Expr::Pipeline { value, args, .. } => {
+            post_order(value, nodes);
+            for arg in args {
+                post_order(arg, nodes);
+            }
+        },
+        Expr::Conditional {
+            condition,
+            then_expr,
+            else_expr,
+        } => {
+            post_order(condition, nodes);
+            post_order(then_expr, nodes);
+            post_order(else_expr, nodes);
+        },
+        Expr::Lambda { body, .. } => post_order(body, nodes),
+        Expr::Object(entries) => {
+            for (_, value) in entries {
+                post_order(value, nodes);
+            }
+        },
+    }
+    nodes.push(expr);
+}
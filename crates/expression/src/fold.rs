@@ -0,0 +1,260 @@
+//! Constant folding over parsed expressions
+//!
+//! The engine runs [`ConstantFolder::fold`] on every AST it parses, so a
+//! sub-tree built only from literals (`2 * 60 * 60`, `-1`, `[1, 2]`) is
+//! evaluated once and replaced by its value instead of being recomputed on
+//! every evaluation.
+//!
+//! A node is folded only when evaluating it succeeds with the same value
+//! under both a lenient and a fully strict [`EvaluationPolicy`], so the
+//! result cannot depend on the policy a context brings at run time. Sub-trees
+//! that fail (`1 / 0`) are left in place and fail when reached, exactly as
+//! without folding — except under an engine in strict mode, where a failure
+//! on a path that always runs is reported at parse time. Function calls are
+//! never folded.
+
+use std::sync::Arc;
+
+use crate::{
+    ast::{BinaryOp, Expr},
+    builtins::BuiltinRegistry,
+    context::EvaluationContext,
+    error::ExpressionResult,
+    eval::Evaluator,
+    policy::EvaluationPolicy,
+};
+
+/// Folds literal-only sub-trees of an AST into literals.
+pub(crate) struct ConstantFolder {
+    lenient: Evaluator,
+    strict: Evaluator,
+    report_errors: bool,
+}
+
+impl ConstantFolder {
+    /// Create a folder for an engine with the given builtins and policy.
+    pub(crate) fn new(builtins: Arc<BuiltinRegistry>, policy: Option<&EvaluationPolicy>) -> Self {
+        let strict_policy = EvaluationPolicy::new()
+            .with_strict_mode(true)
+            .with_strict_conversion_functions(true)
+            .with_strict_numeric_comparisons(true);
+        Self {
+            lenient: Evaluator::new(Arc::clone(&builtins)),
+            strict: Evaluator::with_policy(builtins, Some(Arc::new(strict_policy))),
+            report_errors: policy.is_some_and(EvaluationPolicy::strict_mode),
+        }
+    }
+
+    /// Fold `expr` bottom-up.
+    ///
+    /// # Errors
+    ///
+    /// Only in strict mode: the error of a constant sub-expression that fails
+    /// under every policy and is evaluated whenever the expression is.
+    pub(crate) fn fold(&self, expr: Expr) -> ExpressionResult<Expr> {
+        self.fold_node(expr, &EvaluationContext::new(), true)
+    }
+
+    /// `always` is false below short-circuit operators, conditional branches
+    /// and lambdas, where a sub-expression may never be evaluated.
+    fn fold_node(
+        &self,
+        expr: Expr,
+        context: &EvaluationContext,
+        always: bool,
+    ) -> ExpressionResult<Expr> {
+        let folded = match expr {
+            Expr::Literal(_) | Expr::Variable(_) | Expr::Identifier(_) => return Ok(expr),
+            Expr::Negate(inner) => Expr::Negate(self.fold_boxed(*inner, context, always)?),
+            Expr::Not(inner) => Expr::Not(self.fold_boxed(*inner, context, always)?),
+            Expr::Binary { left, op, right } => {
+                let short_circuits =
+                    matches!(op, BinaryOp::And | BinaryOp::Or | BinaryOp::NullCoalesce);
+                Expr::Binary {
+                    left: self.fold_boxed(*left, context, always)?,
+                    op,
+                    right: self.fold_boxed(*right, context, always && !short_circuits)?,
+                }
+            },
+            Expr::PropertyAccess { object, property } => Expr::PropertyAccess {
+                object: self.fold_boxed(*object, context, always)?,
+                property,
+            },
+            Expr::OptionalPropertyAccess { object, property } => Expr::OptionalPropertyAccess {
+                object: self.fold_boxed(*object, context, always)?,
+                property,
+            },
+            Expr::IndexAccess { object, index } => Expr::IndexAccess {
+                object: self.fold_boxed(*object, context, always)?,
+                index: self.fold_boxed(*index, context, always)?,
+            },
+            Expr::FunctionCall { name, args } => {
+                return Ok(Expr::FunctionCall {
+                    name,
+                    args: self.fold_all(args, context, always)?,
+                });
+            },
+            Expr::Pipeline {
+                value,
+                function,
+                args,
+            } => {
+                return Ok(Expr::Pipeline {
+                    value: self.fold_boxed(*value, context, always)?,
+                    function,
+                    args: self.fold_all(args, context, always)?,
+                });
+            },
+            Expr::Conditional {
+                condition,
+                then_expr,
+                else_expr,
+            } => Expr::Conditional {
+                condition: self.fold_boxed(*condition, context, always)?,
+                then_expr: self.fold_boxed(*then_expr, context, false)?,
+                else_expr: self.fold_boxed(*else_expr, context, false)?,
+            },
+            Expr::Lambda { params, body } => {
+                return Ok(Expr::Lambda {
+                    params,
+                    body: self.fold_boxed(*body, context, false)?,
+                });
+            },
+            Expr::Array(items) => Expr::Array(self.fold_all(items, context, always)?),
+            Expr::Object(entries) => Expr::Object(
+                entries
+                    .into_iter()
+                    .map(|(key, value)| Ok((key, self.fold_node(value, context, always)?)))
+                    .collect::<ExpressionResult<_>>()?,
+            ),
+        };
+
+        if !children_are_literals(&folded) {
+            return Ok(folded);
+        }
+        match (
+            self.lenient.eval(&folded, context),
+            self.strict.eval(&folded, context),
+        ) {
+            (Ok(lenient), Ok(strict)) if lenient == strict => Ok(Expr::Literal(lenient)),
+            (Err(err), Err(_)) if self.report_errors && always => Err(err),
+            _ => Ok(folded),
+        }
+    }
+
+    fn fold_boxed(
+        &self,
+        expr: Expr,
+        context: &EvaluationContext,
+        always: bool,
+    ) -> ExpressionResult<Box<Expr>> {
+        self.fold_node(expr, context, always).map(Box::new)
+    }
+
+    fn fold_all(
+        &self,
+        exprs: Vec<Expr>,
+        context: &EvaluationContext,
+        always: bool,
+    ) -> ExpressionResult<Vec<Expr>> {
+        exprs
+            .into_iter()
+            .map(|expr| self.fold_node(expr, context, always))
+            .collect()
+    }
+}
+
+/// Whether every direct child of a foldable node is already a literal.
+fn children_are_literals(expr: &Expr) -> bool {
+    match expr {
+        Expr::Negate(inner) | Expr::Not(inner) => inner.is_literal(),
+        Expr::Binary { left, right, .. } => left.is_literal() && right.is_literal(),
+        Expr::PropertyAccess { object, .. } | Expr::OptionalPropertyAccess { object, .. } => {
+            object.is_literal()
+        },
+        Expr::IndexAccess { object, index } => object.is_literal() && index.is_literal(),
+        Expr::Conditional {
+            condition,
+            then_expr,
+            else_expr,
+        } => condition.is_literal() && then_expr.is_literal() && else_expr.is_literal(),
+        Expr::Array(items) => items.iter().all(Expr::is_literal),
+        Expr::Object(entries) => entries.iter().all(|(_, value)| value.is_literal()),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{lexer::Lexer, parser::Parser};
+
+    fn parse(source: &str) -> Expr {
+        let tokens = Lexer::new(source).tokenize().unwrap();
+        Parser::new(tokens).parse().unwrap()
+    }
+
+    fn fold(source: &str) -> ExpressionResult<Expr> {
+        ConstantFolder::new(Arc::new(BuiltinRegistry::new()), None).fold(parse(source))
+    }
+
+    #[test]
+    fn constant_expression_folds_to_single_literal() {
+        assert_eq!(fold("2 * 60 * 60").unwrap(), Expr::Literal(json!(7200)));
+        assert_eq!(
+            fold(r#"{a: [1, -2], b: "x" + "y"}"#).unwrap(),
+            Expr::Literal(json!({"a": [1, -2], "b": "xy"}))
+        );
+    }
+
+    #[test]
+    fn variable_expressions_keep_their_shape() {
+        assert_eq!(
+            fold("$input.x * 60").unwrap(),
+            parse("$input.x * 60"),
+            "nothing to fold"
+        );
+        assert_eq!(
+            fold("$input.x * (60 * 60)").unwrap(),
+            Expr::Binary {
+                left: Box::new(parse("$input.x")),
+                op: BinaryOp::Multiply,
+                right: Box::new(Expr::Literal(json!(3600))),
+            },
+            "only the constant operand folds"
+        );
+    }
+
+    #[test]
+    fn function_calls_are_not_folded() {
+        assert_eq!(
+            fold("length([1, 2 * 3])").unwrap(),
+            Expr::FunctionCall {
+                name: Arc::from("length"),
+                args: vec![Expr::Literal(json!([1, 6]))],
+            },
+            "arguments fold, the call stays"
+        );
+    }
+
+    #[test]
+    fn failing_and_policy_dependent_constants_are_left_for_run_time() {
+        assert_eq!(fold("1 / 0").unwrap(), parse("1 / 0"));
+        // Lenient mode coerces to bool, strict mode rejects: keep it unfolded.
+        assert_eq!(fold("!1").unwrap(), parse("!1"));
+    }
+
+    #[test]
+    fn strict_mode_reports_unconditional_failures_at_parse_time() {
+        let policy = EvaluationPolicy::new().with_strict_mode(true);
+        let folder = ConstantFolder::new(Arc::new(BuiltinRegistry::new()), Some(&policy));
+
+        assert!(folder.fold(parse("$input.x + 1 / 0")).is_err());
+        assert!(
+            folder.fold(parse("$input.ok || 1 / 0")).is_ok(),
+            "short-circuited branch may never run"
+        );
+    }
+}
@@ -133,6 +133,16 @@ impl<'a> Lexer<'a> {
                 self.advance();
                 Token::new(TokenKind::Colon, Span::new(start, self.position))
             },
+            '?' if self.peek() == Some('?') => {
+                self.advance();
+                self.advance();
+                Token::new(TokenKind::NullCoalesce, Span::new(start, self.position))
+            },
+            '?' if self.peek() == Some('.') => {
+                self.advance();
+                self.advance();
+                Token::new(TokenKind::OptionalDot, Span::new(start, self.position))
+            },
             '?' => {
                 self.advance();
                 Token::new(TokenKind::Question, Span::new(start, self.position))
@@ -552,6 +562,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_null_handling_tokens() {
+        let mut lexer = Lexer::new("a ?? b?.c ?");
+        let tokens = lexer.tokenize().unwrap();
+        let kinds: Vec<_> = tokens.iter().map(|t| &t.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                &TokenKind::Identifier("a"),
+                &TokenKind::NullCoalesce,
+                &TokenKind::Identifier("b"),
+                &TokenKind::OptionalDot,
+                &TokenKind::Identifier("c"),
+                &TokenKind::Question,
+                &TokenKind::Eof
+            ]
+        );
+    }
+
     #[expect(
         clippy::approx_constant,
         reason = "3.14 is intentional test data, not an approximation of π"
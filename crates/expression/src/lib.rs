@@ -64,6 +64,7 @@
 //! budget remains enforced across every iteration.
 
 // Public modules - exposed for external use
+pub mod analysis;
 #[doc(hidden)]
 pub mod ast;
 pub mod builtins;
@@ -71,6 +72,7 @@ pub mod context;
 pub mod engine;
 pub mod error;
 pub mod error_formatter;
+pub mod eval_trace;
 #[doc(hidden)]
 pub mod interner;
 pub mod maybe;
@@ -86,6 +88,7 @@ pub mod value_utils;
 // These are exposed for advanced use cases but may change between versions
 #[doc(hidden)]
 pub mod eval;
+mod fold;
 #[doc(hidden)]
 pub mod lexer;
 #[doc(hidden)]
@@ -94,12 +97,14 @@ pub mod parser;
 // Re-exports
 // Internal types - only exported for advanced use cases
 // Most users should not need these types directly
+pub use analysis::ExpressionAnalysis;
 #[doc(hidden)]
 pub use ast::{BinaryOp, Expr};
 pub use context::{EvaluationContext, EvaluationContextBuilder};
 pub use engine::{CacheOverview, ExpressionEngine};
 // Re-export error types
 pub use error::{ExpressionError, ExpressionErrorExt, ExpressionResult};
+pub use eval_trace::{EvalTrace, TraceStep};
 pub use maybe::{CachedExpression, MaybeExpression};
 pub use policy::EvaluationPolicy;
 // Re-export serde_json types for convenience
@@ -118,8 +123,9 @@ pub use token::{Token, TokenKind};
 /// context. It is the stable parsing entrypoint for downstream crates that
 /// need parse-only checks.
 ///
-/// Inputs that contain at least one `{{ ... }}` block are parsed as a
-/// template; otherwise the source is parsed as a raw expression. The
+/// Inputs that contain at least one `{{ ... }}` block, `\{{` escape, or
+/// `{% raw %}` block are parsed as a template; otherwise the source is
+/// parsed as a raw expression. The
 /// dispatch is decided by the actual template parser, not by a substring
 /// search — so a raw expression that legitimately contains a `{{` literal
 /// (for example inside a string) does not get mis-routed.
@@ -128,7 +134,10 @@ pub fn parse_expression(source: &str) -> ExpressionResult<()> {
     // the source as raw. If it errors as a template, also fall through —
     // raw parsing will surface the real syntax error in context.
     if let Ok(template) = Template::new(source.to_owned())
-        && template.expression_count() > 0
+        && template
+            .parts()
+            .iter()
+            .any(|part| !matches!(part, TemplatePart::Static { .. }))
     {
         for expression in template.expressions() {
             parse_raw_expression(expression.trim())?;
@@ -28,6 +28,9 @@ const EOF_TOKEN: Token<'static> = Token {
 pub struct Parser<'a> {
     tokens: Vec<Token<'a>>,
     position: usize,
+    /// Source span of every completed node, in post-order; recorded only
+    /// by [`parse_with_spans`](Self::parse_with_spans).
+    spans: Option<Vec<Span>>,
 }
 
 impl<'a> Parser<'a> {
@@ -36,6 +39,7 @@ impl<'a> Parser<'a> {
         Self {
             tokens,
             position: 0,
+            spans: None,
         }
     }
 
@@ -54,6 +58,16 @@ impl<'a> Parser<'a> {
         Ok(expr)
     }
 
+    /// Parse like [`parse`](Self::parse), also returning the source span of
+    /// every AST node in post-order: children left to right, then their
+    /// parent, so the root's span comes last. A parenthesized expression has
+    /// the span of its contents.
+    pub(crate) fn parse_with_spans(&mut self) -> ExpressionResult<(Expr, Vec<Span>)> {
+        self.spans = Some(Vec::new());
+        let expr = self.parse()?;
+        Ok((expr, self.spans.take().unwrap_or_default()))
+    }
+
     /// Parse expression with depth tracking
     fn parse_expression_with_depth(&mut self, depth: usize) -> ExpressionResult<Expr> {
         if depth > MAX_PARSER_DEPTH {
@@ -66,6 +80,7 @@ impl<'a> Parser<'a> {
 
     /// Parse conditional with depth tracking
     fn parse_conditional_with_depth(&mut self, depth: usize) -> ExpressionResult<Expr> {
+        let start = self.start();
         if self.match_token(&TokenKind::If) {
             let condition = Box::new(self.parse_pipeline_with_depth(depth + 1)?);
             self.expect_token(TokenKind::Then)?;
@@ -73,11 +88,14 @@ impl<'a> Parser<'a> {
             self.expect_token(TokenKind::Else)?;
             let else_expr = Box::new(self.parse_pipeline_with_depth(depth + 1)?);
 
-            Ok(Expr::Conditional {
-                condition,
-                then_expr,
-                else_expr,
-            })
+            Ok(self.node(
+                start,
+                Expr::Conditional {
+                    condition,
+                    then_expr,
+                    else_expr,
+                },
+            ))
         } else {
             self.parse_pipeline_with_depth(depth + 1)
         }
@@ -85,6 +103,7 @@ impl<'a> Parser<'a> {
 
     /// Parse pipeline expression with depth tracking
     fn parse_pipeline_with_depth(&mut self, depth: usize) -> ExpressionResult<Expr> {
+        let start = self.start();
         let mut expr = self.parse_binary_op_with_depth(0, depth + 1)?;
 
         while self.current_token().kind == TokenKind::Pipe {
@@ -108,11 +127,14 @@ impl<'a> Parser<'a> {
                 Vec::new()
             };
 
-            expr = Expr::Pipeline {
-                value: Box::new(expr),
-                function,
-                args,
-            };
+            expr = self.node(
+                start,
+                Expr::Pipeline {
+                    value: Box::new(expr),
+                    function,
+                    args,
+                },
+            );
         }
 
         Ok(expr)
@@ -133,6 +155,7 @@ impl<'a> Parser<'a> {
                 "Maximum parser recursion depth ({MAX_PARSER_DEPTH}) exceeded"
             )));
         }
+        let start = self.start();
         let mut left = self.parse_unary_with_depth(depth + 1)?;
 
         while self.current_token().kind.is_binary_operator() {
@@ -160,6 +183,7 @@ impl<'a> Parser<'a> {
                 TokenKind::RegexMatch => BinaryOp::RegexMatch,
                 TokenKind::And => BinaryOp::And,
                 TokenKind::Or => BinaryOp::Or,
+                TokenKind::NullCoalesce => BinaryOp::NullCoalesce,
                 _ => {
                     return Err(ExpressionError::expression_parse_error(format!(
                         "Unexpected operator: {}",
@@ -178,11 +202,14 @@ impl<'a> Parser<'a> {
 
             let right = self.parse_binary_op_with_depth(next_min_precedence, depth + 1)?;
 
-            left = Expr::Binary {
-                left: Box::new(left),
-                op: binary_op,
-                right: Box::new(right),
-            };
+            left = self.node(
+                start,
+                Expr::Binary {
+                    left: Box::new(left),
+                    op: binary_op,
+                    right: Box::new(right),
+                },
+            );
         }
 
         Ok(left)
@@ -198,16 +225,17 @@ impl<'a> Parser<'a> {
                 "Maximum parser recursion depth ({MAX_PARSER_DEPTH}) exceeded"
             )));
         }
+        let start = self.start();
         match &self.current_token().kind {
             TokenKind::Minus => {
                 self.advance();
                 let expr = self.parse_unary_with_depth(depth + 1)?;
-                Ok(Expr::Negate(Box::new(expr)))
+                Ok(self.node(start, Expr::Negate(Box::new(expr))))
             },
             TokenKind::Not => {
                 self.advance();
                 let expr = self.parse_unary_with_depth(depth + 1)?;
-                Ok(Expr::Not(Box::new(expr)))
+                Ok(self.node(start, Expr::Not(Box::new(expr))))
             },
             _ => self.parse_postfix_with_depth(depth + 1),
         }
@@ -215,6 +243,7 @@ impl<'a> Parser<'a> {
 
     /// Parse postfix expression with depth tracking
     fn parse_postfix_with_depth(&mut self, depth: usize) -> ExpressionResult<Expr> {
+        let start = self.start();
         let mut expr = self.parse_primary_with_depth(depth + 1)?;
 
         loop {
@@ -231,20 +260,46 @@ impl<'a> Parser<'a> {
                         ));
                     };
 
-                    expr = Expr::PropertyAccess {
-                        object: Box::new(expr),
-                        property,
+                    expr = self.node(
+                        start,
+                        Expr::PropertyAccess {
+                            object: Box::new(expr),
+                            property,
+                        },
+                    );
+                },
+                TokenKind::OptionalDot => {
+                    self.advance();
+                    let property = if let TokenKind::Identifier(name) = &self.current_token().kind {
+                        let name = Arc::from(*name);
+                        self.advance();
+                        name
+                    } else {
+                        return Err(ExpressionError::expression_parse_error(
+                            "Expected property name after ?.",
+                        ));
                     };
+
+                    expr = self.node(
+                        start,
+                        Expr::OptionalPropertyAccess {
+                            object: Box::new(expr),
+                            property,
+                        },
+                    );
                 },
                 TokenKind::LeftBracket => {
                     self.advance();
                     let index = self.parse_expression_with_depth(depth + 1)?;
                     self.expect_token(TokenKind::RightBracket)?;
 
-                    expr = Expr::IndexAccess {
-                        object: Box::new(expr),
-                        index: Box::new(index),
-                    };
+                    expr = self.node(
+                        start,
+                        Expr::IndexAccess {
+                            object: Box::new(expr),
+                            index: Box::new(index),
+                        },
+                    );
                 },
                 _ => break,
             }
@@ -255,38 +310,39 @@ impl<'a> Parser<'a> {
 
     /// Parse primary expression with depth tracking
     fn parse_primary_with_depth(&mut self, depth: usize) -> ExpressionResult<Expr> {
+        let start = self.start();
         match &self.current_token().kind {
             // Literals
             TokenKind::Integer(n) => {
                 let n = *n;
                 self.advance();
-                Ok(Expr::Literal(Value::Number(n.into())))
+                Ok(self.node(start, Expr::Literal(Value::Number(n.into()))))
             },
             TokenKind::Float(n) => {
                 let n = *n;
                 self.advance();
-                Ok(Expr::Literal(serde_json::json!(n)))
+                Ok(self.node(start, Expr::Literal(serde_json::json!(n))))
             },
             TokenKind::String(s) => {
                 let owned = s.to_string();
                 self.advance();
-                Ok(Expr::Literal(Value::String(owned)))
+                Ok(self.node(start, Expr::Literal(Value::String(owned))))
             },
             TokenKind::Boolean(b) => {
                 let b = *b;
                 self.advance();
-                Ok(Expr::Literal(Value::Bool(b)))
+                Ok(self.node(start, Expr::Literal(Value::Bool(b))))
             },
             TokenKind::Null => {
                 self.advance();
-                Ok(Expr::Literal(Value::Null))
+                Ok(self.node(start, Expr::Literal(Value::Null)))
             },
 
             // Variables
             TokenKind::Variable(name) => {
                 let name = Arc::from(*name);
                 self.advance();
-                Ok(Expr::Variable(name))
+                Ok(self.node(start, Expr::Variable(name)))
             },
 
             // Identifiers (could be function calls)
@@ -296,10 +352,10 @@ impl<'a> Parser<'a> {
                 if self.current_token().kind == TokenKind::LeftParen {
                     // Function call
                     let args = self.parse_function_args_with_depth(depth + 1)?;
-                    Ok(Expr::FunctionCall { name, args })
+                    Ok(self.node(start, Expr::FunctionCall { name, args }))
                 } else {
                     // Just an identifier
-                    Ok(Expr::Identifier(name))
+                    Ok(self.node(start, Expr::Identifier(name)))
                 }
             },
 
@@ -326,7 +382,7 @@ impl<'a> Parser<'a> {
                 }
 
                 self.expect_token(TokenKind::RightBracket)?;
-                Ok(Expr::Array(elements))
+                Ok(self.node(start, Expr::Array(elements)))
             },
 
             // Object literal
@@ -366,7 +422,7 @@ impl<'a> Parser<'a> {
                 }
 
                 self.expect_token(TokenKind::RightBrace)?;
-                Ok(Expr::Object(pairs))
+                Ok(self.node(start, Expr::Object(pairs)))
             },
 
             _ => Err(ExpressionError::expression_parse_error(format!(
@@ -379,7 +435,8 @@ impl<'a> Parser<'a> {
     /// Parse function arguments with depth tracking.
     ///
     /// Lambda detection is a peek-and-restore: when an `Identifier =>` pair
-    /// appears at the head of an argument it produces an `Expr::Lambda`;
+    /// or a parenthesized parameter list `(a, b) =>` appears at the head of
+    /// an argument it produces an `Expr::Lambda`;
     /// anything else (including `Identifier <op> ...`, where `<op>` is a
     /// binary operator, postfix `.` / `[]`, or a function call) restores the
     /// pre-peek position and falls through to the full expression parser so
@@ -393,12 +450,13 @@ impl<'a> Parser<'a> {
 
         if self.current_token().kind != TokenKind::RightParen {
             loop {
-                let lambda_param = self.try_consume_lambda_param();
+                let start = self.start();
+                let lambda_params = self.try_consume_lambda_params()?;
 
-                if let Some(param) = lambda_param {
-                    trace!(?param, "parsing lambda function arg");
+                if let Some(params) = lambda_params {
+                    trace!(?params, "parsing lambda function arg");
                     let body = Box::new(self.parse_expression_with_depth(depth + 1)?);
-                    args.push(Expr::Lambda { param, body });
+                    args.push(self.node(start, Expr::Lambda { params, body }));
                 } else {
                     trace!("parsing expression function arg");
                     args.push(self.parse_expression_with_depth(depth + 1)?);
@@ -414,27 +472,81 @@ impl<'a> Parser<'a> {
         Ok(args)
     }
 
-    /// Peek for a lambda parameter (`Identifier =>`).
+    /// Peek for a lambda head: `Identifier =>` or `(Identifier, ...) =>`.
     ///
-    /// Returns `Some(param)` and leaves `self.position` past the `=>` if a
+    /// Returns `Some(params)` and leaves `self.position` past the `=>` if a
     /// lambda head is present. Returns `None` and restores the original
     /// position otherwise — so the caller can hand control back to the full
-    /// expression parser without losing the consumed identifier.
-    fn try_consume_lambda_param(&mut self) -> Option<Arc<str>> {
-        let TokenKind::Identifier(param) = &self.current_token().kind else {
-            return None;
-        };
+    /// expression parser without losing the consumed tokens. A complete head
+    /// that repeats a parameter name is a parse error.
+    fn try_consume_lambda_params(&mut self) -> ExpressionResult<Option<Vec<Arc<str>>>> {
         let saved_pos = self.position;
-        let param_name: Arc<str> = Arc::from(*param);
-        self.advance();
-        if self.match_token(&TokenKind::Arrow) {
-            Some(param_name)
-        } else {
+        let params = match &self.current_token().kind {
+            TokenKind::Identifier(param) => {
+                let param: Arc<str> = Arc::from(*param);
+                self.advance();
+                Some(vec![param])
+            },
+            TokenKind::LeftParen => {
+                self.advance();
+                self.consume_lambda_param_list()
+            },
+            _ => None,
+        };
+
+        let Some(params) = params.filter(|_| self.match_token(&TokenKind::Arrow)) else {
             self.position = saved_pos;
-            None
+            return Ok(None);
+        };
+        for (i, param) in params.iter().enumerate() {
+            if params[..i].contains(param) {
+                return Err(ExpressionError::expression_parse_error(format!(
+                    "Duplicate lambda parameter '{param}'"
+                )));
+            }
+        }
+        Ok(Some(params))
+    }
+
+    /// Consume `a, b, ...)` after the opening parenthesis of a lambda head.
+    /// Returns `None` (position not restored) if the tokens are not a
+    /// non-empty identifier list closed by `)`.
+    fn consume_lambda_param_list(&mut self) -> Option<Vec<Arc<str>>> {
+        let mut params = Vec::new();
+        loop {
+            let TokenKind::Identifier(param) = &self.current_token().kind else {
+                return None;
+            };
+            params.push(Arc::from(*param));
+            self.advance();
+            if self.match_token(&TokenKind::RightParen) {
+                return Some(params);
+            }
+            if !self.match_token(&TokenKind::Comma) {
+                return None;
+            }
         }
     }
 
+    /// Byte offset where the current token starts.
+    fn start(&self) -> u32 {
+        self.current_token().span.start
+    }
+
+    /// Record the span of a node that began at `start` and ends with the
+    /// last consumed token, when spans are being collected.
+    fn node(&mut self, start: u32, expr: Expr) -> Expr {
+        if let Some(spans) = &mut self.spans {
+            let end = self
+                .position
+                .checked_sub(1)
+                .and_then(|last| self.tokens.get(last))
+                .map_or(start, |token| token.span.end);
+            spans.push(Span { start, end });
+        }
+        expr
+    }
+
     /// Get the current token
     fn current_token(&self) -> &Token<'a> {
         self.tokens.get(self.position).unwrap_or(&EOF_TOKEN)
@@ -484,6 +596,39 @@ mod tests {
         parser.parse()
     }
 
+    #[test]
+    fn parse_with_spans_records_every_node_in_post_order() {
+        let source = "if $x[0] > 1 then map([1, 2], (v, i) => -$v) | length() else {k: !$y?.z}";
+        let tokens = Lexer::new(source).tokenize().unwrap();
+        let (expr, spans) = Parser::new(tokens).parse_with_spans().unwrap();
+
+        let texts: Vec<&str> = spans.iter().map(|span| span.slice(source)).collect();
+        assert_eq!(
+            texts,
+            [
+                "$x",
+                "0",
+                "$x[0]",
+                "1",
+                "$x[0] > 1",
+                "1",
+                "2",
+                "[1, 2]",
+                "$v",
+                "-$v",
+                "(v, i) => -$v",
+                "map([1, 2], (v, i) => -$v)",
+                "map([1, 2], (v, i) => -$v) | length()",
+                "$y",
+                "$y?.z",
+                "!$y?.z",
+                "{k: !$y?.z}",
+                source,
+            ]
+        );
+        assert_eq!(expr, parse(source).unwrap());
+    }
+
     #[test]
     fn test_parse_literal() {
         let expr = parse("42").unwrap();
@@ -526,6 +671,50 @@ mod tests {
         assert!(matches!(expr, Expr::Conditional { .. }));
     }
 
+    #[test]
+    fn test_parse_null_coalesce_precedence() {
+        // Looser than comparison: `$a ?? (0 > 1)`.
+        let expr = parse("$a ?? 0 > 1").unwrap();
+        let Expr::Binary { op, right, .. } = expr else {
+            panic!("expected Binary, got {expr:?}");
+        };
+        assert_eq!(op, BinaryOp::NullCoalesce);
+        assert!(matches!(
+            *right,
+            Expr::Binary {
+                op: BinaryOp::GreaterThan,
+                ..
+            }
+        ));
+
+        // Tighter than `||`: `($a ?? $b) || $c`.
+        let expr = parse("$a ?? $b || $c").unwrap();
+        let Expr::Binary { op, left, .. } = expr else {
+            panic!("expected Binary, got {expr:?}");
+        };
+        assert_eq!(op, BinaryOp::Or);
+        assert!(matches!(
+            *left,
+            Expr::Binary {
+                op: BinaryOp::NullCoalesce,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_parse_optional_property_access() {
+        let expr = parse("$node.data?.user").unwrap();
+        let Expr::OptionalPropertyAccess { object, property } = expr else {
+            panic!("expected OptionalPropertyAccess, got {expr:?}");
+        };
+        assert_eq!(&*property, "user");
+        assert!(matches!(*object, Expr::PropertyAccess { .. }));
+
+        let err = parse("$node?.").unwrap_err();
+        assert!(format!("{err}").contains("Expected property name after ?."));
+    }
+
     #[test]
     fn test_parser_recursion_depth_safe() {
         // Create a moderately nested expression that should parse successfully
@@ -628,10 +817,11 @@ mod tests {
             panic!("expected FunctionCall");
         };
         assert_eq!(args.len(), 1);
-        let Expr::Lambda { param, body } = &args[0] else {
+        let Expr::Lambda { params, body } = &args[0] else {
             panic!("expected Lambda, got {arg:?}", arg = args[0]);
         };
-        assert_eq!(&**param, "x");
+        assert_eq!(params.len(), 1);
+        assert_eq!(&*params[0], "x");
         assert!(matches!(
             &**body,
             Expr::Binary {
@@ -641,11 +831,45 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn parse_multi_param_lambda() {
+        let expr = parse("reduce(arr, 0, (acc, x) => acc + x)").unwrap();
+        let Expr::FunctionCall { args, .. } = expr else {
+            panic!("expected FunctionCall");
+        };
+        let Expr::Lambda { params, .. } = &args[2] else {
+            panic!("expected Lambda, got {arg:?}", arg = args[2]);
+        };
+        let names: Vec<&str> = params.iter().map(AsRef::as_ref).collect();
+        assert_eq!(names, ["acc", "x"]);
+    }
+
+    #[test]
+    fn parse_parenthesized_arg_is_not_a_lambda_head() {
+        // `(a + b)` and `(a)` without `=>` restore and parse as expressions.
+        let expr = parse("f((a + b) * 2, (a))").unwrap();
+        let Expr::FunctionCall { args, .. } = expr else {
+            panic!("expected FunctionCall");
+        };
+        assert!(matches!(
+            args[0],
+            Expr::Binary {
+                op: BinaryOp::Multiply,
+                ..
+            }
+        ));
+        assert!(matches!(args[1], Expr::Identifier(_)));
+    }
+
+    #[test]
+    fn parse_lambda_rejects_duplicate_params() {
+        let err = parse("map(arr, (x, x) => x)").unwrap_err();
+        assert!(err.to_string().contains("Duplicate lambda parameter 'x'"));
+    }
+
     #[test]
     fn parse_function_arg_mixed_lambda_and_expression() {
-        // `reduce(arr, (acc, item) => acc + item, 0)` — but our lambda is single-param,
-        // so use the realistic shape: `filter(arr, x => x > 5)` followed by `f(arr, n + 1)`.
-        // Here we test multiple args where one is a lambda and another is a binary expr.
+        // Multiple args where one is a lambda and another is a binary expr.
         let expr = parse("reduce(arr, x => x + 1, 10 + count)").unwrap();
         let Expr::FunctionCall { args, .. } = expr else {
             panic!("expected FunctionCall");
@@ -15,11 +15,18 @@ use crate::{
     engine::ExpressionEngine,
     error::{ExpressionErrorExt, ExpressionResult},
     error_formatter::format_template_error,
+    span::Span,
 };
 
 /// Maximum number of expressions allowed in a single template (DoS protection)
 const MAX_TEMPLATE_EXPRESSIONS: usize = 1000;
 
+/// Opens a raw block whose content is emitted verbatim, `{{` included
+const RAW_OPEN: &str = "{% raw %}";
+
+/// Closes a raw block
+const RAW_CLOSE: &str = "{% endraw %}";
+
 /// A template part - either static text or an expression to evaluate
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum TemplatePart {
@@ -43,6 +50,16 @@ pub enum TemplatePart {
         /// Strip whitespace to the right (-}})
         strip_right: bool,
     },
+    /// Escaped text emitted verbatim: `\{{` (renders as `{{`) or a
+    /// `{% raw %}...{% endraw %}` block (renders its body unchanged)
+    Raw {
+        /// The text rendered for this part (without the escape markup)
+        content: Arc<str>,
+        /// Starting position of `\{{` or `{% raw %}` in the original template
+        position: Position,
+        /// Length of the escape markup in the source, in characters
+        length: usize,
+    },
 }
 
 /// Position in the template (line and column)
@@ -131,6 +148,10 @@ impl Template {
                         result.push_str(content);
                     }
                 },
+                TemplatePart::Raw { content, .. } => {
+                    result.push_str(content);
+                    strip_next_leading = false;
+                },
                 TemplatePart::Expression {
                     content,
                     position,
@@ -196,6 +217,54 @@ impl Template {
         let mut column = 1;
 
         while i < len {
+            // `\{{` and `{% raw %}...{% endraw %}` escape the delimiters
+            let raw = if chars[i] == '\\' && starts_with_at(&chars, i + 1, "{{") {
+                Some((i + 1, i + 3))
+            } else if starts_with_at(&chars, i, RAW_OPEN) {
+                let body_start = i + RAW_OPEN.chars().count();
+                let Some(body_end) = find_at(&chars, body_start, RAW_CLOSE) else {
+                    return Err(unclosed(
+                        source, &chars, i, line, column, RAW_OPEN, RAW_CLOSE,
+                    ));
+                };
+                Some((body_start, body_end))
+            } else {
+                None
+            };
+            if let Some((content_start, content_end)) = raw {
+                if !current_static.is_empty() {
+                    parts.push(TemplatePart::Static {
+                        content: Arc::from(current_static.as_str()),
+                        position: static_start,
+                    });
+                    current_static.clear();
+                }
+
+                let end = if chars[i] == '\\' {
+                    content_end
+                } else {
+                    content_end + RAW_CLOSE.chars().count()
+                };
+                let content: String = chars[content_start..content_end].iter().collect();
+                parts.push(TemplatePart::Raw {
+                    content: Arc::from(content.as_str()),
+                    position: Position::new(line, column, i),
+                    length: end - i,
+                });
+
+                for &c in &chars[i..end] {
+                    if c == '\n' {
+                        line += 1;
+                        column = 1;
+                    } else {
+                        column += 1;
+                    }
+                }
+                i = end;
+                static_start = Position::new(line, column, i);
+                continue;
+            }
+
             // Look for opening {{
             if i + 1 < len && chars[i] == '{' && chars[i + 1] == '{' {
                 // Save any accumulated static content
@@ -296,14 +365,7 @@ impl Template {
                     column = expr_column + 2;
                     static_start = Position::new(line, column, i);
                 } else {
-                    // Unclosed {{ - this is an error
-                    let formatted_error = format_template_error(
-                        source,
-                        expr_start,
-                        "Unclosed '{{' - expected closing '}}'",
-                        None,
-                    );
-                    return Err(ExpressionError::expression_parse_error(formatted_error));
+                    return Err(unclosed(source, &chars, i, line, column, "{{", "}}"));
                 }
             } else {
                 // Regular character
@@ -358,6 +420,38 @@ impl Template {
     }
 }
 
+/// Whether `pattern` occurs in `chars` starting at index `at`.
+fn starts_with_at(chars: &[char], at: usize, pattern: &str) -> bool {
+    (at..)
+        .zip(pattern.chars())
+        .all(|(idx, expected)| chars.get(idx) == Some(&expected))
+}
+
+/// Index of the first occurrence of `pattern` in `chars` at or after `from`.
+fn find_at(chars: &[char], from: usize, pattern: &str) -> Option<usize> {
+    (from..chars.len()).find(|&idx| starts_with_at(chars, idx, pattern))
+}
+
+/// Error for an opening delimiter at char index `at` that is never closed.
+fn unclosed(
+    source: &str,
+    chars: &[char],
+    at: usize,
+    line: usize,
+    column: usize,
+    opening: &'static str,
+    closing: &'static str,
+) -> ExpressionError {
+    let start: usize = chars[..at].iter().map(|c| c.len_utf8()).sum();
+    ExpressionError::UnclosedTemplate {
+        opening,
+        closing,
+        span: Span::new(start, source.len()),
+        line,
+        column,
+    }
+}
+
 impl fmt::Display for Template {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.source)
@@ -387,7 +481,7 @@ pub enum MaybeTemplate {
 
 impl MaybeTemplate {
     /// Create from a string, automatically detecting if it's a template
-    /// based on `{{ }}` delimiters.
+    /// based on `{{ }}` delimiters or a `{% raw %}` block.
     ///
     /// This constructor is heuristic by design — caller has explicitly
     /// opted into auto-detection. The serde path uses the tagged form
@@ -395,7 +489,7 @@ impl MaybeTemplate {
     /// `{{ }}` do not get mis-routed.
     pub fn from_string(s: impl Into<String>) -> Self {
         let s = s.into();
-        if s.contains("{{") && s.contains("}}") {
+        if (s.contains("{{") && s.contains("}}")) || s.contains(RAW_OPEN) {
             Self::Template(s)
         } else {
             Self::Resolved(s)
@@ -553,6 +647,85 @@ mod tests {
         assert!(err.to_string().contains("Unclosed"));
     }
 
+    #[test]
+    fn unclosed_expression_reports_span_and_position() {
+        let err = Template::new("é\nab {{ $input").unwrap_err();
+
+        let ExpressionError::UnclosedTemplate {
+            opening,
+            span,
+            line,
+            column,
+            ..
+        } = err
+        else {
+            panic!("expected UnclosedTemplate, got {err:?}");
+        };
+        assert_eq!(opening, "{{");
+        assert_eq!((line, column), (2, 4));
+        assert_eq!(span, Span::new(6, 15), "byte offsets, to end of input");
+    }
+
+    #[test]
+    fn escaped_delimiter_renders_literally() {
+        let engine = ExpressionEngine::new();
+        let mut context = EvaluationContext::new();
+        context.set_input(Value::String("Alice".to_string()));
+
+        let template = Template::new(r"\{{ .Values.name }} is {{ $input }}").unwrap();
+        assert_eq!(template.expression_count(), 1);
+        assert_eq!(
+            template.parts()[0],
+            TemplatePart::Raw {
+                content: Arc::from("{{"),
+                position: Position::start(),
+                length: 3,
+            }
+        );
+        assert_eq!(
+            template.render(&engine, &context).unwrap(),
+            "{{ .Values.name }} is Alice"
+        );
+    }
+
+    #[test]
+    fn raw_block_passes_through_unevaluated() {
+        let engine = ExpressionEngine::new();
+        let context = EvaluationContext::new();
+
+        let template =
+            Template::new("a: {% raw %}{{ .Values.a }}\n{{ b }}{% endraw %}\nc: {{ 1 + 1 }}")
+                .unwrap();
+        assert_eq!(template.expressions(), [" 1 + 1 "]);
+        assert!(matches!(
+            &template.parts()[1],
+            TemplatePart::Raw { content, position, length: 44 }
+                if &**content == "{{ .Values.a }}\n{{ b }}" && position.column == 4
+        ));
+        // Position tracking continues across the newline inside the block.
+        assert!(matches!(
+            &template.parts()[2],
+            TemplatePart::Static { position, .. } if (position.line, position.column) == (2, 20)
+        ));
+        assert_eq!(
+            template.render(&engine, &context).unwrap(),
+            "a: {{ .Values.a }}\n{{ b }}\nc: 2"
+        );
+    }
+
+    #[test]
+    fn unclosed_raw_block_is_an_error() {
+        let err = Template::new("x {% raw %}{{ y }}").unwrap_err();
+        assert!(matches!(
+            err,
+            ExpressionError::UnclosedTemplate {
+                opening: RAW_OPEN,
+                column: 3,
+                ..
+            }
+        ));
+    }
+
     #[test]
     fn test_template_multiline() {
         let engine = ExpressionEngine::new();
@@ -81,6 +81,12 @@ pub enum TokenKind<'a> {
     /// Logical NOT operator (!)
     Not,
 
+    // Operators - Null handling
+    /// Null-coalescing operator (??)
+    NullCoalesce,
+    /// Optional property access (?.)
+    OptionalDot,
+
     // Pipeline
     /// Pipeline operator (|)
     Pipe,
@@ -163,6 +169,7 @@ impl TokenKind<'_> {
                 | TokenKind::And
                 | TokenKind::Or
                 | TokenKind::Not
+                | TokenKind::NullCoalesce
                 | TokenKind::Pipe
         )
     }
@@ -185,25 +192,31 @@ impl TokenKind<'_> {
                 | TokenKind::GreaterEqual
                 | TokenKind::RegexMatch
                 | TokenKind::And
+                | TokenKind::NullCoalesce
                 | TokenKind::Or /* Pipe is not a binary operator, it's used for pipeline
                                  * expressions */
         )
     }
 
     /// Get the precedence of this operator (higher number = higher precedence)
+    ///
+    /// `??` binds looser than comparison and tighter than `&&` / `||`, so
+    /// `$a ?? 0 > 1` is `$a ?? (0 > 1)` and `$a ?? $b || $c` is
+    /// `($a ?? $b) || $c`.
     pub fn precedence(&self) -> u8 {
         match self {
             TokenKind::Or => 1,
             TokenKind::And => 2,
-            TokenKind::Equal | TokenKind::NotEqual => 3,
+            TokenKind::NullCoalesce => 3,
+            TokenKind::Equal | TokenKind::NotEqual => 4,
             TokenKind::LessThan
             | TokenKind::GreaterThan
             | TokenKind::LessEqual
             | TokenKind::GreaterEqual
-            | TokenKind::RegexMatch => 4,
-            TokenKind::Plus | TokenKind::Minus => 5,
-            TokenKind::Star | TokenKind::Slash | TokenKind::Percent => 6,
-            TokenKind::Power => 7,
+            | TokenKind::RegexMatch => 5,
+            TokenKind::Plus | TokenKind::Minus => 6,
+            TokenKind::Star | TokenKind::Slash | TokenKind::Percent => 7,
+            TokenKind::Power => 8,
             // Pipe is not a binary operator, handled separately in parse_pipeline
             _ => 0,
         }
@@ -247,6 +260,8 @@ impl std::fmt::Display for TokenKind<'_> {
             TokenKind::And => write!(f, "&&"),
             TokenKind::Or => write!(f, "||"),
             TokenKind::Not => write!(f, "!"),
+            TokenKind::NullCoalesce => write!(f, "??"),
+            TokenKind::OptionalDot => write!(f, "?."),
             TokenKind::Pipe => write!(f, "|"),
             TokenKind::LeftParen => write!(f, "("),
             TokenKind::RightParen => write!(f, ")"),
@@ -165,6 +165,58 @@ fn flat_map_empty_array() {
     assert_eq!(eval("flat_map([], x => x)"), json!([]));
 }
 
+// ──────────────────────────────────────────────
+// Lambdas: multiple parameters
+// ──────────────────────────────────────────────
+
+#[test]
+fn reduce_binds_accumulator_parameter() {
+    assert_eq!(eval("reduce([1,2,3], 0, (acc, x) => acc + x)"), json!(6));
+    assert_eq!(
+        eval("reduce([5,6,7], 0, (sum, x, i) => sum + x * i)"),
+        json!(20)
+    );
+}
+
+#[test]
+fn reduce_legacy_acc_form_still_works() {
+    assert_eq!(eval("reduce([1,2,3], 10, x => $acc + x)"), json!(16));
+}
+
+#[test]
+fn reduce_parameters_do_not_shadow_execution_acc() {
+    let engine = ExpressionEngine::default();
+    let mut ctx = EvaluationContext::default();
+    ctx.set_execution_var("acc", json!(100));
+    let result = engine
+        .evaluate("reduce([1,2], 0, (total, x) => total + x + $acc)", &ctx)
+        .unwrap();
+    assert_eq!(result, json!(203));
+}
+
+#[test]
+fn reduce_mixing_acc_with_parameters_explains_deprecation() {
+    let err = eval_err("reduce([1,2,3], 0, (total, x) => $acc + x)");
+    assert!(err.contains("refer to the accumulator as 'total'"), "{err}");
+    assert!(err.contains("deprecated"), "{err}");
+}
+
+#[test]
+fn map_and_filter_bind_index_parameter() {
+    assert_eq!(eval("map([5,5,5], (x, i) => x * i)"), json!([0, 5, 10]));
+    assert_eq!(
+        eval("filter([10,20,30,40], (x, i) => i % 2 == 0)"),
+        json!([10, 30])
+    );
+    assert_eq!(eval("map([7,8], (x) => x + 1)"), json!([8, 9]));
+}
+
+#[test]
+fn lambda_with_too_many_parameters_is_rejected() {
+    let err = eval_err("map([1], (x, i, extra) => x)");
+    assert!(err.contains("at most 2 parameters"), "{err}");
+}
+
 // ──────────────────────────────────────────────
 // Object: merge
 // ──────────────────────────────────────────────
@@ -806,3 +858,288 @@ fn slice_out_of_range_clamps() {
     // A negative start beyond the start clamps to 0 (whole array).
     assert_eq!(eval("slice([1,2,3], -100)"), json!([1, 2, 3]));
 }
+
+// ──────────────────────────────────────────────
+// Datetime: cron schedules
+// ──────────────────────────────────────────────
+
+#[test]
+fn cron_next_five_field_returns_following_fire_time() {
+    // 2024-01-01T00:00:00Z = 1704067200; every 15 minutes → 00:15.
+    assert_eq!(
+        eval(r#"cron_next("*/15 * * * *", 1704067200)"#),
+        json!(1_704_068_100)
+    );
+    // Daily at 09:30, asked after that day's slot → 09:30 the next day.
+    assert_eq!(
+        eval(r#"cron_next("30 9 * * *", "2024-01-01T10:00:00Z")"#),
+        json!(1_704_187_800)
+    );
+}
+
+#[test]
+fn cron_next_six_field_honours_seconds() {
+    assert_eq!(
+        eval(r#"cron_next("*/10 * * * * *", 1704067200)"#),
+        json!(1_704_067_210)
+    );
+}
+
+#[test]
+fn cron_next_with_tz_reads_schedule_in_that_zone() {
+    // Midnight in Moscow (UTC+3) is 21:00 UTC the previous day.
+    assert_eq!(
+        eval(r#"cron_next("0 0 * * *", 1704067200, "Europe/Moscow")"#),
+        json!(1_704_142_800)
+    );
+}
+
+#[test]
+fn cron_matches_checks_timestamp_against_schedule() {
+    assert_eq!(
+        eval(r#"cron_matches("0 * * * *", 1704067200)"#),
+        json!(true)
+    );
+    assert_eq!(
+        eval(r#"cron_matches("0 * * * *", 1704067260)"#),
+        json!(false)
+    );
+}
+
+#[test]
+fn cron_rejects_invalid_expression() {
+    let err = eval_err(r#"cron_next("61 * * * *", 0)"#);
+    assert!(err.contains("invalid cron expression"), "got: {err}");
+
+    let err = eval_err(r#"cron_matches("* * *", 0)"#);
+    assert!(err.contains("expected 5 fields"), "got: {err}");
+}
+
+// ──────────────────────────────────────────────
+// Units: convert
+// ──────────────────────────────────────────────
+
+#[test]
+fn convert_within_dimension() {
+    assert_eq!(eval(r#"convert(1500, "KB", "MB")"#), json!(1.5));
+    assert_eq!(eval(r#"convert(1536, "KiB", "MiB")"#), json!(1.5));
+    assert_eq!(eval(r#"convert(3, "ft", "in")"#), json!(36.0));
+}
+
+#[test]
+fn convert_temperature_applies_offset() {
+    assert_eq!(eval(r#"convert(100, "C", "F")"#), json!(212.0));
+    assert_eq!(eval(r#"convert(-40, "F", "C")"#), json!(-40.0));
+    assert_eq!(eval(r#"convert(0, "C", "K")"#), json!(273.15));
+    assert_eq!(
+        eval(r#"convert(98.6, "fahrenheit", "celsius")"#),
+        json!(37.0)
+    );
+}
+
+#[test]
+fn convert_rejects_incompatible_dimensions() {
+    let err = eval_err(r#"convert(1, "kg", "m")"#);
+    assert!(
+        err.contains("Cannot convert mass 'kg' to length 'm'"),
+        "got: {err}"
+    );
+}
+
+#[test]
+fn convert_rejects_unknown_unit() {
+    let err = eval_err(r#"convert(1, "parsec", "m")"#);
+    assert!(err.contains("Unknown unit 'parsec'"), "got: {err}");
+}
+
+// ──────────────────────────────────────────────
+// Equality: deep_equal, structural_hash
+// ──────────────────────────────────────────────
+
+#[test]
+fn deep_equal_defaults_to_strict_comparison() {
+    assert_eq!(eval("deep_equal({a: [1, 2]}, {a: [1, 2]})"), json!(true));
+    assert_eq!(eval("deep_equal([1, 2], [2, 1])"), json!(false));
+    assert_eq!(eval("deep_equal({a: 1}, {a: 1, b: 2})"), json!(false));
+}
+
+#[test]
+fn deep_equal_ignore_order_pairs_elements_one_to_one() {
+    assert_eq!(
+        eval("deep_equal([1, {x: [3, 4]}, 2], [2, 1, {x: [4, 3]}], {ignore_order: true})"),
+        json!(true)
+    );
+    assert_eq!(
+        eval("deep_equal([1, 1, 2], [1, 2, 2], {ignore_order: true})"),
+        json!(false)
+    );
+}
+
+#[test]
+fn deep_equal_ignore_extra_keys_treats_first_argument_as_pattern() {
+    assert_eq!(
+        eval("deep_equal({a: {b: 1} }, {a: {b: 1, c: 2}, d: 3}, {ignore_extra_keys: true})"),
+        json!(true)
+    );
+    assert_eq!(
+        eval("deep_equal({a: 1, c: 2}, {a: 1}, {ignore_extra_keys: true})"),
+        json!(false)
+    );
+    // A greedy pairing would give `{}` the only match for `{k: 1}`.
+    assert_eq!(
+        eval(
+            "deep_equal([{}, {k: 1}], [{k: 1}, {j: 2}], {ignore_order: true, ignore_extra_keys: true})"
+        ),
+        json!(true)
+    );
+}
+
+#[test]
+fn deep_equal_rejects_unknown_options() {
+    let err = eval_err("deep_equal(1, 1, {ignore_case: true})");
+    assert!(err.contains("Unknown option 'ignore_case'"), "got: {err}");
+
+    let err = eval_err(r#"deep_equal(1, 1, {ignore_order: "yes"})"#);
+    assert!(err.contains("must be a boolean"), "got: {err}");
+}
+
+#[test]
+fn structural_hash_ignores_key_insertion_order() {
+    assert_eq!(
+        eval("structural_hash({a: 1, b: {c: [1, 2], d: null} })"),
+        eval("structural_hash({b: {d: null, c: [1, 2]}, a: 1})")
+    );
+    assert_ne!(
+        eval("structural_hash([1, 2])"),
+        eval("structural_hash([2, 1])")
+    );
+    assert_ne!(eval(r#"structural_hash("1")"#), eval("structural_hash(1)"));
+
+    let hash = eval("structural_hash(null)");
+    assert_eq!(hash.as_str().map(str::len), Some(16));
+}
+
+// ──────────────────────────────────────────────
+// Statistics: sum, avg, median, percentile, stddev
+// ──────────────────────────────────────────────
+
+fn eval_f64(expr: &str) -> f64 {
+    eval(expr)
+        .as_f64()
+        .unwrap_or_else(|| panic!("{expr} is not a number"))
+}
+
+#[test]
+fn sum_and_avg_aggregate_arrays() {
+    assert_eq!(eval("sum([1, 2, 3])"), json!(6));
+    assert_eq!(eval("sum([1, 2.5])"), json!(3.5));
+    assert_eq!(eval("avg([1, 2, 3, 4])"), json!(2.5));
+    // Past i64::MAX the integer sum falls back to a float.
+    assert_eq!(
+        eval_f64("sum([9223372036854775807, 1])"),
+        9.223_372_036_854_776e18
+    );
+}
+
+#[test]
+fn median_and_percentile_match_known_datasets() {
+    assert_eq!(eval("median([5, 1, 3])"), json!(3.0));
+    assert_eq!(eval("median([3, 1, 4, 1])"), json!(2.0));
+
+    let data = "[15, 20, 35, 40, 50]";
+    assert_eq!(eval(&format!("percentile({data}, 0)")), json!(15.0));
+    assert_eq!(eval(&format!("percentile({data}, 100)")), json!(50.0));
+    assert_eq!(eval(&format!("percentile({data}, 50)")), json!(35.0));
+    assert!((eval_f64(&format!("percentile({data}, 40)")) - 29.0).abs() < 1e-9);
+    assert!((eval_f64("percentile([1, 2, 3, 4, 5], 90)") - 4.6).abs() < 1e-9);
+
+    assert!(eval_err("percentile([1, 2], 101)").contains("between 0 and 100"));
+}
+
+#[test]
+fn stddev_matches_known_dataset() {
+    let data = "[2, 4, 4, 4, 5, 5, 7, 9]";
+    assert_eq!(eval(&format!("stddev({data})")), json!(2.0));
+    let sample = eval_f64(&format!("stddev({data}, true)"));
+    assert!((sample - (32.0_f64 / 7.0).sqrt()).abs() < 1e-9);
+    assert_eq!(eval("stddev([3])"), json!(0.0));
+    assert_eq!(eval("stddev([3], true)"), json!(null));
+}
+
+#[test]
+fn statistics_handle_empty_arrays() {
+    assert_eq!(eval("sum([])"), json!(0));
+    assert_eq!(eval("avg([])"), json!(null));
+    assert_eq!(eval("median([])"), json!(null));
+    assert_eq!(eval("percentile([], 50)"), json!(null));
+    assert_eq!(eval("stddev([])"), json!(null));
+}
+
+#[test]
+fn statistics_reject_non_numeric_elements() {
+    let err = eval_err(r#"avg([1, "x", 3])"#);
+    assert!(err.contains("index 1"), "{err}");
+    assert!(eval_err("sum([1, null])").contains("index 1"));
+    assert!(eval_err("median(5)").contains("must be an array"));
+}
+
+// ──────────────────────────────────────────────
+// Encoding: base32, base58, base64, base64url
+// ──────────────────────────────────────────────
+
+#[test]
+fn encodings_round_trip_strings() {
+    for name in ["base32", "base58", "base64", "base64url"] {
+        let expr = format!(r#"{name}_decode({name}_encode("héllo, wörld?"))"#);
+        assert_eq!(eval(&expr), json!("héllo, wörld?"), "{name}");
+    }
+}
+
+#[test]
+fn encodings_round_trip_bytes() {
+    for name in ["base32", "base58", "base64", "base64url"] {
+        let expr = format!(r#"{name}_decode({name}_encode([0, 0, 255, 128, 7]), "bytes")"#);
+        assert_eq!(eval(&expr), json!([0, 0, 255, 128, 7]), "{name}");
+    }
+}
+
+#[test]
+fn encodings_match_reference_vectors() {
+    assert_eq!(
+        eval(r#"base32_encode("foobar")"#),
+        json!("MZXW6YTBOI======")
+    );
+    assert_eq!(eval(r#"base32_decode("mzxw6ytboi")"#), json!("foobar"));
+    assert_eq!(
+        eval(r#"base58_encode("hello world")"#),
+        json!("StV1DL6CwTryKyV")
+    );
+    assert_eq!(eval("base58_encode([0, 0, 1])"), json!("112"));
+    assert_eq!(eval(r#"base64_encode("hi?")"#), json!("aGk/"));
+    assert_eq!(eval(r#"base64url_encode("hi?")"#), json!("aGk_"));
+    assert_eq!(eval(r#"base64url_decode("aGk_")"#), json!("hi?"));
+}
+
+#[test]
+fn encodings_reject_invalid_input() {
+    let err = eval_err(r#"base32_decode("NBUQ1")"#);
+    assert!(err.contains("Invalid base32 input"), "got: {err}");
+
+    let err = eval_err(r#"base58_decode("abc0")"#);
+    assert!(
+        err.contains("Invalid base58 input: invalid symbol '0' at 3"),
+        "got: {err}"
+    );
+
+    let err = eval_err(r#"base64_decode("aGk_")"#);
+    assert!(err.contains("Invalid base64 input"), "got: {err}");
+
+    let err = eval_err(r#"base64url_decode("aGk/")"#);
+    assert!(err.contains("Invalid base64url input"), "got: {err}");
+
+    let err = eval_err("base64_decode(base64_encode([255]))");
+    assert!(err.contains("not valid UTF-8"), "got: {err}");
+
+    let err = eval_err("base64_encode([256])");
+    assert!(err.contains("integers 0-255"), "got: {err}");
+}
@@ -200,6 +200,68 @@ mod tests {
         assert_eq!(*handle.current_filter(), "info");
     }
 
+    #[tokio::test]
+    async fn watcher_follows_file_replaced_by_rename() {
+        let filter = tracing_subscriber::EnvFilter::try_new("info").unwrap();
+        let (_layer, handle) = super::super::reload::create_filter_layer(filter, "info", true);
+        let handle = handle.unwrap();
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("log-level.conf");
+        std::fs::write(&path, "info").unwrap();
+
+        let _guard =
+            watch_config_with_interval(path.clone(), handle.clone(), Duration::from_millis(50));
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        // Editors and config-map updates write a new file and rename it over
+        // the old one, so the watched path now names a different inode.
+        let staged = dir.path().join("log-level.conf.tmp");
+        std::fs::write(&staged, "debug").unwrap();
+        std::fs::rename(&staged, &path).unwrap();
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        assert_eq!(*handle.current_filter(), "debug");
+
+        // A second replacement is picked up too.
+        std::fs::write(&staged, "trace").unwrap();
+        std::fs::rename(&staged, &path).unwrap();
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        assert_eq!(*handle.current_filter(), "trace");
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn watcher_follows_swapped_symlink_target() {
+        let filter = tracing_subscriber::EnvFilter::try_new("info").unwrap();
+        let (_layer, handle) = super::super::reload::create_filter_layer(filter, "info", true);
+        let handle = handle.unwrap();
+
+        // Kubernetes config-map layout: the watched path is a symlink into a
+        // data directory that is replaced by swapping another symlink.
+        let dir = tempfile::tempdir().unwrap();
+        let v1 = dir.path().join("v1");
+        let v2 = dir.path().join("v2");
+        std::fs::create_dir(&v1).unwrap();
+        std::fs::create_dir(&v2).unwrap();
+        std::fs::write(v1.join("log-level.conf"), "info").unwrap();
+        std::fs::write(v2.join("log-level.conf"), "debug").unwrap();
+        let data = dir.path().join("..data");
+        std::os::unix::fs::symlink(&v1, &data).unwrap();
+        let path = dir.path().join("log-level.conf");
+        std::os::unix::fs::symlink(data.join("log-level.conf"), &path).unwrap();
+
+        let _guard =
+            watch_config_with_interval(path.clone(), handle.clone(), Duration::from_millis(50));
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        let staged = dir.path().join("..data_tmp");
+        std::os::unix::fs::symlink(&v2, &staged).unwrap();
+        std::fs::rename(&staged, &data).unwrap();
+        tokio::time::sleep(Duration::from_millis(200)).await;
+
+        assert_eq!(*handle.current_filter(), "debug");
+    }
+
     #[tokio::test]
     async fn watcher_stops_on_guard_drop() {
         let filter = tracing_subscriber::EnvFilter::try_new("info").unwrap();
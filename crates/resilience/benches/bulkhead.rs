@@ -4,11 +4,15 @@
 //! - `acquire` + drop permit (uncontended)
 //! - `call` happy path
 //! - Contention under concurrent callers
+//! - `PriorityBulkhead` acquire + drop permit (uncontended)
 
 use std::{hint::black_box, sync::Arc};
 
 use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
-use nebula_resilience::bulkhead::{Bulkhead, BulkheadConfig};
+use nebula_resilience::{
+    bulkhead::{Bulkhead, BulkheadConfig},
+    priority_bulkhead::{Priority, PriorityBulkhead, PriorityBulkheadConfig},
+};
 
 fn bulkhead_acquire(c: &mut Criterion) {
     let mut group = c.benchmark_group("bulkhead/acquire");
@@ -104,10 +108,33 @@ fn bulkhead_contention(c: &mut Criterion) {
     group.finish();
 }
 
+fn priority_bulkhead_acquire(c: &mut Criterion) {
+    let mut group = c.benchmark_group("priority_bulkhead/acquire");
+
+    group.bench_function("uncontended", |b| {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        let bh = PriorityBulkhead::new(PriorityBulkheadConfig {
+            max_concurrency: 100,
+            queue_depth: 100,
+            timeout: None,
+            ..PriorityBulkheadConfig::default()
+        })
+        .unwrap();
+
+        b.to_async(&rt).iter(|| async {
+            let permit = bh.acquire::<&str>(Priority::Normal).await.unwrap();
+            black_box(permit);
+        });
+    });
+
+    group.finish();
+}
+
 criterion_group!(
     benches,
     bulkhead_acquire,
     bulkhead_call,
     bulkhead_contention,
+    priority_bulkhead_acquire,
 );
 criterion_main!(benches);
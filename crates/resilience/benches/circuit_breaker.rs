@@ -9,7 +9,10 @@
 use std::{hint::black_box, sync::Arc, time::Duration};
 
 use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
-use nebula_resilience::circuit_breaker::{CircuitBreaker, CircuitBreakerConfig, Outcome};
+use nebula_resilience::{
+    ShardedCircuitBreaker,
+    circuit_breaker::{CircuitBreaker, CircuitBreakerConfig, Outcome},
+};
 
 fn closed_config() -> CircuitBreakerConfig {
     CircuitBreakerConfig {
@@ -147,11 +150,31 @@ fn cb_contention(c: &mut Criterion) {
     group.finish();
 }
 
+fn cb_sharded_lookup(c: &mut Criterion) {
+    let mut group = c.benchmark_group("circuit_breaker/sharded");
+
+    // Existing shard — read-lock hit path
+    group.bench_function("existing_shard", |b| {
+        let breakers = ShardedCircuitBreaker::<u64>::new(closed_config()).unwrap();
+        for key in 0..1024 {
+            breakers.shard(&key).unwrap();
+        }
+        let mut key = 0u64;
+        b.iter(|| {
+            key = (key + 1) % 1024;
+            let _ = black_box(breakers.shard(&key));
+        });
+    });
+
+    group.finish();
+}
+
 criterion_group!(
     benches,
     cb_try_acquire,
     cb_record_outcome,
     cb_call_happy_path,
     cb_contention,
+    cb_sharded_lookup,
 );
 criterion_main!(benches);
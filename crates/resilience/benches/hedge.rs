@@ -38,6 +38,7 @@ fn never_hedge_config() -> HedgeConfig {
         exponential_backoff: false,
         backoff_multiplier: 1.0,
         duplicate_safety: HedgeSafety::Idempotent,
+        ..Default::default()
     }
 }
 
@@ -4,8 +4,9 @@ use std::{
     future::Future,
     sync::{
         Arc,
-        atomic::{AtomicUsize, Ordering},
+        atomic::{AtomicU64, AtomicUsize, Ordering},
     },
+    time::{Duration, Instant},
 };
 
 use tokio::sync::Semaphore;
@@ -45,9 +46,10 @@ pub struct BulkheadConfig {
     /// `0` means **no queue**: if no permit is free, [`Bulkhead::acquire`] returns
     /// [`CallError::BulkheadFull`] immediately (fail-fast) instead of waiting in line.
     pub queue_size: usize,
-    /// Optional timeout while waiting for a permit.
+    /// Optional timeout while waiting for a permit. A waiter that runs out gets
+    /// [`CallError::BulkheadTimeout`].
     #[cfg_attr(feature = "serde", serde(default))]
-    pub timeout: Option<std::time::Duration>,
+    pub timeout: Option<Duration>,
 }
 
 impl Default for BulkheadConfig {
@@ -55,12 +57,42 @@ impl Default for BulkheadConfig {
         Self {
             max_concurrency: 10,
             queue_size: 100,
-            timeout: Some(std::time::Duration::from_secs(30)),
+            timeout: Some(Duration::from_secs(30)),
         }
     }
 }
 
 impl BulkheadConfig {
+    /// Let up to `max_waiters` callers wait up to `wait_timeout` for a permit
+    /// instead of being rejected as soon as the bulkhead is full.
+    ///
+    /// Waiters are served in arrival order. A caller that finds `max_waiters`
+    /// already waiting is rejected with [`CallError::BulkheadFull`]; one whose
+    /// wait runs out gets [`CallError::BulkheadTimeout`]. `max_waiters == 0`
+    /// turns the queue off.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    ///
+    /// use nebula_resilience::BulkheadConfig;
+    ///
+    /// let cfg = BulkheadConfig {
+    ///     max_concurrency: 8,
+    ///     ..BulkheadConfig::default()
+    /// }
+    /// .with_queue(32, Duration::from_millis(250));
+    /// assert_eq!(cfg.queue_size, 32);
+    /// assert_eq!(cfg.timeout, Some(Duration::from_millis(250)));
+    /// ```
+    #[must_use]
+    pub const fn with_queue(mut self, max_waiters: usize, wait_timeout: Duration) -> Self {
+        self.queue_size = max_waiters;
+        self.timeout = Some(wait_timeout);
+        self
+    }
+
     /// Validate configuration. Called by `Bulkhead::new()`.
     ///
     /// # Errors
@@ -105,6 +137,8 @@ pub struct Bulkhead {
     config: BulkheadConfig,
     semaphore: Arc<Semaphore>,
     waiting_count: Arc<AtomicUsize>,
+    /// Nanoseconds callers have spent queued, summed over every finished wait.
+    wait_nanos: Arc<AtomicU64>,
     sink: Arc<dyn MetricsSink>,
 }
 
@@ -113,6 +147,7 @@ impl std::fmt::Debug for Bulkhead {
         f.debug_struct("Bulkhead")
             .field("max_concurrency", &self.config.max_concurrency)
             .field("active", &self.active_operations())
+            .field("queued", &self.queued_waiters())
             .finish_non_exhaustive()
     }
 }
@@ -128,6 +163,7 @@ impl Bulkhead {
         Ok(Self {
             semaphore: Arc::new(Semaphore::new(config.max_concurrency)),
             waiting_count: Arc::new(AtomicUsize::new(0)),
+            wait_nanos: Arc::new(AtomicU64::new(0)),
             config,
             sink: Arc::new(NoopSink),
         })
@@ -152,6 +188,19 @@ impl Bulkhead {
         self.semaphore.available_permits()
     }
 
+    /// Current number of callers waiting in the queue for a permit.
+    #[must_use]
+    pub fn queued_waiters(&self) -> usize {
+        self.waiting_count.load(Ordering::Acquire)
+    }
+
+    /// Total time callers have spent waiting in the queue, summed over every
+    /// wait that has ended — with a permit, a timeout, or the caller giving up.
+    #[must_use]
+    pub fn total_wait_time(&self) -> Duration {
+        Duration::from_nanos(self.wait_nanos.load(Ordering::Relaxed))
+    }
+
     /// Whether the bulkhead is at capacity (no permits available).
     #[must_use]
     pub fn is_at_capacity(&self) -> bool {
@@ -169,6 +218,7 @@ impl Bulkhead {
     /// # Errors
     ///
     /// Returns `Err(CallError::BulkheadFull)` when the queue is full,
+    /// `Err(CallError::BulkheadTimeout)` if the wait for a permit runs out,
     /// or `Err(CallError::Operation)` if the operation itself fails.
     pub async fn call<T, E, Fut>(&self, f: impl FnOnce() -> Fut) -> Result<T, CallError<E>>
     where
@@ -187,8 +237,9 @@ impl Bulkhead {
     /// # Errors
     ///
     /// Returns `Err(CallError::Cancelled)` if the context is cancelled,
-    /// `Err(CallError::Timeout)` if the context deadline or bulkhead queue timeout
-    /// expires, `Err(CallError::BulkheadFull)` when capacity/queue is exhausted,
+    /// `Err(CallError::Timeout)` if the context deadline expires,
+    /// `Err(CallError::BulkheadTimeout)` if the bulkhead queue timeout expires,
+    /// `Err(CallError::BulkheadFull)` when capacity/queue is exhausted,
     /// or `Err(CallError::Operation)` if the operation itself fails.
     pub async fn call_with_policy_context<T, E, Fut>(
         &self,
@@ -210,11 +261,7 @@ impl Bulkhead {
     /// # Errors
     ///
     /// Returns `Err(CallError::BulkheadFull)` when the queue is full,
-    /// or `Err(CallError::Timeout)` if a permit timeout is configured and exceeded.
-    ///
-    /// **Note:** Queue timeout returns `CallError::Timeout`, not `BulkheadFull`.
-    /// When used in a pipeline alongside a `Timeout` step, callers cannot
-    /// distinguish the two by variant alone — check the duration value if needed.
+    /// or `Err(CallError::BulkheadTimeout)` if a permit timeout is configured and exceeded.
     pub async fn acquire<E>(&self) -> Result<BulkheadPermit, CallError<E>> {
         self.acquire_permit().await
     }
@@ -224,8 +271,9 @@ impl Bulkhead {
     /// # Errors
     ///
     /// Returns `Err(CallError::Cancelled)` if the context is cancelled,
-    /// `Err(CallError::Timeout)` if the context deadline or configured queue
-    /// timeout expires, or `Err(CallError::BulkheadFull)` when the queue is full.
+    /// `Err(CallError::Timeout)` if the context deadline expires,
+    /// `Err(CallError::BulkheadTimeout)` if the configured queue timeout expires,
+    /// or `Err(CallError::BulkheadFull)` when the queue is full.
     pub async fn acquire_with_policy_context<E>(
         &self,
         context: &PolicyContext,
@@ -266,21 +314,22 @@ impl Bulkhead {
             return Err(CallError::BulkheadFull);
         }
 
-        // RAII guard: if this future is dropped while waiting for a permit,
-        // decrement waiting_count so the queue slot isn't permanently leaked.
-        let mut wait_guard = WaitCountGuard {
+        // RAII guard: however the wait ends — including this future being
+        // dropped — the queue slot is released and the wait time recorded.
+        let _wait_guard = WaitGuard {
             count: &self.waiting_count,
-            defused: false,
+            wait_nanos: &self.wait_nanos,
+            started: Instant::now(),
         };
 
         // Wait for a permit (with optional timeout)
-        let result = if let Some(timeout_dur) = self.config.timeout {
+        if let Some(timeout_dur) = self.config.timeout {
             match tokio::time::timeout(timeout_dur, Arc::clone(&self.semaphore).acquire_owned())
                 .await
             {
                 Ok(Ok(permit)) => Ok(BulkheadPermit { _permit: permit }),
                 Ok(Err(_closed)) => Err(CallError::BulkheadFull),
-                Err(_elapsed) => Err(CallError::Timeout(timeout_dur)),
+                Err(_elapsed) => Err(CallError::BulkheadTimeout(timeout_dur)),
             }
         } else {
             Arc::clone(&self.semaphore)
@@ -288,35 +337,25 @@ impl Bulkhead {
                 .await
                 .map(|permit| BulkheadPermit { _permit: permit })
                 .map_err(|_| CallError::BulkheadFull)
-        };
-
-        // Defuse the guard and decrement manually.
-        wait_guard.defuse();
-        self.waiting_count.fetch_sub(1, Ordering::AcqRel);
-        result
+        }
     }
 }
 
-/// RAII guard that decrements `waiting_count` on drop.
+/// RAII guard that frees a queue slot and records the wait time on drop.
 ///
 /// Prevents the queue counter from leaking when the `acquire_permit` future
 /// is dropped mid-wait (e.g. by `tokio::select!` or a pipeline timeout).
-struct WaitCountGuard<'a> {
+struct WaitGuard<'a> {
     count: &'a AtomicUsize,
-    defused: bool,
-}
-
-impl WaitCountGuard<'_> {
-    const fn defuse(&mut self) {
-        self.defused = true;
-    }
+    wait_nanos: &'a AtomicU64,
+    started: Instant,
 }
 
-impl Drop for WaitCountGuard<'_> {
+impl Drop for WaitGuard<'_> {
     fn drop(&mut self) {
-        if !self.defused {
-            self.count.fetch_sub(1, Ordering::AcqRel);
-        }
+        self.count.fetch_sub(1, Ordering::AcqRel);
+        let waited = u64::try_from(self.started.elapsed().as_nanos()).unwrap_or(u64::MAX);
+        self.wait_nanos.fetch_add(waited, Ordering::Relaxed);
     }
 }
 
@@ -342,6 +381,12 @@ pub struct BulkheadStats {
     pub available_permits: usize,
     /// Whether bulkhead is at capacity.
     pub is_at_capacity: bool,
+    /// Callers currently waiting in the queue for a permit.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub queued_waiters: usize,
+    /// Total time callers have spent queued; see [`Bulkhead::total_wait_time`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub total_wait_time: Duration,
 }
 
 impl Bulkhead {
@@ -355,6 +400,8 @@ impl Bulkhead {
             active_operations: self.config.max_concurrency - available_permits,
             available_permits,
             is_at_capacity: available_permits == 0,
+            queued_waiters: self.queued_waiters(),
+            total_wait_time: self.total_wait_time(),
         }
     }
 }
@@ -554,4 +601,97 @@ mod tests {
         drop(p2);
         assert_eq!(bh.active_operations(), 0);
     }
+
+    fn queued(max_waiters: usize, wait_timeout: Duration) -> Bulkhead {
+        Bulkhead::new(
+            BulkheadConfig {
+                max_concurrency: 1,
+                ..BulkheadConfig::default()
+            }
+            .with_queue(max_waiters, wait_timeout),
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn with_queue_waits_for_released_permit() {
+        let bh = queued(2, Duration::from_secs(5));
+        let permit = bh.acquire::<&str>().await.unwrap();
+
+        let bh2 = bh.clone();
+        let waiter = tokio::spawn(async move { bh2.acquire::<&str>().await });
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        assert_eq!(bh.queued_waiters(), 1);
+        assert_eq!(bh.available_permits(), 0);
+        assert_eq!(bh.stats().queued_waiters, 1);
+
+        drop(permit);
+        waiter.await.unwrap().unwrap();
+        assert_eq!(bh.queued_waiters(), 0);
+    }
+
+    #[tokio::test]
+    async fn with_queue_times_out_waiter() {
+        let bh = queued(2, Duration::from_millis(20));
+        let _permit = bh.acquire::<&str>().await.unwrap();
+
+        let err = bh.acquire::<&str>().await.unwrap_err();
+
+        assert!(matches!(err, CallError::BulkheadTimeout(d) if d == Duration::from_millis(20)));
+        assert_eq!(bh.queued_waiters(), 0);
+        assert!(bh.stats().total_wait_time >= Duration::from_millis(20));
+    }
+
+    #[tokio::test]
+    async fn queue_full_is_distinct_from_timed_out_waiting() {
+        let bh = Bulkhead::new(
+            BulkheadConfig {
+                max_concurrency: 1,
+                ..BulkheadConfig::default()
+            }
+            .with_queue(1, Duration::from_millis(50)),
+        )
+        .unwrap();
+        let _permit = bh.acquire::<&str>().await.unwrap();
+
+        let bh2 = bh.clone();
+        let waiter = tokio::spawn(async move { bh2.acquire::<&str>().await });
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        assert_eq!(bh.stats().queued_waiters, 1);
+
+        // Queue is full: rejected at once, without waiting.
+        let err = bh.acquire::<&str>().await.unwrap_err();
+        assert!(matches!(err, CallError::BulkheadFull));
+
+        // The queued caller gives up once the wait bound passes.
+        let err = waiter.await.unwrap().unwrap_err();
+        assert!(matches!(err, CallError::BulkheadTimeout(_)));
+        assert_eq!(bh.stats().queued_waiters, 0);
+    }
+
+    #[tokio::test]
+    async fn with_queue_serves_waiters_in_arrival_order() {
+        let bh = queued(3, Duration::from_secs(5));
+        let permit = bh.acquire::<&str>().await.unwrap();
+        let order = Arc::new(parking_lot::Mutex::new(Vec::new()));
+
+        let mut waiters = Vec::new();
+        for i in 0..3 {
+            let bh = bh.clone();
+            let order = Arc::clone(&order);
+            waiters.push(tokio::spawn(async move {
+                let _permit = bh.acquire::<&str>().await.unwrap();
+                order.lock().push(i);
+            }));
+            // Make arrival order deterministic.
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+        assert_eq!(bh.queued_waiters(), 3);
+
+        drop(permit);
+        for waiter in waiters {
+            waiter.await.unwrap();
+        }
+        assert_eq!(*order.lock(), vec![0, 1, 2]);
+    }
 }
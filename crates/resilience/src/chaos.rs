@@ -0,0 +1,354 @@
+//! Chaos injection — wrap an operation so it misbehaves on purpose.
+//!
+//! Pattern tests prove the pattern logic against hand-written failing
+//! closures. [`ChaosLayer`] instead sits between a stack (circuit breaker,
+//! retry, fallback, …) and the *real* operation, so integration tests can
+//! check how the whole stack reacts when that operation starts failing or
+//! stalling.
+//!
+//! A [`ChaosPolicy`] describes which faults to inject:
+//!
+//! - [`error_rate`](ChaosPolicy::error_rate) — fail a random fraction of calls
+//! - [`fail_nth`](ChaosPolicy::fail_nth) — fail specific calls by number
+//! - [`force_timeout`](ChaosPolicy::force_timeout) — stall every call, so an
+//!   enclosing timeout fires
+//!
+//! Injected failures return the policy's error value as `Err(E)`, exactly as
+//! the real operation would. A faulted call never runs the wrapped operation.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use nebula_resilience::chaos::{ChaosLayer, ChaosPolicy};
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let chaos = ChaosLayer::new(ChaosPolicy::new("injected").fail_nth(2));
+//! let op = chaos.wrap(|| async { Ok::<u32, &str>(1) });
+//!
+//! assert_eq!(op().await, Ok(1));
+//! assert_eq!(op().await, Err("injected"));
+//! assert_eq!(op().await, Ok(1));
+//!
+//! chaos.set_enabled(false);
+//! assert_eq!(chaos.injected(), 1);
+//! # }
+//! ```
+
+use std::{
+    fmt,
+    future::Future,
+    pin::Pin,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, AtomicU64, Ordering},
+    },
+    time::Duration,
+};
+
+use parking_lot::Mutex;
+use smallvec::SmallVec;
+
+/// Which faults a [`ChaosLayer`] injects.
+///
+/// Built from the error value injected calls return; every fault is off
+/// until enabled through a builder method.
+#[derive(Clone)]
+pub struct ChaosPolicy<E> {
+    error: E,
+    error_rate: f64,
+    fail_on: SmallVec<[u64; 4]>,
+    stall: Option<Duration>,
+    seed: Option<u64>,
+}
+
+impl<E: fmt::Debug> fmt::Debug for ChaosPolicy<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ChaosPolicy")
+            .field("error", &self.error)
+            .field("error_rate", &self.error_rate)
+            .field("fail_on", &self.fail_on)
+            .field("stall", &self.stall)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<E> ChaosPolicy<E> {
+    /// Create a policy that injects `error`. No fault is active yet.
+    #[must_use]
+    pub fn new(error: E) -> Self {
+        Self {
+            error,
+            error_rate: 0.0,
+            fail_on: SmallVec::new(),
+            stall: None,
+            seed: None,
+        }
+    }
+
+    /// Fail each call with probability `rate`, clamped to `0.0..=1.0`
+    /// (`NaN` is treated as `0.0`).
+    #[must_use = "builder methods must be chained or built"]
+    pub const fn error_rate(mut self, rate: f64) -> Self {
+        self.error_rate = if rate.is_nan() {
+            0.0
+        } else {
+            rate.clamp(0.0, 1.0)
+        };
+        self
+    }
+
+    /// Fail the `n`-th call (1-based) made through the layer. Chain to fail
+    /// several calls.
+    #[must_use = "builder methods must be chained or built"]
+    pub fn fail_nth(mut self, n: u64) -> Self {
+        self.fail_on.push(n);
+        self
+    }
+
+    /// Stall every call for `stall` before it runs (or fails), so an
+    /// enclosing timeout with a shorter limit fires.
+    #[must_use = "builder methods must be chained or built"]
+    pub const fn force_timeout(mut self, stall: Duration) -> Self {
+        self.stall = Some(stall);
+        self
+    }
+
+    /// Seed the random source behind [`error_rate`](Self::error_rate) so a
+    /// test run is reproducible.
+    #[must_use = "builder methods must be chained or built"]
+    pub const fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+}
+
+/// Future returned by closures from [`ChaosLayer::wrap`].
+pub type ChaosFuture<T, E> = Pin<Box<dyn Future<Output = Result<T, E>> + Send>>;
+
+/// Shared state behind every closure a [`ChaosLayer`] wraps.
+struct ChaosState<E> {
+    policy: ChaosPolicy<E>,
+    rng: Mutex<fastrand::Rng>,
+    enabled: AtomicBool,
+    calls: AtomicU64,
+    injected: AtomicU64,
+}
+
+impl<E: Clone> ChaosState<E> {
+    /// Decide this call's fault: `Some(error)` to inject, `None` to pass through.
+    fn next_fault(&self) -> Option<E> {
+        let call = self.calls.fetch_add(1, Ordering::AcqRel) + 1;
+        let policy = &self.policy;
+        let fail = policy.fail_on.contains(&call)
+            || (policy.error_rate > 0.0 && self.rng.lock().f64() < policy.error_rate);
+        if !fail {
+            return None;
+        }
+        self.injected.fetch_add(1, Ordering::AcqRel);
+        Some(policy.error.clone())
+    }
+}
+
+/// Fault injector for integration tests of resilience stacks.
+///
+/// Cloning is cheap and shares call counters and the enabled flag, so a test
+/// can keep a handle to switch chaos off while the wrapped closure is owned
+/// by the stack under test. See the [module docs](self) for an example.
+pub struct ChaosLayer<E> {
+    state: Arc<ChaosState<E>>,
+}
+
+impl<E> Clone for ChaosLayer<E> {
+    fn clone(&self) -> Self {
+        Self {
+            state: Arc::clone(&self.state),
+        }
+    }
+}
+
+impl<E: fmt::Debug> fmt::Debug for ChaosLayer<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ChaosLayer")
+            .field("policy", &self.state.policy)
+            .field("enabled", &self.is_enabled())
+            .field("calls", &self.calls())
+            .field("injected", &self.injected())
+            .finish()
+    }
+}
+
+impl<E> ChaosLayer<E> {
+    /// Create an enabled layer that injects faults per `policy`.
+    #[must_use]
+    pub fn new(policy: ChaosPolicy<E>) -> Self {
+        let rng = policy
+            .seed
+            .map_or_else(fastrand::Rng::new, fastrand::Rng::with_seed);
+        Self {
+            state: Arc::new(ChaosState {
+                policy,
+                rng: Mutex::new(rng),
+                enabled: AtomicBool::new(true),
+                calls: AtomicU64::new(0),
+                injected: AtomicU64::new(0),
+            }),
+        }
+    }
+
+    /// Turn injection on or off. While disabled, wrapped calls run the real
+    /// operation untouched and are not counted.
+    pub fn set_enabled(&self, enabled: bool) {
+        self.state.enabled.store(enabled, Ordering::Release);
+    }
+
+    /// Whether faults are currently being injected.
+    #[must_use]
+    pub fn is_enabled(&self) -> bool {
+        self.state.enabled.load(Ordering::Acquire)
+    }
+
+    /// Calls made while enabled — the counter [`fail_nth`](ChaosPolicy::fail_nth) matches.
+    #[must_use]
+    pub fn calls(&self) -> u64 {
+        self.state.calls.load(Ordering::Acquire)
+    }
+
+    /// Calls that received an injected error.
+    #[must_use]
+    pub fn injected(&self) -> u64 {
+        self.state.injected.load(Ordering::Acquire)
+    }
+}
+
+impl<E: Clone + Send + Sync + 'static> ChaosLayer<E> {
+    /// Wrap `f` so each call may be stalled or failed per the policy.
+    ///
+    /// The returned closure has the same shape as `f` and can be handed to
+    /// any pattern that takes an operation factory.
+    ///
+    /// # Cancel safety
+    ///
+    /// Cancel-safe with respect to this crate: the fault decision is taken
+    /// when the closure is called, before the returned future is polled, so
+    /// dropping the future mid-stall leaves only the call counted.
+    pub fn wrap<T, F, Fut>(&self, f: F) -> impl Fn() -> ChaosFuture<T, E> + Send + Sync
+    where
+        T: 'static,
+        F: Fn() -> Fut + Send + Sync,
+        Fut: Future<Output = Result<T, E>> + Send + 'static,
+    {
+        let state = Arc::clone(&self.state);
+        move || {
+            let (stall, outcome) = if state.enabled.load(Ordering::Acquire) {
+                let fault = state.next_fault();
+                (state.policy.stall, fault.map_or_else(|| Ok(f()), Err))
+            } else {
+                (None, Ok(f()))
+            };
+            Box::pin(async move {
+                if let Some(stall) = stall {
+                    tokio::time::sleep(stall).await;
+                }
+                match outcome {
+                    Ok(operation) => operation.await,
+                    Err(error) => Err(error),
+                }
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicU32;
+
+    use super::*;
+
+    fn counted(runs: &Arc<AtomicU32>) -> impl Fn() -> ChaosFuture<u32, &'static str> + Send + Sync {
+        let runs = Arc::clone(runs);
+        move || {
+            runs.fetch_add(1, Ordering::SeqCst);
+            Box::pin(async { Ok(7) })
+        }
+    }
+
+    #[tokio::test]
+    async fn empty_policy_passes_every_call_through() {
+        let chaos = ChaosLayer::new(ChaosPolicy::new("boom"));
+        let op = chaos.wrap(|| async { Ok::<_, &str>(1u32) });
+
+        for _ in 0..10 {
+            assert_eq!(op().await, Ok(1));
+        }
+        assert_eq!(chaos.calls(), 10);
+        assert_eq!(chaos.injected(), 0);
+    }
+
+    #[tokio::test]
+    async fn fail_nth_skips_the_real_operation() {
+        let runs = Arc::new(AtomicU32::new(0));
+        let chaos = ChaosLayer::new(ChaosPolicy::new("boom").fail_nth(1).fail_nth(3));
+        let op = chaos.wrap(counted(&runs));
+
+        let results = [op().await, op().await, op().await, op().await];
+        assert_eq!(results, [Err("boom"), Ok(7), Err("boom"), Ok(7)]);
+        assert_eq!(runs.load(Ordering::SeqCst), 2);
+        assert_eq!(chaos.injected(), 2);
+    }
+
+    #[tokio::test]
+    async fn error_rate_extremes_are_deterministic() {
+        let always = ChaosLayer::new(ChaosPolicy::new("boom").error_rate(1.0));
+        let never = ChaosLayer::new(ChaosPolicy::new("boom").error_rate(f64::NAN));
+        let op_always = always.wrap(|| async { Ok::<_, &str>(()) });
+        let op_never = never.wrap(|| async { Ok::<_, &str>(()) });
+
+        for _ in 0..20 {
+            assert_eq!(op_always().await, Err("boom"));
+            assert_eq!(op_never().await, Ok(()));
+        }
+    }
+
+    #[tokio::test]
+    async fn seeded_error_rate_is_reproducible() {
+        let run = || async {
+            let chaos = ChaosLayer::new(ChaosPolicy::new("boom").error_rate(0.5).seed(42));
+            let op = chaos.wrap(|| async { Ok::<_, &str>(()) });
+            let mut outcomes = Vec::new();
+            for _ in 0..32 {
+                outcomes.push(op().await.is_ok());
+            }
+            outcomes
+        };
+
+        let first = run().await;
+        assert_eq!(first, run().await);
+        assert!(first.contains(&true) && first.contains(&false));
+    }
+
+    #[tokio::test]
+    async fn force_timeout_trips_an_enclosing_timeout() {
+        let chaos =
+            ChaosLayer::new(ChaosPolicy::new("boom").force_timeout(Duration::from_secs(10)));
+        let op = chaos.wrap(|| async { Ok::<_, &str>(1u32) });
+
+        let result = crate::timeout::timeout(Duration::from_millis(10), op()).await;
+        assert!(matches!(result, Err(crate::CallError::Timeout(_))));
+    }
+
+    #[tokio::test]
+    async fn disabled_layer_neither_injects_nor_counts() {
+        let runs = Arc::new(AtomicU32::new(0));
+        let chaos = ChaosLayer::new(ChaosPolicy::new("boom").error_rate(1.0));
+        let op = chaos.wrap(counted(&runs));
+
+        chaos.set_enabled(false);
+        assert_eq!(op().await, Ok(7));
+        assert_eq!((chaos.calls(), chaos.injected()), (0, 0));
+
+        chaos.clone().set_enabled(true);
+        assert_eq!(op().await, Err("boom"));
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+    }
+}
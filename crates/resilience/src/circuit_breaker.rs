@@ -27,17 +27,22 @@
 
 #[cfg(not(loom))]
 use std::sync::atomic::{AtomicU32, Ordering};
-use std::{sync::Arc, time::Duration};
+use std::{
+    collections::VecDeque,
+    sync::Arc,
+    time::{Duration, Instant, SystemTime},
+};
 
 // Under loom, swap std atomics for loom-instrumented equivalents.
 #[cfg(loom)]
 use loom::sync::atomic::{AtomicU32, Ordering};
-use parking_lot::Mutex;
+use parking_lot::{Mutex, RwLock};
 
 use crate::{
     CallError, ConfigError, PolicyContext,
     clock::{Clock, SystemClock},
     sink::{CircuitState, MetricsSink, NoopSink, ResilienceEvent},
+    span::PatternSpan,
 };
 
 // ── Config ────────────────────────────────────────────────────────────────────
@@ -75,9 +80,22 @@ pub struct CircuitBreakerConfig {
     pub slow_call_rate_threshold: f64,
     /// Size of the count-based sliding window. 0 = use simple counters (default).
     pub sliding_window_size: u32,
+    /// Span of the time-based sliding window. `None` = no time window (default).
+    ///
+    /// Mutually exclusive with `sliding_window_size`; see [`WindowMode`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub sliding_window_duration: Option<Duration>,
     /// Failure rate threshold (0.0--1.0) used with sliding window. `None` = use
     /// `failure_threshold` count.
     pub failure_rate_threshold: Option<f64>,
+    /// Only failures within this trailing span count toward `failure_threshold`.
+    /// `None` = count failures since the last reset (default).
+    ///
+    /// Successes do not forgive failures in this mode; failures age out instead.
+    /// Mutually exclusive with a sliding window and `failure_rate_threshold`;
+    /// see [`WindowMode::RecentFailures`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub failure_window: Option<Duration>,
 }
 
 impl Default for CircuitBreakerConfig {
@@ -94,12 +112,177 @@ impl Default for CircuitBreakerConfig {
             slow_call_threshold: None,
             slow_call_rate_threshold: 1.0,
             sliding_window_size: 0,
+            sliding_window_duration: None,
             failure_rate_threshold: None,
+            failure_window: None,
         }
     }
 }
 
+/// Window of recent calls the breaker judges failures over.
+///
+/// Set with [`CircuitBreakerConfig::with_window`]; the modes are mutually
+/// exclusive and the last call wins.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum WindowMode {
+    /// The last `n` recorded calls. Feeds failure and slow-call rates.
+    Count(u32),
+    /// Calls recorded within the trailing duration. Feeds failure and
+    /// slow-call rates.
+    ///
+    /// Outcomes are grouped into ten buckets, so calls expire with a
+    /// granularity of a tenth of the span.
+    Time(Duration),
+    /// Failures within the trailing duration, compared against
+    /// `failure_threshold`. Failures age out instead of being forgiven by
+    /// successes; no rate is evaluated, so selecting this mode clears
+    /// `failure_rate_threshold`.
+    RecentFailures(Duration),
+}
+
 impl CircuitBreakerConfig {
+    /// Window size used by [`with_failure_rate`](Self::with_failure_rate) when no
+    /// sliding window is configured.
+    pub const DEFAULT_FAILURE_RATE_WINDOW: u32 = 100;
+
+    /// Trip on the failure ratio over the last `window` calls instead of the
+    /// failure count.
+    ///
+    /// The breaker opens once at least `min_calls` outcomes are in the window and
+    /// `failures / window_total >= failure_ratio`. The default count-based mode
+    /// forgives a failure for every success, so intermittent errors never reach
+    /// `failure_threshold`; the rolling window catches them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use nebula_resilience::circuit_breaker::CircuitBreakerConfig;
+    ///
+    /// // Open when half of the last 20 calls failed, once 10 calls were seen.
+    /// let config = CircuitBreakerConfig::default().with_rolling_window(20, 10, 0.5);
+    /// assert_eq!(config.sliding_window_size, 20);
+    /// assert_eq!(config.failure_rate_threshold, Some(0.5));
+    /// ```
+    ///
+    /// Shorthand for `with_window(WindowMode::Count(window))`,
+    /// [`with_failure_rate`](Self::with_failure_rate) and
+    /// [`with_min_operations`](Self::with_min_operations).
+    #[must_use]
+    pub const fn with_rolling_window(
+        self,
+        window: u32,
+        min_calls: u32,
+        failure_ratio: f64,
+    ) -> Self {
+        self.with_window(WindowMode::Count(window))
+            .with_failure_rate(failure_ratio)
+            .with_min_operations(min_calls)
+    }
+
+    /// Judge failures over `mode` instead of raw counters.
+    ///
+    /// This is the one place the window is chosen: it replaces any previously
+    /// configured [`WindowMode`]. Combine `Count` or `Time` with
+    /// [`with_failure_rate`](Self::with_failure_rate) to trip on a failure
+    /// ratio; without a rate they only feed [`CircuitBreakerStats`] and the
+    /// slow-call rate. `RecentFailures` keeps the count-based trip and clears
+    /// any failure rate.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    ///
+    /// use nebula_resilience::circuit_breaker::{CircuitBreakerConfig, WindowMode};
+    ///
+    /// // Open when half of the calls in the last 10 s failed, once 20 calls were seen.
+    /// let config = CircuitBreakerConfig::default()
+    ///     .with_window(WindowMode::Time(Duration::from_secs(10)))
+    ///     .with_failure_rate(0.5)
+    ///     .with_min_operations(20);
+    /// assert_eq!(config.window_mode(), Some(WindowMode::Time(Duration::from_secs(10))));
+    ///
+    /// // Open after `failure_threshold` failures within any 30 s span.
+    /// let config = CircuitBreakerConfig::default()
+    ///     .with_window(WindowMode::RecentFailures(Duration::from_secs(30)));
+    /// assert_eq!(config.failure_window, Some(Duration::from_secs(30)));
+    /// ```
+    #[must_use]
+    pub const fn with_window(mut self, mode: WindowMode) -> Self {
+        match mode {
+            WindowMode::Count(n) => {
+                self.sliding_window_size = n;
+                self.sliding_window_duration = None;
+                self.failure_window = None;
+            },
+            WindowMode::Time(span) => {
+                self.sliding_window_size = 0;
+                self.sliding_window_duration = Some(span);
+                self.failure_window = None;
+            },
+            WindowMode::RecentFailures(span) => {
+                self.sliding_window_size = 0;
+                self.sliding_window_duration = None;
+                self.failure_window = Some(span);
+                self.failure_rate_threshold = None;
+            },
+        }
+        self
+    }
+
+    /// The configured window, if any.
+    #[must_use]
+    pub const fn window_mode(&self) -> Option<WindowMode> {
+        if let Some(span) = self.sliding_window_duration {
+            Some(WindowMode::Time(span))
+        } else if self.sliding_window_size > 0 {
+            Some(WindowMode::Count(self.sliding_window_size))
+        } else if let Some(span) = self.failure_window {
+            Some(WindowMode::RecentFailures(span))
+        } else {
+            None
+        }
+    }
+
+    /// Trip when the failure rate over the sliding window reaches `rate`.
+    ///
+    /// Keeps an already configured `Count` or `Time` window. Otherwise — including
+    /// after `RecentFailures`, which evaluates no rate — switches to a window of
+    /// [`DEFAULT_FAILURE_RATE_WINDOW`](Self::DEFAULT_FAILURE_RATE_WINDOW) calls.
+    /// Pair with [`with_min_operations`](Self::with_min_operations) so a handful of
+    /// early failures cannot open the circuit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use nebula_resilience::circuit_breaker::CircuitBreakerConfig;
+    ///
+    /// let config = CircuitBreakerConfig::default()
+    ///     .with_failure_rate(0.5)
+    ///     .with_min_operations(50);
+    /// assert_eq!(config.sliding_window_size, CircuitBreakerConfig::DEFAULT_FAILURE_RATE_WINDOW);
+    /// ```
+    #[must_use]
+    pub const fn with_failure_rate(mut self, rate: f64) -> Self {
+        if !matches!(
+            self.window_mode(),
+            Some(WindowMode::Count(_) | WindowMode::Time(_))
+        ) {
+            self = self.with_window(WindowMode::Count(Self::DEFAULT_FAILURE_RATE_WINDOW));
+        }
+        self.failure_rate_threshold = Some(rate);
+        self
+    }
+
+    /// Set the minimum number of recorded operations before the breaker may trip.
+    #[must_use]
+    pub const fn with_min_operations(mut self, min_operations: u32) -> Self {
+        self.min_operations = min_operations;
+        self
+    }
+
     /// Validate configuration. Called by `CircuitBreaker::new()`.
     ///
     /// # Errors
@@ -137,6 +320,31 @@ impl CircuitBreakerConfig {
                 "must be between 0.0 and 1.0",
             ));
         }
+        if let Some(span) = self.sliding_window_duration {
+            if span.is_zero() {
+                return Err(ConfigError::new("sliding_window_duration", "must be > 0"));
+            }
+            if self.sliding_window_size > 0 {
+                return Err(ConfigError::new(
+                    "sliding_window_duration",
+                    "cannot be combined with sliding_window_size",
+                ));
+            }
+        }
+        if let Some(span) = self.failure_window {
+            if span.is_zero() {
+                return Err(ConfigError::new("failure_window", "must be > 0 when set"));
+            }
+            if self.sliding_window_size > 0
+                || self.sliding_window_duration.is_some()
+                || self.failure_rate_threshold.is_some()
+            {
+                return Err(ConfigError::new(
+                    "failure_window",
+                    "cannot be combined with a sliding window or failure_rate_threshold",
+                ));
+            }
+        }
         if self
             .failure_rate_threshold
             .is_some_and(|r| !(0.0..=1.0).contains(&r))
@@ -192,7 +400,7 @@ impl From<crate::classifier::ErrorClass> for Outcome {
 #[derive(Debug, Clone, Copy, PartialEq)]
 enum State {
     Closed,
-    Open { opened_at: std::time::Instant },
+    Open { opened_at: Instant },
     HalfOpen,
 }
 
@@ -203,6 +411,12 @@ const STATE_HALF_OPEN: u32 = 2;
 // ── CircuitBreaker ────────────────────────────────────────────────────────────
 
 /// Snapshot of circuit breaker state for health reporting.
+///
+/// Under [`WindowMode::RecentFailures`] only failures are kept per window:
+/// `failures` counts the trailing span while `total` and `slow_calls` are
+/// cumulative since the counters were last reset, so
+/// [`successes`](Self::successes) and [`failure_rate`](Self::failure_rate)
+/// return `None` there.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CircuitBreakerStats {
@@ -214,9 +428,173 @@ pub struct CircuitBreakerStats {
     pub total: u32,
     /// Number of slow calls in current window.
     pub slow_calls: u32,
+    /// Window the counts were taken over; `None` for the default counters.
+    pub window: Option<WindowMode>,
+}
+
+impl CircuitBreakerStats {
+    /// Whether `failures` and `total` cover different spans.
+    const fn mixes_windows(&self) -> bool {
+        matches!(self.window, Some(WindowMode::RecentFailures(_)))
+    }
+
+    /// Calls in the current window that did not fail.
+    ///
+    /// `None` under [`WindowMode::RecentFailures`].
+    #[must_use]
+    pub const fn successes(&self) -> Option<u32> {
+        if self.mixes_windows() {
+            None
+        } else {
+            Some(self.total.saturating_sub(self.failures))
+        }
+    }
+
+    /// Measured failure rate, `failures / total` (0.0 when nothing was recorded).
+    ///
+    /// With a sliding window this is the rate the breaker compares against
+    /// `failure_rate_threshold`. In count mode `failures` is forgiven by successes,
+    /// so the value is only a rough indicator. `None` under
+    /// [`WindowMode::RecentFailures`].
+    #[must_use]
+    pub fn failure_rate(&self) -> Option<f64> {
+        if self.mixes_windows() {
+            None
+        } else if self.total == 0 {
+            Some(0.0)
+        } else {
+            Some(f64::from(self.failures) / f64::from(self.total))
+        }
+    }
+}
+
+/// Persistable circuit breaker state, used to survive process restarts.
+///
+/// Produced by [`CircuitBreaker::snapshot`] and consumed by
+/// [`CircuitBreaker::restore`]. Timestamps are wall-clock so they stay
+/// meaningful across processes. Sliding-window contents and in-flight
+/// half-open probes are not persisted.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PersistentCircuitBreakerState {
+    /// Circuit state at snapshot time.
+    pub state: CircuitState,
+    /// Failure counter (count mode).
+    pub failures: u32,
+    /// Operation counter (count mode).
+    pub total: u32,
+    /// Consecutive opens, which drive the dynamic break duration.
+    pub consecutive_opens: u32,
+    /// When the circuit last opened. `None` unless `state` is `Open`.
+    pub opened_at: Option<SystemTime>,
+}
+
+/// Durable home for one breaker's [`PersistentCircuitBreakerState`],
+/// attached with [`CircuitBreaker::with_store`].
+///
+/// The breaker hydrates from [`load_state`](Self::load_state) when the store
+/// is attached and calls [`save_state`](Self::save_state) after every state
+/// transition, so a process that restarts while the circuit is open keeps
+/// rejecting calls. Each store instance backs a single breaker; key it by the
+/// protected dependency when several breakers share a backend.
+///
+/// Both methods run on the calling task, `save_state` after the breaker's
+/// internal lock is released. They must not block for long and cannot fail
+/// the call that triggered them: a store that talks to the network should
+/// log its own errors and do the write in the background.
+///
+/// # Backing with Redis
+///
+/// Enable the `serde` feature and store the state as JSON under one key per
+/// breaker. `load_state` does a `GET` and deserializes; a missing or
+/// unreadable value returns `None`, so the breaker starts closed.
+/// `save_state` pushes the state onto a channel drained by a task that does
+/// a `SET`; if several processes share the key, the last transition wins.
+/// The state's `opened_at` is wall-clock time, so processes only need
+/// roughly synchronized clocks.
+pub trait CircuitBreakerStore: Send + Sync {
+    /// Most recently saved state, or `None` if nothing was saved yet.
+    fn load_state(&self) -> Option<PersistentCircuitBreakerState>;
+
+    /// Persist `state` after a transition.
+    fn save_state(&self, state: PersistentCircuitBreakerState);
+}
+
+/// [`CircuitBreakerStore`] that keeps the last saved state in memory.
+///
+/// Survives breaker reconstruction within one process — e.g. a breaker
+/// rebuilt on config reload — but not a process restart.
+#[derive(Debug, Default)]
+pub struct InMemoryCircuitBreakerStore {
+    state: Mutex<Option<PersistentCircuitBreakerState>>,
+}
+
+impl InMemoryCircuitBreakerStore {
+    /// Create an empty store.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl CircuitBreakerStore for InMemoryCircuitBreakerStore {
+    fn load_state(&self) -> Option<PersistentCircuitBreakerState> {
+        *self.state.lock()
+    }
+
+    fn save_state(&self, state: PersistentCircuitBreakerState) {
+        *self.state.lock() = Some(state);
+    }
 }
 
 type StateChangeCallback = Box<dyn Fn(CircuitState, CircuitState) + Send + Sync>;
+type TransitionListener = Arc<dyn Fn(&StateTransitionEvent) + Send + Sync>;
+
+/// A circuit state transition, delivered to [`CircuitBreaker::subscribe`] listeners.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct StateTransitionEvent {
+    /// State before the transition.
+    pub from: CircuitState,
+    /// State after the transition.
+    pub to: CircuitState,
+    /// Failures in the current window (or counter) right after the transition.
+    pub failures: u32,
+    /// When the transition happened, according to the breaker clock.
+    pub at: Instant,
+}
+
+/// Log a transition through `tracing`; pass it to [`CircuitBreaker::subscribe`].
+///
+/// Opening logs at `warn`, every other transition at `info`.
+///
+/// # Examples
+///
+/// ```rust
+/// use nebula_resilience::circuit_breaker::{
+///     CircuitBreaker, CircuitBreakerConfig, log_state_transition,
+/// };
+///
+/// let cb = CircuitBreaker::new(CircuitBreakerConfig::default()).expect("valid config");
+/// cb.subscribe(log_state_transition);
+/// ```
+pub fn log_state_transition(event: &StateTransitionEvent) {
+    if event.to == CircuitState::Open {
+        tracing::warn!(
+            from = ?event.from,
+            to = ?event.to,
+            failures = event.failures,
+            "circuit breaker opened"
+        );
+    } else {
+        tracing::info!(
+            from = ?event.from,
+            to = ?event.to,
+            failures = event.failures,
+            "circuit breaker state changed"
+        );
+    }
+}
 
 /// Circuit breaker — protects downstream calls by rejecting requests when failure rate is high.
 ///
@@ -279,6 +657,8 @@ pub struct CircuitBreaker {
     sink: Arc<dyn MetricsSink>,
     state: Mutex<InnerState>,
     on_state_change: Option<StateChangeCallback>,
+    subscribers: RwLock<Vec<TransitionListener>>,
+    store: Option<Arc<dyn CircuitBreakerStore>>,
 }
 
 /// Sum a slice of 0/1 bytes into a u32.
@@ -473,6 +853,205 @@ impl OutcomeWindow {
     }
 }
 
+/// Outcomes of one time-window bucket.
+#[derive(Debug)]
+struct Bucket {
+    start: Instant,
+    total: u32,
+    failures: u32,
+    slow: u32,
+}
+
+/// Time-based sliding window: outcomes aggregated into fixed-width buckets,
+/// dropped once their bucket is older than the span.
+#[derive(Debug)]
+struct TimeWindow {
+    span: Duration,
+    bucket_width: Duration,
+    buckets: VecDeque<Bucket>,
+    total: u32,
+    failures: u32,
+    slow: u32,
+}
+
+impl TimeWindow {
+    const BUCKETS: u32 = 10;
+
+    fn new(span: Duration) -> Self {
+        let bucket_width = span / Self::BUCKETS;
+        Self {
+            span,
+            bucket_width: if bucket_width.is_zero() {
+                span
+            } else {
+                bucket_width
+            },
+            buckets: VecDeque::with_capacity(Self::BUCKETS as usize + 1),
+            total: 0,
+            failures: 0,
+            slow: 0,
+        }
+    }
+
+    /// Drop buckets that fell out of the window.
+    fn expire(&mut self, now: Instant) {
+        while let Some(bucket) = self.buckets.front() {
+            if now.saturating_duration_since(bucket.start) < self.span {
+                break;
+            }
+            self.total -= bucket.total;
+            self.failures -= bucket.failures;
+            self.slow -= bucket.slow;
+            self.buckets.pop_front();
+        }
+    }
+
+    fn record(&mut self, now: Instant, is_failure: bool, is_slow: bool) {
+        self.expire(now);
+        let current = match self.buckets.back_mut() {
+            Some(bucket) if now.saturating_duration_since(bucket.start) < self.bucket_width => {
+                bucket
+            },
+            _ => {
+                self.buckets.push_back(Bucket {
+                    start: now,
+                    total: 0,
+                    failures: 0,
+                    slow: 0,
+                });
+                self.buckets.back_mut().expect("bucket was just pushed")
+            },
+        };
+        current.total += 1;
+        current.failures += u32::from(is_failure);
+        current.slow += u32::from(is_slow);
+        self.total += 1;
+        self.failures += u32::from(is_failure);
+        self.slow += u32::from(is_slow);
+    }
+
+    fn reset(&mut self) {
+        self.buckets.clear();
+        self.total = 0;
+        self.failures = 0;
+        self.slow = 0;
+    }
+}
+
+/// Timestamps of recent failures, for [`CircuitBreakerConfig::failure_window`].
+///
+/// A ring buffer of at most `failure_threshold` entries: once full, a new
+/// failure replaces the oldest, which can no longer affect whether the
+/// threshold is reached. Recording is O(1) amortized.
+#[derive(Debug)]
+struct FailureLog {
+    span: Duration,
+    capacity: usize,
+    failures: VecDeque<Instant>,
+}
+
+impl FailureLog {
+    fn new(span: Duration, capacity: u32) -> Self {
+        let capacity = capacity.max(1) as usize;
+        Self {
+            span,
+            capacity,
+            failures: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Drop failures older than the span.
+    fn expire(&mut self, now: Instant) {
+        while let Some(&at) = self.failures.front() {
+            if now.saturating_duration_since(at) < self.span {
+                break;
+            }
+            self.failures.pop_front();
+        }
+    }
+
+    fn record(&mut self, now: Instant) {
+        self.expire(now);
+        if self.failures.len() == self.capacity {
+            self.failures.pop_front();
+        }
+        self.failures.push_back(now);
+    }
+
+    // Reason: the log never holds more than `failure_threshold` (a u32) entries.
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "bounded by failure_threshold, which is a u32"
+    )]
+    fn count(&self) -> u32 {
+        self.failures.len() as u32
+    }
+
+    fn reset(&mut self) {
+        self.failures.clear();
+    }
+}
+
+/// The breaker's sliding window, by [`WindowMode`].
+#[derive(Debug)]
+enum SlidingWindow {
+    Count(OutcomeWindow),
+    Time(TimeWindow),
+}
+
+impl SlidingWindow {
+    fn from_config(config: &CircuitBreakerConfig) -> Option<Self> {
+        match config.window_mode()? {
+            WindowMode::Count(n) => Some(Self::Count(OutcomeWindow::new(n as usize))),
+            WindowMode::Time(span) => Some(Self::Time(TimeWindow::new(span))),
+            // Tracked by `FailureLog`, not a sliding window.
+            WindowMode::RecentFailures(_) => None,
+        }
+    }
+
+    fn record(&mut self, is_failure: bool, is_slow: bool, clock: &dyn Clock) {
+        match self {
+            Self::Count(window) => window.record(is_failure, is_slow),
+            Self::Time(window) => window.record(clock.now(), is_failure, is_slow),
+        }
+    }
+
+    /// Drop expired outcomes so the counts reflect `clock`'s current time.
+    fn expire(&mut self, clock: &dyn Clock) {
+        if let Self::Time(window) = self {
+            window.expire(clock.now());
+        }
+    }
+
+    const fn total(&self) -> u32 {
+        match self {
+            Self::Count(window) => window.total(),
+            Self::Time(window) => window.total,
+        }
+    }
+
+    fn failure_count(&self) -> u32 {
+        match self {
+            Self::Count(window) => window.failure_count(),
+            Self::Time(window) => window.failures,
+        }
+    }
+
+    fn slow_count(&self) -> u32 {
+        match self {
+            Self::Count(window) => window.slow_count(),
+            Self::Time(window) => window.slow,
+        }
+    }
+
+    fn reset(&mut self) {
+        match self {
+            Self::Count(window) => window.reset(),
+            Self::Time(window) => window.reset(),
+        }
+    }
+}
+
 struct InnerState {
     state: State,
     failures: u32,
@@ -485,8 +1064,10 @@ struct InnerState {
     consecutive_opens: u32,
     /// Number of slow calls in the current window.
     slow_calls: u32,
-    /// Sliding window (used when `config.sliding_window_size > 0`).
-    window: Option<OutcomeWindow>,
+    /// Sliding window (used when `config.window_mode()` is set).
+    window: Option<SlidingWindow>,
+    /// Recent failures (used when `config.failure_window` is set).
+    failure_log: Option<FailureLog>,
 }
 
 impl CircuitBreaker {
@@ -497,7 +1078,10 @@ impl CircuitBreaker {
     /// Returns `Err(ConfigError)` if configuration is invalid.
     pub fn new(config: CircuitBreakerConfig) -> Result<Self, ConfigError> {
         config.validate()?;
-        let window_size = config.sliding_window_size;
+        let window = SlidingWindow::from_config(&config);
+        let failure_log = config
+            .failure_window
+            .map(|span| FailureLog::new(span, config.failure_threshold));
         Ok(Self {
             config,
             atomic_state: AtomicU32::new(STATE_CLOSED),
@@ -509,15 +1093,14 @@ impl CircuitBreaker {
                 half_open_successes: 0,
                 consecutive_opens: 0,
                 slow_calls: 0,
-                window: if window_size > 0 {
-                    Some(OutcomeWindow::new(window_size as usize))
-                } else {
-                    None
-                },
+                window,
+                failure_log,
             }),
             clock: Arc::new(SystemClock),
             sink: Arc::new(NoopSink),
             on_state_change: None,
+            subscribers: RwLock::new(Vec::new()),
+            store: None,
         })
     }
 
@@ -528,6 +1111,13 @@ impl CircuitBreaker {
         self
     }
 
+    /// Inject a shared metrics sink.
+    #[must_use]
+    pub fn with_shared_sink(mut self, sink: Arc<dyn MetricsSink>) -> Self {
+        self.sink = sink;
+        self
+    }
+
     /// Replace the clock (builder-style, for testing).
     #[must_use]
     pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
@@ -545,6 +1135,79 @@ impl CircuitBreaker {
         self
     }
 
+    /// Persist state transitions to `store` (builder-style).
+    ///
+    /// The breaker first hydrates from [`CircuitBreakerStore::load_state`],
+    /// with the same rules as [`restore`](Self::restore): an `Open` state
+    /// keeps rejecting for the rest of its reset timeout. After that, every
+    /// transition — including forced ones — is written through with
+    /// [`CircuitBreakerStore::save_state`]. Call [`with_clock`](Self::with_clock)
+    /// first when replacing the clock.
+    #[must_use]
+    pub fn with_store(mut self, store: Arc<dyn CircuitBreakerStore>) -> Self {
+        if let Some(snapshot) = store.load_state() {
+            self.apply_snapshot(snapshot);
+        }
+        self.store = Some(store);
+        self
+    }
+
+    /// Subscribe to state transitions on a live (possibly shared) breaker.
+    ///
+    /// Listeners fire for every transition — Closed→Open, Open→HalfOpen and
+    /// HalfOpen→Closed/Open, including forced ones — after the breaker's
+    /// internal lock is released, so a slow listener delays only the call that
+    /// caused the transition and may safely call back into the breaker.
+    pub fn subscribe<F>(&self, listener: F)
+    where
+        F: Fn(&StateTransitionEvent) + Send + Sync + 'static,
+    {
+        self.subscribers.write().push(Arc::new(listener));
+    }
+
+    /// Report a transition to the store, the sink, the callback and all
+    /// subscribers.
+    ///
+    /// Must be called after the state lock is dropped.
+    fn notify_transition(&self, from: CircuitState, to: CircuitState, failures: u32) {
+        if let Some(ref store) = self.store {
+            store.save_state(self.snapshot());
+        }
+        self.sink
+            .record(ResilienceEvent::CircuitStateChanged { from, to });
+        if let Some(ref cb) = self.on_state_change {
+            cb(from, to);
+        }
+        // Snapshot the list so listeners run without holding the subscriber lock.
+        let subscribers = self.subscribers.read().clone();
+        if subscribers.is_empty() {
+            return;
+        }
+        let event = StateTransitionEvent {
+            from,
+            to,
+            failures,
+            at: self.clock.now(),
+        };
+        for listener in &subscribers {
+            listener(&event);
+        }
+    }
+
+    /// Failures as reported by [`stats`](Self::stats): window count when a window is used,
+    /// then the failure log's count when a failure window is used.
+    fn failure_count(inner: &InnerState) -> u32 {
+        inner.window.as_ref().map_or_else(
+            || {
+                inner
+                    .failure_log
+                    .as_ref()
+                    .map_or(inner.failures, FailureLog::count)
+            },
+            SlidingWindow::failure_count,
+        )
+    }
+
     /// Classify an operation result with timing information.
     ///
     /// If `slow_call_threshold` is configured and `duration` exceeds it,
@@ -586,7 +1249,7 @@ impl CircuitBreaker {
 
     /// Return the current instant from the breaker clock.
     #[must_use]
-    pub(crate) fn clock_now(&self) -> std::time::Instant {
+    pub(crate) fn clock_now(&self) -> Instant {
         self.clock.now()
     }
 
@@ -601,15 +1264,10 @@ impl CircuitBreaker {
         inner.half_open_probes = 0;
         inner.half_open_successes = 0;
         self.atomic_state.store(STATE_OPEN, Ordering::Relaxed);
+        let failures = Self::failure_count(&inner);
         drop(inner);
         if prev != CircuitState::Open {
-            self.sink.record(ResilienceEvent::CircuitStateChanged {
-                from: prev,
-                to: CircuitState::Open,
-            });
-            if let Some(ref cb) = self.on_state_change {
-                cb(prev, CircuitState::Open);
-            }
+            self.notify_transition(prev, CircuitState::Open, failures);
         }
     }
 
@@ -621,13 +1279,22 @@ impl CircuitBreaker {
         self.atomic_state.store(STATE_CLOSED, Ordering::Relaxed);
         drop(inner);
         if prev != CircuitState::Closed {
-            self.sink.record(ResilienceEvent::CircuitStateChanged {
-                from: prev,
-                to: CircuitState::Closed,
-            });
-            if let Some(ref cb) = self.on_state_change {
-                cb(prev, CircuitState::Closed);
-            }
+            self.notify_transition(prev, CircuitState::Closed, 0);
+        }
+    }
+
+    /// Manually move the circuit to half-open, admitting up to
+    /// `max_half_open_operations` probe calls.
+    ///
+    /// Useful after a fix to let probes decide the next state instead of
+    /// waiting out the reset timeout. The probes' outcomes close or re-open
+    /// the circuit exactly as for an automatic half-open.
+    pub fn force_half_open(&self) {
+        let mut inner = self.state.lock();
+        let (prev, to) = self.enter_half_open(&mut inner);
+        drop(inner);
+        if prev != to {
+            self.notify_transition(prev, to, 0);
         }
     }
 
@@ -689,15 +1356,18 @@ impl CircuitBreaker {
     where
         Fut: Future<Output = Result<T, E>> + Send,
     {
-        self.try_acquire()?;
-        let mut guard = ProbeGuard::new(self);
-        let start = self.clock.now();
-        let result = f().await;
-        let duration = self.clock.now().duration_since(start);
-        let outcome = self.classify_outcome(result.is_ok(), duration);
-        guard.defuse();
-        self.record_outcome(outcome);
-        result.map_err(CallError::Operation)
+        self.traced(async {
+            self.try_acquire()?;
+            let mut guard = ProbeGuard::new(self);
+            let start = self.clock.now();
+            let result = f().await;
+            let duration = self.clock.now().duration_since(start);
+            let outcome = self.classify_outcome(result.is_ok(), duration);
+            guard.defuse();
+            self.record_outcome(outcome);
+            result.map_err(CallError::Operation)
+        })
+        .await
     }
 
     /// Execute a closure under the circuit breaker with a shared policy context.
@@ -748,20 +1418,23 @@ impl CircuitBreaker {
     where
         Fut: Future<Output = Result<T, E>> + Send,
     {
-        self.try_acquire()?;
-        let mut guard = ProbeGuard::new(self);
-        let start = self.clock.now();
-        let result = f().await;
-        let duration = self.clock.now().duration_since(start);
-
-        let outcome = match &result {
-            Ok(_) => self.classify_outcome(true, duration),
-            Err(e) => self.classify_error_outcome(classifier.classify(e), duration),
-        };
-
-        guard.defuse();
-        self.record_outcome(outcome);
-        result.map_err(CallError::Operation)
+        self.traced(async {
+            self.try_acquire()?;
+            let mut guard = ProbeGuard::new(self);
+            let start = self.clock.now();
+            let result = f().await;
+            let duration = self.clock.now().duration_since(start);
+
+            let outcome = match &result {
+                Ok(_) => self.classify_outcome(true, duration),
+                Err(e) => self.classify_error_outcome(classifier.classify(e), duration),
+            };
+
+            guard.defuse();
+            self.record_outcome(outcome);
+            result.map_err(CallError::Operation)
+        })
+        .await
     }
 
     /// Execute a closure under the circuit breaker with both error
@@ -795,27 +1468,41 @@ impl CircuitBreaker {
     where
         Fut: Future<Output = Result<T, E>> + Send,
     {
-        self.try_acquire()?;
-        let mut guard = ProbeGuard::new(self);
-        let start = self.clock.now();
-        let result = context
-            .run_result(async { f().await.map_err(CallError::Operation) })
-            .await;
-        let duration = self.clock.now().duration_since(start);
-
-        let outcome = match &result {
-            Ok(_) => self.classify_outcome(true, duration),
-            Err(CallError::Operation(error)) => classifier.map_or_else(
-                || self.classify_outcome(false, duration),
-                |classifier| self.classify_error_outcome(classifier.classify(error), duration),
-            ),
-            Err(CallError::Timeout(_)) => Outcome::Timeout,
-            Err(CallError::Cancelled { .. }) => Outcome::Cancelled,
-            Err(_) => self.classify_outcome(false, duration),
-        };
+        self.traced(async {
+            self.try_acquire()?;
+            let mut guard = ProbeGuard::new(self);
+            let start = self.clock.now();
+            let result = context
+                .run_result(async { f().await.map_err(CallError::Operation) })
+                .await;
+            let duration = self.clock.now().duration_since(start);
+
+            let outcome = match &result {
+                Ok(_) => self.classify_outcome(true, duration),
+                Err(CallError::Operation(error)) => classifier.map_or_else(
+                    || self.classify_outcome(false, duration),
+                    |classifier| self.classify_error_outcome(classifier.classify(error), duration),
+                ),
+                Err(CallError::Timeout(_)) => Outcome::Timeout,
+                Err(CallError::Cancelled { .. }) => Outcome::Cancelled,
+                Err(_) => self.classify_outcome(false, duration),
+            };
+
+            guard.defuse();
+            self.record_outcome(outcome);
+            result
+        })
+        .await
+    }
 
-        guard.defuse();
-        self.record_outcome(outcome);
+    /// Run one call inside a `resilience.circuit_breaker` span (`tracing` feature).
+    async fn traced<T, E>(
+        &self,
+        call: impl Future<Output = Result<T, CallError<E>>>,
+    ) -> Result<T, CallError<E>> {
+        let span = PatternSpan::circuit_breaker(self.circuit_state());
+        let result = span.run(call).await;
+        span.record_outcome(&result);
         result
     }
 
@@ -842,35 +1529,41 @@ impl CircuitBreaker {
                 let elapsed = self.clock.now().duration_since(opened_at);
                 let timeout = self.effective_reset_timeout(inner.consecutive_opens);
                 if elapsed >= timeout {
-                    let prev = to_circuit_state(inner.state);
-                    inner.state = State::HalfOpen;
-                    inner.failures = 0;
-                    inner.total = 0;
-                    inner.slow_calls = 0;
-                    inner.half_open_successes = 0;
+                    transition = Some(self.enter_half_open(&mut inner));
                     inner.half_open_probes = 1; // this call is the first probe
-                    if let Some(ref mut window) = inner.window {
-                        window.reset();
-                    }
-                    self.atomic_state.store(STATE_HALF_OPEN, Ordering::Relaxed);
-                    transition = Some((prev, CircuitState::HalfOpen));
                     Ok(())
                 } else {
                     Err(CallError::CircuitOpen)
                 }
             },
         };
+        let failures = transition.map_or(0, |_| Self::failure_count(&inner));
         drop(inner);
         if let Some((from, to)) = transition {
-            self.sink
-                .record(ResilienceEvent::CircuitStateChanged { from, to });
-            if let Some(ref cb) = self.on_state_change {
-                cb(from, to);
-            }
+            self.notify_transition(from, to, failures);
         }
         result
     }
 
+    /// Transition to `HalfOpen` with fresh counters and no probes admitted yet.
+    fn enter_half_open(&self, inner: &mut InnerState) -> (CircuitState, CircuitState) {
+        let prev = to_circuit_state(inner.state);
+        inner.state = State::HalfOpen;
+        inner.failures = 0;
+        inner.total = 0;
+        inner.slow_calls = 0;
+        inner.half_open_successes = 0;
+        inner.half_open_probes = 0;
+        if let Some(ref mut window) = inner.window {
+            window.reset();
+        }
+        if let Some(ref mut log) = inner.failure_log {
+            log.reset();
+        }
+        self.atomic_state.store(STATE_HALF_OPEN, Ordering::Relaxed);
+        (prev, CircuitState::HalfOpen)
+    }
+
     /// Whether the failure rate/count has exceeded the configured threshold.
     fn should_trip_on_failure(&self, inner: &InnerState) -> bool {
         if let (Some(window), Some(rate_threshold)) =
@@ -879,8 +1572,11 @@ impl CircuitBreaker {
             window.total() >= self.config.min_operations
                 && rate_exceeds(window.failure_count(), window.total(), rate_threshold)
         } else {
-            inner.failures >= self.config.failure_threshold
-                && inner.total >= self.config.min_operations
+            let failures = inner
+                .failure_log
+                .as_ref()
+                .map_or(inner.failures, FailureLog::count);
+            failures >= self.config.failure_threshold && inner.total >= self.config.min_operations
         }
     }
 
@@ -932,6 +1628,9 @@ impl CircuitBreaker {
         if let Some(ref mut window) = inner.window {
             window.reset();
         }
+        if let Some(ref mut log) = inner.failure_log {
+            log.reset();
+        }
     }
 
     /// Reset all counters and transition to `Closed` from the current state.
@@ -977,7 +1676,7 @@ impl CircuitBreaker {
                     inner.failures = inner.failures.saturating_sub(1);
                     inner.total = inner.total.saturating_add(1);
                     if let Some(ref mut window) = inner.window {
-                        window.record(false, false);
+                        window.record(false, false, &*self.clock);
                     }
                 }
             },
@@ -986,29 +1685,31 @@ impl CircuitBreaker {
                     // Don't count as failure, but still release the probe slot
                     // so half-open probes aren't permanently leaked.
                     inner.half_open_probes = inner.half_open_probes.saturating_sub(1);
+                } else if inner.state == State::HalfOpen {
+                    transition = Some(self.trip_open_from_half_open(&mut inner));
                 } else {
                     inner.failures = inner.failures.saturating_add(1);
                     inner.total = inner.total.saturating_add(1);
                     if let Some(ref mut window) = inner.window {
-                        window.record(true, false);
+                        window.record(true, false, &*self.clock);
                     }
-
-                    if inner.state == State::HalfOpen {
-                        transition = Some(self.trip_open_from_half_open(&mut inner));
-                    } else if self.should_trip_on_failure(&inner) {
+                    if let Some(ref mut log) = inner.failure_log {
+                        log.record(self.clock.now());
+                    }
+                    if self.should_trip_on_failure(&inner) {
                         transition = Some(self.trip_open(&mut inner));
                     }
                 }
             },
             Outcome::SlowSuccess => {
-                inner.slow_calls = inner.slow_calls.saturating_add(1);
-                inner.total = inner.total.saturating_add(1);
-                if let Some(ref mut window) = inner.window {
-                    window.record(false, true);
-                }
                 if inner.state == State::HalfOpen {
                     transition = self.record_half_open_success(&mut inner);
                 } else {
+                    inner.slow_calls = inner.slow_calls.saturating_add(1);
+                    inner.total = inner.total.saturating_add(1);
+                    if let Some(ref mut window) = inner.window {
+                        window.record(false, true, &*self.clock);
+                    }
                     inner.failures = inner.failures.saturating_sub(1);
                     if self.slow_rate_trips(&inner) {
                         transition = Some(self.trip_open(&mut inner));
@@ -1016,26 +1717,28 @@ impl CircuitBreaker {
                 }
             },
             Outcome::SlowFailure => {
-                inner.slow_calls = inner.slow_calls.saturating_add(1);
-                inner.failures = inner.failures.saturating_add(1);
-                inner.total = inner.total.saturating_add(1);
-                if let Some(ref mut window) = inner.window {
-                    window.record(true, true);
-                }
                 if inner.state == State::HalfOpen {
                     transition = Some(self.trip_open_from_half_open(&mut inner));
-                } else if self.should_trip_on_failure(&inner) || self.slow_rate_trips(&inner) {
-                    transition = Some(self.trip_open(&mut inner));
+                } else {
+                    inner.slow_calls = inner.slow_calls.saturating_add(1);
+                    inner.failures = inner.failures.saturating_add(1);
+                    inner.total = inner.total.saturating_add(1);
+                    if let Some(ref mut window) = inner.window {
+                        window.record(true, true, &*self.clock);
+                    }
+                    if let Some(ref mut log) = inner.failure_log {
+                        log.record(self.clock.now());
+                    }
+                    if self.should_trip_on_failure(&inner) || self.slow_rate_trips(&inner) {
+                        transition = Some(self.trip_open(&mut inner));
+                    }
                 }
             },
         }
+        let failures = transition.map_or(0, |_| Self::failure_count(&inner));
         drop(inner);
         if let Some((from, to)) = transition {
-            self.sink
-                .record(ResilienceEvent::CircuitStateChanged { from, to });
-            if let Some(ref cb) = self.on_state_change {
-                cb(from, to);
-            }
+            self.notify_transition(from, to, failures);
         }
     }
 
@@ -1050,10 +1753,17 @@ impl CircuitBreaker {
 
     /// Returns a stats snapshot.
     pub fn stats(&self) -> CircuitBreakerStats {
-        let inner = self.state.lock();
+        let mut inner = self.state.lock();
+        if let Some(ref mut window) = inner.window {
+            window.expire(&*self.clock);
+        }
+        if let Some(ref mut log) = inner.failure_log {
+            log.expire(self.clock.now());
+        }
         let state = to_circuit_state(inner.state);
+        // A failure log keeps no successes, so `total` stays cumulative there.
         let (failures, total, slow_calls) = inner.window.as_ref().map_or_else(
-            || (inner.failures, inner.total, inner.slow_calls),
+            || (Self::failure_count(&inner), inner.total, inner.slow_calls),
             |window| (window.failure_count(), window.total(), window.slow_count()),
         );
         drop(inner);
@@ -1062,7 +1772,83 @@ impl CircuitBreaker {
             failures,
             total,
             slow_calls,
+            window: self.config.window_mode(),
+        }
+    }
+
+    /// Capture the breaker state for persistence.
+    pub fn snapshot(&self) -> PersistentCircuitBreakerState {
+        let now = self.clock.now();
+        let wall_now = SystemTime::now();
+        let inner = self.state.lock();
+        let opened_at = match inner.state {
+            State::Open { opened_at } => {
+                let open_for = now.saturating_duration_since(opened_at);
+                Some(wall_now.checked_sub(open_for).unwrap_or(wall_now))
+            },
+            State::Closed | State::HalfOpen => None,
+        };
+        let snapshot = PersistentCircuitBreakerState {
+            state: to_circuit_state(inner.state),
+            failures: inner.failures,
+            total: inner.total,
+            consecutive_opens: inner.consecutive_opens,
+            opened_at,
+        };
+        drop(inner);
+        snapshot
+    }
+
+    /// Create a breaker that resumes from a persisted [`snapshot`](Self::snapshot).
+    ///
+    /// An `Open` snapshot keeps rejecting calls for the rest of its reset timeout.
+    /// If that timeout already elapsed while the process was down, the breaker
+    /// starts `Closed` with fresh counters. The restored breaker uses the system
+    /// clock; state changes during restore are not reported to sinks or callbacks.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ConfigError)` if configuration is invalid.
+    pub fn restore(
+        config: CircuitBreakerConfig,
+        snapshot: PersistentCircuitBreakerState,
+    ) -> Result<Self, ConfigError> {
+        let breaker = Self::new(config)?;
+        breaker.apply_snapshot(snapshot);
+        Ok(breaker)
+    }
+
+    /// Load `snapshot` into this breaker without reporting the change.
+    /// Shared by [`restore`](Self::restore) and [`with_store`](Self::with_store).
+    fn apply_snapshot(&self, snapshot: PersistentCircuitBreakerState) {
+        let mut inner = self.state.lock();
+        inner.failures = snapshot.failures;
+        inner.total = snapshot.total;
+        inner.consecutive_opens = snapshot.consecutive_opens;
+        match snapshot.state {
+            CircuitState::Open => {
+                // A timestamp in the future (clock skew) counts as "just opened".
+                let open_for = snapshot
+                    .opened_at
+                    .and_then(|at| SystemTime::now().duration_since(at).ok())
+                    .unwrap_or(Duration::ZERO);
+                if open_for >= self.effective_reset_timeout(snapshot.consecutive_opens) {
+                    Self::reset_counters(&mut inner);
+                } else {
+                    let now = self.clock.now();
+                    inner.state = State::Open {
+                        opened_at: now.checked_sub(open_for).unwrap_or(now),
+                    };
+                    self.atomic_state.store(STATE_OPEN, Ordering::Relaxed);
+                }
+            },
+            CircuitState::HalfOpen => {
+                inner.state = State::HalfOpen;
+                self.atomic_state.store(STATE_HALF_OPEN, Ordering::Relaxed);
+            },
+            CircuitState::Closed => {},
         }
+        drop(inner);
     }
 }
 
@@ -1156,7 +1942,9 @@ mod tests {
             slow_call_threshold: None,
             slow_call_rate_threshold: 1.0,
             sliding_window_size: 0,
+            sliding_window_duration: None,
             failure_rate_threshold: None,
+            failure_window: None,
         }
     }
 
@@ -1450,6 +2238,69 @@ mod tests {
         assert!(matches!(err, CallError::CircuitOpen));
     }
 
+    #[tokio::test]
+    async fn force_open_short_circuits_without_running_operation() {
+        let cb = CircuitBreaker::new(default_config()).unwrap();
+        let ran = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        cb.force_open();
+
+        let flag = Arc::clone(&ran);
+        let err = cb
+            .call::<(), &str, _>(|| {
+                flag.store(true, Ordering::SeqCst);
+                Box::pin(async { Ok(()) })
+            })
+            .await
+            .unwrap_err();
+        assert!(matches!(err, CallError::CircuitOpen));
+        assert!(!ran.load(Ordering::SeqCst));
+        assert_eq!(cb.stats().state, CS::Open);
+    }
+
+    #[tokio::test]
+    async fn force_open_persists_until_reset_timeout() {
+        use crate::clock::MockClock;
+        let clock = Arc::new(MockClock::new());
+        let cb = CircuitBreaker::new(default_config())
+            .unwrap()
+            .with_clock(Arc::clone(&clock) as Arc<dyn Clock>);
+        cb.force_open();
+
+        assert!(matches!(
+            cb.try_acquire::<()>(),
+            Err(CallError::CircuitOpen)
+        ));
+        clock.advance(default_config().reset_timeout);
+        assert!(cb.try_acquire::<()>().is_ok());
+        assert_eq!(cb.circuit_state(), CS::HalfOpen);
+    }
+
+    #[tokio::test]
+    async fn force_half_open_admits_probe_that_closes_circuit() {
+        let cb = CircuitBreaker::new(default_config()).unwrap();
+        let transitions = Arc::new(Mutex::new(Vec::new()));
+        let seen = Arc::clone(&transitions);
+        cb.subscribe(move |event| seen.lock().push((event.from, event.to)));
+
+        cb.force_open();
+        cb.force_half_open();
+        cb.force_half_open();
+        assert_eq!(cb.circuit_state(), CS::HalfOpen);
+        assert_eq!(cb.stats().failures, 0);
+
+        let result = cb.call::<u32, &str, _>(|| Box::pin(async { Ok(7) })).await;
+        assert_eq!(result.unwrap(), 7);
+        assert_eq!(cb.circuit_state(), CS::Closed);
+        assert_eq!(
+            *transitions.lock(),
+            vec![
+                (CS::Closed, CS::Open),
+                (CS::Open, CS::HalfOpen),
+                (CS::HalfOpen, CS::Closed),
+            ]
+        );
+    }
+
     #[tokio::test]
     async fn force_close_resets_circuit() {
         let cb = CircuitBreaker::new(default_config()).unwrap();
@@ -1486,6 +2337,88 @@ mod tests {
         drop(t);
     }
 
+    #[tokio::test]
+    async fn subscribers_receive_full_transition_cycle() {
+        let cb = Arc::new(CircuitBreaker::new(default_config()).unwrap());
+        let events = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = Arc::clone(&events);
+        let weak = Arc::downgrade(&cb);
+        cb.subscribe(move |event: &StateTransitionEvent| {
+            // Re-entering the breaker must not deadlock: listeners run outside the lock.
+            let state = weak.upgrade().map(|cb| cb.stats().state);
+            sink.lock()
+                .unwrap()
+                .push((event.from, event.to, event.failures, state));
+        });
+
+        for _ in 0..3 {
+            cb.record_outcome(Outcome::Failure);
+        }
+        tokio::time::sleep(Duration::from_millis(110)).await;
+        assert!(cb.try_acquire::<()>().is_ok());
+        cb.record_outcome(Outcome::Success);
+
+        let events = events.lock().unwrap().clone();
+        assert_eq!(
+            events,
+            vec![
+                (CS::Closed, CS::Open, 3, Some(CS::Open)),
+                (CS::Open, CS::HalfOpen, 0, Some(CS::HalfOpen)),
+                (CS::HalfOpen, CS::Closed, 0, Some(CS::Closed)),
+            ]
+        );
+    }
+
+    #[test]
+    fn subscribers_receive_from_to_pairs_including_forced() {
+        use crate::clock::MockClock;
+        let clock = Arc::new(MockClock::new());
+        let cb = CircuitBreaker::new(default_config())
+            .unwrap()
+            .with_clock(clock.clone());
+        let transitions = Arc::new(Mutex::new(Vec::new()));
+        let seen = Arc::clone(&transitions);
+        cb.subscribe(move |event: &StateTransitionEvent| {
+            seen.lock().push((event.from, event.to, event.failures));
+        });
+        cb.subscribe(log_state_transition);
+
+        for _ in 0..3 {
+            cb.record_outcome(Outcome::Failure);
+        }
+        clock.advance(Duration::from_millis(110));
+        assert!(cb.try_acquire::<()>().is_ok());
+        cb.record_outcome(Outcome::Failure);
+        cb.force_close();
+
+        assert_eq!(
+            *transitions.lock(),
+            vec![
+                (CS::Closed, CS::Open, 3),
+                (CS::Open, CS::HalfOpen, 0),
+                (CS::HalfOpen, CS::Open, 0),
+                (CS::Open, CS::Closed, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn multiple_subscribers_see_forced_transitions() {
+        let cb = CircuitBreaker::new(default_config()).unwrap();
+        let count = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        for _ in 0..2 {
+            let count = Arc::clone(&count);
+            cb.subscribe(move |_| {
+                count.fetch_add(1, Ordering::SeqCst);
+            });
+        }
+
+        cb.force_open();
+        cb.force_open();
+        cb.force_close();
+        assert_eq!(count.load(Ordering::SeqCst), 4);
+    }
+
     #[tokio::test]
     async fn dynamic_break_duration_increases_on_repeated_opens() {
         use crate::clock::MockClock;
@@ -1502,7 +2435,9 @@ mod tests {
             slow_call_threshold: None,
             slow_call_rate_threshold: 1.0,
             sliding_window_size: 0,
+            sliding_window_duration: None,
             failure_rate_threshold: None,
+            failure_window: None,
         })
         .unwrap()
         .with_clock(Arc::clone(&clock) as Arc<dyn Clock>);
@@ -1688,6 +2623,488 @@ mod tests {
         assert_eq!(stats.failures, 1);
     }
 
+    #[test]
+    fn rolling_window_trips_on_intermittent_failures() {
+        let consecutive = CircuitBreaker::new(default_config()).unwrap();
+        let windowed =
+            CircuitBreaker::new(default_config().with_rolling_window(4, 4, 0.5)).unwrap();
+
+        for _ in 0..4 {
+            for cb in [&consecutive, &windowed] {
+                cb.record_outcome(Outcome::Failure);
+                cb.record_outcome(Outcome::Success);
+            }
+        }
+
+        assert_eq!(consecutive.circuit_state(), CS::Closed);
+        assert_eq!(windowed.circuit_state(), CS::Open);
+    }
+
+    #[test]
+    fn rolling_window_waits_for_min_calls() {
+        let cb = CircuitBreaker::new(default_config().with_rolling_window(10, 5, 0.5)).unwrap();
+
+        for _ in 0..4 {
+            cb.record_outcome(Outcome::Failure);
+        }
+        assert_eq!(cb.circuit_state(), CS::Closed);
+
+        cb.record_outcome(Outcome::Failure);
+        assert_eq!(cb.circuit_state(), CS::Open);
+    }
+
+    #[test]
+    fn rolling_window_rejects_invalid_ratio() {
+        let result = CircuitBreaker::new(default_config().with_rolling_window(10, 5, 2.0));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn failure_rate_mode_uses_default_window_and_min_operations() {
+        let config = default_config()
+            .with_failure_rate(0.5)
+            .with_min_operations(10);
+        assert_eq!(
+            config.sliding_window_size,
+            CircuitBreakerConfig::DEFAULT_FAILURE_RATE_WINDOW
+        );
+        let cb = CircuitBreaker::new(config).unwrap();
+
+        // 40% failures over 10 calls: below the rate even though the count threshold is passed.
+        for i in 0..10 {
+            cb.record_outcome(if i % 5 < 2 {
+                Outcome::Failure
+            } else {
+                Outcome::Success
+            });
+        }
+        assert_eq!(cb.circuit_state(), CS::Closed);
+        let stats = cb.stats();
+        assert!((stats.failure_rate().unwrap() - 0.4).abs() < f64::EPSILON);
+
+        for _ in 0..2 {
+            cb.record_outcome(Outcome::Failure);
+        }
+        assert_eq!(cb.circuit_state(), CS::Open);
+    }
+
+    #[test]
+    fn failure_rate_keeps_configured_window() {
+        let config = CircuitBreakerConfig {
+            sliding_window_size: 8,
+            ..default_config()
+        }
+        .with_failure_rate(0.5);
+        assert_eq!(config.sliding_window_size, 8);
+    }
+
+    #[test]
+    fn stats_failure_rate_is_zero_without_samples() {
+        let cb = CircuitBreaker::new(default_config().with_failure_rate(0.5)).unwrap();
+        assert_eq!(cb.stats().failure_rate(), Some(0.0));
+    }
+
+    #[test]
+    fn with_window_replaces_previous_mode() {
+        let config = default_config()
+            .with_window(WindowMode::Time(Duration::from_secs(10)))
+            .with_failure_rate(0.5);
+        assert_eq!(config.sliding_window_size, 0);
+        assert_eq!(
+            config.window_mode(),
+            Some(WindowMode::Time(Duration::from_secs(10)))
+        );
+
+        let config = config.with_window(WindowMode::Count(20));
+        assert_eq!(config.sliding_window_duration, None);
+        assert_eq!(config.window_mode(), Some(WindowMode::Count(20)));
+        assert_eq!(default_config().window_mode(), None);
+    }
+
+    #[test]
+    fn invalid_time_window_rejected() {
+        let zero = default_config().with_window(WindowMode::Time(Duration::ZERO));
+        assert!(CircuitBreaker::new(zero).is_err());
+
+        let both = CircuitBreakerConfig {
+            sliding_window_size: 10,
+            sliding_window_duration: Some(Duration::from_secs(1)),
+            ..default_config()
+        };
+        assert!(CircuitBreaker::new(both).is_err());
+    }
+
+    #[test]
+    fn time_window_trips_on_failure_ratio() {
+        use crate::clock::MockClock;
+        let clock = Arc::new(MockClock::new());
+        let cb = CircuitBreaker::new(
+            default_config()
+                .with_window(WindowMode::Time(Duration::from_secs(10)))
+                .with_failure_rate(0.5)
+                .with_min_operations(4),
+        )
+        .unwrap()
+        .with_clock(clock);
+
+        cb.record_outcome(Outcome::Failure);
+        cb.record_outcome(Outcome::Success);
+        cb.record_outcome(Outcome::Success);
+        assert_eq!(cb.circuit_state(), CS::Closed);
+
+        cb.record_outcome(Outcome::Failure);
+        assert_eq!(cb.circuit_state(), CS::Open);
+    }
+
+    #[test]
+    fn time_window_forgets_expired_outcomes() {
+        use crate::clock::MockClock;
+        let clock = Arc::new(MockClock::new());
+        let cb = CircuitBreaker::new(
+            default_config()
+                .with_window(WindowMode::Time(Duration::from_secs(10)))
+                .with_failure_rate(0.5)
+                .with_min_operations(4),
+        )
+        .unwrap()
+        .with_clock(clock.clone());
+
+        cb.record_outcome(Outcome::Failure);
+        cb.record_outcome(Outcome::Failure);
+        clock.advance(Duration::from_secs(6));
+        cb.record_outcome(Outcome::Success);
+        let stats = cb.stats();
+        assert_eq!((stats.successes(), stats.failures), (Some(1), 2));
+
+        // The two failures age out; the success is still inside the window.
+        clock.advance(Duration::from_secs(5));
+        let stats = cb.stats();
+        assert_eq!((stats.successes(), stats.failures), (Some(1), 0));
+
+        cb.record_outcome(Outcome::Failure);
+        cb.record_outcome(Outcome::Success);
+        cb.record_outcome(Outcome::Success);
+        assert_eq!(cb.circuit_state(), CS::Closed);
+        let stats = cb.stats();
+        assert_eq!(
+            (stats.total, stats.successes(), stats.failures),
+            (4, Some(3), 1)
+        );
+    }
+
+    #[test]
+    fn failure_window_ages_out_old_failures() {
+        use crate::clock::MockClock;
+        let clock = Arc::new(MockClock::new());
+        let cb = CircuitBreaker::new(
+            default_config().with_window(WindowMode::RecentFailures(Duration::from_secs(10))),
+        )
+        .unwrap()
+        .with_clock(clock.clone());
+
+        cb.record_outcome(Outcome::Failure);
+        cb.record_outcome(Outcome::Failure);
+        assert_eq!(cb.stats().failures, 2);
+
+        // The burst ages out; two fresh failures are below the threshold of 3.
+        clock.advance(Duration::from_secs(11));
+        let stats = cb.stats();
+        assert_eq!((stats.failures, stats.total), (0, 2));
+        // The failures and the cumulative total cover different spans.
+        assert_eq!((stats.successes(), stats.failure_rate()), (None, None));
+        cb.record_outcome(Outcome::Failure);
+        cb.record_outcome(Outcome::Failure);
+        assert_eq!(cb.circuit_state(), CS::Closed);
+
+        cb.record_outcome(Outcome::Failure);
+        assert_eq!(cb.circuit_state(), CS::Open);
+    }
+
+    #[test]
+    fn failure_window_ignores_success_forgiveness() {
+        use crate::clock::MockClock;
+        let clock = Arc::new(MockClock::new());
+        let cb = CircuitBreaker::new(
+            default_config().with_window(WindowMode::RecentFailures(Duration::from_secs(10))),
+        )
+        .unwrap()
+        .with_clock(clock);
+
+        for _ in 0..3 {
+            cb.record_outcome(Outcome::Failure);
+            cb.record_outcome(Outcome::Success);
+        }
+        assert_eq!(cb.circuit_state(), CS::Open);
+    }
+
+    #[test]
+    fn failure_log_wraps_without_panicking() {
+        use crate::clock::MockClock;
+        let clock = Arc::new(MockClock::new());
+        let cb = CircuitBreaker::new(CircuitBreakerConfig {
+            failure_threshold: 1_000,
+            ..default_config().with_window(WindowMode::RecentFailures(Duration::from_millis(50)))
+        })
+        .unwrap()
+        .with_clock(clock.clone());
+
+        // Many times the ring capacity; spacing keeps at most ~5 in the window.
+        for _ in 0..10_000 {
+            cb.record_outcome(Outcome::Failure);
+            clock.advance(Duration::from_millis(10));
+        }
+        assert_eq!(cb.circuit_state(), CS::Closed);
+        assert!(cb.stats().failures <= 5);
+
+        let mut log = FailureLog::new(Duration::from_mins(1), 3);
+        let start = clock.now();
+        for i in 0..10 {
+            log.record(start + Duration::from_millis(i));
+        }
+        assert_eq!(log.count(), 3);
+        assert_eq!(
+            log.failures.front(),
+            Some(&(start + Duration::from_millis(7)))
+        );
+    }
+
+    #[test]
+    fn zero_failure_window_rejected() {
+        let config = default_config().with_window(WindowMode::RecentFailures(Duration::ZERO));
+        assert!(CircuitBreaker::new(config).is_err());
+    }
+
+    #[test]
+    fn window_builders_resolve_to_one_mode_in_any_order() {
+        let recent = WindowMode::RecentFailures(Duration::from_secs(10));
+
+        // A failure rate needs a rate window, so it replaces `RecentFailures`...
+        let config = default_config().with_window(recent).with_failure_rate(0.5);
+        assert_eq!(
+            config.window_mode(),
+            Some(WindowMode::Count(
+                CircuitBreakerConfig::DEFAULT_FAILURE_RATE_WINDOW
+            ))
+        );
+        assert_eq!(config.failure_window, None);
+        assert!(config.validate().is_ok());
+
+        // ...and `RecentFailures` clears a failure rate set before it.
+        let config = default_config().with_failure_rate(0.5).with_window(recent);
+        assert_eq!(config.window_mode(), Some(recent));
+        assert_eq!(config.failure_rate_threshold, None);
+        assert!(config.validate().is_ok());
+
+        // The rolling-window shorthand replaces a time window instead of adding to it.
+        let config = default_config()
+            .with_window(WindowMode::Time(Duration::from_secs(10)))
+            .with_rolling_window(20, 10, 0.5);
+        assert_eq!(config.window_mode(), Some(WindowMode::Count(20)));
+        assert_eq!(config.min_operations, 10);
+        assert!(config.validate().is_ok());
+
+        // Field-level configs that mix modes are rejected rather than resolved silently.
+        for mixed in [
+            CircuitBreakerConfig {
+                sliding_window_size: 10,
+                failure_window: Some(Duration::from_secs(10)),
+                ..default_config()
+            },
+            CircuitBreakerConfig {
+                failure_rate_threshold: Some(0.5),
+                failure_window: Some(Duration::from_secs(10)),
+                ..default_config()
+            },
+        ] {
+            assert_eq!(mixed.validate().unwrap_err().field, "failure_window");
+        }
+    }
+
+    #[tokio::test]
+    async fn failure_rate_window_resets_through_recovery_cycle() {
+        let cb = CircuitBreaker::new(
+            CircuitBreakerConfig {
+                max_half_open_operations: 2,
+                half_open_success_threshold: Some(2),
+                ..default_config()
+            }
+            .with_failure_rate(0.5)
+            .with_min_operations(4),
+        )
+        .unwrap();
+
+        for _ in 0..4 {
+            cb.record_outcome(Outcome::Failure);
+        }
+        assert_eq!(cb.circuit_state(), CS::Open);
+
+        tokio::time::sleep(Duration::from_millis(110)).await;
+        assert!(cb.try_acquire::<&str>().is_ok());
+        assert_eq!(cb.circuit_state(), CS::HalfOpen);
+        assert_eq!(cb.stats().total, 0);
+
+        // Probes are judged by the half-open threshold, not the closed-state window.
+        cb.record_outcome(Outcome::SlowSuccess);
+        assert_eq!(cb.stats().total, 0);
+        assert!(cb.try_acquire::<&str>().is_ok());
+        cb.record_outcome(Outcome::Success);
+        assert_eq!(cb.circuit_state(), CS::Closed);
+
+        let stats = cb.stats();
+        assert_eq!(stats.total, 0);
+        assert_eq!(stats.failure_rate(), Some(0.0));
+
+        // A fresh window needs min_operations again before tripping.
+        for _ in 0..3 {
+            cb.record_outcome(Outcome::Failure);
+        }
+        assert_eq!(cb.circuit_state(), CS::Closed);
+    }
+
+    #[test]
+    fn snapshot_restore_keeps_counters() {
+        let cb = CircuitBreaker::new(default_config()).unwrap();
+        cb.record_outcome(Outcome::Failure);
+        cb.record_outcome(Outcome::Failure);
+
+        let snapshot = cb.snapshot();
+        assert_eq!(snapshot.state, CS::Closed);
+        assert_eq!(snapshot.failures, 2);
+        assert_eq!(snapshot.opened_at, None);
+
+        let restored = CircuitBreaker::restore(default_config(), snapshot).unwrap();
+        assert_eq!(restored.snapshot(), snapshot);
+        restored.record_outcome(Outcome::Failure);
+        assert_eq!(restored.circuit_state(), CS::Open);
+    }
+
+    #[test]
+    fn restore_open_keeps_rejecting_until_reset_timeout() {
+        let config = CircuitBreakerConfig {
+            reset_timeout: Duration::from_mins(1),
+            ..default_config()
+        };
+        let cb = CircuitBreaker::new(config.clone()).unwrap();
+        cb.force_open();
+        let snapshot = cb.snapshot();
+        assert_eq!(snapshot.state, CS::Open);
+        assert!(snapshot.opened_at.is_some());
+
+        let restored = CircuitBreaker::restore(config, snapshot).unwrap();
+        assert_eq!(restored.circuit_state(), CS::Open);
+        assert!(matches!(
+            restored.try_acquire::<()>(),
+            Err(CallError::CircuitOpen)
+        ));
+    }
+
+    /// Store that counts saves, standing in for an external backend.
+    #[derive(Default)]
+    struct RecordingStore {
+        saved: Mutex<Vec<PersistentCircuitBreakerState>>,
+    }
+
+    impl CircuitBreakerStore for RecordingStore {
+        fn load_state(&self) -> Option<PersistentCircuitBreakerState> {
+            self.saved.lock().last().copied()
+        }
+
+        fn save_state(&self, state: PersistentCircuitBreakerState) {
+            self.saved.lock().push(state);
+        }
+    }
+
+    #[test]
+    fn store_writes_through_transitions_and_survives_restart() {
+        let config = CircuitBreakerConfig {
+            reset_timeout: Duration::from_mins(1),
+            ..default_config()
+        };
+        let store = Arc::new(RecordingStore::default());
+        let cb = CircuitBreaker::new(config.clone())
+            .unwrap()
+            .with_store(store.clone());
+        assert_eq!(cb.circuit_state(), CS::Closed);
+        for _ in 0..3 {
+            cb.record_outcome(Outcome::Failure);
+        }
+        assert_eq!(cb.circuit_state(), CS::Open);
+        assert_eq!(store.saved.lock().len(), 1);
+        drop(cb);
+
+        // "Restart": a fresh breaker over the same store starts open.
+        let restarted = CircuitBreaker::new(config)
+            .unwrap()
+            .with_store(store.clone());
+        assert_eq!(restarted.circuit_state(), CS::Open);
+        assert!(matches!(
+            restarted.try_acquire::<()>(),
+            Err(CallError::CircuitOpen)
+        ));
+
+        restarted.force_close();
+        let last = store.load_state().unwrap();
+        assert_eq!((last.state, last.opened_at), (CS::Closed, None));
+    }
+
+    #[test]
+    fn in_memory_store_starts_empty_and_keeps_last_state() {
+        let store = Arc::new(InMemoryCircuitBreakerStore::new());
+        assert_eq!(store.load_state(), None);
+
+        let cb = CircuitBreaker::new(default_config())
+            .unwrap()
+            .with_store(store.clone());
+        cb.force_open();
+        cb.force_half_open();
+        assert_eq!(store.load_state().map(|s| s.state), Some(CS::HalfOpen));
+    }
+
+    #[test]
+    fn restore_open_after_reset_timeout_starts_closed() {
+        let snapshot = PersistentCircuitBreakerState {
+            state: CS::Open,
+            failures: 3,
+            total: 3,
+            consecutive_opens: 1,
+            opened_at: SystemTime::now().checked_sub(Duration::from_secs(1)),
+        };
+
+        let restored = CircuitBreaker::restore(default_config(), snapshot).unwrap();
+        assert_eq!(restored.circuit_state(), CS::Closed);
+        assert_eq!(restored.stats().failures, 0);
+        assert!(restored.try_acquire::<()>().is_ok());
+    }
+
+    #[test]
+    fn restore_half_open_admits_probe() {
+        let snapshot = PersistentCircuitBreakerState {
+            state: CS::HalfOpen,
+            failures: 0,
+            total: 0,
+            consecutive_opens: 1,
+            opened_at: None,
+        };
+
+        let restored = CircuitBreaker::restore(default_config(), snapshot).unwrap();
+        assert_eq!(restored.circuit_state(), CS::HalfOpen);
+        assert!(restored.try_acquire::<()>().is_ok());
+        restored.record_outcome(Outcome::Success);
+        assert_eq!(restored.circuit_state(), CS::Closed);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn snapshot_round_trips_through_serde() {
+        let cb = CircuitBreaker::new(default_config()).unwrap();
+        cb.force_open();
+        let snapshot = cb.snapshot();
+
+        let json = serde_json::to_string(&snapshot).unwrap();
+        let decoded: PersistentCircuitBreakerState = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, snapshot);
+    }
+
     // ── C1: min_operations validation ────────────────────────────────────
 
     #[test]
@@ -31,6 +31,35 @@ impl Deadline {
         Self { start, budget }
     }
 
+    /// Create a deadline that expires at an absolute instant.
+    ///
+    /// An instant in the past yields an already-expired deadline.
+    #[must_use]
+    pub fn until(expires_at: Instant) -> Self {
+        let now = Instant::now();
+        Self::from_start(now, expires_at.saturating_duration_since(now))
+    }
+
+    /// The instant at which the deadline expires, or `None` if the budget is
+    /// too large to represent.
+    #[must_use]
+    pub fn expires_at(self) -> Option<Instant> {
+        self.start.checked_add(self.budget)
+    }
+
+    /// Whichever of two optional deadlines expires first.
+    #[must_use]
+    pub fn earliest(a: Option<Self>, b: Option<Self>) -> Option<Self> {
+        match (a, b) {
+            (Some(a), Some(b)) => match (a.expires_at(), b.expires_at()) {
+                (Some(a_at), Some(b_at)) if b_at < a_at => Some(b),
+                (None, Some(_)) => Some(b),
+                _ => Some(a),
+            },
+            (a, b) => a.or(b),
+        }
+    }
+
     /// Total configured budget.
     #[must_use]
     pub const fn budget(self) -> Duration {
@@ -117,6 +146,24 @@ impl Deadline {
 mod tests {
     use super::*;
 
+    #[test]
+    fn earliest_picks_first_expiry() {
+        let now = Instant::now();
+        let short = Deadline::from_start(now, Duration::from_secs(1));
+        let long = Deadline::from_start(now, Duration::from_secs(10));
+        assert_eq!(Deadline::earliest(Some(long), Some(short)), Some(short));
+        assert_eq!(Deadline::earliest(Some(short), Some(long)), Some(short));
+        assert_eq!(Deadline::earliest(None, Some(long)), Some(long));
+        assert_eq!(Deadline::earliest(None, None), None);
+    }
+
+    #[test]
+    fn until_past_instant_is_expired() {
+        let past = Instant::now();
+        std::thread::sleep(Duration::from_millis(1));
+        assert!(Deadline::until(past).remaining_or_timeout::<()>().is_err());
+    }
+
     #[tokio::test]
     async fn sleep_rejects_delay_past_deadline() {
         let deadline = Deadline::after(Duration::from_millis(1));
@@ -31,6 +31,11 @@ pub enum CallError<E> {
     CircuitOpen,
     /// Bulkhead is at capacity — request rejected.
     BulkheadFull,
+    /// Waited the bulkhead's configured maximum for a permit without getting one.
+    ///
+    /// Distinct from [`Timeout`](Self::Timeout) so a saturated bulkhead can be
+    /// told apart from a slow operation.
+    BulkheadTimeout(Duration),
     /// Timeout elapsed before the operation completed.
     Timeout(Duration),
     /// All retry attempts exhausted; contains the last operation error.
@@ -40,6 +45,17 @@ pub enum CallError<E> {
         /// Last error returned by the operation.
         last: E,
     },
+    /// The retry [total budget](crate::retry::RetryConfig::total_budget) or
+    /// [shared budget](crate::retry::RetryConfig::shared_budget) ran out before
+    /// another attempt could start; contains the last operation error.
+    BudgetExhausted {
+        /// Total number of attempts made.
+        attempts: u32,
+        /// Time spent across all attempts and backoff sleeps.
+        elapsed: Duration,
+        /// Last error returned by the operation.
+        last: E,
+    },
     /// Operation was cancelled via `CancellationContext`.
     Cancelled {
         /// Optional human-readable reason for cancellation.
@@ -78,10 +94,19 @@ impl<E: std::fmt::Display> std::fmt::Display for CallError<E> {
             Self::Operation(e) => write!(f, "operation error: {e}"),
             Self::CircuitOpen => write!(f, "circuit breaker is open"),
             Self::BulkheadFull => write!(f, "bulkhead is at capacity"),
+            Self::BulkheadTimeout(d) => write!(f, "no bulkhead permit within {d:?}"),
             Self::Timeout(d) => write!(f, "operation timed out after {d:?}"),
             Self::RetriesExhausted { attempts, last } => {
                 write!(f, "operation failed after {attempts} attempt(s): {last}")
             },
+            Self::BudgetExhausted {
+                attempts,
+                elapsed,
+                last,
+            } => write!(
+                f,
+                "retry budget exhausted after {attempts} attempt(s) in {elapsed:?}: {last}"
+            ),
             Self::Cancelled { reason: Some(r) } => write!(f, "operation cancelled: {r}"),
             Self::Cancelled { reason: None } => write!(f, "operation cancelled"),
             Self::LoadShed => write!(f, "request load-shed due to overload"),
@@ -103,7 +128,7 @@ impl<E: std::error::Error + 'static> std::error::Error for CallError<E> {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
             Self::Operation(e) => Some(e),
-            Self::RetriesExhausted { last, .. } => Some(last),
+            Self::RetriesExhausted { last, .. } | Self::BudgetExhausted { last, .. } => Some(last),
             Self::FallbackFailedWithContext { fallback, .. } => Some(fallback.as_ref()),
             _ => None,
         }
@@ -171,8 +196,8 @@ impl<E> CallError<E> {
 
     /// Returns true if the error class suggests a retry might succeed.
     ///
-    /// `Timeout`, `RateLimited`, and `BulkheadFull` are considered retryable because
-    /// they represent transient resource pressure, not permanent failures.
+    /// `Timeout`, `RateLimited`, `BulkheadFull`, and `BulkheadTimeout` are considered
+    /// retryable because they represent transient resource pressure, not permanent failures.
     ///
     /// `Operation` is never automatically retryable — classification is delegated
     /// to the inner error's [`Classify`](nebula_error::Classify) implementation.
@@ -180,7 +205,10 @@ impl<E> CallError<E> {
     pub const fn is_retryable(&self) -> bool {
         matches!(
             self,
-            Self::Timeout(_) | Self::RateLimited { .. } | Self::BulkheadFull
+            Self::Timeout(_)
+                | Self::RateLimited { .. }
+                | Self::BulkheadFull
+                | Self::BulkheadTimeout(_)
         )
     }
 
@@ -190,21 +218,26 @@ impl<E> CallError<E> {
         matches!(self, Self::Cancelled { .. })
     }
 
-    /// Extract the inner operation error, if this is an `Operation` or `RetriesExhausted` variant.
+    /// Extract the inner operation error, if this is an `Operation`, `RetriesExhausted` or
+    /// `BudgetExhausted` variant.
     #[must_use]
     pub fn into_operation(self) -> Option<E> {
         match self {
-            Self::Operation(e) | Self::RetriesExhausted { last: e, .. } => Some(e),
+            Self::Operation(e)
+            | Self::RetriesExhausted { last: e, .. }
+            | Self::BudgetExhausted { last: e, .. } => Some(e),
             _ => None,
         }
     }
 
-    /// Reference to the inner operation error, if this is an `Operation` or `RetriesExhausted`
-    /// variant.
+    /// Reference to the inner operation error, if this is an `Operation`, `RetriesExhausted`
+    /// or `BudgetExhausted` variant.
     #[must_use]
     pub const fn operation(&self) -> Option<&E> {
         match self {
-            Self::Operation(e) | Self::RetriesExhausted { last: e, .. } => Some(e),
+            Self::Operation(e)
+            | Self::RetriesExhausted { last: e, .. }
+            | Self::BudgetExhausted { last: e, .. } => Some(e),
             _ => None,
         }
     }
@@ -227,8 +260,18 @@ impl<E> CallError<E> {
                 attempts,
                 last: f(last),
             },
+            Self::BudgetExhausted {
+                attempts,
+                elapsed,
+                last,
+            } => CallError::BudgetExhausted {
+                attempts,
+                elapsed,
+                last: f(last),
+            },
             Self::CircuitOpen => CallError::CircuitOpen,
             Self::BulkheadFull => CallError::BulkheadFull,
+            Self::BulkheadTimeout(d) => CallError::BulkheadTimeout(d),
             Self::Timeout(d) => CallError::Timeout(d),
             Self::Cancelled { reason } => CallError::Cancelled { reason },
             Self::LoadShed => CallError::LoadShed,
@@ -246,6 +289,9 @@ impl<E> CallError<E> {
     /// Transform the inner error with separate handlers for `Operation` and
     /// `RetriesExhausted`. All other (fieldless) variants pass through unchanged.
     ///
+    /// `BudgetExhausted` goes through `on_retries`; if that returns
+    /// `RetriesExhausted`, the result is turned back into `BudgetExhausted`.
+    ///
     /// Unlike [`map_operation`](Self::map_operation), the handlers return
     /// `CallError<E2>` directly, allowing variant changes (e.g., converting
     /// `Operation(())` into `Cancelled`).
@@ -269,8 +315,21 @@ impl<E> CallError<E> {
         match self {
             Self::Operation(e) => on_operation(e),
             Self::RetriesExhausted { attempts, last } => on_retries(attempts, last),
+            Self::BudgetExhausted {
+                attempts,
+                elapsed,
+                last,
+            } => match on_retries(attempts, last) {
+                CallError::RetriesExhausted { attempts, last } => CallError::BudgetExhausted {
+                    attempts,
+                    elapsed,
+                    last,
+                },
+                other => other,
+            },
             Self::CircuitOpen => CallError::CircuitOpen,
             Self::BulkheadFull => CallError::BulkheadFull,
+            Self::BulkheadTimeout(d) => CallError::BulkheadTimeout(d),
             Self::Timeout(d) => CallError::Timeout(d),
             Self::Cancelled { reason } => CallError::Cancelled { reason },
             Self::LoadShed => CallError::LoadShed,
@@ -310,8 +369,28 @@ impl<E> CallError<E> {
                 CallError::RetriesExhausted { attempts, last: () },
                 Self::RetriesExhausted { attempts, last },
             ),
+            Self::BudgetExhausted {
+                attempts,
+                elapsed,
+                last,
+            } => (
+                CallError::BudgetExhausted {
+                    attempts,
+                    elapsed,
+                    last: (),
+                },
+                Self::BudgetExhausted {
+                    attempts,
+                    elapsed,
+                    last,
+                },
+            ),
             Self::CircuitOpen => (CallError::CircuitOpen, Self::CircuitOpen),
             Self::BulkheadFull => (CallError::BulkheadFull, Self::BulkheadFull),
+            Self::BulkheadTimeout(duration) => (
+                CallError::BulkheadTimeout(duration),
+                Self::BulkheadTimeout(duration),
+            ),
             Self::Timeout(duration) => (CallError::Timeout(duration), Self::Timeout(duration)),
             Self::Cancelled { reason } => (
                 CallError::Cancelled {
@@ -343,11 +422,13 @@ impl<E> CallError<E> {
 impl<E: nebula_error::Classify> nebula_error::Classify for CallError<E> {
     fn category(&self) -> nebula_error::ErrorCategory {
         match self {
-            Self::Operation(e) | Self::RetriesExhausted { last: e, .. } => e.category(),
+            Self::Operation(e)
+            | Self::RetriesExhausted { last: e, .. }
+            | Self::BudgetExhausted { last: e, .. } => e.category(),
             Self::CircuitOpen | Self::LoadShed | Self::BulkheadFull => {
                 nebula_error::ErrorCategory::Exhausted
             },
-            Self::Timeout(_) => nebula_error::ErrorCategory::Timeout,
+            Self::Timeout(_) | Self::BulkheadTimeout(_) => nebula_error::ErrorCategory::Timeout,
             Self::Cancelled { .. } => nebula_error::ErrorCategory::Cancelled,
             Self::RateLimited { .. } => nebula_error::ErrorCategory::RateLimit,
             Self::FallbackFailed { .. } | Self::FallbackFailedWithContext { .. } => {
@@ -358,9 +439,12 @@ impl<E: nebula_error::Classify> nebula_error::Classify for CallError<E> {
 
     fn code(&self) -> nebula_error::ErrorCode {
         match self {
-            Self::Operation(e) | Self::RetriesExhausted { last: e, .. } => e.code(),
+            Self::Operation(e)
+            | Self::RetriesExhausted { last: e, .. }
+            | Self::BudgetExhausted { last: e, .. } => e.code(),
             Self::CircuitOpen => nebula_error::ErrorCode::new("RESILIENCE:CIRCUIT_OPEN"),
             Self::BulkheadFull => nebula_error::ErrorCode::new("RESILIENCE:BULKHEAD_FULL"),
+            Self::BulkheadTimeout(_) => nebula_error::ErrorCode::new("RESILIENCE:BULKHEAD_TIMEOUT"),
             Self::Timeout(_) => nebula_error::ErrorCode::new("RESILIENCE:TIMEOUT"),
             Self::Cancelled { .. } => nebula_error::ErrorCode::new("RESILIENCE:CANCELLED"),
             Self::LoadShed => nebula_error::ErrorCode::new("RESILIENCE:LOAD_SHED"),
@@ -374,7 +458,10 @@ impl<E: nebula_error::Classify> nebula_error::Classify for CallError<E> {
     fn is_retryable(&self) -> bool {
         matches!(
             self,
-            Self::Timeout(_) | Self::RateLimited { .. } | Self::BulkheadFull
+            Self::Timeout(_)
+                | Self::RateLimited { .. }
+                | Self::BulkheadFull
+                | Self::BulkheadTimeout(_)
         )
     }
 
@@ -426,10 +513,14 @@ pub enum CallErrorKind {
     CircuitOpen,
     /// [`CallError::BulkheadFull`]
     BulkheadFull,
+    /// [`CallError::BulkheadTimeout`]
+    BulkheadTimeout,
     /// [`CallError::Timeout`]
     Timeout,
     /// [`CallError::RetriesExhausted`]
     RetriesExhausted,
+    /// [`CallError::BudgetExhausted`]
+    BudgetExhausted,
     /// [`CallError::Cancelled`]
     Cancelled,
     /// [`CallError::LoadShed`]
@@ -448,8 +539,10 @@ impl<E> CallError<E> {
             Self::Operation(_) => CallErrorKind::Operation,
             Self::CircuitOpen => CallErrorKind::CircuitOpen,
             Self::BulkheadFull => CallErrorKind::BulkheadFull,
+            Self::BulkheadTimeout(_) => CallErrorKind::BulkheadTimeout,
             Self::Timeout(_) => CallErrorKind::Timeout,
             Self::RetriesExhausted { .. } => CallErrorKind::RetriesExhausted,
+            Self::BudgetExhausted { .. } => CallErrorKind::BudgetExhausted,
             Self::Cancelled { .. } => CallErrorKind::Cancelled,
             Self::LoadShed => CallErrorKind::LoadShed,
             Self::RateLimited { .. } => CallErrorKind::RateLimited,
@@ -520,6 +613,14 @@ mod tests {
         assert!(e.is_retryable());
     }
 
+    #[test]
+    fn bulkhead_timeout_is_retryable_and_distinct_from_timeout() {
+        let e: CallError<MyErr> = CallError::BulkheadTimeout(Duration::from_millis(5));
+        assert!(e.is_retryable());
+        assert_eq!(e.kind(), CallErrorKind::BulkheadTimeout);
+        assert_ne!(e.kind(), CallErrorKind::Timeout);
+    }
+
     #[test]
     fn cancelled_is_not_retryable() {
         let e: CallError<MyErr> = CallError::cancelled_with("shutdown");
@@ -564,6 +665,15 @@ mod tests {
             CallError::Operation(MyErr::Timeout).kind(),
             CallErrorKind::Operation
         );
+        assert_eq!(
+            CallError::BudgetExhausted {
+                attempts: 2,
+                elapsed: Duration::from_millis(90),
+                last: MyErr::Timeout,
+            }
+            .kind(),
+            CallErrorKind::BudgetExhausted
+        );
     }
 
     #[test]
@@ -71,6 +71,7 @@ pub trait FallbackStrategy<T, E>: Send + Sync {
             error,
             CallError::Operation(_)
                 | CallError::RetriesExhausted { .. }
+                | CallError::BudgetExhausted { .. }
                 | CallError::Timeout(_)
                 | CallError::CircuitOpen
         )
@@ -437,6 +438,181 @@ impl<T: Send + Sync + 'static, E: Send + 'static> FallbackStrategy<T, E> for Cha
     }
 }
 
+/// A source of fallback values for [`FallbackChain`].
+///
+/// Implemented for every `Fn() -> impl Future<Output = Result<T, CallError<E>>>`
+/// closure. Wrap a synchronous closure in [`SyncProvider`] to use it in the
+/// same chain.
+pub trait AsyncProvider<T, E>: Send + Sync {
+    /// Produce a value, or the error that moves the chain to its next step.
+    fn provide(&self) -> Pin<Box<dyn Future<Output = Result<T, CallError<E>>> + Send + '_>>;
+}
+
+impl<T, E, F, Fut> AsyncProvider<T, E> for F
+where
+    F: Fn() -> Fut + Send + Sync,
+    Fut: Future<Output = Result<T, CallError<E>>> + Send + 'static,
+{
+    fn provide(&self) -> Pin<Box<dyn Future<Output = Result<T, CallError<E>>> + Send + '_>> {
+        Box::pin(self())
+    }
+}
+
+/// Adapts a synchronous `Fn() -> Result<T, CallError<E>>` into an [`AsyncProvider`].
+#[derive(Clone, Copy)]
+pub struct SyncProvider<F>(F);
+
+impl<F> SyncProvider<F> {
+    /// Wrap a synchronous provider closure.
+    #[must_use]
+    pub const fn new(function: F) -> Self {
+        Self(function)
+    }
+}
+
+impl<F> fmt::Debug for SyncProvider<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SyncProvider").finish_non_exhaustive()
+    }
+}
+
+impl<T, E, F> AsyncProvider<T, E> for SyncProvider<F>
+where
+    T: Send + 'static,
+    E: Send + 'static,
+    F: Fn() -> Result<T, CallError<E>> + Send + Sync,
+{
+    fn provide(&self) -> Pin<Box<dyn Future<Output = Result<T, CallError<E>>> + Send + '_>> {
+        Box::pin(std::future::ready((self.0)()))
+    }
+}
+
+/// Predicate deciding whether a [`FallbackChain`] step handles an error.
+type StepCondition<E> = Box<dyn Fn(&CallError<E>) -> bool + Send + Sync>;
+
+/// One conditional step of a [`FallbackChain`].
+struct ChainStep<T, E> {
+    condition: StepCondition<E>,
+    provider: Box<dyn AsyncProvider<T, E>>,
+}
+
+/// Ordered fallback chain with a condition per step.
+///
+/// Each step runs only if the error left by the previous attempt (the primary
+/// operation, or an earlier step) matches its condition; non-matching steps are
+/// skipped. The first provider to succeed wins. If none does, the last error is
+/// returned. Unlike [`ChainFallback`], which hands the error to each strategy,
+/// steps here are plain value providers gated by a predicate.
+///
+/// [`should_fallback`](FallbackStrategy::should_fallback) is true when any
+/// step's condition matches, so the conditions — not the default policy —
+/// decide which errors are recovered.
+///
+/// # Examples
+///
+/// ```rust
+/// use nebula_resilience::{
+///     CallError,
+///     fallback::{FallbackChain, FallbackStrategy, SyncProvider},
+/// };
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let chain = FallbackChain::new()
+///     // Circuit open: try the cache — here a miss.
+///     .push(
+///         |e: &CallError<&str>| matches!(e, CallError::CircuitOpen),
+///         || async { Err::<String, _>(CallError::Operation("cache miss")) },
+///     )
+///     // Anything else left over: a static default.
+///     .push(
+///         |_: &CallError<&str>| true,
+///         SyncProvider::new(|| Ok("default".to_string())),
+///     );
+///
+/// let recovered = chain.fallback(CallError::CircuitOpen).await;
+/// assert_eq!(recovered.unwrap(), "default");
+/// # }
+/// ```
+pub struct FallbackChain<T, E> {
+    steps: Vec<ChainStep<T, E>>,
+}
+
+impl<T, E> fmt::Debug for FallbackChain<T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FallbackChain")
+            .field("steps", &self.steps.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T, E> Default for FallbackChain<T, E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, E> FallbackChain<T, E> {
+    /// Create an empty chain.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { steps: Vec::new() }
+    }
+
+    /// Append a step that runs `provider` when the current error matches `condition`.
+    #[must_use = "builder methods must be chained or built"]
+    pub fn push(
+        mut self,
+        condition: impl Fn(&CallError<E>) -> bool + Send + Sync + 'static,
+        provider: impl AsyncProvider<T, E> + 'static,
+    ) -> Self {
+        self.steps.push(ChainStep {
+            condition: Box::new(condition),
+            provider: Box::new(provider),
+        });
+        self
+    }
+
+    /// Number of steps in the chain.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// Whether the chain has no steps.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+}
+
+impl<T: Send + Sync + 'static, E: Send + 'static> FallbackStrategy<T, E> for FallbackChain<T, E> {
+    fn recover<'a>(
+        &'a self,
+        error: CallError<E>,
+    ) -> Pin<Box<dyn Future<Output = Result<T, CallError<E>>> + Send + 'a>> {
+        Box::pin(async move {
+            let mut last_error = error;
+
+            for step in &self.steps {
+                if !(step.condition)(&last_error) {
+                    continue;
+                }
+                match step.provider.provide().await {
+                    Ok(value) => return Ok(value),
+                    Err(e) => last_error = e,
+                }
+            }
+
+            Err(last_error)
+        })
+    }
+
+    fn should_fallback(&self, error: &CallError<E>) -> bool {
+        self.steps.iter().any(|step| (step.condition)(error))
+    }
+}
+
 /// Priority fallback — selects fallback based on error kind.
 ///
 /// Uses a `Vec` internally — `CallErrorKind` has few variants, so linear
@@ -883,6 +1059,109 @@ mod tests {
         assert!(matches!(fallback, CallError::Cancelled { .. }));
     }
 
+    // -----------------------------------------------------------------------
+    // FallbackChain
+    // -----------------------------------------------------------------------
+
+    fn is_circuit_open(error: &CallError<&'static str>) -> bool {
+        matches!(error, CallError::CircuitOpen)
+    }
+
+    fn cache_miss() -> Result<u32, CallError<&'static str>> {
+        Err(CallError::Operation("cache miss"))
+    }
+
+    #[tokio::test]
+    async fn fallback_chain_continues_past_failing_middle_provider() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counted = Arc::clone(&calls);
+        let chain = FallbackChain::new()
+            .push(is_circuit_open, move || {
+                counted.fetch_add(1, Ordering::SeqCst);
+                async { cache_miss() }
+            })
+            .push(
+                |e: &CallError<&str>| matches!(e, CallError::Operation("cache miss")),
+                SyncProvider::new(|| Ok(7u32)),
+            );
+
+        let result = chain.fallback(CallError::CircuitOpen).await;
+        assert_eq!(result.unwrap(), 7);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn fallback_chain_stops_at_first_success() {
+        let later = Arc::new(AtomicUsize::new(0));
+        let counted = Arc::clone(&later);
+        let chain = FallbackChain::new()
+            .push(is_circuit_open, || async { Ok(1u32) })
+            .push(
+                |_: &CallError<&str>| true,
+                SyncProvider::new(move || {
+                    counted.fetch_add(1, Ordering::SeqCst);
+                    Ok(2u32)
+                }),
+            );
+
+        assert_eq!(chain.fallback(CallError::CircuitOpen).await.unwrap(), 1);
+        assert_eq!(later.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn fallback_chain_skips_steps_whose_condition_does_not_match() {
+        let chain = FallbackChain::new()
+            .push(is_circuit_open, || async { Ok(1u32) })
+            .push(
+                |e: &CallError<&str>| matches!(e, CallError::Timeout(_)),
+                || async { Ok(2u32) },
+            );
+
+        assert_eq!(chain.fallback(timeout_error()).await.unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn fallback_chain_returns_last_error_when_all_fail() {
+        let chain = FallbackChain::new()
+            .push(is_circuit_open, SyncProvider::new(cache_miss))
+            // Matches the cache miss, then fails with a different error.
+            .push(
+                |e: &CallError<&str>| matches!(e, CallError::Operation(_)),
+                || async { Err::<u32, _>(CallError::Operation("default unavailable")) },
+            );
+
+        let result = chain.fallback(CallError::CircuitOpen).await;
+        assert!(matches!(
+            result,
+            Err(CallError::Operation("default unavailable"))
+        ));
+    }
+
+    #[tokio::test]
+    async fn fallback_chain_declines_errors_no_step_matches() {
+        let chain = FallbackChain::new().push(is_circuit_open, || async { Ok(1u32) });
+
+        assert!(!chain.should_fallback(&cancelled_error()));
+        assert!(matches!(
+            chain.fallback(cancelled_error()).await,
+            Err(CallError::Cancelled { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn fallback_chain_plugs_into_fallback_operation() {
+        fn assert_send_sync<T: Send + Sync>(_: &T) {}
+
+        let chain = FallbackChain::new().push(is_circuit_open, SyncProvider::new(|| Ok(5u32)));
+        assert_send_sync(&chain);
+
+        let op = FallbackOperation::new(Arc::new(chain));
+        let result = op
+            .call(|| async { Err::<u32, _>(CallError::CircuitOpen) })
+            .await;
+        assert_eq!(result.unwrap(), 5);
+    }
+
     // -----------------------------------------------------------------------
     // PriorityFallback / CallErrorKind
     // -----------------------------------------------------------------------
@@ -67,7 +67,8 @@ pub enum HedgeSafety {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone)]
 pub struct HedgeConfig {
-    /// Delay before sending each hedge request.
+    /// Delay before sending each hedge request. Ignored when
+    /// `delay_schedule` is non-empty.
     pub hedge_delay: Duration,
     /// Maximum number of hedge (duplicate) requests beyond the first.
     pub max_hedges: usize,
@@ -77,6 +78,11 @@ pub struct HedgeConfig {
     pub backoff_multiplier: f64,
     /// Whether speculative duplicate operations are safe.
     pub duplicate_safety: HedgeSafety,
+    /// Per-hedge delays: entry `i` is the wait before hedge `i + 1`, measured
+    /// from the previous send. The last entry repeats for any further hedges.
+    /// When non-empty, replaces `hedge_delay` and exponential backoff.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub delay_schedule: Vec<Duration>,
 }
 
 impl Default for HedgeConfig {
@@ -87,6 +93,7 @@ impl Default for HedgeConfig {
             exponential_backoff: true,
             backoff_multiplier: 2.0,
             duplicate_safety: HedgeSafety::Unknown,
+            delay_schedule: Vec::new(),
         }
     }
 }
@@ -96,14 +103,20 @@ impl HedgeConfig {
     ///
     /// # Errors
     ///
-    /// Returns `Err(ConfigError)` if `hedge_delay` is zero, or
-    /// `backoff_multiplier` is not finite or less than 1.0 when exponential backoff is enabled.
+    /// Returns `Err(ConfigError)` if `hedge_delay` or any `delay_schedule` entry is zero,
+    /// or `backoff_multiplier` is not finite or less than 1.0 when exponential backoff is enabled.
     ///
     /// `max_hedges = 0` is valid and disables speculative duplicate requests.
     pub fn validate(&self) -> Result<(), crate::ConfigError> {
         if self.hedge_delay.is_zero() {
             return Err(crate::ConfigError::new("hedge_delay", "must be > 0"));
         }
+        if self.delay_schedule.iter().any(Duration::is_zero) {
+            return Err(crate::ConfigError::new(
+                "delay_schedule",
+                "every delay must be > 0",
+            ));
+        }
         if self.max_hedges > 0 && self.duplicate_safety != HedgeSafety::Idempotent {
             return Err(crate::ConfigError::new(
                 "duplicate_safety",
@@ -121,6 +134,55 @@ impl HedgeConfig {
         }
         Ok(())
     }
+
+    /// Set the maximum number of hedge requests beyond the first.
+    ///
+    /// Any value above zero also requires `duplicate_safety` to be
+    /// [`HedgeSafety::Idempotent`].
+    #[must_use]
+    pub const fn with_max_hedges(mut self, max_hedges: usize) -> Self {
+        self.max_hedges = max_hedges;
+        self
+    }
+
+    /// Set a per-hedge delay schedule; see [`delay_schedule`](Self::delay_schedule).
+    #[must_use]
+    pub fn with_delay_schedule(mut self, delays: impl IntoIterator<Item = Duration>) -> Self {
+        self.delay_schedule = delays.into_iter().collect();
+        self
+    }
+
+    /// Scheduled delay before hedge `hedge_index + 1`, if a schedule is set.
+    fn scheduled_delay(&self, hedge_index: usize) -> Option<Duration> {
+        self.delay_schedule
+            .get(hedge_index)
+            .or_else(|| self.delay_schedule.last())
+            .copied()
+    }
+}
+
+// ── HedgeOutcome ──────────────────────────────────────────────────────────────
+
+/// One request sent by a hedged call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HedgeAttempt {
+    /// The first request, sent immediately.
+    Primary,
+    /// The n-th hedge request, numbered from 1 as in
+    /// [`ResilienceEvent::HedgeFired`].
+    Hedge(u32),
+}
+
+/// How a successful hedged call was won.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct HedgeOutcome {
+    /// The request whose result was returned.
+    pub winner: HedgeAttempt,
+    /// Time from sending the winning request to its completion.
+    pub latency: Duration,
+    /// Number of hedge requests sent, not counting the primary.
+    pub hedges_fired: usize,
 }
 
 // ── HedgeExecutor ─────────────────────────────────────────────────────────────
@@ -191,6 +253,8 @@ impl HedgeExecutor {
     ///
     /// - Returns the first `Ok(T)` result, aborting remaining requests.
     /// - Returns the last `Err` if all attempts fail.
+    /// - If every request in flight has failed, the next hedge is sent at once
+    ///   instead of after its delay.
     ///
     /// # Errors
     ///
@@ -208,12 +272,79 @@ impl HedgeExecutor {
         F: Fn() -> Fut + Send + Sync,
         Fut: Future<Output = Result<T, E>> + Send + 'static,
     {
-        let mut set: JoinSet<Result<T, E>> = JoinSet::new();
-        set.spawn(operation());
+        self.call_gated(|| true, operation).await
+    }
+
+    /// Like [`call`](Self::call), but also reports which request won, its
+    /// latency, and how many hedges were sent.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`call`](Self::call).
+    pub async fn call_with_outcome<T, E, F, Fut>(
+        &self,
+        operation: F,
+    ) -> Result<(T, HedgeOutcome), CallError<E>>
+    where
+        T: Send + 'static,
+        E: Send + 'static,
+        F: Fn() -> Fut + Send + Sync,
+        Fut: Future<Output = Result<T, E>> + Send + 'static,
+    {
+        self.call_gated_with_outcome(|| true, operation).await
+    }
+
+    /// Like [`call`](Self::call), but a hedge is only fired while `allow_hedge`
+    /// returns `true`. Once it returns `false`, no further hedges are sent and
+    /// the call waits for the requests already in flight.
+    pub(crate) async fn call_gated<T, E, F, Fut>(
+        &self,
+        allow_hedge: impl Fn() -> bool + Send + Sync,
+        operation: F,
+    ) -> Result<T, CallError<E>>
+    where
+        T: Send + 'static,
+        E: Send + 'static,
+        F: Fn() -> Fut + Send + Sync,
+        Fut: Future<Output = Result<T, E>> + Send + 'static,
+    {
+        self.call_gated_with_outcome(allow_hedge, operation)
+            .await
+            .map(|(value, _)| value)
+    }
+
+    async fn call_gated_with_outcome<T, E, F, Fut>(
+        &self,
+        allow_hedge: impl Fn() -> bool + Send + Sync,
+        operation: F,
+    ) -> Result<(T, HedgeOutcome), CallError<E>>
+    where
+        T: Send + 'static,
+        E: Send + 'static,
+        F: Fn() -> Fut + Send + Sync,
+        Fut: Future<Output = Result<T, E>> + Send + 'static,
+    {
+        // Each task reports which request it ran and when that request was sent.
+        fn spawn_attempt<T, E, Fut>(
+            set: &mut JoinSet<(HedgeAttempt, Instant, Result<T, E>)>,
+            attempt: HedgeAttempt,
+            fut: Fut,
+        ) where
+            T: Send + 'static,
+            E: Send + 'static,
+            Fut: Future<Output = Result<T, E>> + Send + 'static,
+        {
+            let sent_at = Instant::now();
+            set.spawn(async move { (attempt, sent_at, fut.await) });
+        }
+
+        let mut max_hedges = self.config.max_hedges;
+        let mut set = JoinSet::new();
+        spawn_attempt(&mut set, HedgeAttempt::Primary, operation());
 
         let mut hedge_delay = self.config.hedge_delay;
         let mut hedges_sent = 0usize;
-        let mut delay = Box::pin(sleep(hedge_delay));
+        let mut delay = Box::pin(sleep(self.config.scheduled_delay(0).unwrap_or(hedge_delay)));
         let mut last_err: Option<E> = None;
 
         loop {
@@ -225,27 +356,46 @@ impl HedgeExecutor {
                 // waiting for the delay to fire the next hedge.
                 Some(join_result) = set.join_next(), if !set.is_empty() => {
                     match join_result {
-                        Ok(Ok(v)) => {
+                        Ok((winner, sent_at, Ok(v))) => {
                             set.abort_all();
-                            return Ok(v);
+                            let outcome = HedgeOutcome {
+                                winner,
+                                latency: sent_at.elapsed(),
+                                hedges_fired: hedges_sent,
+                            };
+                            return Ok((v, outcome));
                         }
-                        Ok(Err(e)) => last_err = Some(e),
+                        Ok((_, _, Err(e))) => last_err = Some(e),
                         Err(_) => {} // task panicked or was aborted
                     }
-                    if set.is_empty() && hedges_sent >= self.config.max_hedges {
-                        return Err(
-                            last_err.map_or(CallError::cancelled(), CallError::Operation)
-                        );
+                    if set.is_empty() {
+                        if hedges_sent >= max_hedges {
+                            return Err(
+                                last_err.map_or(CallError::cancelled(), CallError::Operation)
+                            );
+                        }
+                        // Nothing left in flight: waiting out the delay cannot
+                        // help, so send the next hedge now.
+                        delay.as_mut().reset(Instant::now());
                     }
                 }
 
                 // Fire the next hedge after the configured delay.
-                () = &mut delay, if hedges_sent < self.config.max_hedges => {
+                () = &mut delay, if hedges_sent < max_hedges => {
+                    if !allow_hedge() {
+                        max_hedges = hedges_sent;
+                        if set.is_empty() {
+                            return Err(
+                                last_err.map_or(CallError::cancelled(), CallError::Operation)
+                            );
+                        }
+                        continue;
+                    }
                     // Reason: max_hedges is a small config value, never exceeds u32.
                     #[expect(clippy::cast_possible_truncation)]
                     let hedge_num = (hedges_sent + 1) as u32;
                     self.sink.record(ResilienceEvent::HedgeFired { hedge_number: hedge_num });
-                    set.spawn(operation());
+                    spawn_attempt(&mut set, HedgeAttempt::Hedge(hedge_num), operation());
                     hedges_sent += 1;
 
                     if self.config.exponential_backoff {
@@ -255,7 +405,11 @@ impl HedgeExecutor {
                         // overflow to infinity with large max_hedges.
                         hedge_delay = Duration::from_secs_f64(next_secs.min(3600.0));
                     }
-                    delay.as_mut().reset(Instant::now() + hedge_delay);
+                    let next_delay = self
+                        .config
+                        .scheduled_delay(hedges_sent)
+                        .unwrap_or(hedge_delay);
+                    delay.as_mut().reset(Instant::now() + next_delay);
                 }
             }
         }
@@ -266,6 +420,9 @@ impl HedgeExecutor {
 
 /// Hedge executor that adjusts delay based on observed latency percentiles.
 ///
+/// The computed delay replaces both `hedge_delay` and any `delay_schedule` in
+/// the base config.
+///
 /// # Examples
 ///
 /// ```rust
@@ -393,6 +550,7 @@ impl AdaptiveHedgeExecutor {
 
         let config = HedgeConfig {
             hedge_delay,
+            delay_schedule: Vec::new(),
             ..self.base_config.clone()
         };
         let executor = HedgeExecutor {
@@ -464,6 +622,18 @@ impl LatencyTracker {
         self.ring.push_back(nanos);
     }
 
+    /// Number of samples currently retained.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.ring.len()
+    }
+
+    /// Whether no samples have been recorded.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.ring.is_empty()
+    }
+
     // Reason: f64 precision loss and sign loss are acceptable for percentile index calculation.
     #[expect(
         clippy::cast_possible_truncation,
@@ -644,6 +814,107 @@ mod tests {
         .unwrap();
     }
 
+    #[tokio::test]
+    async fn outcome_reports_winning_hedge_and_aborts_primary() {
+        let started = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let dropped = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let executor = HedgeExecutor::new(
+            HedgeConfig {
+                hedge_delay: Duration::from_millis(10),
+                duplicate_safety: HedgeSafety::Idempotent,
+                ..Default::default()
+            }
+            .with_max_hedges(2),
+        )
+        .unwrap();
+
+        let (value, outcome) = executor
+            .call_with_outcome(|| {
+                let dropped = Arc::clone(&dropped);
+                let attempt = started.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                let latency = if attempt == 0 {
+                    Duration::from_mins(1)
+                } else {
+                    Duration::ZERO
+                };
+                Box::pin(async move {
+                    let _drop_counter = DropCounter(dropped);
+                    sleep(latency).await;
+                    Ok::<_, &str>(attempt)
+                })
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(value, 1);
+        assert_eq!(outcome.winner, HedgeAttempt::Hedge(1));
+        assert_eq!(outcome.hedges_fired, 1);
+        assert!(outcome.latency < Duration::from_secs(1));
+        tokio::time::timeout(Duration::from_secs(1), async {
+            while dropped.load(std::sync::atomic::Ordering::SeqCst) < 2 {
+                tokio::task::yield_now().await;
+            }
+        })
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn fast_primary_failure_fires_hedge_immediately() {
+        let started = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let executor = HedgeExecutor::new(HedgeConfig {
+            hedge_delay: Duration::from_mins(1),
+            max_hedges: 1,
+            duplicate_safety: HedgeSafety::Idempotent,
+            ..Default::default()
+        })
+        .unwrap();
+
+        let (value, outcome) = tokio::time::timeout(
+            Duration::from_secs(1),
+            executor.call_with_outcome(|| {
+                let attempt = started.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                let result = if attempt == 0 {
+                    Err("primary failed")
+                } else {
+                    Ok(attempt)
+                };
+                Box::pin(async move { result })
+            }),
+        )
+        .await
+        .expect("hedge should not wait out its delay")
+        .unwrap();
+
+        assert_eq!(value, 1);
+        assert_eq!(outcome.winner, HedgeAttempt::Hedge(1));
+    }
+
+    #[tokio::test]
+    async fn delay_schedule_sets_each_hedge_delay() {
+        let started = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let dropped = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let executor = HedgeExecutor::new(
+            HedgeConfig {
+                hedge_delay: Duration::from_mins(1),
+                max_hedges: 3,
+                duplicate_safety: HedgeSafety::Idempotent,
+                ..Default::default()
+            }
+            .with_delay_schedule([Duration::from_millis(5), Duration::from_mins(1)]),
+        )
+        .unwrap();
+
+        let result = tokio::time::timeout(
+            Duration::from_millis(200),
+            executor.call(|| pending_hedge_operation(Arc::clone(&started), Arc::clone(&dropped))),
+        )
+        .await;
+
+        assert!(result.is_err());
+        assert_eq!(started.load(std::sync::atomic::Ordering::SeqCst), 2);
+    }
+
     // ── C2: HedgeConfig validation ───────────────────────────────────────
 
     #[test]
@@ -686,6 +957,13 @@ mod tests {
         assert_eq!(config.validate().unwrap_err().field, "duplicate_safety");
     }
 
+    #[test]
+    fn rejects_zero_scheduled_delay() {
+        let config =
+            HedgeConfig::default().with_delay_schedule([Duration::from_millis(10), Duration::ZERO]);
+        assert_eq!(config.validate().unwrap_err().field, "delay_schedule");
+    }
+
     #[test]
     fn accepts_valid_config() {
         assert!(HedgeExecutor::new(HedgeConfig::default()).is_ok());
@@ -107,11 +107,13 @@
 //! | Variant | Retryable | Produced by |
 //! |---------|-----------|-------------|
 //! | `Operation(E)` | depends on `E` | user's operation |
-//! | `Timeout(Duration)` | yes | timeout, bulkhead queue |
+//! | `Timeout(Duration)` | yes | timeout |
 //! | `RateLimited { retry_after }` | yes | rate limiter |
 //! | `BulkheadFull` | yes | bulkhead |
+//! | `BulkheadTimeout(Duration)` | yes | bulkhead queue |
 //! | `CircuitOpen` | no | circuit breaker |
 //! | `RetriesExhausted { attempts, last }` | no | retry |
+//! | `BudgetExhausted { attempts, elapsed, last }` | no | retry (total or shared budget) |
 //! | `Cancelled { reason }` | no | cancellation |
 //! | `LoadShed` | no | load shedder |
 //! | `FallbackFailed { reason }` / `FallbackFailedWithContext {.. }` | no | fallback |
@@ -146,6 +148,7 @@ pub mod policy;
 
 // Observability
 pub mod sink;
+mod span;
 
 // Patterns
 pub mod bulkhead;
@@ -153,11 +156,14 @@ pub mod circuit_breaker;
 pub mod fallback;
 pub mod hedge;
 pub mod load_shed;
+pub mod priority_bulkhead;
 pub mod rate_limiter;
 pub mod retry;
+pub mod sharded_circuit_breaker;
 pub mod timeout;
 
 // Infrastructure
+pub mod chaos;
 pub mod clock;
 pub mod gate;
 pub mod pipeline;
@@ -171,7 +177,10 @@ pub use cancellation::{CancellableFuture, CancellationContext, CancellationExt};
 // ── Internals exposed for benchmarking ───────────────────────────────────────
 #[doc(hidden)]
 pub use circuit_breaker::OutcomeWindow;
-pub use circuit_breaker::{CircuitBreaker, CircuitBreakerConfig};
+pub use circuit_breaker::{
+    CircuitBreaker, CircuitBreakerConfig, CircuitBreakerStore, InMemoryCircuitBreakerStore,
+    PersistentCircuitBreakerState, StateTransitionEvent, WindowMode, log_state_transition,
+};
 pub use classifier::{
     AlwaysPermanent, AlwaysTransient, ErrorClass, ErrorClassifier, FnClassifier, NebulaClassifier,
 };
@@ -183,24 +192,39 @@ pub use fallback::{FallbackStrategy, ValueFallback};
 pub use gate::{Gate, GateCloseTimeout, GateClosed, GateGuard};
 #[doc(hidden)]
 pub use hedge::LatencyTracker;
-pub use hedge::{HedgeConfig, HedgeExecutor, HedgeSafety};
+pub use hedge::{HedgeAttempt, HedgeConfig, HedgeExecutor, HedgeOutcome, HedgeSafety};
 pub use load_shed::{
     load_shed, load_shed_with_policy_context, load_shed_with_policy_context_and_sink,
     load_shed_with_sink,
 };
-pub use pipeline::{LoadShedPredicate, PipelineBuilder, RateLimitCheck, ResiliencePipeline};
+pub use pipeline::{
+    LayerMetrics, LoadShedPredicate, PipelineBuilder, PipelineMetrics, RateLimitCheck,
+    ResiliencePipeline,
+};
 pub use policy::{ConstantLoad, LoadSignal, LoadSnapshot, PolicySource};
+pub use priority_bulkhead::{
+    Priority, PriorityBulkhead, PriorityBulkheadConfig, PriorityBulkheadPermit,
+};
 pub use rate_limiter::{
-    AdaptiveRateLimiter, ErasedRateLimiter, LeakyBucket, RateLimiter, SlidingWindow, TokenBucket,
+    AdaptiveRateLimiter, AdaptiveRateLimiterStats, AimdPolicy, BurstingTokenBucket,
+    ErasedRateLimiter, LeakyBucket, RateAdaptationPolicy, RateLimiter, RateLimiterStats,
+    SlidingWindow, TokenBucket,
 };
+#[cfg(feature = "dyn-backoff")]
+pub use retry::DynBackoffPolicy;
 #[doc(hidden)]
 pub use retry::retry_with_inner;
-pub use retry::{BackoffConfig, JitterConfig, RetryConfig, retry, retry_with};
+pub use retry::{
+    BackoffConfig, JitterConfig, RetryConfig, RetryStats, SharedRetryBudget, retry, retry_with,
+    retry_with_cancel, retry_with_deadline, retry_with_stats,
+};
+pub use sharded_circuit_breaker::ShardedCircuitBreaker;
 // Observability
 pub use sink::{
     CircuitState, MetricsSink, NoopSink, PipelineOutcome, PolicyScope, RecordingSink,
     ResilienceEvent, ResilienceEventKind, ScopeValue,
 };
 pub use timeout::{
-    TimeoutExecutor, timeout, timeout_with_policy_context, timeout_with_policy_context_and_sink,
+    AdaptiveTimeout, TimeoutExecutor, timeout, timeout_with_policy_context,
+    timeout_with_policy_context_and_sink, timeout_with_token, timeout_with_token_and_sink,
 };
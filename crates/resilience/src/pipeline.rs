@@ -1,7 +1,7 @@
 //! `ResiliencePipeline` — compose multiple resilience patterns into a single call chain.
 //!
 //! Recommended layer order (outermost → innermost):
-//! `load_shed → rate_limiter → timeout → retry → circuit_breaker → bulkhead`
+//! `load_shed → rate_limiter → timeout → retry → hedge → circuit_breaker → bulkhead`
 //!
 //! Layers are applied in the order added: first added = outermost.
 //!
@@ -34,17 +34,28 @@
 //! # }
 //! ```
 
-use std::{fmt, future::Future, pin::Pin, sync::Arc, time::Duration};
+use std::{
+    fmt,
+    future::Future,
+    pin::Pin,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, AtomicU64, Ordering},
+    },
+    time::{Duration, Instant},
+};
 
 use crate::{
-    CallError, PolicyContext,
-    bulkhead::Bulkhead,
+    CallError, CallErrorKind, PolicyContext,
+    bulkhead::{Bulkhead, BulkheadStats},
     cancellation::CancellationContext,
-    circuit_breaker::{CircuitBreaker, Outcome, ProbeGuard},
+    circuit_breaker::{CircuitBreaker, CircuitBreakerStats, Outcome, ProbeGuard},
     classifier::{ErrorClass, ErrorClassifier, FnClassifier},
-    rate_limiter::{ErasedRateLimiter, map_acquire_error},
+    hedge::HedgeExecutor,
+    rate_limiter::{AdaptiveRateLimiter, ErasedRateLimiter, map_acquire_error},
     retry::{RetryConfig, retry_with},
-    sink::{MetricsSink, NoopSink, PipelineOutcome, PolicyScope, ResilienceEvent},
+    sink::{CircuitState, MetricsSink, NoopSink, PipelineOutcome, PolicyScope, ResilienceEvent},
+    timeout::AdaptiveTimeout,
 };
 
 // ── Execution ────────────────────────────────────────────────────────────────
@@ -52,10 +63,13 @@ use crate::{
 // Steps are processed recursively by `run_operation_with_shells`. Each step
 // type is handled exactly once, in order:
 //
-// - LoadShed / RateLimiter: checked before recursing to inner steps.
+// - LoadShed / RateLimiter: checked before recursing to inner steps. An
+//   adaptive rate limiter also gets the outcome and latency of the inner steps.
 // - CircuitBreaker: `try_acquire()` + `ProbeGuard` + `record_outcome()`.
 // - Bulkhead: `acquire()` permit held for the inner scope.
 // - Timeout / Retry: wrap the remainder of the pipeline.
+// - Hedge: runs the remainder of the pipeline once per hedged request, so
+//   each request passes its own circuit-breaker check and outcome.
 //
 // `run_operation_with_shells` wraps every recursive call in `Box::pin`
 // (required because the async fn is recursive). Timeout and Retry add
@@ -73,15 +87,153 @@ type RetryHintFn<E> = Arc<dyn Fn(&E) -> Option<Duration> + Send + Sync>;
 
 // ── Steps ─────────────────────────────────────────────────────────────────────
 
+/// Limit of a timeout step.
+enum TimeoutLimit {
+    Fixed(Duration),
+    /// Read before every call and fed each call's latency afterwards.
+    Adaptive(Arc<AdaptiveTimeout>),
+}
+
+impl TimeoutLimit {
+    fn current(&self) -> Duration {
+        match self {
+            Self::Fixed(d) => *d,
+            Self::Adaptive(timeout) => timeout.current(),
+        }
+    }
+
+    fn observe(&self, latency: Duration) {
+        if let Self::Adaptive(timeout) = self {
+            timeout.update(latency);
+        }
+    }
+}
+
 enum Step<E: 'static> {
-    Timeout(Duration),
+    Timeout(TimeoutLimit),
     Retry(Box<RetryConfig<E>>),
+    Hedge(HedgeExecutor),
     CircuitBreaker(Arc<CircuitBreaker>),
     Bulkhead(Arc<Bulkhead>),
-    RateLimiter(RateLimitCheck),
+    /// The check, plus the limiter to report outcomes to when it adapts.
+    RateLimiter(RateLimitCheck, Option<Arc<AdaptiveRateLimiter>>),
     LoadShed(LoadShedPredicate),
 }
 
+impl<E> Step<E> {
+    fn metrics(&self) -> LayerMetrics {
+        match self {
+            Self::Timeout(limit) => LayerMetrics::Timeout(limit.current()),
+            Self::Retry(config) => LayerMetrics::Retry {
+                max_attempts: config.max_attempts().get(),
+            },
+            Self::Hedge(_) => LayerMetrics::Hedge,
+            Self::CircuitBreaker(cb) => LayerMetrics::CircuitBreaker(cb.stats()),
+            Self::Bulkhead(bh) => LayerMetrics::Bulkhead(bh.stats()),
+            Self::RateLimiter(..) => LayerMetrics::RateLimiter,
+            Self::LoadShed(_) => LayerMetrics::LoadShed,
+        }
+    }
+}
+
+// ── Metrics ───────────────────────────────────────────────────────────────────
+
+/// Point-in-time view of one pipeline layer, as reported by
+/// [`ResiliencePipeline::metrics`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum LayerMetrics {
+    /// Timeout layer and its limit; the current one for an adaptive timeout.
+    Timeout(Duration),
+    /// Retry layer.
+    Retry {
+        /// Configured attempts per call, the first included.
+        max_attempts: u32,
+    },
+    /// Hedge layer.
+    Hedge,
+    /// Circuit breaker layer and its current stats.
+    CircuitBreaker(CircuitBreakerStats),
+    /// Bulkhead layer and its current stats.
+    Bulkhead(BulkheadStats),
+    /// Rate limiter layer.
+    RateLimiter,
+    /// Load shed layer.
+    LoadShed,
+}
+
+impl LayerMetrics {
+    /// Whether the layer is in its normal operating state: a circuit breaker
+    /// is closed and a bulkhead has a free permit. Stateless layers are
+    /// always healthy.
+    #[must_use]
+    pub fn is_healthy(&self) -> bool {
+        match self {
+            Self::CircuitBreaker(stats) => stats.state == CircuitState::Closed,
+            Self::Bulkhead(stats) => !stats.is_at_capacity,
+            _ => true,
+        }
+    }
+}
+
+/// Aggregate view of a [`ResiliencePipeline`]: call totals since it was
+/// built plus a snapshot of every layer.
+///
+/// Counters cover every entry point (`call`, `call_with_context`, the
+/// fallback variants, …).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct PipelineMetrics {
+    /// Completed pipeline calls.
+    pub calls: u64,
+    /// Calls that ended because a layer refused them — circuit open,
+    /// bulkhead full, rate limited, or load shed — including ones a
+    /// fallback then recovered.
+    pub rejections: u64,
+    /// Attempts made by retry layers beyond each call's first.
+    pub retries: u64,
+    /// Per-layer snapshots, outermost first.
+    pub layers: Vec<LayerMetrics>,
+}
+
+impl PipelineMetrics {
+    /// `true` only when every layer is [healthy](LayerMetrics::is_healthy).
+    #[must_use]
+    pub fn is_healthy(&self) -> bool {
+        self.layers.iter().all(LayerMetrics::is_healthy)
+    }
+}
+
+/// Call totals behind [`PipelineMetrics`].
+#[derive(Debug, Default)]
+struct PipelineCounters {
+    calls: AtomicU64,
+    rejections: AtomicU64,
+    retries: AtomicU64,
+}
+
+impl PipelineCounters {
+    fn record(&self, outcome: PipelineOutcome) {
+        self.calls.fetch_add(1, Ordering::Relaxed);
+        let error = match outcome {
+            PipelineOutcome::Failure { error } => error,
+            PipelineOutcome::FallbackSucceeded { primary_error }
+            | PipelineOutcome::FallbackFailed { primary_error, .. } => primary_error,
+            _ => return,
+        };
+        if matches!(
+            error,
+            CallErrorKind::CircuitOpen
+                | CallErrorKind::BulkheadFull
+                | CallErrorKind::BulkheadTimeout
+                | CallErrorKind::RateLimited
+                | CallErrorKind::LoadShed
+        ) {
+            self.rejections.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
+
 // ── Builder ───────────────────────────────────────────────────────────────────
 
 /// Builder for [`ResiliencePipeline`].
@@ -201,7 +353,20 @@ impl<E: Send + 'static> PipelineBuilder<E> {
     /// Add a timeout step (outermost wrapper if added first).
     #[must_use]
     pub fn timeout(mut self, d: Duration) -> Self {
-        self.steps.push(Step::Timeout(d));
+        self.steps.push(Step::Timeout(TimeoutLimit::Fixed(d)));
+        self
+    }
+
+    /// Add a timeout step whose limit follows observed latency.
+    ///
+    /// Each call runs within [`AdaptiveTimeout::current`] and records its
+    /// latency into `timeout`. A call that times out records the limit it hit;
+    /// a cancelled call records nothing. Orders like [`timeout`](Self::timeout).
+    /// Keep a clone of the `Arc` to read the limit or share it between pipelines.
+    #[must_use]
+    pub fn adaptive_timeout(mut self, timeout: Arc<AdaptiveTimeout>) -> Self {
+        self.steps
+            .push(Step::Timeout(TimeoutLimit::Adaptive(timeout)));
         self
     }
 
@@ -217,6 +382,53 @@ impl<E: Send + 'static> PipelineBuilder<E> {
         self
     }
 
+    /// Add a hedge step.
+    ///
+    /// Each hedged request runs the steps added after this one. Place the hedge
+    /// outside a [`circuit_breaker`](Self::circuit_breaker) step: every request
+    /// then counts toward the breaker, and no hedge is fired while the first
+    /// breaker inside the hedge is not `Closed`. A half-open breaker gets
+    /// only its probe, not a speculative duplicate.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::{sync::Arc, time::Duration};
+    ///
+    /// use nebula_resilience::{
+    ///     ResiliencePipeline,
+    ///     circuit_breaker::{CircuitBreaker, CircuitBreakerConfig},
+    ///     hedge::{HedgeConfig, HedgeExecutor, HedgeSafety},
+    /// };
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let hedge = HedgeExecutor::new(HedgeConfig {
+    ///     hedge_delay: Duration::from_millis(20),
+    ///     max_hedges: 1,
+    ///     duplicate_safety: HedgeSafety::Idempotent,
+    ///     ..Default::default()
+    /// })
+    /// .expect("valid config");
+    /// let breaker = Arc::new(CircuitBreaker::new(CircuitBreakerConfig::default()).unwrap());
+    ///
+    /// let pipeline = ResiliencePipeline::<&str>::builder()
+    ///     .hedge(hedge)
+    ///     .circuit_breaker(breaker)
+    ///     .build();
+    /// let value = pipeline
+    ///     .call(|| Box::pin(async { Ok::<_, &str>(1u32) }))
+    ///     .await
+    ///     .unwrap();
+    /// assert_eq!(value, 1);
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn hedge(mut self, executor: HedgeExecutor) -> Self {
+        self.steps.push(Step::Hedge(executor));
+        self
+    }
+
     /// Add a circuit breaker step.
     #[must_use]
     pub fn circuit_breaker(mut self, cb: Arc<CircuitBreaker>) -> Self {
@@ -305,7 +517,39 @@ impl<E: Send + 'static> PipelineBuilder<E> {
     /// implementations. Use this for custom bridging logic.
     #[must_use]
     pub fn rate_limiter(mut self, check: RateLimitCheck) -> Self {
-        self.steps.push(Step::RateLimiter(check));
+        self.steps.push(Step::RateLimiter(check, None));
+        self
+    }
+
+    /// Add a rate limiter step that also feeds an [`AdaptiveRateLimiter`].
+    ///
+    /// Besides gating calls, the step reports the outcome and latency of the
+    /// inner steps through
+    /// [`record_outcome()`](AdaptiveRateLimiter::record_outcome), so the
+    /// limiter adapts without callers recording anything. Successes count as
+    /// successes; operation errors, exhausted retries and timeouts count as
+    /// errors. Rejections by inner layers and cancellations are not reported,
+    /// since they say nothing about the downstream.
+    ///
+    /// ```rust,no_run
+    /// use std::sync::Arc;
+    ///
+    /// use nebula_resilience::{ResiliencePipeline, rate_limiter::AdaptiveRateLimiter};
+    ///
+    /// let rl = Arc::new(AdaptiveRateLimiter::new(50.0, 10.0, 100.0).unwrap());
+    /// let pipeline = ResiliencePipeline::<String>::builder()
+    ///     .adaptive_rate_limiter(Arc::clone(&rl))
+    ///     .build();
+    /// # let _ = pipeline;
+    /// ```
+    #[must_use]
+    pub fn adaptive_rate_limiter(mut self, rl: Arc<AdaptiveRateLimiter>) -> Self {
+        let limiter = Arc::clone(&rl);
+        let check: RateLimitCheck = Arc::new(move || {
+            let rl = Arc::clone(&limiter);
+            Box::pin(async move { crate::RateLimiter::acquire(&*rl).await })
+        });
+        self.steps.push(Step::RateLimiter(check, Some(rl)));
         self
     }
 
@@ -341,7 +585,7 @@ impl<E: Send + 'static> PipelineBuilder<E> {
     ///
     /// This preserves insertion order among steps of the same kind and orders
     /// different kinds as: `load_shed -> rate_limiter -> timeout -> retry ->
-    /// circuit_breaker -> bulkhead`.
+    /// hedge -> circuit_breaker -> bulkhead`.
     #[must_use]
     pub fn build_recommended_order(mut self) -> ResiliencePipeline<E> {
         self.steps.sort_by_key(step_rank);
@@ -357,6 +601,7 @@ impl<E: Send + 'static> PipelineBuilder<E> {
             sink_overrides_steps,
             retry_hint: self.retry_hint,
             scope: self.scope,
+            counters: Arc::default(),
         }
     }
 }
@@ -382,20 +627,22 @@ impl<E: nebula_error::Classify + Send + Sync + 'static> PipelineBuilder<E> {
 const fn step_rank<E>(step: &Step<E>) -> u8 {
     match step {
         Step::LoadShed(_) => 0,
-        Step::RateLimiter(_) => 1,
+        Step::RateLimiter(..) => 1,
         Step::Timeout(_) => 2,
         Step::Retry(_) => 3,
-        Step::CircuitBreaker(_) => 4,
-        Step::Bulkhead(_) => 5,
+        Step::Hedge(_) => 4,
+        Step::CircuitBreaker(_) => 5,
+        Step::Bulkhead(_) => 6,
     }
 }
 
 const fn step_name<E>(step: &Step<E>) -> &'static str {
     match step {
         Step::LoadShed(_) => "load_shed",
-        Step::RateLimiter(_) => "rate_limiter",
+        Step::RateLimiter(..) => "rate_limiter",
         Step::Timeout(_) => "timeout",
         Step::Retry(_) => "retry",
+        Step::Hedge(_) => "hedge",
         Step::CircuitBreaker(_) => "circuit_breaker",
         Step::Bulkhead(_) => "bulkhead",
     }
@@ -433,9 +680,10 @@ fn validate_order<E>(steps: &[Step<E>]) {
         .map(|s| match s {
             Step::Timeout(_) => "timeout",
             Step::Retry(_) => "retry",
+            Step::Hedge(_) => "hedge",
             Step::CircuitBreaker(_) => "circuit_breaker",
             Step::Bulkhead(_) => "bulkhead",
-            Step::RateLimiter(_) => "rate_limiter",
+            Step::RateLimiter(..) => "rate_limiter",
             Step::LoadShed(_) => "load_shed",
         })
         .collect();
@@ -443,6 +691,8 @@ fn validate_order<E>(steps: &[Step<E>]) {
     let retry_pos = names.iter().position(|&n| n == "retry");
     let timeout_pos = names.iter().position(|&n| n == "timeout");
     let rate_limiter_pos = names.iter().position(|&n| n == "rate_limiter");
+    let hedge_pos = names.iter().position(|&n| n == "hedge");
+    let circuit_breaker_pos = names.iter().position(|&n| n == "circuit_breaker");
 
     if let (Some(r), Some(t)) = (retry_pos, timeout_pos)
         && t > r
@@ -461,6 +711,15 @@ fn validate_order<E>(steps: &[Step<E>]) {
              Move rate_limiter before retry to reject once before entering the retry loop."
         );
     }
+
+    if let (Some(h), Some(cb)) = (hedge_pos, circuit_breaker_pos)
+        && h > cb
+    {
+        tracing::warn!(
+            "ResiliencePipeline: hedge is inside circuit_breaker (hedged requests count as one outcome \
+             and are fired while the breaker is half-open). Move hedge before circuit_breaker."
+        );
+    }
 }
 
 // ── Pipeline ──────────────────────────────────────────────────────────────────
@@ -496,6 +755,7 @@ pub struct ResiliencePipeline<E: 'static> {
     sink_overrides_steps: bool,
     retry_hint: Option<RetryHintFn<E>>,
     scope: PolicyScope,
+    counters: Arc<PipelineCounters>,
 }
 
 struct PipelineRunContext<E: 'static> {
@@ -505,6 +765,7 @@ struct PipelineRunContext<E: 'static> {
     sink_overrides_steps: bool,
     retry_hint: Option<RetryHintFn<E>>,
     cancellation: Option<CancellationContext>,
+    counters: Arc<PipelineCounters>,
 }
 
 impl<E: 'static> Clone for PipelineRunContext<E> {
@@ -516,6 +777,7 @@ impl<E: 'static> Clone for PipelineRunContext<E> {
             sink_overrides_steps: self.sink_overrides_steps,
             retry_hint: self.retry_hint.clone(),
             cancellation: self.cancellation.clone(),
+            counters: Arc::clone(&self.counters),
         }
     }
 }
@@ -535,6 +797,21 @@ impl<E: Send + 'static> ResiliencePipeline<E> {
         PipelineBuilder::new()
     }
 
+    /// Aggregate call totals and per-layer state.
+    ///
+    /// Use [`PipelineMetrics::is_healthy`] for a single "is the chain
+    /// healthy?" answer. Circuit breakers and bulkheads shared with other
+    /// pipelines report their shared state.
+    #[must_use]
+    pub fn metrics(&self) -> PipelineMetrics {
+        PipelineMetrics {
+            calls: self.counters.calls.load(Ordering::Relaxed),
+            rejections: self.counters.rejections.load(Ordering::Relaxed),
+            retries: self.counters.retries.load(Ordering::Relaxed),
+            layers: self.steps.iter().map(Step::metrics).collect(),
+        }
+    }
+
     /// Execute `f` through all pipeline steps.
     ///
     /// # Errors
@@ -695,26 +972,31 @@ impl<E: Send + 'static> ResiliencePipeline<E> {
         // type for Arc<F> sharing across retry iterations) only allocates
         // once per call instead of once per pipeline step.
         let boxed = move || -> Pin<Box<dyn Future<Output = Result<T, E>> + Send>> { Box::pin(f()) };
-        execute_pipeline(
-            Arc::clone(&self.steps),
-            self.classifier.clone(),
-            Arc::clone(&self.sink),
-            self.sink_overrides_steps,
-            self.retry_hint.clone(),
+        let ctx = PipelineRunContext {
+            steps: Arc::clone(&self.steps),
+            classifier: self.classifier.clone(),
+            sink: Arc::clone(&self.sink),
+            sink_overrides_steps: self.sink_overrides_steps,
+            retry_hint: self.retry_hint.clone(),
             cancellation,
-            Arc::new(boxed),
-        )
-        .await
+            counters: Arc::clone(&self.counters),
+        };
+        run_operation_with_shells(ctx, 0, Arc::new(boxed)).await
     }
 
     fn record_pipeline_completed(&self, outcome: PipelineOutcome) {
+        self.counters.record(outcome);
         if !self.sink_overrides_steps {
             return;
         }
-        self.record_pipeline_completed_for_scope(self.scope.clone(), outcome);
+        self.sink.record(ResilienceEvent::PipelineCompleted {
+            scope: self.scope.clone(),
+            outcome,
+        });
     }
 
     fn record_pipeline_completed_for_scope(&self, scope: PolicyScope, outcome: PipelineOutcome) {
+        self.counters.record(outcome);
         if !self.sink_overrides_steps {
             return;
         }
@@ -1015,31 +1297,6 @@ impl<E: Send + 'static> ResiliencePipeline<E> {
     }
 }
 
-async fn execute_pipeline<T, E, F>(
-    steps: Arc<Vec<Step<E>>>,
-    classifier: Option<Arc<dyn ErrorClassifier<E>>>,
-    sink: Arc<dyn MetricsSink>,
-    sink_overrides_steps: bool,
-    retry_hint: Option<RetryHintFn<E>>,
-    cancellation: Option<CancellationContext>,
-    f: Arc<F>,
-) -> Result<T, CallError<E>>
-where
-    T: Send + 'static,
-    E: Send + 'static,
-    F: Fn() -> Pin<Box<dyn Future<Output = Result<T, E>> + Send>> + Send + Sync + 'static,
-{
-    let ctx = PipelineRunContext {
-        steps,
-        classifier,
-        sink,
-        sink_overrides_steps,
-        retry_hint,
-        cancellation,
-    };
-    run_operation_with_shells(ctx, 0, f).await
-}
-
 /// Recursively apply pipeline steps (one `Box::pin` per Timeout/Retry shell),
 /// then call the user function.
 fn run_operation_with_shells<T, E, F>(
@@ -1072,29 +1329,9 @@ where
         }
 
         match &steps[idx] {
-            Step::Timeout(d) => {
-                let d = *d;
-                let inner = run_operation_with_shells(ctx.clone(), idx + 1, f);
-                if let Some(cancellation) = ctx.cancellation.clone() {
-                    tokio::select! {
-                        result = tokio::time::timeout(d, inner) => {
-                            result.unwrap_or_else(|_| {
-                                ctx.sink
-                                    .record(ResilienceEvent::TimeoutElapsed { duration: d });
-                                Err(CallError::Timeout(d))
-                            })
-                        },
-                        () = cancellation.token().cancelled() => Err(cancellation.cancelled_error()),
-                    }
-                } else {
-                    tokio::time::timeout(d, inner).await.unwrap_or_else(|_| {
-                        ctx.sink
-                            .record(ResilienceEvent::TimeoutElapsed { duration: d });
-                        Err(CallError::Timeout(d))
-                    })
-                }
-            },
+            Step::Timeout(limit) => run_timeout_step(limit, ctx, idx, f).await,
             Step::Retry(config) => run_retry_step(config, ctx, idx, f).await,
+            Step::Hedge(executor) => run_hedge_step(executor, &ctx, idx, f).await,
             Step::CircuitBreaker(cb) => {
                 cb.try_acquire()?;
 
@@ -1120,24 +1357,8 @@ where
                 };
                 run_operation_with_shells(ctx, idx + 1, f).await
             },
-            Step::RateLimiter(check) => {
-                let check_result = if let Some(cancellation) = ctx.cancellation.clone() {
-                    tokio::select! {
-                        result = check() => result,
-                        () = cancellation.token().cancelled() => return Err(cancellation.cancelled_error()),
-                    }
-                } else {
-                    check().await
-                };
-                match check_result {
-                    Ok(()) => {},
-                    Err(CallError::RateLimited { retry_after }) => {
-                        ctx.sink.record(ResilienceEvent::RateLimitExceeded);
-                        return Err(CallError::RateLimited { retry_after });
-                    },
-                    Err(error) => return Err(map_acquire_error(error)),
-                }
-                run_operation_with_shells(ctx, idx + 1, f).await
+            Step::RateLimiter(check, adaptive) => {
+                run_rate_limiter_step(check, adaptive.as_ref(), ctx, idx, f).await
             },
             Step::LoadShed(predicate) => {
                 if predicate() {
@@ -1151,6 +1372,21 @@ where
     })
 }
 
+/// Whether an inner pipeline result reports a downstream success or failure
+/// to an adaptive rate limiter; `None` for rejections and cancellations.
+const fn downstream_outcome<T, E>(result: &Result<T, CallError<E>>) -> Option<bool> {
+    match result {
+        Ok(_) => Some(true),
+        Err(
+            CallError::Operation(_)
+            | CallError::RetriesExhausted { .. }
+            | CallError::BudgetExhausted { .. }
+            | CallError::Timeout(_),
+        ) => Some(false),
+        Err(_) => None,
+    }
+}
+
 /// Classify the outcome of an inner pipeline result for the CB step.
 ///
 /// When a classifier is available, operation errors are mapped via
@@ -1174,14 +1410,16 @@ fn classify_cb_outcome<T, E>(
             },
             |c| classify_error_cb_outcome(cb, c.classify(e), duration),
         ),
-        Err(CallError::RetriesExhausted { last, .. }) => classifier.map_or_else(
-            || {
-                duration.map_or(Outcome::Failure, |duration| {
-                    cb.classify_outcome(false, duration)
-                })
-            },
-            |c| classify_error_cb_outcome(cb, c.classify(last), duration),
-        ),
+        Err(CallError::RetriesExhausted { last, .. } | CallError::BudgetExhausted { last, .. }) => {
+            classifier.map_or_else(
+                || {
+                    duration.map_or(Outcome::Failure, |duration| {
+                        cb.classify_outcome(false, duration)
+                    })
+                },
+                |c| classify_error_cb_outcome(cb, c.classify(last), duration),
+            )
+        },
         Err(CallError::Timeout(_)) => Outcome::Timeout,
         Err(_) => Outcome::Cancelled,
     }
@@ -1198,6 +1436,120 @@ fn classify_error_cb_outcome(
     )
 }
 
+/// Execute the `RateLimiter` step of the pipeline.
+async fn run_rate_limiter_step<T, E, F>(
+    check: &RateLimitCheck,
+    adaptive: Option<&Arc<AdaptiveRateLimiter>>,
+    ctx: PipelineRunContext<E>,
+    idx: usize,
+    f: Arc<F>,
+) -> Result<T, CallError<E>>
+where
+    T: Send + 'static,
+    E: Send + 'static,
+    F: Fn() -> Pin<Box<dyn Future<Output = Result<T, E>> + Send>> + Send + Sync + 'static,
+{
+    let check_result = if let Some(cancellation) = ctx.cancellation.clone() {
+        tokio::select! {
+            result = check() => result,
+            () = cancellation.token().cancelled() => return Err(cancellation.cancelled_error()),
+        }
+    } else {
+        check().await
+    };
+    match check_result {
+        Ok(()) => {},
+        Err(CallError::RateLimited { retry_after }) => {
+            ctx.sink.record(ResilienceEvent::RateLimitExceeded);
+            return Err(CallError::RateLimited { retry_after });
+        },
+        Err(error) => return Err(map_acquire_error(error)),
+    }
+    let Some(adaptive) = adaptive else {
+        return run_operation_with_shells(ctx, idx + 1, f).await;
+    };
+    let started = Instant::now();
+    let result = run_operation_with_shells(ctx, idx + 1, f).await;
+    if let Some(success) = downstream_outcome(&result) {
+        adaptive.record_outcome(started.elapsed(), success);
+    }
+    result
+}
+
+/// Execute the Timeout step: use the adaptive limit, observe latency, `TimeoutElapsed` on expiry.
+async fn run_timeout_step<T, E, F>(
+    limit: &TimeoutLimit,
+    ctx: PipelineRunContext<E>,
+    idx: usize,
+    f: Arc<F>,
+) -> Result<T, CallError<E>>
+where
+    T: Send + 'static,
+    E: Send + 'static,
+    F: Fn() -> Pin<Box<dyn Future<Output = Result<T, E>> + Send>> + Send + Sync + 'static,
+{
+    let d = limit.current();
+    // Inner steps run under a child token, so work they hand off (such as
+    // spawned hedge attempts) stops when the deadline fires.
+    let scope = ctx
+        .cancellation
+        .as_ref()
+        .map_or_else(CancellationContext::new, CancellationContext::child);
+    let inner_ctx = PipelineRunContext {
+        cancellation: Some(scope.clone()),
+        ..ctx.clone()
+    };
+    let started = Instant::now();
+    let inner = tokio::time::timeout(d, run_operation_with_shells(inner_ctx, idx + 1, f));
+    let outcome = if let Some(cancellation) = ctx.cancellation.clone() {
+        tokio::select! {
+            outcome = inner => outcome,
+            () = cancellation.token().cancelled() => return Err(cancellation.cancelled_error()),
+        }
+    } else {
+        inner.await
+    };
+    if let Ok(result) = outcome {
+        limit.observe(started.elapsed());
+        result
+    } else {
+        scope.cancel();
+        limit.observe(d);
+        ctx.sink
+            .record(ResilienceEvent::TimeoutElapsed { duration: d });
+        Err(CallError::Timeout(d))
+    }
+}
+
+/// Execute the Hedge step of the pipeline.
+///
+/// Hedges are suppressed while the first circuit breaker inside the hedge is
+/// not `Closed`.
+async fn run_hedge_step<T, E, F>(
+    executor: &HedgeExecutor,
+    ctx: &PipelineRunContext<E>,
+    idx: usize,
+    f: Arc<F>,
+) -> Result<T, CallError<E>>
+where
+    T: Send + 'static,
+    E: Send + 'static,
+    F: Fn() -> Pin<Box<dyn Future<Output = Result<T, E>> + Send>> + Send + Sync + 'static,
+{
+    let breaker = ctx.steps[idx + 1..].iter().find_map(|step| match step {
+        Step::CircuitBreaker(cb) => Some(cb),
+        _ => None,
+    });
+    executor
+        .call_gated(
+            || breaker.is_none_or(|cb| cb.circuit_state() == CircuitState::Closed),
+            || run_operation_with_shells(ctx.clone(), idx + 1, Arc::clone(&f)),
+        )
+        .await
+        // The hedge wraps the inner pipeline's `CallError` as its operation error.
+        .map_err(|err| err.flat_map_inner(|inner| inner, |_, inner| inner))
+}
+
 /// Execute the Retry step of the pipeline.
 async fn run_retry_step<T, E, F>(
     config: &RetryConfig<E>,
@@ -1217,9 +1569,16 @@ where
         RetryConfig::<RetryStepError<E>>::from_nonzero_attempts(config.max_attempts())
             .backoff(config.backoff_config().clone())
             .jitter(config.jitter_config().clone());
+    #[cfg(feature = "dyn-backoff")]
+    {
+        inner_config.backoff_policy = config.backoff_policy.clone();
+    }
     if let Some(total_budget) = config.total_budget_config() {
         inner_config = inner_config.total_budget(total_budget);
     }
+    if let Some(attempt_timeout) = config.attempt_timeout_config() {
+        inner_config = inner_config.attempt_timeout(attempt_timeout);
+    }
     inner_config.sink = if ctx.sink_overrides_steps {
         Arc::clone(&ctx.sink)
     } else {
@@ -1258,7 +1617,11 @@ where
     let retry_future = retry_with(inner_config, {
         let ctx = ctx.clone();
         let f = Arc::clone(&f);
+        let first_attempt = AtomicBool::new(true);
         move || {
+            if !first_attempt.swap(false, Ordering::Relaxed) {
+                ctx.counters.retries.fetch_add(1, Ordering::Relaxed);
+            }
             let ctx = ctx.clone();
             let f = Arc::clone(&f);
             Box::pin(async move {
@@ -1311,7 +1674,9 @@ impl<E> nebula_error::Classify for RetryStepError<E> {
     fn category(&self) -> nebula_error::ErrorCategory {
         match self {
             Self::Operation { .. } => nebula_error::ErrorCategory::External,
-            Self::RetryablePattern(CallError::Timeout(_)) => nebula_error::ErrorCategory::Timeout,
+            Self::RetryablePattern(CallError::Timeout(_) | CallError::BulkheadTimeout(_)) => {
+                nebula_error::ErrorCategory::Timeout
+            },
             Self::RetryablePattern(CallError::RateLimited { .. }) => {
                 nebula_error::ErrorCategory::RateLimit
             },
@@ -1338,6 +1703,9 @@ impl<E> nebula_error::Classify for RetryStepError<E> {
             Self::RetryablePattern(CallError::BulkheadFull) => {
                 nebula_error::ErrorCode::new("RESILIENCE:BULKHEAD_FULL")
             },
+            Self::RetryablePattern(CallError::BulkheadTimeout(_)) => {
+                nebula_error::ErrorCode::new("RESILIENCE:BULKHEAD_TIMEOUT")
+            },
             Self::RetryablePattern(_) | Self::FatalPattern(_) => {
                 nebula_error::ErrorCode::new("RESILIENCE:PIPELINE_RETRY_PATTERN")
             },
@@ -1361,7 +1729,7 @@ impl<E> nebula_error::Classify for RetryStepError<E> {
 /// Classify an inner pipeline result for the retry layer.
 ///
 /// `Operation` errors use the retry classifier for `E`; retryable pattern errors
-/// (`Timeout`, `RateLimited`, `BulkheadFull`) can be retried by layer order; all
+/// (`Timeout`, `RateLimited`, `BulkheadFull`, `BulkheadTimeout`) can be retried by layer order; all
 /// other pattern errors stop the retry loop immediately.
 fn classify_inner<T, E>(
     result: Result<T, CallError<E>>,
@@ -1411,6 +1779,42 @@ mod tests {
         ResilienceEventKind, retry::BackoffConfig,
     };
 
+    #[tokio::test]
+    async fn adaptive_timeout_step_learns_from_calls() {
+        let adaptive = Arc::new(
+            AdaptiveTimeout::new(Duration::from_millis(30))
+                .with_percentile(1.0)
+                .unwrap()
+                .with_safety_factor(3.0)
+                .unwrap()
+                .with_bounds(Duration::from_millis(1), Duration::from_secs(1))
+                .unwrap(),
+        );
+        let pipeline = ResiliencePipeline::<&str>::builder()
+            .adaptive_timeout(Arc::clone(&adaptive))
+            .build();
+
+        // The initial limit is too tight for a 40ms call.
+        let slow = || -> Pin<Box<dyn Future<Output = Result<u32, &str>> + Send>> {
+            Box::pin(async {
+                tokio::time::sleep(Duration::from_millis(40)).await;
+                Ok(1)
+            })
+        };
+        let err = pipeline.call(slow).await.unwrap_err();
+        assert!(matches!(err, CallError::Timeout(d) if d == Duration::from_millis(30)));
+        assert_eq!(adaptive.sample_count(), 1);
+        assert_eq!(adaptive.current(), Duration::from_millis(90));
+
+        // The timed-out sample widened the limit, so the same call now fits.
+        assert_eq!(pipeline.call(slow).await.unwrap(), 1);
+        assert_eq!(adaptive.sample_count(), 2);
+        assert_eq!(
+            pipeline.metrics().layers[0],
+            LayerMetrics::Timeout(adaptive.current())
+        );
+    }
+
     #[derive(Debug, Clone, Copy)]
     struct RetryAfterErr;
 
@@ -1615,7 +2019,7 @@ mod tests {
             .rate_limiter(rate_limiter)
             .build();
 
-        let start = std::time::Instant::now();
+        let start = Instant::now();
         let result = pipeline
             .call(move || {
                 let seen_operations = Arc::clone(&seen_operations);
@@ -1649,7 +2053,7 @@ mod tests {
             )
             .build();
 
-        let start = std::time::Instant::now();
+        let start = Instant::now();
         let result = pipeline
             .call(move || {
                 let seen = Arc::clone(&seen);
@@ -1709,6 +2113,76 @@ mod tests {
         assert_eq!(operations.load(Ordering::SeqCst), 0);
     }
 
+    #[tokio::test]
+    async fn metrics_aggregate_calls_retries_and_layer_health() {
+        let cb = Arc::new(CircuitBreaker::new(crate::CircuitBreakerConfig::default()).unwrap());
+        let pipeline = ResiliencePipeline::<&str>::builder()
+            .retry(
+                RetryConfig::new(3)
+                    .unwrap()
+                    .backoff(BackoffConfig::Fixed(Duration::ZERO))
+                    .retry_if(|_: &&str| true),
+            )
+            .circuit_breaker(Arc::clone(&cb))
+            .build();
+
+        let result = pipeline
+            .call(|| Box::pin(async { Err::<u32, &str>("boom") }))
+            .await;
+        assert!(matches!(result, Err(CallError::RetriesExhausted { .. })));
+
+        let metrics = pipeline.metrics();
+        assert_eq!(
+            (metrics.calls, metrics.rejections, metrics.retries),
+            (1, 0, 2)
+        );
+        assert_eq!(metrics.layers[0], LayerMetrics::Retry { max_attempts: 3 });
+        assert!(metrics.is_healthy());
+
+        cb.force_open();
+        let result = pipeline
+            .call(|| Box::pin(async { Ok::<u32, &str>(1) }))
+            .await;
+        assert!(matches!(result, Err(CallError::CircuitOpen)));
+
+        let metrics = pipeline.metrics();
+        assert_eq!(
+            (metrics.calls, metrics.rejections, metrics.retries),
+            (2, 1, 2)
+        );
+        assert!(matches!(
+            &metrics.layers[1],
+            LayerMetrics::CircuitBreaker(stats) if stats.state == CircuitState::Open
+        ));
+        assert!(!metrics.is_healthy());
+    }
+
+    #[tokio::test]
+    async fn metrics_report_full_bulkhead_as_unhealthy() {
+        let bh = Arc::new(
+            Bulkhead::new(crate::BulkheadConfig {
+                max_concurrency: 1,
+                queue_size: 0,
+                timeout: None,
+            })
+            .unwrap(),
+        );
+        let pipeline = ResiliencePipeline::<&str>::builder()
+            .bulkhead(Arc::clone(&bh))
+            .build();
+        assert!(pipeline.metrics().is_healthy());
+
+        let _permit = bh.acquire::<&str>().await.unwrap();
+        let result = pipeline
+            .call(|| Box::pin(async { Ok::<u32, &str>(42) }))
+            .await;
+
+        assert!(matches!(result, Err(CallError::BulkheadFull)));
+        let metrics = pipeline.metrics();
+        assert_eq!(metrics.rejections, 1);
+        assert!(!metrics.is_healthy());
+    }
+
     #[tokio::test]
     async fn build_recommended_order_rejects_before_retry() {
         let checks = Arc::new(AtomicU32::new(0));
@@ -1985,6 +2459,39 @@ mod tests {
         assert!(matches!(result, Err(CallError::RateLimited { .. })));
     }
 
+    #[tokio::test]
+    async fn adaptive_rate_limiter_step_records_downstream_outcomes() {
+        use std::sync::atomic::AtomicBool;
+
+        use crate::rate_limiter::AdaptiveRateLimiter;
+
+        let rl = Arc::new(AdaptiveRateLimiter::new(50.0, 10.0, 100.0).unwrap());
+        let shed = Arc::new(AtomicBool::new(false));
+        let shed_flag = Arc::clone(&shed);
+        let pipeline = ResiliencePipeline::<&str>::builder()
+            .adaptive_rate_limiter(Arc::clone(&rl))
+            .load_shed(Arc::new(move || shed_flag.load(Ordering::Relaxed)))
+            .build();
+
+        pipeline
+            .call(|| Box::pin(async { Ok::<u32, &str>(1) }))
+            .await
+            .unwrap();
+        let _ = pipeline
+            .call(|| Box::pin(async { Err::<u32, _>("boom") }))
+            .await;
+        // A rejection by an inner layer is not a downstream failure.
+        shed.store(true, Ordering::Relaxed);
+        let rejected = pipeline
+            .call(|| Box::pin(async { Ok::<u32, &str>(1) }))
+            .await;
+        assert!(matches!(rejected, Err(CallError::LoadShed)));
+
+        let recent = rl.stats().recent;
+        assert_eq!((recent.successes, recent.errors), (1, 1));
+        assert!(recent.p95_latency.is_some());
+    }
+
     #[tokio::test]
     async fn pipeline_with_sink_emits_timeout_event() {
         let sink = RecordingSink::new();
@@ -2142,6 +2649,43 @@ mod tests {
         assert_eq!(cb.circuit_state(), CircuitState::Closed);
     }
 
+    #[tokio::test]
+    async fn pipeline_timeout_releases_bulkhead_permit_held_by_hedge() {
+        let bh = Arc::new(
+            Bulkhead::new(crate::BulkheadConfig {
+                max_concurrency: 1,
+                queue_size: 1,
+                timeout: None,
+            })
+            .unwrap(),
+        );
+        // The hedge runs the bulkhead-guarded operation in a spawned task,
+        // outside the future the timeout drops.
+        let pipeline = ResiliencePipeline::<&str>::builder()
+            .timeout(Duration::from_millis(20))
+            .hedge(idempotent_hedge(Duration::from_secs(10)))
+            .bulkhead(Arc::clone(&bh))
+            .build();
+
+        let result = pipeline
+            .call(|| {
+                Box::pin(async {
+                    tokio::time::sleep(Duration::from_secs(10)).await;
+                    Ok::<u32, &str>(1)
+                })
+            })
+            .await;
+
+        assert!(matches!(result, Err(CallError::Timeout(_))));
+        tokio::time::timeout(Duration::from_secs(1), async {
+            while bh.available_permits() < 1 {
+                tokio::task::yield_now().await;
+            }
+        })
+        .await
+        .expect("permit should be released once the timeout fires");
+    }
+
     #[tokio::test]
     async fn pipeline_bulkhead_takes_single_permit() {
         let bh = Arc::new(
@@ -2209,7 +2753,7 @@ mod tests {
             Some((
                 _,
                 PipelineOutcome::FallbackSucceeded {
-                    primary_error: crate::CallErrorKind::Timeout,
+                    primary_error: CallErrorKind::Timeout,
                 }
             ))
         ));
@@ -2324,7 +2868,7 @@ mod tests {
             Some((
                 scope,
                 PipelineOutcome::Failure {
-                    error: crate::CallErrorKind::Timeout,
+                    error: CallErrorKind::Timeout,
                 }
             )) if scope.tenant_id.as_deref() == Some("tenant-context")
         ));
@@ -2413,4 +2957,91 @@ mod tests {
 
         assert_eq!(result.unwrap(), 42);
     }
+
+    async fn counted_slow_ok(calls: Arc<AtomicU32>) -> Result<u32, &'static str> {
+        calls.fetch_add(1, Ordering::SeqCst);
+        tokio::time::sleep(Duration::from_millis(40)).await;
+        Ok(1)
+    }
+
+    async fn primary_fails_hedge_succeeds(attempt: u32) -> Result<u32, &'static str> {
+        if attempt == 0 {
+            tokio::time::sleep(Duration::from_millis(30)).await;
+            Err("primary failed")
+        } else {
+            tokio::time::sleep(Duration::from_millis(40)).await;
+            Ok(7)
+        }
+    }
+
+    fn idempotent_hedge(delay: Duration) -> HedgeExecutor {
+        HedgeExecutor::new(crate::hedge::HedgeConfig {
+            hedge_delay: delay,
+            max_hedges: 1,
+            duplicate_safety: crate::hedge::HedgeSafety::Idempotent,
+            ..Default::default()
+        })
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn hedge_suppressed_while_breaker_half_open() {
+        let cb = Arc::new(
+            CircuitBreaker::new(crate::CircuitBreakerConfig {
+                failure_threshold: 1,
+                min_operations: 1,
+                reset_timeout: Duration::from_millis(50),
+                ..Default::default()
+            })
+            .unwrap(),
+        );
+        cb.record_outcome(Outcome::Failure);
+        assert_eq!(cb.circuit_state(), CircuitState::Open);
+        tokio::time::sleep(Duration::from_millis(60)).await;
+
+        let pipeline = ResiliencePipeline::<&str>::builder()
+            .hedge(idempotent_hedge(Duration::from_millis(10)))
+            .circuit_breaker(Arc::clone(&cb))
+            .build_checked()
+            .unwrap();
+        let calls = Arc::new(AtomicU32::new(0));
+        let slow_op = {
+            let calls = Arc::clone(&calls);
+            move || Box::pin(counted_slow_ok(Arc::clone(&calls)))
+        };
+
+        // The half-open probe runs alone; no hedge is fired alongside it.
+        assert_eq!(pipeline.call(slow_op.clone()).await.unwrap(), 1);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(cb.circuit_state(), CircuitState::Closed);
+
+        // Once closed, the slow primary is hedged again.
+        assert_eq!(pipeline.call(slow_op).await.unwrap(), 1);
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn hedged_requests_each_feed_breaker() {
+        let cb = Arc::new(CircuitBreaker::new(crate::CircuitBreakerConfig::default()).unwrap());
+        let pipeline = ResiliencePipeline::<&str>::builder()
+            .hedge(idempotent_hedge(Duration::from_millis(10)))
+            .circuit_breaker(Arc::clone(&cb))
+            .build();
+        let calls = Arc::new(AtomicU32::new(0));
+
+        // The primary fails after the hedge was fired; the hedge then succeeds.
+        let result = pipeline
+            .call({
+                let calls = Arc::clone(&calls);
+                move || {
+                    Box::pin(primary_fails_hedge_succeeds(
+                        calls.fetch_add(1, Ordering::SeqCst),
+                    ))
+                }
+            })
+            .await;
+
+        assert_eq!(result.unwrap(), 7);
+        assert_eq!(cb.stats().total, 2);
+    }
 }
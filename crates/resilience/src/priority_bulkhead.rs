@@ -0,0 +1,613 @@
+//! Priority bulkhead — concurrency limit with a priority-ordered wait queue.
+//!
+//! [`Bulkhead`](crate::Bulkhead) wakes queued callers in FIFO order. When a
+//! saturated bulkhead is shared between critical and best-effort work, FIFO lets
+//! a burst of background calls delay the critical ones. [`PriorityBulkhead`]
+//! hands each freed permit to the highest-[`Priority`] waiter instead, and
+//! promotes any waiter older than
+//! [`starvation_timeout`](PriorityBulkheadConfig::starvation_timeout) so low
+//! priority work still makes progress under sustained pressure.
+
+use std::{
+    future::Future,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use parking_lot::Mutex;
+use tokio::sync::oneshot;
+
+use crate::{
+    CallError, ConfigError, PolicyContext,
+    sink::{MetricsSink, NoopSink, ResilienceEvent},
+};
+
+// ── Priority ──────────────────────────────────────────────────────────────────
+
+/// Scheduling priority of a [`PriorityBulkhead`] caller.
+///
+/// Variants are ordered from lowest to highest, so `Priority::Critical >
+/// Priority::Low`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Priority {
+    /// Best-effort work (background sync, prefetch).
+    Low,
+    /// Regular traffic.
+    #[default]
+    Normal,
+    /// Latency-sensitive traffic.
+    High,
+    /// Work that must not be starved by anything else.
+    Critical,
+}
+
+// ── Config ────────────────────────────────────────────────────────────────────
+
+/// Configuration for [`PriorityBulkhead`].
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+///
+/// use nebula_resilience::{PriorityBulkhead, PriorityBulkheadConfig};
+///
+/// let cfg = PriorityBulkheadConfig {
+///     max_concurrency: 8,
+///     queue_depth: 64,
+///     starvation_timeout: Duration::from_secs(2),
+///     timeout: Some(Duration::from_secs(10)),
+/// };
+///
+/// let _bulkhead = PriorityBulkhead::new(cfg).expect("config is valid");
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PriorityBulkheadConfig {
+    /// Maximum number of concurrent operations. Min: 1.
+    pub max_concurrency: usize,
+    /// Maximum number of callers allowed to wait for a permit.
+    ///
+    /// `0` means **no queue**: saturation rejects immediately with
+    /// [`CallError::BulkheadFull`].
+    pub queue_depth: usize,
+    /// How long a waiter may be passed over before it is treated as
+    /// [`Priority::Critical`]. Min: non-zero.
+    ///
+    /// Promoted waiters are served oldest-first, ahead of fresh critical
+    /// arrivals, so every queued caller is eventually scheduled.
+    pub starvation_timeout: Duration,
+    /// Optional timeout while waiting for a permit. A waiter that runs out gets
+    /// [`CallError::BulkheadTimeout`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub timeout: Option<Duration>,
+}
+
+impl Default for PriorityBulkheadConfig {
+    fn default() -> Self {
+        Self {
+            max_concurrency: 10,
+            queue_depth: 100,
+            starvation_timeout: Duration::from_secs(5),
+            timeout: Some(Duration::from_secs(30)),
+        }
+    }
+}
+
+impl PriorityBulkheadConfig {
+    /// Validate configuration. Called by `PriorityBulkhead::new()`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ConfigError)` if `max_concurrency` is 0 or
+    /// `starvation_timeout` is zero.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if self.max_concurrency == 0 {
+            return Err(ConfigError::new("max_concurrency", "must be >= 1"));
+        }
+        if self.starvation_timeout.is_zero() {
+            return Err(ConfigError::new("starvation_timeout", "must be > 0"));
+        }
+        Ok(())
+    }
+}
+
+// ── Shared state ──────────────────────────────────────────────────────────────
+
+struct Waiter {
+    id: u64,
+    priority: Priority,
+    enqueued_at: Instant,
+    tx: oneshot::Sender<PriorityBulkheadPermit>,
+}
+
+struct State {
+    active: usize,
+    next_id: u64,
+    waiters: Vec<Waiter>,
+}
+
+struct Shared {
+    config: PriorityBulkheadConfig,
+    state: Mutex<State>,
+}
+
+impl Shared {
+    /// Remove and return the waiter that should receive the next permit.
+    ///
+    /// The queue is bounded by `queue_depth`, so a linear scan keeps the aging
+    /// rule simple: effective priority is recomputed at dispatch time rather
+    /// than maintained in a heap whose keys change as waiters age.
+    fn pop_next(&self, state: &mut State) -> Option<Waiter> {
+        let now = Instant::now();
+        let starvation = self.config.starvation_timeout;
+        let index = state
+            .waiters
+            .iter()
+            .enumerate()
+            .max_by_key(|(_, waiter)| {
+                let effective = if now.saturating_duration_since(waiter.enqueued_at) >= starvation {
+                    Priority::Critical
+                } else {
+                    waiter.priority
+                };
+                // Older waiters (lower id) win ties.
+                (effective, std::cmp::Reverse(waiter.id))
+            })
+            .map(|(index, _)| index)?;
+        Some(state.waiters.swap_remove(index))
+    }
+
+    /// Return a permit: hand it to the next live waiter or free the slot.
+    fn release(self: Arc<Self>) {
+        let mut permit = PriorityBulkheadPermit {
+            shared: Some(Arc::clone(&self)),
+        };
+        loop {
+            let next = {
+                let mut state = self.state.lock();
+                let next = self.pop_next(&mut state);
+                if next.is_none() {
+                    state.active -= 1;
+                }
+                next
+            };
+            let Some(waiter) = next else {
+                // Slot already freed above; disarm so dropping does not recurse.
+                permit.shared = None;
+                return;
+            };
+            // Sent outside the lock: if the receiver is gone the permit comes
+            // back and the next waiter is tried.
+            match waiter.tx.send(permit) {
+                Ok(()) => return,
+                Err(returned) => permit = returned,
+            }
+        }
+    }
+}
+
+// ── PriorityBulkhead ──────────────────────────────────────────────────────────
+
+/// Bulkhead whose wait queue is ordered by [`Priority`] with anti-starvation
+/// aging.
+///
+/// Cloning is cheap and shares the same limit and queue. Callers arriving while
+/// others already wait are queued even if a permit is momentarily free, so a
+/// newcomer cannot overtake the queue.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use nebula_resilience::{CallError, Priority, PriorityBulkhead, PriorityBulkheadConfig};
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let bulkhead = PriorityBulkhead::new(PriorityBulkheadConfig::default())?;
+///
+/// let value: Result<&str, CallError<&str>> = bulkhead
+///     .call(Priority::High, || async { Ok("ok") })
+///     .await;
+/// assert_eq!(value.unwrap(), "ok");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct PriorityBulkhead {
+    shared: Arc<Shared>,
+    sink: Arc<dyn MetricsSink>,
+}
+
+impl std::fmt::Debug for PriorityBulkhead {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PriorityBulkhead")
+            .field("max_concurrency", &self.shared.config.max_concurrency)
+            .field("active", &self.active_operations())
+            .field("waiting", &self.waiting())
+            .finish_non_exhaustive()
+    }
+}
+
+impl PriorityBulkhead {
+    /// Create a new priority bulkhead.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ConfigError)` if config is invalid.
+    pub fn new(config: PriorityBulkheadConfig) -> Result<Self, ConfigError> {
+        config.validate()?;
+        Ok(Self {
+            shared: Arc::new(Shared {
+                state: Mutex::new(State {
+                    active: 0,
+                    next_id: 0,
+                    waiters: Vec::with_capacity(config.queue_depth.min(1024)),
+                }),
+                config,
+            }),
+            sink: Arc::new(NoopSink),
+        })
+    }
+
+    /// Replace the metrics sink (builder-style).
+    #[must_use]
+    pub fn with_sink(mut self, sink: impl MetricsSink + 'static) -> Self {
+        self.sink = Arc::new(sink);
+        self
+    }
+
+    /// Current number of active (in-flight) operations.
+    #[must_use]
+    pub fn active_operations(&self) -> usize {
+        self.shared.state.lock().active
+    }
+
+    /// Current number of callers waiting for a permit.
+    #[must_use]
+    pub fn waiting(&self) -> usize {
+        self.shared.state.lock().waiters.len()
+    }
+
+    /// Maximum concurrency limit.
+    #[must_use]
+    pub fn max_concurrency(&self) -> usize {
+        self.shared.config.max_concurrency
+    }
+
+    /// Execute a closure under the bulkhead at the given priority.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(CallError::BulkheadFull)` when the queue is full,
+    /// `Err(CallError::BulkheadTimeout)` if the queue timeout expires,
+    /// or `Err(CallError::Operation)` if the operation itself fails.
+    pub async fn call<T, E, Fut>(
+        &self,
+        priority: Priority,
+        f: impl FnOnce() -> Fut,
+    ) -> Result<T, CallError<E>>
+    where
+        Fut: Future<Output = Result<T, E>> + Send,
+    {
+        let _permit = self.acquire_permit(priority).await?;
+        f().await.map_err(CallError::Operation)
+    }
+
+    /// Execute a closure under the bulkhead with a shared policy context.
+    ///
+    /// The context cancellation/deadline bounds both waiting for a permit and
+    /// the operation itself.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(CallError::Cancelled)` if the context is cancelled,
+    /// `Err(CallError::Timeout)` if the context deadline expires,
+    /// `Err(CallError::BulkheadTimeout)` if the queue timeout expires,
+    /// `Err(CallError::BulkheadFull)` when the queue is full,
+    /// or `Err(CallError::Operation)` if the operation itself fails.
+    pub async fn call_with_policy_context<T, E, Fut>(
+        &self,
+        context: &PolicyContext,
+        priority: Priority,
+        f: impl FnOnce() -> Fut + Send,
+    ) -> Result<T, CallError<E>>
+    where
+        Fut: Future<Output = Result<T, E>> + Send,
+    {
+        let _permit = context.run_result(self.acquire_permit(priority)).await?;
+        context
+            .run_result(async { f().await.map_err(CallError::Operation) })
+            .await
+    }
+
+    /// Acquire a permit directly. Use [`call`](PriorityBulkhead::call) for the
+    /// typical execute-and-release pattern.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(CallError::BulkheadFull)` when the queue is full,
+    /// or `Err(CallError::BulkheadTimeout)` if a queue timeout is configured and exceeded.
+    pub async fn acquire<E>(
+        &self,
+        priority: Priority,
+    ) -> Result<PriorityBulkheadPermit, CallError<E>> {
+        self.acquire_permit(priority).await
+    }
+
+    // ── internal ──────────────────────────────────────────────────────────────
+
+    async fn acquire_permit<E>(
+        &self,
+        priority: Priority,
+    ) -> Result<PriorityBulkheadPermit, CallError<E>> {
+        let (id, rx) = {
+            let mut state = self.shared.state.lock();
+
+            // Fast path — free slot and nobody ahead of us.
+            if state.active < self.shared.config.max_concurrency && state.waiters.is_empty() {
+                state.active += 1;
+                return Ok(PriorityBulkheadPermit {
+                    shared: Some(Arc::clone(&self.shared)),
+                });
+            }
+
+            if state.waiters.len() >= self.shared.config.queue_depth {
+                drop(state);
+                self.sink.record(ResilienceEvent::BulkheadRejected);
+                return Err(CallError::BulkheadFull);
+            }
+
+            let id = state.next_id;
+            state.next_id += 1;
+            let (tx, rx) = oneshot::channel();
+            state.waiters.push(Waiter {
+                id,
+                priority,
+                enqueued_at: Instant::now(),
+                tx,
+            });
+            drop(state);
+            (id, rx)
+        };
+
+        // RAII guard: if this future is dropped (or times out) while queued,
+        // remove the waiter so its queue slot is not leaked. A permit already
+        // handed to the dropped receiver is returned by the permit's own Drop.
+        let _guard = QueuedWaiterGuard {
+            shared: &self.shared,
+            id,
+        };
+
+        let received = match self.shared.config.timeout {
+            Some(timeout_dur) => match tokio::time::timeout(timeout_dur, rx).await {
+                Ok(received) => received,
+                Err(_elapsed) => return Err(CallError::BulkheadTimeout(timeout_dur)),
+            },
+            None => rx.await,
+        };
+        received.map_err(|_closed| CallError::BulkheadFull)
+    }
+}
+
+/// RAII guard that removes a queued waiter on drop.
+struct QueuedWaiterGuard<'a> {
+    shared: &'a Shared,
+    id: u64,
+}
+
+impl Drop for QueuedWaiterGuard<'_> {
+    fn drop(&mut self) {
+        let mut state = self.shared.state.lock();
+        if let Some(index) = state.waiters.iter().position(|w| w.id == self.id) {
+            state.waiters.swap_remove(index);
+        }
+    }
+}
+
+// ── Permit ────────────────────────────────────────────────────────────────────
+
+/// RAII permit — dropping it hands the slot to the next queued caller or frees it.
+pub struct PriorityBulkheadPermit {
+    shared: Option<Arc<Shared>>,
+}
+
+impl std::fmt::Debug for PriorityBulkheadPermit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PriorityBulkheadPermit")
+            .finish_non_exhaustive()
+    }
+}
+
+impl Drop for PriorityBulkheadPermit {
+    fn drop(&mut self) {
+        if let Some(shared) = self.shared.take() {
+            shared.release();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::{RecordingSink, ResilienceEventKind};
+
+    fn cfg(max: usize, depth: usize) -> PriorityBulkheadConfig {
+        PriorityBulkheadConfig {
+            max_concurrency: max,
+            queue_depth: depth,
+            starvation_timeout: Duration::from_mins(1),
+            timeout: None,
+        }
+    }
+
+    /// Spawn a queued acquire that records its priority label once admitted.
+    fn spawn_waiter(
+        bh: &PriorityBulkhead,
+        priority: Priority,
+        order: &Arc<Mutex<Vec<Priority>>>,
+    ) -> tokio::task::JoinHandle<()> {
+        let bh = bh.clone();
+        let order = Arc::clone(order);
+        tokio::spawn(async move {
+            let permit = bh.acquire::<&str>(priority).await.unwrap();
+            order.lock().push(priority);
+            drop(permit);
+        })
+    }
+
+    async fn wait_for_waiters(bh: &PriorityBulkhead, n: usize) {
+        while bh.waiting() < n {
+            tokio::time::sleep(Duration::from_millis(1)).await;
+        }
+    }
+
+    #[test]
+    fn rejects_zero_concurrency_and_zero_starvation_timeout() {
+        let err = PriorityBulkhead::new(cfg(0, 1)).unwrap_err();
+        assert_eq!(err.field, "max_concurrency");
+
+        let err = PriorityBulkhead::new(PriorityBulkheadConfig {
+            starvation_timeout: Duration::ZERO,
+            ..cfg(1, 1)
+        })
+        .unwrap_err();
+        assert_eq!(err.field, "starvation_timeout");
+    }
+
+    #[tokio::test]
+    async fn call_succeeds_within_capacity() {
+        let bh = PriorityBulkhead::new(cfg(2, 0)).unwrap();
+        let result = bh
+            .call::<_, &str, _>(Priority::Normal, || async { Ok("ok") })
+            .await;
+        assert_eq!(result.unwrap(), "ok");
+        assert_eq!(bh.active_operations(), 0);
+    }
+
+    #[tokio::test]
+    async fn higher_priority_waiter_is_admitted_first() {
+        let bh = PriorityBulkhead::new(cfg(1, 10)).unwrap();
+        let order = Arc::new(Mutex::new(Vec::new()));
+
+        let permit = bh.acquire::<&str>(Priority::Normal).await.unwrap();
+
+        // Low arrives first; without priority ordering it would run first.
+        let low = spawn_waiter(&bh, Priority::Low, &order);
+        wait_for_waiters(&bh, 1).await;
+        let normal = spawn_waiter(&bh, Priority::Normal, &order);
+        wait_for_waiters(&bh, 2).await;
+        let critical = spawn_waiter(&bh, Priority::Critical, &order);
+        wait_for_waiters(&bh, 3).await;
+
+        drop(permit);
+        for handle in [low, normal, critical] {
+            handle.await.unwrap();
+        }
+
+        assert_eq!(
+            *order.lock(),
+            vec![Priority::Critical, Priority::Normal, Priority::Low]
+        );
+        assert_eq!(bh.active_operations(), 0);
+    }
+
+    #[tokio::test]
+    async fn starved_waiter_is_promoted_ahead_of_critical() {
+        let bh = PriorityBulkhead::new(PriorityBulkheadConfig {
+            starvation_timeout: Duration::from_millis(20),
+            ..cfg(1, 10)
+        })
+        .unwrap();
+        let order = Arc::new(Mutex::new(Vec::new()));
+
+        let permit = bh.acquire::<&str>(Priority::Normal).await.unwrap();
+        let low = spawn_waiter(&bh, Priority::Low, &order);
+        wait_for_waiters(&bh, 1).await;
+
+        // Let the low-priority waiter age past the starvation timeout.
+        tokio::time::sleep(Duration::from_millis(40)).await;
+        let critical = spawn_waiter(&bh, Priority::Critical, &order);
+        wait_for_waiters(&bh, 2).await;
+
+        drop(permit);
+        low.await.unwrap();
+        critical.await.unwrap();
+
+        assert_eq!(*order.lock(), vec![Priority::Low, Priority::Critical]);
+    }
+
+    #[tokio::test]
+    async fn rejects_when_queue_full() {
+        let sink = RecordingSink::new();
+        let bh = PriorityBulkhead::new(cfg(1, 1))
+            .unwrap()
+            .with_sink(sink.clone());
+
+        let permit = bh.acquire::<&str>(Priority::Normal).await.unwrap();
+        let bh2 = bh.clone();
+        let waiter = tokio::spawn(async move { bh2.acquire::<&str>(Priority::Low).await });
+        wait_for_waiters(&bh, 1).await;
+
+        // Even a critical caller is rejected once the queue itself is full.
+        let err = bh.acquire::<&str>(Priority::Critical).await.unwrap_err();
+        assert!(matches!(err, CallError::BulkheadFull));
+        assert_eq!(bh.waiting(), 1);
+        assert_eq!(sink.count(ResilienceEventKind::BulkheadRejected), 1);
+
+        drop(permit);
+        waiter.await.unwrap().unwrap();
+    }
+
+    #[tokio::test]
+    async fn rejects_immediately_when_queue_depth_zero() {
+        let bh = PriorityBulkhead::new(cfg(1, 0)).unwrap();
+        let permit = bh.acquire::<&str>(Priority::Normal).await.unwrap();
+        let err = bh.acquire::<&str>(Priority::Critical).await.unwrap_err();
+        assert!(matches!(err, CallError::BulkheadFull));
+        drop(permit);
+    }
+
+    #[tokio::test]
+    async fn queue_timeout_releases_slot() {
+        let bh = PriorityBulkhead::new(PriorityBulkheadConfig {
+            timeout: Some(Duration::from_millis(10)),
+            ..cfg(1, 1)
+        })
+        .unwrap();
+
+        let permit = bh.acquire::<&str>(Priority::Normal).await.unwrap();
+        let err = bh.acquire::<&str>(Priority::High).await.unwrap_err();
+        assert!(matches!(err, CallError::BulkheadTimeout(_)));
+        assert_eq!(bh.waiting(), 0);
+
+        drop(permit);
+        assert_eq!(bh.active_operations(), 0);
+    }
+
+    #[tokio::test]
+    async fn dropped_waiter_is_skipped_on_release() {
+        let bh = PriorityBulkhead::new(cfg(1, 2)).unwrap();
+        let order = Arc::new(Mutex::new(Vec::new()));
+
+        let permit = bh.acquire::<&str>(Priority::Normal).await.unwrap();
+        let abandoned = {
+            let bh = bh.clone();
+            tokio::spawn(async move { bh.acquire::<&str>(Priority::Critical).await })
+        };
+        wait_for_waiters(&bh, 1).await;
+        let low = spawn_waiter(&bh, Priority::Low, &order);
+        wait_for_waiters(&bh, 2).await;
+
+        abandoned.abort();
+        let _ = abandoned.await;
+        assert_eq!(bh.waiting(), 1);
+
+        drop(permit);
+        low.await.unwrap();
+        assert_eq!(*order.lock(), vec![Priority::Low]);
+        assert_eq!(bh.active_operations(), 0);
+    }
+}
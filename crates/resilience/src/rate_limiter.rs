@@ -5,9 +5,10 @@
 //! | Implementation | Algorithm | Best for |
 //! |---|---|---|
 //! | [`TokenBucket`] | Token bucket with configurable refill rate | Bursty traffic with a steady average |
+//! | [`BurstingTokenBucket`] | Lock-free token bucket with idle burst headroom and a penalty box | Client-facing limits that absorb bursts but throttle abuse |
 //! | [`LeakyBucket`] | Leaky bucket with constant drain rate | Smoothing request bursts into a constant outflow |
 //! | [`SlidingWindow`] | Sliding time-window counter | Hard per-window request caps |
-//! | [`AdaptiveRateLimiter`] | Token bucket auto-tuned by error rate | Self-protecting services with variable load |
+//! | [`AdaptiveRateLimiter`] | Token bucket auto-tuned by a [`RateAdaptationPolicy`] (error rate by default) | Self-protecting services with variable load |
 //! # Trait contract
 //!
 //! Every implementation must satisfy the [`RateLimiter`] contract:
@@ -86,7 +87,11 @@ use std::{
 
 use parking_lot::{Mutex, RwLock};
 
-use crate::{CallError, PolicyContext};
+use crate::{
+    CallError, PolicyContext,
+    clock::{Clock, SystemClock},
+    hedge::LatencyTracker,
+};
 
 fn retry_after_from_rate(units_needed: f64, units_per_second: f64) -> Option<Duration> {
     if !units_needed.is_finite() || !units_per_second.is_finite() || units_per_second <= 0.0 {
@@ -118,6 +123,7 @@ pub(crate) fn map_acquire_error<E>(err: CallError<()>) -> CallError<E> {
         CallError::Timeout(duration) => CallError::Timeout(duration),
         CallError::CircuitOpen => CallError::CircuitOpen,
         CallError::BulkheadFull => CallError::BulkheadFull,
+        CallError::BulkheadTimeout(duration) => CallError::BulkheadTimeout(duration),
         CallError::Cancelled { reason } => CallError::Cancelled { reason },
         CallError::LoadShed => CallError::LoadShed,
         CallError::FallbackFailed { reason } => CallError::FallbackFailed { reason },
@@ -127,7 +133,65 @@ pub(crate) fn map_acquire_error<E>(err: CallError<()>) -> CallError<E> {
                 fallback: Box::new(map_acquire_error(*fallback)),
             }
         },
-        CallError::Operation(()) | CallError::RetriesExhausted { .. } => CallError::rate_limited(),
+        CallError::Operation(())
+        | CallError::RetriesExhausted { .. }
+        | CallError::BudgetExhausted { .. } => CallError::rate_limited(),
+    }
+}
+
+/// Sleep used by [`wait_for_permit`] when a rejection carries no `retry_after` hint.
+const UNHINTED_RETRY_DELAY: Duration = Duration::from_millis(10);
+
+/// Default [`RateLimiter::acquire_wait`] / [`RateLimiter::acquire_timeout`]:
+/// retry `acquire`, sleeping for each rejection's `retry_after` hint.
+///
+/// Gives up with [`CallError::Timeout`] as soon as the next permit is known
+/// to arrive after the deadline, rather than sleeping until it.
+async fn wait_for_permit<L>(limiter: &L, timeout: Option<Duration>) -> Result<(), CallError<()>>
+where
+    L: RateLimiter + ?Sized,
+{
+    let deadline = timeout.map(|timeout| (tokio::time::Instant::now() + timeout, timeout));
+    loop {
+        let retry_after = match limiter.acquire().await {
+            Err(CallError::RateLimited { retry_after }) => {
+                retry_after.unwrap_or(UNHINTED_RETRY_DELAY)
+            },
+            other => return other,
+        };
+        if let Some((deadline, timeout)) = deadline
+            && tokio::time::Instant::now() + retry_after > deadline
+        {
+            return Err(CallError::Timeout(timeout));
+        }
+        tokio::time::sleep(retry_after).await;
+    }
+}
+
+/// A permit reserved ahead of time by a waiting `acquire_wait`, handed back
+/// if the waiter is dropped before its turn.
+struct Reservation<F: FnMut()> {
+    release: Option<F>,
+}
+
+impl<F: FnMut()> Reservation<F> {
+    const fn new(release: F) -> Self {
+        Self {
+            release: Some(release),
+        }
+    }
+
+    /// The waiter reached its turn; keep the permit.
+    fn keep(mut self) {
+        self.release = None;
+    }
+}
+
+impl<F: FnMut()> Drop for Reservation<F> {
+    fn drop(&mut self) {
+        if let Some(release) = self.release.as_mut() {
+            release();
+        }
     }
 }
 
@@ -176,6 +240,30 @@ pub trait RateLimiter: Send + Sync {
         async move { context.run_result(self.acquire()).await }
     }
 
+    /// Wait until a permit is available, then consume it.
+    ///
+    /// Unlike [`acquire()`](Self::acquire), a rejection is not returned: the
+    /// caller sleeps until the limiter says the next permit is due. The default
+    /// implementation sleeps for each rejection's `retry_after` hint and tries
+    /// again; [`TokenBucket`] and [`SlidingWindow`] override it to reserve the
+    /// next free permit up front, so waiters are served in arrival order.
+    /// Dropping the future gives back a reserved permit.
+    fn acquire_wait(&self) -> impl Future<Output = Result<(), CallError<()>>> + Send {
+        wait_for_permit(self, None)
+    }
+
+    /// Like [`acquire_wait()`](Self::acquire_wait), but give up with
+    /// [`CallError::Timeout`] if no permit is available within `timeout`.
+    ///
+    /// Fails right away, without sleeping, when the next permit is already
+    /// known to arrive after `timeout`.
+    fn acquire_timeout(
+        &self,
+        timeout: Duration,
+    ) -> impl Future<Output = Result<(), CallError<()>>> + Send {
+        wait_for_permit(self, Some(timeout))
+    }
+
     /// Acquire a permit and, if successful, execute `operation`.
     ///
     /// Returns <code>Err([`CallError::RateLimited`])</code> without calling `operation`
@@ -261,6 +349,15 @@ pub trait ErasedRateLimiter: Send + Sync {
         Box::pin(context.run_result(self.acquire_boxed()))
     }
 
+    /// Wait until a permit is available, then consume it.
+    fn acquire_wait_boxed(&self) -> BoxRateLimiterFuture<'_, Result<(), CallError<()>>>;
+
+    /// Wait up to `timeout` for a permit, then consume it.
+    fn acquire_timeout_boxed(
+        &self,
+        timeout: Duration,
+    ) -> BoxRateLimiterFuture<'_, Result<(), CallError<()>>>;
+
     /// Returns the current rate or available capacity (implementation-dependent).
     fn current_rate_boxed(&self) -> BoxRateLimiterFuture<'_, f64>;
 
@@ -283,6 +380,17 @@ where
         Box::pin(self.acquire_with_policy_context(context))
     }
 
+    fn acquire_wait_boxed(&self) -> BoxRateLimiterFuture<'_, Result<(), CallError<()>>> {
+        Box::pin(self.acquire_wait())
+    }
+
+    fn acquire_timeout_boxed(
+        &self,
+        timeout: Duration,
+    ) -> BoxRateLimiterFuture<'_, Result<(), CallError<()>>> {
+        Box::pin(self.acquire_timeout(timeout))
+    }
+
     fn current_rate_boxed(&self) -> BoxRateLimiterFuture<'_, f64> {
         Box::pin(self.current_rate())
     }
@@ -414,24 +522,54 @@ impl TokenBucket {
         self.burst_size
             .store(new_burst.clamp(1, 100_000), Ordering::Release);
     }
-}
 
-impl RateLimiter for TokenBucket {
+    /// Add the tokens refilled since the last update; returns the refill rate.
     // Reason: usize burst_size cast to f64 for token math — acceptable for rate limiting.
     #[expect(
         clippy::cast_precision_loss,
         reason = "usize burst_size cast to f64 for token math — acceptable for rate limiting"
     )]
-    async fn acquire(&self) -> Result<(), CallError<()>> {
-        let mut state = self.state.lock();
-
+    fn refill_locked(&self, state: &mut TokenBucketState) -> f64 {
         let now = Instant::now();
         let elapsed = now.duration_since(state.last_refill).as_secs_f64();
         let refill_rate = f64::from_bits(self.refill_rate.load(Ordering::Acquire));
         let burst = self.burst_size.load(Ordering::Acquire);
-        let tokens_to_add = elapsed * refill_rate;
-        state.tokens = (state.tokens + tokens_to_add).min(burst as f64);
+        state.tokens = elapsed.mul_add(refill_rate, state.tokens).min(burst as f64);
         state.last_refill = now;
+        refill_rate
+    }
+
+    /// Take the next token, going into debt if none is left, and sleep until
+    /// the refill covers it. Waiters queue up behind each other's debt, so they
+    /// are served in arrival order, and [`acquire`](RateLimiter::acquire) cannot
+    /// jump the queue.
+    async fn reserve(&self, timeout: Option<Duration>) -> Result<(), CallError<()>> {
+        let wait = {
+            let mut state = self.state.lock();
+            let refill_rate = self.refill_locked(&mut state);
+            let wait =
+                retry_after_from_rate(1.0 - state.tokens, refill_rate).unwrap_or(Duration::ZERO);
+            if let Some(timeout) = timeout.filter(|timeout| wait > *timeout) {
+                return Err(CallError::Timeout(timeout));
+            }
+            state.tokens -= 1.0;
+            wait
+        };
+        if wait.is_zero() {
+            return Ok(());
+        }
+
+        let reservation = Reservation::new(|| self.state.lock().tokens += 1.0);
+        tokio::time::sleep(wait).await;
+        reservation.keep();
+        Ok(())
+    }
+}
+
+impl RateLimiter for TokenBucket {
+    async fn acquire(&self) -> Result<(), CallError<()>> {
+        let mut state = self.state.lock();
+        let refill_rate = self.refill_locked(&mut state);
 
         if state.tokens >= 1.0 {
             state.tokens -= 1.0;
@@ -444,6 +582,14 @@ impl RateLimiter for TokenBucket {
         }
     }
 
+    async fn acquire_wait(&self) -> Result<(), CallError<()>> {
+        self.reserve(None).await
+    }
+
+    async fn acquire_timeout(&self, timeout: Duration) -> Result<(), CallError<()>> {
+        self.reserve(Some(timeout)).await
+    }
+
     // Reason: usize burst_size cast to f64 for token math — acceptable for rate limiting.
     #[expect(
         clippy::cast_precision_loss,
@@ -472,6 +618,248 @@ impl RateLimiter for TokenBucket {
     }
 }
 
+// ═══════════════════════════════════════════════════════════════════════════════
+// BURSTING TOKEN BUCKET
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Token bucket that absorbs bursts after idle periods and puts abusive
+/// callers in a **penalty box**.
+///
+/// The bucket starts at its steady-state fill of `capacity` tokens and
+/// refills at `refill_rate` tokens per second. While idle it keeps
+/// accumulating up to `burst_capacity` extra tokens, so a caller that has
+/// been quiet can fire `capacity + burst_capacity` requests at once — a page
+/// load fanning out 20 parallel requests, say.
+///
+/// A request that arrives with no token left is rejected **and** charged
+/// `penalty_factor` tokens, driving the balance negative. While the balance
+/// is negative the limiter is [penalized](Self::is_penalized); it recovers
+/// at the normal refill rate, so one excess request keeps it closed for
+/// `penalty_factor / refill_rate` seconds. The debt is capped at a single
+/// penalty below empty: a caller that keeps hammering stays throttled, since
+/// each rejection restarts that penalty, but once it backs off it recovers
+/// within `(penalty_factor + 1) / refill_rate` seconds however long it
+/// hammered.
+///
+/// # Lock-free state
+///
+/// The token balance lives in a single [`AtomicU64`] as a GCRA-style
+/// theoretical arrival time: the instant (in nanoseconds since the limiter
+/// was created) at which the bucket would be full again. `acquire()` is one
+/// compare-and-swap loop over that value; no lock is taken.
+///
+/// # Examples
+///
+/// ```rust
+/// use nebula_resilience::{RateLimiter, rate_limiter::BurstingTokenBucket};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// // 10 tokens steady, up to 20 more banked while idle, 5 req/s refill;
+/// // each excess request costs 3 tokens.
+/// let limiter = BurstingTokenBucket::new(10, 5.0)
+///     .unwrap()
+///     .with_burst_capacity(20)
+///     .with_penalty_factor(3.0);
+///
+/// limiter.acquire().await.expect("steady-state token available");
+/// assert!(!limiter.is_penalized());
+/// # }
+/// ```
+pub struct BurstingTokenBucket {
+    /// Steady-state fill: tokens available on creation and after `reset`.
+    capacity: usize,
+    /// Extra tokens that can accumulate on top of `capacity` while idle.
+    burst_capacity: usize,
+    /// Tokens refilled per second.
+    refill_rate: f64,
+    /// Tokens charged for each request rejected with an empty bucket.
+    penalty_factor: f64,
+    /// Nanoseconds to refill one token (`1 / refill_rate`).
+    emission_nanos: u64,
+    /// Theoretical arrival time, in nanoseconds since `anchor`.
+    tat: AtomicU64,
+    anchor: Instant,
+    clock: Arc<dyn Clock>,
+}
+
+impl fmt::Debug for BurstingTokenBucket {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BurstingTokenBucket")
+            .field("capacity", &self.capacity)
+            .field("burst_capacity", &self.burst_capacity)
+            .field("refill_rate", &self.refill_rate)
+            .field("penalty_factor", &self.penalty_factor)
+            .field("penalized", &self.is_penalized())
+            .finish_non_exhaustive()
+    }
+}
+
+impl BurstingTokenBucket {
+    /// Create a bucket with `capacity` steady-state tokens refilled at
+    /// `refill_rate` per second. No burst headroom and no penalty until
+    /// configured through the builder methods.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ConfigError)` if `capacity` is 0 or > 100,000,
+    /// or `refill_rate` is outside 0.001..=10,000.0.
+    pub fn new(capacity: usize, refill_rate: f64) -> Result<Self, crate::ConfigError> {
+        if capacity == 0 || capacity > 100_000 {
+            return Err(crate::ConfigError::new("capacity", "must be 1..=100,000"));
+        }
+        if !(0.001..=10_000.0).contains(&refill_rate) {
+            return Err(crate::ConfigError::new(
+                "refill_rate",
+                "must be 0.001..=10,000.0",
+            ));
+        }
+        let clock: Arc<dyn Clock> = Arc::new(SystemClock);
+        let limiter = Self {
+            capacity,
+            burst_capacity: 0,
+            refill_rate,
+            penalty_factor: 0.0,
+            emission_nanos: duration_as_nanos_u64(Duration::from_secs_f64(refill_rate.recip()))
+                .max(1),
+            tat: AtomicU64::new(0),
+            anchor: clock.now(),
+            clock,
+        };
+        limiter.refill_to_steady_state();
+        Ok(limiter)
+    }
+
+    /// Set how many tokens above `capacity` may accumulate while idle
+    /// (clamped to `0..=100,000`).
+    #[must_use = "builder methods must be chained or built"]
+    pub fn with_burst_capacity(mut self, burst_capacity: usize) -> Self {
+        self.burst_capacity = burst_capacity.min(100_000);
+        self.refill_to_steady_state();
+        self
+    }
+
+    /// Set the tokens charged per request rejected with an empty bucket
+    /// (clamped to `0.0..=1,000.0`; `NaN` is treated as `0.0`, which
+    /// disables the penalty box).
+    #[must_use = "builder methods must be chained or built"]
+    pub const fn with_penalty_factor(mut self, penalty_factor: f64) -> Self {
+        self.penalty_factor = if penalty_factor.is_nan() {
+            0.0
+        } else {
+            penalty_factor.clamp(0.0, 1_000.0)
+        };
+        self
+    }
+
+    /// Replace the clock (builder-style, for testing). Restarts the bucket
+    /// at its steady-state fill on the new clock.
+    #[must_use = "builder methods must be chained or built"]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.anchor = clock.now();
+        self.clock = clock;
+        self.refill_to_steady_state();
+        self
+    }
+
+    /// Whether the bucket is in the penalty box: its token balance is
+    /// negative because of rejected excess requests.
+    #[must_use]
+    pub fn is_penalized(&self) -> bool {
+        self.debt_nanos() > self.window_nanos()
+    }
+
+    /// Nanoseconds of refill owed right now: zero for a full bucket, the
+    /// full window for an empty one, more while penalized.
+    fn debt_nanos(&self) -> u64 {
+        self.tat
+            .load(Ordering::Acquire)
+            .saturating_sub(self.now_nanos())
+    }
+
+    /// Nanoseconds since `anchor` on the limiter's clock.
+    fn now_nanos(&self) -> u64 {
+        duration_as_nanos_u64(self.clock.now().saturating_duration_since(self.anchor))
+    }
+
+    /// Refill time of a completely full bucket (`capacity + burst_capacity`
+    /// tokens).
+    fn window_nanos(&self) -> u64 {
+        self.whole_tokens_to_nanos(self.capacity + self.burst_capacity)
+    }
+
+    fn whole_tokens_to_nanos(&self, tokens: usize) -> u64 {
+        u64::try_from(tokens)
+            .unwrap_or(u64::MAX)
+            .saturating_mul(self.emission_nanos)
+    }
+
+    #[expect(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss,
+        reason = "penalty_factor is clamped to 0..=1,000; saturating float-to-int cast"
+    )]
+    fn penalty_nanos(&self) -> u64 {
+        (self.penalty_factor * self.emission_nanos as f64) as u64
+    }
+
+    /// Reset to `capacity` tokens: `burst_capacity` tokens short of full.
+    fn refill_to_steady_state(&self) {
+        let tat = self
+            .now_nanos()
+            .saturating_add(self.whole_tokens_to_nanos(self.burst_capacity));
+        self.tat.store(tat, Ordering::Release);
+    }
+}
+
+impl RateLimiter for BurstingTokenBucket {
+    async fn acquire(&self) -> Result<(), CallError<()>> {
+        let now = self.now_nanos();
+        let window = self.window_nanos();
+        let emission = self.emission_nanos;
+        let penalty = self.penalty_nanos();
+        // Debt never exceeds an empty bucket plus one penalty, so a flood of
+        // rejected requests cannot push the lockout further out than that.
+        let max_tat = now.saturating_add(window).saturating_add(penalty);
+        let mut tat = self.tat.load(Ordering::Acquire);
+        loop {
+            let base = tat.max(now);
+            let admitted = (base - now).saturating_add(emission) <= window;
+            let next = if admitted {
+                base.saturating_add(emission)
+            } else {
+                base.saturating_add(penalty).min(max_tat)
+            };
+            match self
+                .tat
+                .compare_exchange_weak(tat, next, Ordering::AcqRel, Ordering::Acquire)
+            {
+                Ok(_) if admitted => return Ok(()),
+                Ok(_) => {
+                    // Time until the balance (after this penalty) reaches one token.
+                    let wait = (next - now).saturating_add(emission).saturating_sub(window);
+                    return Err(CallError::rate_limited_after(Duration::from_nanos(wait)));
+                },
+                Err(current) => tat = current,
+            }
+        }
+    }
+
+    /// Returns the current token balance — negative while penalized.
+    #[expect(
+        clippy::cast_precision_loss,
+        reason = "nanosecond offsets fit f64 closely enough for a gauge"
+    )]
+    async fn current_rate(&self) -> f64 {
+        (self.window_nanos() as f64 - self.debt_nanos() as f64) / self.emission_nanos as f64
+    }
+
+    async fn reset(&self) {
+        self.refill_to_steady_state();
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════════════
 // LEAKY BUCKET
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -750,6 +1138,43 @@ impl SlidingWindow {
                 .unwrap_or(Duration::ZERO),
         )
     }
+
+    /// Claim the earliest free slot — now, or when the entry `max_requests`
+    /// places back leaves the window — and sleep until it. The slot is logged
+    /// right away, so later waiters queue behind it in arrival order.
+    async fn reserve(&self, timeout: Option<Duration>) -> Result<(), CallError<()>> {
+        let now = Instant::now();
+        let (slot, wait) = {
+            let mut requests = self.requests.lock();
+            let cutoff = now.checked_sub(self.window_duration).unwrap_or(now);
+            Self::clean_old_requests_locked(&mut requests, cutoff);
+            let slot = requests
+                .len()
+                .checked_sub(self.max_requests)
+                .and_then(|index| requests[index].checked_add(self.window_duration))
+                .unwrap_or(now);
+            let wait = slot.saturating_duration_since(now);
+            if let Some(timeout) = timeout.filter(|timeout| wait > *timeout) {
+                return Err(CallError::Timeout(timeout));
+            }
+            requests.push_back(slot);
+            drop(requests);
+            (slot, wait)
+        };
+        if wait.is_zero() {
+            return Ok(());
+        }
+
+        let reservation = Reservation::new(|| {
+            let mut requests = self.requests.lock();
+            if let Some(index) = requests.iter().rposition(|&at| at == slot) {
+                requests.remove(index);
+            }
+        });
+        tokio::time::sleep(wait).await;
+        reservation.keep();
+        Ok(())
+    }
 }
 
 impl RateLimiter for SlidingWindow {
@@ -775,6 +1200,14 @@ impl RateLimiter for SlidingWindow {
         }
     }
 
+    async fn acquire_wait(&self) -> Result<(), CallError<()>> {
+        self.reserve(None).await
+    }
+
+    async fn acquire_timeout(&self, timeout: Duration) -> Result<(), CallError<()>> {
+        self.reserve(Some(timeout)).await
+    }
+
     // Reason: usize request count cast to f64 — acceptable for rate reporting.
     #[expect(
         clippy::cast_precision_loss,
@@ -801,6 +1234,245 @@ impl RateLimiter for SlidingWindow {
 // ADAPTIVE RATE LIMITER
 // ═══════════════════════════════════════════════════════════════════════════════
 
+/// Outcomes an [`AdaptiveRateLimiter`] observed over one stats window.
+///
+/// Handed to [`RateAdaptationPolicy::adjust`] when the window elapses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimiterStats {
+    /// Successful operations recorded in the window.
+    pub successes: u64,
+    /// Failed operations recorded in the window.
+    pub errors: u64,
+    /// Mean latency of the operations run through
+    /// [`call()`](RateLimiter::call) or reported with
+    /// [`record_outcome()`](AdaptiveRateLimiter::record_outcome) in the window;
+    /// `None` when every outcome came from
+    /// [`record_success()`](AdaptiveRateLimiter::record_success) /
+    /// [`record_error()`](AdaptiveRateLimiter::record_error).
+    pub mean_latency: Option<Duration>,
+    /// 95th percentile of the same latencies, over at most the last
+    /// 1024 samples of the window.
+    pub p95_latency: Option<Duration>,
+    /// How long the window actually lasted.
+    pub window: Duration,
+}
+
+impl RateLimiterStats {
+    /// Total outcomes recorded in the window.
+    #[must_use]
+    pub const fn total(&self) -> u64 {
+        self.successes + self.errors
+    }
+
+    /// Fraction of failed outcomes, or `None` if nothing was recorded.
+    #[must_use]
+    // Reason: u64 counts cast to f64 for a ratio — precision loss is irrelevant here.
+    #[expect(clippy::cast_precision_loss)]
+    pub fn error_rate(&self) -> Option<f64> {
+        let total = self.total();
+        (total > 0).then(|| self.errors as f64 / total as f64)
+    }
+}
+
+/// Decides the next rate of an [`AdaptiveRateLimiter`].
+///
+/// Called once per stats window with the current rate and the window's
+/// outcomes. The returned rate is clamped to the limiter's
+/// `[min_rate, max_rate]`; a non-finite result keeps the current rate.
+/// Windows with no recorded outcomes are skipped.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use nebula_resilience::rate_limiter::{
+///     AdaptiveRateLimiter, RateAdaptationPolicy, RateLimiterStats,
+/// };
+///
+/// /// Halve the rate when the downstream slows past 200 ms, else add 5 req/s.
+/// struct LatencyPolicy;
+///
+/// impl RateAdaptationPolicy for LatencyPolicy {
+///     fn adjust(&self, current: f64, recent: &RateLimiterStats) -> f64 {
+///         match recent.mean_latency {
+///             Some(latency) if latency > Duration::from_millis(200) => current / 2.0,
+///             _ => current + 5.0,
+///         }
+///     }
+/// }
+///
+/// let limiter = AdaptiveRateLimiter::new(50.0, 10.0, 100.0)
+///     .expect("valid config")
+///     .with_policy(LatencyPolicy);
+/// ```
+pub trait RateAdaptationPolicy: Send + Sync {
+    /// Return the rate (tokens/second) to use for the next window.
+    fn adjust(&self, current: f64, recent: &RateLimiterStats) -> f64;
+}
+
+/// The default [`RateAdaptationPolicy`], driven by the error rate and,
+/// optionally, the p95 latency.
+///
+/// With the default settings:
+///
+/// | Window | Next rate |
+/// |---|---|
+/// | Error rate > 10 % | `current * 0.9` |
+/// | Error rate < 1 % | `current * 1.1` |
+/// | otherwise | `current` |
+///
+/// [`with_increase_step()`](Self::with_increase_step) turns the increase into
+/// a classic additive one, [`with_decrease_factor()`](Self::with_decrease_factor)
+/// and [`with_error_rate_threshold()`](Self::with_error_rate_threshold) tune
+/// the cut, and [`with_p95_latency_threshold()`](Self::with_p95_latency_threshold)
+/// also cuts whenever [`RateLimiterStats::p95_latency`] exceeds the limit. The
+/// result is clamped to the limiter's `[min_rate, max_rate]`.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use nebula_resilience::rate_limiter::{AdaptiveRateLimiter, AimdPolicy};
+///
+/// // +2 req/s per healthy window; halve on 5 % errors or p95 above 250 ms.
+/// let policy = AimdPolicy::new()
+///     .with_increase_step(2.0)
+///     .and_then(|p| p.with_decrease_factor(0.5))
+///     .and_then(|p| p.with_error_rate_threshold(0.05))
+///     .expect("valid policy")
+///     .with_p95_latency_threshold(Duration::from_millis(250));
+///
+/// let limiter = AdaptiveRateLimiter::new(50.0, 10.0, 100.0)
+///     .expect("valid config")
+///     .with_policy(policy);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AimdPolicy {
+    increase_factor: f64,
+    increase_step: f64,
+    decrease_factor: f64,
+    error_rate_threshold: f64,
+    healthy_error_rate: f64,
+    p95_latency_threshold: Option<Duration>,
+}
+
+impl AimdPolicy {
+    /// Policy with the default settings from the table above.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            increase_factor: 1.1,
+            increase_step: 0.0,
+            decrease_factor: 0.9,
+            error_rate_threshold: 0.1,
+            healthy_error_rate: 0.01,
+            p95_latency_threshold: None,
+        }
+    }
+
+    /// Add `step` tokens/second per healthy window instead of growing by 10 %.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ConfigError)` if `step` is not finite and positive.
+    pub fn with_increase_step(mut self, step: f64) -> Result<Self, crate::ConfigError> {
+        if !step.is_finite() || step <= 0.0 {
+            return Err(crate::ConfigError::new(
+                "increase_step",
+                "must be finite and > 0",
+            ));
+        }
+        self.increase_factor = 1.0;
+        self.increase_step = step;
+        Ok(self)
+    }
+
+    /// Multiply the rate by `factor` when a threshold is crossed (default: `0.9`).
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ConfigError)` if `factor` is not within `(0.0, 1.0)`.
+    pub fn with_decrease_factor(mut self, factor: f64) -> Result<Self, crate::ConfigError> {
+        if !(factor > 0.0 && factor < 1.0) {
+            return Err(crate::ConfigError::new(
+                "decrease_factor",
+                "must be within (0.0, 1.0)",
+            ));
+        }
+        self.decrease_factor = factor;
+        Ok(self)
+    }
+
+    /// Cut the rate when the window's error rate exceeds `rate` (default: `0.1`).
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ConfigError)` if `rate` is not within `(0.0, 1.0]`.
+    pub fn with_error_rate_threshold(mut self, rate: f64) -> Result<Self, crate::ConfigError> {
+        if !(rate > 0.0 && rate <= 1.0) {
+            return Err(crate::ConfigError::new(
+                "error_rate_threshold",
+                "must be within (0.0, 1.0]",
+            ));
+        }
+        self.error_rate_threshold = rate;
+        self.healthy_error_rate = self.healthy_error_rate.min(rate);
+        Ok(self)
+    }
+
+    /// Also cut the rate when the window's p95 latency exceeds `limit`.
+    ///
+    /// Windows without latency samples never trip this threshold.
+    #[must_use]
+    pub const fn with_p95_latency_threshold(mut self, limit: Duration) -> Self {
+        self.p95_latency_threshold = Some(limit);
+        self
+    }
+}
+
+impl Default for AimdPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RateAdaptationPolicy for AimdPolicy {
+    fn adjust(&self, current: f64, recent: &RateLimiterStats) -> f64 {
+        let too_slow = self
+            .p95_latency_threshold
+            .zip(recent.p95_latency)
+            .is_some_and(|(limit, p95)| p95 > limit);
+        match recent.error_rate() {
+            _ if too_slow => current * self.decrease_factor,
+            Some(rate) if rate > self.error_rate_threshold => current * self.decrease_factor,
+            Some(rate) if rate < self.healthy_error_rate => {
+                current.mul_add(self.increase_factor, self.increase_step)
+            },
+            _ => current,
+        }
+    }
+}
+
+/// Snapshot of an [`AdaptiveRateLimiter`], as returned by
+/// [`stats()`](AdaptiveRateLimiter::stats).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AdaptiveRateLimiterStats {
+    /// Current refill rate (tokens/second).
+    pub current_rate: f64,
+    /// Lower bound the policy's output is clamped to.
+    pub min_rate: f64,
+    /// Upper bound the policy's output is clamped to.
+    pub max_rate: f64,
+    /// Outcomes recorded so far in the current stats window; the policy sees
+    /// them once the window elapses.
+    pub recent: RateLimiterStats,
+}
+
+/// Latency samples an [`AdaptiveRateLimiter`] keeps per window for the p95.
+const LATENCY_SAMPLE_CAPACITY: usize = 1024;
+
 /// Mutable state behind a single lock — only fields that need coordinated mutation.
 struct AdaptiveState {
     inner: Arc<TokenBucket>,
@@ -813,8 +1485,9 @@ struct AdaptiveState {
 ///
 /// Wraps a [`TokenBucket`] and periodically adjusts its refill rate based on
 /// the ratio of successful to failed operations recorded via
+/// [`record_outcome()`](Self::record_outcome),
 /// [`record_success()`](Self::record_success) and
-/// [`record_error()`](Self::record_error):
+/// [`record_error()`](Self::record_error). The default [`AimdPolicy`]:
 ///
 /// | Condition | Action |
 /// |---|---|
@@ -822,14 +1495,21 @@ struct AdaptiveState {
 /// | Error rate < 1 % | Increase rate by 10 % (ceiling: `max_rate`) |
 /// | 1 % ≤ error rate ≤ 10 % | No change |
 ///
+/// Configure the [`AimdPolicy`] thresholds, or plug in a different
+/// [`RateAdaptationPolicy`] with [`with_policy()`](Self::with_policy), e.g. one
+/// keyed on [`RateLimiterStats::mean_latency`]. [`stats()`](Self::stats)
+/// reports the current rate and the window observed so far.
+///
 /// Adjustments happen at most once per stats window (default: 1 minute).
 /// Lock-free atomics are used for the counters and fast-path window check, so
 /// recording outcomes does not take the rate-adjustment lock until the window
 /// elapses.
 ///
 /// The [`call()`](RateLimiter::call) override automatically records success/
-/// error outcomes, so manual calls to `record_*` are only needed when you
-/// invoke `acquire()` directly.
+/// error outcomes, and so does a pipeline built with
+/// [`adaptive_rate_limiter()`](crate::pipeline::PipelineBuilder::adaptive_rate_limiter),
+/// so manual calls to `record_*` are only needed when you invoke `acquire()`
+/// directly.
 ///
 /// # Configuration
 ///
@@ -870,6 +1550,13 @@ pub struct AdaptiveRateLimiter {
     success_count: AtomicU64,
     /// Lock-free error counter — swapped to zero on adjustment.
     error_count: AtomicU64,
+    /// Summed latency (ns) of operations run through `call()` — swapped on adjustment.
+    latency_ns: AtomicU64,
+    /// Number of latency samples in `latency_ns` — swapped on adjustment.
+    latency_samples: AtomicU64,
+    /// Recent latencies of the window, for the p95 — replaced on adjustment.
+    latency_tracker: Mutex<LatencyTracker>,
+    policy: Arc<dyn RateAdaptationPolicy>,
     stats_window: Duration,
     min_rate: f64,
     max_rate: f64,
@@ -942,12 +1629,23 @@ impl AdaptiveRateLimiter {
             atomic_rate: AtomicU64::new(initial_rate.to_bits()),
             success_count: AtomicU64::new(0),
             error_count: AtomicU64::new(0),
+            latency_ns: AtomicU64::new(0),
+            latency_samples: AtomicU64::new(0),
+            latency_tracker: Mutex::new(LatencyTracker::new(LATENCY_SAMPLE_CAPACITY)),
+            policy: Arc::new(AimdPolicy::new()),
             stats_window,
             min_rate,
             max_rate,
         })
     }
 
+    /// Replace the adaptation policy (default: [`AimdPolicy`]).
+    #[must_use]
+    pub fn with_policy(mut self, policy: impl RateAdaptationPolicy + 'static) -> Self {
+        self.policy = Arc::new(policy);
+        self
+    }
+
     /// Try to adjust rate if stats window has elapsed.
     ///
     /// Uses an atomic deadline for the fast path (window not yet elapsed) and
@@ -976,9 +1674,8 @@ impl AdaptiveRateLimiter {
         let mut state = self.state.write();
         // Double-check after acquiring write lock (another thread may have adjusted)
         if state.last_stats_reset.elapsed() >= self.stats_window {
-            let success = self.success_count.swap(0, Ordering::Relaxed);
-            let error = self.error_count.swap(0, Ordering::Relaxed);
-            self.do_adjust_rate(&mut state, success, error);
+            let recent = self.take_stats(&state);
+            self.do_adjust_rate(&mut state, &recent);
         }
 
         let elapsed_ns = duration_as_nanos_u64(self.adjustment_origin.elapsed());
@@ -988,23 +1685,58 @@ impl AdaptiveRateLimiter {
         drop(state);
     }
 
+    /// Drain the window counters into a [`RateLimiterStats`]. Caller must hold the write lock.
+    fn take_stats(&self, state: &AdaptiveState) -> RateLimiterStats {
+        let latency_ns = self.latency_ns.swap(0, Ordering::Relaxed);
+        let latency_samples = self.latency_samples.swap(0, Ordering::Relaxed);
+        let tracker = std::mem::replace(
+            &mut *self.latency_tracker.lock(),
+            LatencyTracker::new(LATENCY_SAMPLE_CAPACITY),
+        );
+        RateLimiterStats {
+            successes: self.success_count.swap(0, Ordering::Relaxed),
+            errors: self.error_count.swap(0, Ordering::Relaxed),
+            mean_latency: latency_ns
+                .checked_div(latency_samples)
+                .map(Duration::from_nanos),
+            p95_latency: tracker.percentile(0.95),
+            window: state.last_stats_reset.elapsed(),
+        }
+    }
+
+    /// Current rate, bounds and the stats window observed so far.
+    ///
+    /// Reading does not reset the window, so this is safe to poll for graphs.
+    #[must_use]
+    pub fn stats(&self) -> AdaptiveRateLimiterStats {
+        let window = self.state.read().last_stats_reset.elapsed();
+        let latency_ns = self.latency_ns.load(Ordering::Relaxed);
+        let latency_samples = self.latency_samples.load(Ordering::Relaxed);
+        AdaptiveRateLimiterStats {
+            current_rate: f64::from_bits(self.atomic_rate.load(Ordering::Acquire)),
+            min_rate: self.min_rate,
+            max_rate: self.max_rate,
+            recent: RateLimiterStats {
+                successes: self.success_count.load(Ordering::Relaxed),
+                errors: self.error_count.load(Ordering::Relaxed),
+                mean_latency: latency_ns
+                    .checked_div(latency_samples)
+                    .map(Duration::from_nanos),
+                p95_latency: self.latency_tracker.lock().percentile(0.95),
+                window,
+            },
+        }
+    }
+
     /// Perform the rate adjustment. Caller must hold the write lock.
-    // Reason: u64 counts cast to f64 for rate calculation, and f64 rate cast to usize for
-    // token bucket capacity — acceptable for approximate rate limiting.
-    #[expect(
-        clippy::cast_precision_loss,
-        clippy::cast_possible_truncation,
-        clippy::cast_sign_loss
-    )]
-    fn do_adjust_rate(&self, state: &mut AdaptiveState, success: u64, error: u64) {
-        let total = success + error;
-        if total > 0 {
-            let error_rate = error as f64 / total as f64;
-
-            if error_rate > 0.1 {
-                state.current_rate = (state.current_rate * 0.9).max(self.min_rate);
-            } else if error_rate < 0.01 {
-                state.current_rate = (state.current_rate * 1.1).min(self.max_rate);
+    // Reason: f64 rate cast to usize for token bucket capacity — acceptable for
+    // approximate rate limiting.
+    #[expect(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn do_adjust_rate(&self, state: &mut AdaptiveState, recent: &RateLimiterStats) {
+        if recent.total() > 0 {
+            let next = self.policy.adjust(state.current_rate, recent);
+            if next.is_finite() {
+                state.current_rate = next.clamp(self.min_rate, self.max_rate);
             }
 
             // Update rate and burst capacity in-place to stay in sync.
@@ -1030,6 +1762,23 @@ impl AdaptiveRateLimiter {
         self.error_count.fetch_add(1, Ordering::Relaxed);
         self.maybe_adjust_rate();
     }
+
+    /// Record the outcome and latency of an operation gated by this limiter.
+    ///
+    /// Feeds [`RateLimiterStats::mean_latency`] and
+    /// [`RateLimiterStats::p95_latency`] on top of the success/error counts.
+    /// [`call()`](RateLimiter::call) records its own operations this way.
+    pub fn record_outcome(&self, latency: Duration, success: bool) {
+        self.latency_ns
+            .fetch_add(duration_as_nanos_u64(latency), Ordering::Relaxed);
+        self.latency_samples.fetch_add(1, Ordering::Relaxed);
+        self.latency_tracker.lock().record(latency);
+        if success {
+            self.record_success();
+        } else {
+            self.record_error();
+        }
+    }
 }
 
 impl RateLimiter for AdaptiveRateLimiter {
@@ -1049,12 +1798,9 @@ impl RateLimiter for AdaptiveRateLimiter {
         T: Send,
     {
         self.acquire().await.map_err(map_acquire_error)?;
+        let started = Instant::now();
         let result = operation().await;
-
-        match &result {
-            Ok(_) => self.record_success(),
-            Err(_) => self.record_error(),
-        }
+        self.record_outcome(started.elapsed(), result.is_ok());
 
         result.map_err(CallError::Operation)
     }
@@ -1074,14 +1820,11 @@ impl RateLimiter for AdaptiveRateLimiter {
             .await
             .map_err(map_acquire_error)?;
 
+        let started = Instant::now();
         let result = context
             .run_result(async { operation().await.map_err(CallError::Operation) })
             .await;
-
-        match &result {
-            Ok(_) => self.record_success(),
-            Err(_) => self.record_error(),
-        }
+        self.record_outcome(started.elapsed(), result.is_ok());
 
         result
     }
@@ -1095,6 +1838,9 @@ impl RateLimiter for AdaptiveRateLimiter {
     async fn reset(&self) {
         self.success_count.store(0, Ordering::Relaxed);
         self.error_count.store(0, Ordering::Relaxed);
+        self.latency_ns.store(0, Ordering::Relaxed);
+        self.latency_samples.store(0, Ordering::Relaxed);
+        *self.latency_tracker.lock() = LatencyTracker::new(LATENCY_SAMPLE_CAPACITY);
         let mut state = self.state.write();
         state.last_stats_reset = Instant::now();
         let elapsed_ns = duration_as_nanos_u64(self.adjustment_origin.elapsed());
@@ -1133,7 +1879,7 @@ mod tests {
     };
 
     use super::*;
-    use crate::PolicyContext;
+    use crate::{PolicyContext, clock::MockClock};
 
     #[tokio::test]
     async fn token_bucket_respects_capacity() {
@@ -1290,6 +2036,142 @@ mod tests {
         assert!(registry[0].acquire_boxed().await.is_ok());
     }
 
+    fn bursting_bucket(capacity: usize, rate: f64, clock: &Arc<MockClock>) -> BurstingTokenBucket {
+        BurstingTokenBucket::new(capacity, rate)
+            .unwrap()
+            .with_clock(Arc::clone(clock) as Arc<dyn Clock>)
+    }
+
+    #[tokio::test]
+    async fn bursting_bucket_absorbs_burst_after_idle() {
+        let clock = Arc::new(MockClock::new());
+        let limiter = bursting_bucket(5, 10.0, &clock).with_burst_capacity(15);
+
+        // Steady state: only `capacity` tokens until the bucket has idled.
+        for _ in 0..5 {
+            limiter.acquire().await.unwrap();
+        }
+        assert!(limiter.acquire().await.is_err());
+
+        // 2s idle refills 20 tokens: the steady 5 plus the full 15 of burst.
+        clock.advance(Duration::from_secs(2));
+        for _ in 0..20 {
+            limiter.acquire().await.unwrap();
+        }
+        assert!(limiter.acquire().await.is_err());
+        assert!(!limiter.is_penalized(), "no penalty_factor configured");
+    }
+
+    #[tokio::test]
+    async fn bursting_bucket_penalty_clears_after_refill_interval() {
+        let clock = Arc::new(MockClock::new());
+        let limiter = bursting_bucket(2, 10.0, &clock).with_penalty_factor(5.0);
+
+        limiter.acquire().await.unwrap();
+        limiter.acquire().await.unwrap();
+        assert!(!limiter.is_penalized(), "an empty bucket is not a penalty");
+
+        // The excess request costs 5 tokens: 500ms to clear, then 100ms more
+        // for the next whole token.
+        assert_eq!(
+            limiter.acquire().await,
+            Err(CallError::RateLimited {
+                retry_after: Some(Duration::from_millis(600))
+            })
+        );
+        assert!(limiter.is_penalized());
+        assert!((limiter.current_rate().await + 5.0).abs() < 1e-9);
+
+        clock.advance(Duration::from_millis(499));
+        assert!(limiter.is_penalized());
+        clock.advance(Duration::from_millis(1));
+        assert!(!limiter.is_penalized());
+
+        clock.advance(Duration::from_millis(100));
+        assert!(limiter.acquire().await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn bursting_bucket_repeated_excess_does_not_stack_penalty() {
+        let clock = Arc::new(MockClock::new());
+        let limiter = bursting_bucket(1, 10.0, &clock).with_penalty_factor(2.0);
+
+        limiter.acquire().await.unwrap();
+        for _ in 0..3 {
+            assert!(limiter.acquire().await.is_err());
+        }
+
+        // Three back-to-back excess requests still owe one penalty: 200ms.
+        clock.advance(Duration::from_millis(199));
+        assert!(limiter.is_penalized());
+        clock.advance(Duration::from_millis(1));
+        assert!(!limiter.is_penalized());
+
+        limiter.reset().await;
+        assert!(limiter.acquire().await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn bursting_bucket_recovers_from_flood_within_one_penalty() {
+        let clock = Arc::new(MockClock::new());
+        let limiter = bursting_bucket(2, 10.0, &clock).with_penalty_factor(5.0);
+
+        limiter.acquire().await.unwrap();
+        limiter.acquire().await.unwrap();
+        let mut last = Ok(());
+        for _ in 0..1_000 {
+            last = limiter.acquire().await;
+            clock.advance(Duration::from_millis(1));
+        }
+        // Each rejection restarts the penalty; it never accumulates beyond
+        // 500ms of debt plus 100ms for the next token.
+        assert_eq!(
+            last,
+            Err(CallError::RateLimited {
+                retry_after: Some(Duration::from_millis(600))
+            })
+        );
+
+        // Quiet period: the bound, measured from the last rejection.
+        clock.advance(Duration::from_millis(498));
+        assert!(limiter.is_penalized());
+        clock.advance(Duration::from_millis(1));
+        assert!(!limiter.is_penalized());
+        clock.advance(Duration::from_millis(100));
+        assert!(limiter.acquire().await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn bursting_bucket_admits_exactly_capacity_under_contention() {
+        async fn hammer(limiter: Arc<BurstingTokenBucket>) -> usize {
+            let mut admitted = 0;
+            for _ in 0..25 {
+                admitted += usize::from(limiter.acquire().await.is_ok());
+            }
+            admitted
+        }
+
+        let clock = Arc::new(MockClock::new());
+        let limiter = Arc::new(bursting_bucket(50, 0.001, &clock).with_penalty_factor(1.0));
+        let tasks: Vec<_> = (0..8)
+            .map(|_| tokio::spawn(hammer(Arc::clone(&limiter))))
+            .collect();
+        let mut admitted = 0;
+        for task in tasks {
+            admitted += task.await.unwrap();
+        }
+
+        assert_eq!(admitted, 50);
+        assert!(limiter.is_penalized());
+    }
+
+    #[test]
+    fn bursting_bucket_rejects_invalid_config() {
+        assert!(BurstingTokenBucket::new(0, 1.0).is_err());
+        assert!(BurstingTokenBucket::new(10, 0.0).is_err());
+        assert!(BurstingTokenBucket::new(10, f64::NAN).is_err());
+    }
+
     #[test]
     fn leaky_bucket_rejects_zero_capacity() {
         assert!(LeakyBucket::new(0, 1.0).is_err());
@@ -1445,4 +2327,218 @@ mod tests {
         let rate = limiter.current_rate().await;
         assert!((rate - 50.0).abs() < 0.001, "expected ~50.0, got {rate}");
     }
+    // ── Adaptation policy ────────────────────────────────────────────────
+
+    struct FixedPolicy(f64);
+
+    impl RateAdaptationPolicy for FixedPolicy {
+        fn adjust(&self, _current: f64, _recent: &RateLimiterStats) -> f64 {
+            self.0
+        }
+    }
+
+    fn window(successes: u64, errors: u64) -> RateLimiterStats {
+        RateLimiterStats {
+            successes,
+            errors,
+            mean_latency: None,
+            p95_latency: None,
+            window: Duration::from_mins(1),
+        }
+    }
+
+    /// Force one adjustment with `stats`, as if the stats window had elapsed.
+    fn adjust_once(limiter: &AdaptiveRateLimiter, recent: &RateLimiterStats) -> f64 {
+        let mut state = limiter.state.write();
+        limiter.do_adjust_rate(&mut state, recent);
+        state.current_rate
+    }
+
+    #[test]
+    fn aimd_policy_keeps_error_rate_thresholds() {
+        let policy = AimdPolicy::default();
+        assert!((policy.adjust(50.0, &window(80, 20)) - 45.0).abs() < 1e-9);
+        assert!((policy.adjust(50.0, &window(1000, 0)) - 55.0).abs() < 1e-9);
+        assert!((policy.adjust(50.0, &window(95, 5)) - 50.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn aimd_policy_adds_step_and_cuts_on_p95_latency() {
+        let policy = AimdPolicy::new()
+            .with_increase_step(2.0)
+            .and_then(|p| p.with_decrease_factor(0.5))
+            .unwrap()
+            .with_p95_latency_threshold(Duration::from_millis(100));
+        assert!((policy.adjust(50.0, &window(1000, 0)) - 52.0).abs() < 1e-9);
+        assert!((policy.adjust(50.0, &window(80, 20)) - 25.0).abs() < 1e-9);
+
+        let slow = RateLimiterStats {
+            p95_latency: Some(Duration::from_millis(150)),
+            ..window(1000, 0)
+        };
+        assert!((policy.adjust(50.0, &slow) - 25.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn aimd_policy_rejects_invalid_settings() {
+        assert!(AimdPolicy::new().with_increase_step(0.0).is_err());
+        assert!(AimdPolicy::new().with_increase_step(f64::NAN).is_err());
+        assert!(AimdPolicy::new().with_decrease_factor(1.0).is_err());
+        assert!(AimdPolicy::new().with_error_rate_threshold(0.0).is_err());
+    }
+
+    #[test]
+    fn custom_policy_sets_rate_within_bounds() {
+        let limiter = AdaptiveRateLimiter::new(50.0, 10.0, 100.0)
+            .unwrap()
+            .with_policy(FixedPolicy(42.0));
+        assert!((adjust_once(&limiter, &window(1, 0)) - 42.0).abs() < 1e-9);
+
+        let limiter = limiter.with_policy(FixedPolicy(1_000.0));
+        assert!((adjust_once(&limiter, &window(1, 0)) - 100.0).abs() < 1e-9);
+
+        let limiter = limiter.with_policy(FixedPolicy(f64::NAN));
+        assert!((adjust_once(&limiter, &window(1, 0)) - 100.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn empty_window_skips_policy() {
+        let limiter = AdaptiveRateLimiter::new(50.0, 10.0, 100.0)
+            .unwrap()
+            .with_policy(FixedPolicy(10.0));
+        assert!((adjust_once(&limiter, &window(0, 0)) - 50.0).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn call_records_latency_for_policy() {
+        let limiter = AdaptiveRateLimiter::new(50.0, 10.0, 100.0).unwrap();
+        limiter
+            .call(|| async {
+                tokio::time::sleep(Duration::from_millis(5)).await;
+                Ok::<_, &str>(())
+            })
+            .await
+            .unwrap();
+        let _ = limiter.call(|| async { Err::<(), _>("boom") }).await;
+
+        let stats = limiter.take_stats(&limiter.state.read());
+        assert_eq!((stats.successes, stats.errors), (1, 1));
+        assert!(
+            stats
+                .mean_latency
+                .is_some_and(|l| l >= Duration::from_micros(2_500))
+        );
+
+        limiter.record_success();
+        let stats = limiter.take_stats(&limiter.state.read());
+        assert_eq!(stats.mean_latency, None);
+    }
+
+    #[test]
+    fn record_outcome_feeds_stats_without_draining_the_window() {
+        let limiter = AdaptiveRateLimiter::new(50.0, 10.0, 100.0).unwrap();
+        for ms in 1..=100 {
+            limiter.record_outcome(Duration::from_millis(ms), ms % 10 != 0);
+        }
+
+        let stats = limiter.stats();
+        assert!((stats.current_rate - 50.0).abs() < 1e-9);
+        assert!((stats.min_rate - 10.0).abs() < 1e-9);
+        assert!((stats.max_rate - 100.0).abs() < 1e-9);
+        assert_eq!((stats.recent.successes, stats.recent.errors), (90, 10));
+        assert_eq!(stats.recent.p95_latency, Some(Duration::from_millis(95)));
+        // Reading the stats leaves the window in place.
+        assert_eq!(limiter.stats().recent.total(), 100);
+
+        let drained = limiter.take_stats(&limiter.state.read());
+        assert_eq!(drained.p95_latency, Some(Duration::from_millis(95)));
+        assert_eq!(limiter.stats().recent.total(), 0);
+        assert_eq!(limiter.stats().recent.p95_latency, None);
+    }
+
+    #[tokio::test]
+    async fn acquire_wait_serves_concurrent_waiters_in_arrival_order() {
+        async fn take_three(
+            limiter: Arc<TokenBucket>,
+            order: Arc<Mutex<Vec<&'static str>>>,
+            name: &'static str,
+        ) {
+            for _ in 0..3 {
+                limiter.acquire_wait().await.unwrap();
+                order.lock().push(name);
+            }
+        }
+
+        // One token every 50ms; the initial token is spent up front.
+        let limiter = Arc::new(TokenBucket::new(1, 20.0).unwrap());
+        limiter.acquire().await.unwrap();
+        let order = Arc::new(Mutex::new(Vec::new()));
+
+        let first = tokio::spawn(take_three(Arc::clone(&limiter), Arc::clone(&order), "a"));
+        tokio::time::sleep(Duration::from_millis(5)).await;
+        let second = tokio::spawn(take_three(Arc::clone(&limiter), Arc::clone(&order), "b"));
+        first.await.unwrap();
+        second.await.unwrap();
+
+        assert_eq!(*order.lock(), ["a", "b", "a", "b", "a", "b"]);
+    }
+
+    #[tokio::test]
+    async fn sliding_window_acquire_wait_sleeps_until_oldest_entry_expires() {
+        let limiter = SlidingWindow::new(Duration::from_millis(50), 1).unwrap();
+        limiter.acquire().await.unwrap();
+
+        let started = Instant::now();
+        limiter.acquire_wait().await.unwrap();
+
+        assert!(started.elapsed() >= Duration::from_millis(45));
+        assert!(limiter.acquire().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn acquire_timeout_fails_fast_when_next_permit_is_too_late() {
+        let limiter = TokenBucket::new(1, 0.001).unwrap();
+        limiter.acquire().await.unwrap();
+
+        let started = Instant::now();
+        let err = limiter
+            .acquire_timeout(Duration::from_secs(1))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, CallError::Timeout(d) if d == Duration::from_secs(1)));
+        assert!(started.elapsed() < Duration::from_millis(500));
+    }
+
+    #[tokio::test]
+    async fn dropped_waiter_gives_back_its_reservation() {
+        let limiter = TokenBucket::new(1, 20.0).unwrap();
+        limiter.acquire().await.unwrap();
+
+        let waiting = tokio::time::timeout(Duration::from_millis(5), limiter.acquire_wait()).await;
+        assert!(waiting.is_err());
+
+        // Without the reservation handed back, the next permit would be ~100ms away.
+        let err = limiter.acquire().await.unwrap_err();
+        assert!(matches!(
+            err,
+            CallError::RateLimited { retry_after: Some(d) } if d <= Duration::from_millis(50)
+        ));
+    }
+
+    #[tokio::test]
+    async fn default_acquire_wait_follows_retry_after_hints() {
+        let limiter = LeakyBucket::new(1, 20.0).unwrap();
+        limiter.acquire().await.unwrap();
+
+        limiter
+            .acquire_timeout(Duration::from_secs(1))
+            .await
+            .unwrap();
+        let erased: Arc<dyn ErasedRateLimiter> = Arc::new(limiter);
+        erased
+            .acquire_timeout_boxed(Duration::from_secs(1))
+            .await
+            .unwrap();
+    }
 }
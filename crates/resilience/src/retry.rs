@@ -34,15 +34,26 @@
 //! # }
 //! ```
 
-use std::{fmt, future::Future, num::NonZeroU32, sync::Arc, time::Duration};
+use std::{
+    fmt,
+    future::Future,
+    num::NonZeroU32,
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+    time::Duration,
+};
 
 use smallvec::SmallVec;
+use tokio_util::sync::CancellationToken;
 
 use crate::{
     CallError,
     classifier::{ErrorClass, ErrorClassifier, FnClassifier},
     deadline::Deadline,
     sink::{MetricsSink, NoopSink, ResilienceEvent},
+    span::{PatternSpan, record_backoff},
 };
 
 // ── Backoff ───────────────────────────────────────────────────────────────────
@@ -165,6 +176,57 @@ impl BackoffConfig {
     }
 }
 
+/// Object-safe backoff policy for delays chosen at runtime.
+///
+/// [`BackoffConfig`] covers the built-in strategies. Implement this trait when
+/// the schedule comes from configuration or needs logic the enum cannot
+/// express, then hand it to [`RetryConfig::backoff_policy`]. `BackoffConfig`
+/// itself implements the trait, so a config-loaded enum can be boxed and
+/// passed through the same seam.
+///
+/// Requires the `dyn-backoff` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::{sync::Arc, time::Duration};
+///
+/// use nebula_resilience::retry::{BackoffConfig, DynBackoffPolicy, RetryConfig};
+///
+/// let policy: Arc<dyn DynBackoffPolicy> = Arc::new(BackoffConfig::exponential_default());
+/// assert_eq!(policy.next_delay(1), Duration::from_millis(200));
+///
+/// let config = RetryConfig::<&str>::new(3)
+///     .expect("max_attempts >= 1")
+///     .backoff_policy(policy);
+/// # let _ = config;
+/// ```
+#[cfg(feature = "dyn-backoff")]
+pub trait DynBackoffPolicy: fmt::Debug + Send + Sync {
+    /// Delay before the retry that follows the given zero-based attempt.
+    fn next_delay(&self, attempt: u32) -> Duration;
+
+    /// Like [`next_delay`](Self::next_delay), for policies that carry state
+    /// from one attempt to the next.
+    ///
+    /// `state` belongs to a single retry call: it starts as a random value
+    /// and whatever the policy writes is handed back on the next attempt,
+    /// so a policy can use it as an RNG seed or to remember its last delay.
+    /// The retry loop calls this method; the default ignores `state` and
+    /// delegates to `next_delay`.
+    fn next_delay_with_state(&self, attempt: u32, state: &mut u64) -> Duration {
+        let _ = state;
+        self.next_delay(attempt)
+    }
+}
+
+#[cfg(feature = "dyn-backoff")]
+impl DynBackoffPolicy for BackoffConfig {
+    fn next_delay(&self, attempt: u32) -> Duration {
+        self.delay_for(attempt)
+    }
+}
+
 fn exponential_delay_by_doubling(base: Duration, attempt: u32, max: Duration) -> Duration {
     let base_ms = base.as_millis();
     let delay_ms = base_ms.checked_shl(attempt).unwrap_or(u128::MAX);
@@ -223,6 +285,31 @@ pub enum JitterConfig {
         /// Optional seed for deterministic jitter (useful for testing).
         seed: Option<u64>,
     },
+    /// Replace the delay with one sampled uniformly from `[0, delay]`.
+    ///
+    /// This is the "full jitter" strategy from AWS's *Exponential Backoff
+    /// and Jitter*; it spreads synchronized clients out the most.
+    Uniform {
+        /// Optional seed for deterministic jitter (useful for testing).
+        seed: Option<u64>,
+    },
+    /// Sample each delay uniformly from `[delay, prev * multiplier]`, capped
+    /// at `max`, where `prev` is the previous sleep (AWS "decorrelated
+    /// jitter").
+    ///
+    /// Each delay depends on the one before it rather than on the attempt
+    /// number, so retrying clients drift apart instead of following a shared
+    /// schedule. Pair with [`BackoffConfig::Fixed`] for the classic schedule,
+    /// where `delay` is the constant base.
+    Decorrelated {
+        /// Upper bound for every sampled delay.
+        max: Duration,
+        /// Growth factor for the sampling range; AWS uses `3.0`. Values below
+        /// `1.0` (and `NaN`) are treated as `1.0`.
+        multiplier: f64,
+        /// Optional seed for deterministic jitter (useful for testing).
+        seed: Option<u64>,
+    },
 }
 
 // ── RetryConfig ───────────────────────────────────────────────────────────────
@@ -263,11 +350,18 @@ pub struct RetryConfig<E = ()> {
     max_attempts: NonZeroU32,
     /// Backoff strategy between attempts.
     backoff: BackoffConfig,
+    /// Runtime backoff policy; overrides `backoff` when set.
+    #[cfg(feature = "dyn-backoff")]
+    pub(crate) backoff_policy: Option<Arc<dyn DynBackoffPolicy>>,
     /// Optional jitter applied to backoff delays.
     jitter: JitterConfig,
     /// If set, retries stop when the deadline is reached. This bounds both
     /// operation execution and sleep time.
     total_budget: Option<Duration>,
+    /// If set, each attempt is abandoned (and retried) after this long.
+    attempt_timeout: Option<Duration>,
+    /// If set, every retry must take a token from this budget first.
+    shared_budget: Option<Arc<SharedRetryBudget>>,
     pub(crate) classifier: Option<Arc<dyn ErrorClassifier<E>>>,
     pub(crate) on_retry: Option<RetryNotify<E>>,
     pub(crate) sink: Arc<dyn MetricsSink>,
@@ -275,11 +369,17 @@ pub struct RetryConfig<E = ()> {
 
 impl<E> fmt::Debug for RetryConfig<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("RetryConfig")
+        let mut debug = f.debug_struct("RetryConfig");
+        debug
             .field("max_attempts", &self.max_attempts)
-            .field("backoff", &self.backoff)
+            .field("backoff", &self.backoff);
+        #[cfg(feature = "dyn-backoff")]
+        debug.field("backoff_policy", &self.backoff_policy);
+        debug
             .field("jitter", &self.jitter)
             .field("total_budget", &self.total_budget)
+            .field("attempt_timeout", &self.attempt_timeout)
+            .field("shared_budget", &self.shared_budget)
             .finish_non_exhaustive()
     }
 }
@@ -299,8 +399,12 @@ impl<E: 'static> RetryConfig<E> {
         Ok(Self {
             max_attempts,
             backoff: BackoffConfig::Fixed(Duration::ZERO),
+            #[cfg(feature = "dyn-backoff")]
+            backoff_policy: None,
             jitter: JitterConfig::None,
             total_budget: None,
+            attempt_timeout: None,
+            shared_budget: None,
             classifier: None,
             on_retry: None,
             sink: Arc::new(NoopSink),
@@ -331,13 +435,70 @@ impl<E: 'static> RetryConfig<E> {
         self.total_budget
     }
 
+    /// Per-attempt timeout, if configured.
+    #[must_use]
+    pub const fn attempt_timeout_config(&self) -> Option<Duration> {
+        self.attempt_timeout
+    }
+
+    /// Shared retry budget, if configured.
+    #[must_use]
+    pub const fn shared_budget_config(&self) -> Option<&Arc<SharedRetryBudget>> {
+        self.shared_budget.as_ref()
+    }
+
     /// Set the backoff strategy.
+    ///
+    /// Clears any runtime policy previously set with `backoff_policy`
+    /// (feature `dyn-backoff`).
     #[must_use]
     pub fn backoff(mut self, backoff: BackoffConfig) -> Self {
         self.backoff = backoff;
+        #[cfg(feature = "dyn-backoff")]
+        {
+            self.backoff_policy = None;
+        }
         self
     }
 
+    /// Set a runtime [`DynBackoffPolicy`], overriding the [`BackoffConfig`].
+    ///
+    /// Use this when the delay schedule is only known at runtime (for example,
+    /// loaded from configuration) and cannot be expressed as a `BackoffConfig`.
+    /// Requires the `dyn-backoff` feature.
+    #[cfg(feature = "dyn-backoff")]
+    #[must_use]
+    pub fn backoff_policy(mut self, policy: Arc<dyn DynBackoffPolicy>) -> Self {
+        self.backoff_policy = Some(policy);
+        self
+    }
+
+    /// Delay for the given zero-based attempt, before jitter and hint floors.
+    /// `state` is the per-call word threaded through
+    /// `DynBackoffPolicy::next_delay_with_state`.
+    #[cfg_attr(
+        not(feature = "dyn-backoff"),
+        expect(
+            clippy::needless_pass_by_ref_mut,
+            reason = "state is only written by runtime policies"
+        )
+    )]
+    fn base_delay(&self, attempt: u32, state: &mut u64) -> Duration {
+        #[cfg(feature = "dyn-backoff")]
+        if let Some(policy) = &self.backoff_policy {
+            return policy.next_delay_with_state(attempt, state);
+        }
+        #[cfg(not(feature = "dyn-backoff"))]
+        let _ = state;
+        self.backoff.delay_for(attempt)
+    }
+
+    /// [`base_delay`](Self::base_delay) with jitter applied; `prev` is the
+    /// previous sleep of this retry call.
+    fn jittered_delay(&self, attempt: u32, prev: Option<Duration>, state: &mut u64) -> Duration {
+        apply_jitter(self.base_delay(attempt, state), &self.jitter, attempt, prev)
+    }
+
     /// Set jitter.
     #[must_use]
     pub const fn jitter(mut self, jitter: JitterConfig) -> Self {
@@ -347,12 +508,41 @@ impl<E: 'static> RetryConfig<E> {
 
     /// Set a total time budget. The retry loop bounds each operation attempt
     /// and retry sleep by the remaining budget.
+    ///
+    /// When the backoff sleep after a failed attempt would not fit in the
+    /// remaining budget, the loop stops without sleeping and returns the last
+    /// error as [`CallError::BudgetExhausted`]. An attempt that is still running
+    /// when the budget expires is abandoned with [`CallError::Timeout`].
     #[must_use]
     pub const fn total_budget(mut self, budget: Duration) -> Self {
         self.total_budget = Some(budget);
         self
     }
 
+    /// Bound each attempt to `timeout`.
+    ///
+    /// A timed-out attempt is dropped and always treated as retryable; the
+    /// classifier and `on_retry` callback are not consulted because there is no
+    /// error value. When fewer than `timeout` of the [`total_budget`](Self::total_budget)
+    /// remains, the attempt runs with the remaining budget instead.
+    #[must_use]
+    pub const fn attempt_timeout(mut self, timeout: Duration) -> Self {
+        self.attempt_timeout = Some(timeout);
+        self
+    }
+
+    /// Draw retries from a [`SharedRetryBudget`].
+    ///
+    /// Each retry takes one token before its backoff sleep; the first attempt
+    /// is free. When no token is left, the loop stops and returns the last
+    /// error as [`CallError::BudgetExhausted`]. Callers holding clones of the
+    /// same `Arc` share one pool of retries between them.
+    #[must_use]
+    pub fn shared_budget(mut self, budget: Arc<SharedRetryBudget>) -> Self {
+        self.shared_budget = Some(budget);
+        self
+    }
+
     /// Set a custom [`ErrorClassifier`] for retry decisions.
     ///
     /// When set, [`ErrorClassifier::classify`] → [`ErrorClass::is_retryable`]
@@ -404,13 +594,28 @@ impl<E: 'static> RetryConfig<E> {
         self
     }
 
+    /// Whether a retry sleeping `delay` is ruled out by the total budget or by
+    /// an empty shared budget. Takes a shared-budget token when it is not.
+    fn out_of_budget(&self, budget: Option<Deadline>, delay: Option<Duration>) -> bool {
+        exceeds_budget(budget, delay)
+            || (delay.is_some()
+                && !self
+                    .shared_budget
+                    .as_ref()
+                    .is_none_or(|shared| shared.try_acquire()))
+    }
+
     /// Internal constructor that accepts an already validated attempt count.
     pub(crate) fn from_nonzero_attempts(max_attempts: NonZeroU32) -> Self {
         Self {
             max_attempts,
             backoff: BackoffConfig::Fixed(Duration::ZERO),
+            #[cfg(feature = "dyn-backoff")]
+            backoff_policy: None,
             jitter: JitterConfig::None,
             total_budget: None,
+            attempt_timeout: None,
+            shared_budget: None,
             classifier: None,
             on_retry: None,
             sink: Arc::new(NoopSink),
@@ -418,6 +623,86 @@ impl<E: 'static> RetryConfig<E> {
     }
 }
 
+// ── SharedRetryBudget ─────────────────────────────────────────────────────────
+
+/// A pool of retry tokens shared by every caller that holds it.
+///
+/// Without it, each caller retries up to its own `max_attempts`, so a burst of
+/// failing callers (a reconnect storm, say) multiplies the load on a struggling
+/// dependency. With a shared budget, all callers together make at most
+/// [`total`](Self::total) retries. First attempts never take a token.
+///
+/// Tokens are not refilled. Create a new budget to start over.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::sync::Arc;
+///
+/// use nebula_resilience::retry::{RetryConfig, SharedRetryBudget};
+///
+/// let budget = Arc::new(SharedRetryBudget::new(100));
+/// let config = RetryConfig::<&str>::new(5)
+///     .expect("max_attempts >= 1")
+///     .shared_budget(Arc::clone(&budget));
+/// # let _ = config;
+/// assert_eq!(budget.remaining(), 100);
+/// ```
+#[derive(Debug)]
+pub struct SharedRetryBudget {
+    total: usize,
+    remaining: AtomicUsize,
+}
+
+impl SharedRetryBudget {
+    /// Create a budget that allows `total` retries across all callers.
+    #[must_use]
+    pub const fn new(total: usize) -> Self {
+        Self {
+            total,
+            remaining: AtomicUsize::new(total),
+        }
+    }
+
+    /// Number of retries the budget started with.
+    #[must_use]
+    pub const fn total(&self) -> usize {
+        self.total
+    }
+
+    /// Number of retries still available.
+    #[must_use]
+    pub fn remaining(&self) -> usize {
+        self.remaining.load(Ordering::Acquire)
+    }
+
+    /// Take one token. Returns `false` when the budget is spent.
+    pub fn try_acquire(&self) -> bool {
+        self.remaining
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |n| n.checked_sub(1))
+            .is_ok()
+    }
+}
+
+// ── RetryStats ────────────────────────────────────────────────────────────────
+
+/// Per-call attempt counts returned by [`retry_with_stats`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct RetryStats {
+    /// Attempts started, including the first.
+    pub attempts: u32,
+    /// Attempts abandoned by the [per-attempt timeout](RetryConfig::attempt_timeout).
+    pub timed_out: u32,
+    /// Attempts that returned an error.
+    pub failed: u32,
+    /// Wall-clock time spent in the call, attempts and backoff sleeps included.
+    pub total_elapsed: Duration,
+    /// Whether the [total budget](RetryConfig::total_budget) or the
+    /// [shared budget](RetryConfig::shared_budget) ended the call.
+    pub budget_exhausted: bool,
+}
+
 // ── retry_with ────────────────────────────────────────────────────────────────
 
 /// Execute `f` with retry according to `config`.
@@ -479,13 +764,124 @@ where
 {
     retry_loop(
         &config,
+        None,
+        None,
         f,
         |e: &E| e.is_retryable(),
         |e: &E| e.retry_hint().and_then(|h| h.after),
+        &mut RetryStats::default(),
     )
     .await
 }
 
+/// Like [`retry_with`] but bounded by an externally imposed deadline.
+///
+/// The remaining budget is checked before every attempt and before every
+/// backoff sleep: if the next sleep would outlast `deadline`, the loop stops
+/// right away instead of spending one more attempt. When the config also has a
+/// [`total_budget`](RetryConfig::total_budget), whichever expires first wins.
+///
+/// # Errors
+///
+/// Returns `Err(CallError::Timeout)` once the deadline is reached, otherwise
+/// the same errors as [`retry_with`].
+///
+/// # Cancel safety
+///
+/// Same as [`retry_with`].
+pub async fn retry_with_deadline<T, E, F, Fut>(
+    config: RetryConfig<E>,
+    deadline: std::time::Instant,
+    f: F,
+) -> Result<T, CallError<E>>
+where
+    E: nebula_error::Classify + 'static,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, E>> + Send,
+{
+    retry_loop(
+        &config,
+        Some(Deadline::until(deadline)),
+        None,
+        f,
+        |e: &E| e.is_retryable(),
+        |e: &E| e.retry_hint().and_then(|h| h.after),
+        &mut RetryStats::default(),
+    )
+    .await
+}
+
+/// Like [`retry_with`] but aborted by `token`.
+///
+/// Cancellation is observed at every `.await` of the loop: the in-flight
+/// attempt is dropped, and a backoff sleep wakes immediately instead of
+/// running out its delay. A token that is already cancelled skips the first
+/// attempt.
+///
+/// # Errors
+///
+/// Returns `Err(CallError::Cancelled)` once `token` is cancelled, otherwise
+/// the same errors as [`retry_with`].
+///
+/// # Cancel safety
+///
+/// Same as [`retry_with`].
+pub async fn retry_with_cancel<T, E, F, Fut>(
+    config: RetryConfig<E>,
+    token: &CancellationToken,
+    f: F,
+) -> Result<T, CallError<E>>
+where
+    E: nebula_error::Classify + 'static,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, E>> + Send,
+{
+    retry_loop(
+        &config,
+        None,
+        Some(token),
+        f,
+        |e: &E| e.is_retryable(),
+        |e: &E| e.retry_hint().and_then(|h| h.after),
+        &mut RetryStats::default(),
+    )
+    .await
+}
+
+/// Like [`retry_with`] but also returns [`RetryStats`] for the call.
+///
+/// # Errors
+///
+/// Same as [`retry_with`]. If the last attempt hit the
+/// [per-attempt timeout](RetryConfig::attempt_timeout), returns
+/// `Err(CallError::Timeout)` with that timeout.
+///
+/// # Cancel safety
+///
+/// Same as [`retry_with`]; the stats are lost if the future is dropped.
+pub async fn retry_with_stats<T, E, F, Fut>(
+    config: RetryConfig<E>,
+    f: F,
+) -> (Result<T, CallError<E>>, RetryStats)
+where
+    E: nebula_error::Classify + 'static,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, E>> + Send,
+{
+    let mut stats = RetryStats::default();
+    let result = retry_loop(
+        &config,
+        None,
+        None,
+        f,
+        |e: &E| e.is_retryable(),
+        |e: &E| e.retry_hint().and_then(|h| h.after),
+        &mut stats,
+    )
+    .await;
+    (result, stats)
+}
+
 /// Retry without a [`Classify`](nebula_error::Classify) bound.
 ///
 /// Used by the pipeline and benchmarks. Retries all errors when no predicate
@@ -497,90 +893,238 @@ where
     F: FnMut() -> Fut,
     Fut: Future<Output = Result<T, E>> + Send,
 {
-    retry_loop(&config, f, |_| true, |_| None).await
+    retry_loop(
+        &config,
+        None,
+        None,
+        f,
+        |_| true,
+        |_| None,
+        &mut RetryStats::default(),
+    )
+    .await
+}
+
+/// Why the previous attempt did not succeed.
+enum LastFailure<E> {
+    Error(E),
+    TimedOut(Duration),
+}
+
+/// Result of a single attempt under the per-attempt timeout.
+enum AttemptOutcome<T, E> {
+    Completed(Result<T, E>),
+    TimedOut(Duration),
+}
+
+/// Run one attempt bounded by the per-attempt timeout and the total deadline.
+///
+/// When the deadline leaves less than `attempt_timeout`, the deadline governs and
+/// its expiry ends the whole retry loop with `CallError::Timeout`.
+async fn run_attempt<T, E, Fut>(
+    attempt_timeout: Option<Duration>,
+    deadline: Option<Deadline>,
+    attempt: Fut,
+) -> Result<AttemptOutcome<T, E>, CallError<E>>
+where
+    Fut: Future<Output = Result<T, E>> + Send,
+{
+    match (attempt_timeout, deadline) {
+        (None, None) => Ok(AttemptOutcome::Completed(attempt.await)),
+        (None, Some(deadline)) => deadline
+            .timeout(attempt)
+            .await
+            .map(AttemptOutcome::Completed),
+        (Some(limit), Some(deadline))
+            if deadline
+                .remaining()
+                .is_none_or(|remaining| remaining <= limit) =>
+        {
+            deadline
+                .timeout(attempt)
+                .await
+                .map(AttemptOutcome::Completed)
+        },
+        (Some(limit), _) => Ok(tokio::time::timeout(limit, attempt)
+            .await
+            .map_or(AttemptOutcome::TimedOut(limit), AttemptOutcome::Completed)),
+    }
+}
+
+/// Whether a retry sleeping `delay` would run past `budget`.
+fn exceeds_budget(budget: Option<Deadline>, delay: Option<Duration>) -> bool {
+    let (Some(budget), Some(delay)) = (budget, delay) else {
+        return false;
+    };
+    budget
+        .remaining()
+        .is_none_or(|remaining| remaining.is_zero() || delay > remaining)
 }
 
 /// Core retry loop shared by [`retry_with`] and [`retry_with_inner`].
 ///
+/// `external_deadline` is combined with the config's `total_budget`.
+/// `cancel` aborts the loop with `CallError::Cancelled`, dropping the current
+/// attempt or backoff sleep.
 /// `default_should_retry` is called when no predicate is set on the config.
 /// `hint_fn` extracts an optional backoff floor from the error (e.g., `retry_hint().after`).
 async fn retry_loop<T, E, F, Fut>(
     config: &RetryConfig<E>,
+    external_deadline: Option<Deadline>,
+    cancel: Option<&CancellationToken>,
+    f: F,
+    default_should_retry: impl Fn(&E) -> bool,
+    hint_fn: impl Fn(&E) -> Option<Duration>,
+    stats: &mut RetryStats,
+) -> Result<T, CallError<E>>
+where
+    E: 'static,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, E>> + Send,
+{
+    let started = std::time::Instant::now();
+    let span = PatternSpan::retry(config.max_attempts.get());
+    let attempts = retry_attempts(
+        config,
+        started,
+        external_deadline,
+        f,
+        default_should_retry,
+        hint_fn,
+        stats,
+    );
+    let result = span
+        .run(async {
+            let Some(token) = cancel else {
+                return attempts.await;
+            };
+            tokio::select! {
+                biased;
+                () = token.cancelled() => Err(CallError::cancelled()),
+                result = attempts => result,
+            }
+        })
+        .await;
+    stats.total_elapsed = started.elapsed();
+    span.record_attempts(stats.attempts);
+    span.record_outcome(&result);
+    result
+}
+
+/// The attempt loop behind [`retry_loop`]; `started` anchors the config's total budget.
+async fn retry_attempts<T, E, F, Fut>(
+    config: &RetryConfig<E>,
+    started: std::time::Instant,
+    external_deadline: Option<Deadline>,
     mut f: F,
     default_should_retry: impl Fn(&E) -> bool,
     hint_fn: impl Fn(&E) -> Option<Duration>,
+    stats: &mut RetryStats,
 ) -> Result<T, CallError<E>>
 where
     E: 'static,
     F: FnMut() -> Fut,
     Fut: Future<Output = Result<T, E>> + Send,
 {
-    let mut last_err: Option<E> = None;
-    let mut attempts_executed: u32 = 0;
-    let deadline = config.total_budget.map(Deadline::after);
+    let mut last: Option<LastFailure<E>> = None;
+    let mut prev_delay: Option<Duration> = None;
+    let mut backoff_state = fastrand::u64(..);
+    let budget = config
+        .total_budget
+        .map(|budget| Deadline::from_start(started, budget));
+    let deadline = Deadline::earliest(budget, external_deadline);
     let max_attempts = config.max_attempts.get();
 
     for attempt in 0..max_attempts {
-        attempts_executed = attempt + 1;
-        let attempt_result = if let Some(deadline) = deadline {
-            deadline.timeout(f()).await?
-        } else {
-            f().await
-        };
-
-        match attempt_result {
-            Ok(value) => return Ok(value),
-            Err(e) => {
-                let is_last = attempt + 1 >= max_attempts;
+        stats.attempts = attempt + 1;
+        let is_last = attempt + 1 >= max_attempts;
+
+        let outcome = run_attempt(config.attempt_timeout, deadline, f())
+            .await
+            .inspect_err(|_| {
+                stats.budget_exhausted =
+                    budget.is_some_and(|b| b.remaining_or_timeout::<()>().is_err());
+            })?;
+        match outcome {
+            AttemptOutcome::Completed(Ok(value)) => return Ok(value),
+            AttemptOutcome::TimedOut(limit) => {
+                stats.timed_out += 1;
+                let delay = (!is_last)
+                    .then(|| config.jittered_delay(attempt, prev_delay, &mut backoff_state));
+                let out_of_budget = config.out_of_budget(budget, delay);
+                config.sink.record(ResilienceEvent::RetryAttempt {
+                    attempt: attempt + 1,
+                    will_retry: delay.is_some() && !out_of_budget,
+                });
+                last = Some(LastFailure::TimedOut(limit));
+                let Some(delay) = delay else {
+                    break;
+                };
+                if out_of_budget {
+                    stats.budget_exhausted = true;
+                    return Err(CallError::Timeout(budget.map_or(limit, Deadline::budget)));
+                }
 
+                prev_delay = Some(delay);
+                sleep_with_deadline(attempt + 1, delay, deadline).await?;
+            },
+            AttemptOutcome::Completed(Err(e)) => {
+                stats.failed += 1;
                 let should_retry = config.classifier.as_ref().map_or_else(
                     || default_should_retry(&e),
                     |c| c.classify(&e).is_retryable(),
                 );
 
+                let delay = (!is_last && should_retry).then(|| {
+                    let delay = config.jittered_delay(attempt, prev_delay, &mut backoff_state);
+                    hint_fn(&e).map_or(delay, |floor| delay.max(floor))
+                });
+                let out_of_budget = config.out_of_budget(budget, delay);
+
                 config.sink.record(ResilienceEvent::RetryAttempt {
                     attempt: attempt + 1,
-                    will_retry: !is_last && should_retry,
+                    will_retry: delay.is_some() && !out_of_budget,
                 });
 
                 if !should_retry {
                     return Err(CallError::Operation(e));
                 }
 
-                if is_last {
-                    last_err = Some(e);
+                let Some(delay) = delay else {
+                    last = Some(LastFailure::Error(e));
                     break;
+                };
+
+                if out_of_budget {
+                    stats.budget_exhausted = true;
+                    return Err(CallError::BudgetExhausted {
+                        attempts: stats.attempts,
+                        elapsed: started.elapsed(),
+                        last: e,
+                    });
                 }
-
-                let mut delay =
-                    apply_jitter(config.backoff.delay_for(attempt), &config.jitter, attempt);
-                if let Some(floor) = hint_fn(&e) {
-                    delay = delay.max(floor);
-                }
+                prev_delay = Some(delay);
 
                 if let Some(ref notify) = config.on_retry {
                     notify(&e, delay, attempt + 1);
                 }
-                last_err = Some(e);
+                last = Some(LastFailure::Error(e));
 
-                sleep_with_deadline(delay, deadline).await?;
+                sleep_with_deadline(attempt + 1, delay, deadline).await?;
             },
         }
     }
 
-    last_err.map_or_else(
-        || {
-            Err(CallError::Timeout(
-                deadline.map_or(Duration::ZERO, Deadline::budget),
-            ))
-        },
-        |e| {
-            Err(CallError::RetriesExhausted {
-                attempts: attempts_executed.max(1),
-                last: e,
-            })
-        },
-    )
+    match last {
+        Some(LastFailure::Error(e)) => Err(CallError::RetriesExhausted {
+            attempts: stats.attempts.max(1),
+            last: e,
+        }),
+        Some(LastFailure::TimedOut(limit)) => Err(CallError::Timeout(limit)),
+        None => Err(CallError::Timeout(
+            deadline.map_or(Duration::ZERO, Deadline::budget),
+        )),
+    }
 }
 
 /// Convenience: retry up to `n` times with no backoff.
@@ -638,10 +1182,13 @@ where
     retry_with(config, f).await
 }
 
+/// Sleep `delay` after the failed 1-based `attempt`, bounded by `deadline`.
 async fn sleep_with_deadline<E>(
+    attempt: u32,
     delay: Duration,
     deadline: Option<Deadline>,
 ) -> Result<(), CallError<E>> {
+    record_backoff(attempt, delay);
     if delay.is_zero() {
         return Ok(());
     }
@@ -658,16 +1205,71 @@ async fn sleep_with_deadline<E>(
 ///
 /// When `seed` is set, the jitter is deterministic but varies per `attempt`
 /// (seed is mixed with the attempt number to avoid identical jitter across retries).
+/// `prev` is the previous sleep, used only by `Decorrelated`.
 ///
-/// Split into leaf dispatcher + outlined `Full` path so that `JitterConfig::None`
+/// Split into leaf dispatcher + outlined jitter paths so that `JitterConfig::None`
 /// (the common case) compiles to a 2-instruction function with no register saves.
-fn apply_jitter(delay: Duration, jitter: &JitterConfig, attempt: u32) -> Duration {
+fn apply_jitter(
+    delay: Duration,
+    jitter: &JitterConfig,
+    attempt: u32,
+    prev: Option<Duration>,
+) -> Duration {
     match jitter {
         JitterConfig::None => delay,
         JitterConfig::Full { factor, seed } => apply_jitter_full(delay, *factor, *seed, attempt),
+        JitterConfig::Uniform { seed } => apply_jitter_uniform(delay, *seed, attempt),
+        JitterConfig::Decorrelated {
+            max,
+            multiplier,
+            seed,
+        } => apply_jitter_decorrelated(delay, prev, *max, *multiplier, *seed, attempt),
     }
 }
 
+/// Uniform sample in `[0, 1)`, seeded per attempt when `seed` is set.
+fn jitter_sample(seed: Option<u64>, attempt: u32) -> f64 {
+    seed.map_or_else(fastrand::f64, |s| {
+        fastrand::Rng::with_seed(s.wrapping_add(u64::from(attempt))).f64()
+    })
+}
+
+#[inline(never)]
+fn apply_jitter_uniform(delay: Duration, seed: Option<u64>, attempt: u32) -> Duration {
+    let sampled = delay.as_secs_f64() * jitter_sample(seed, attempt);
+    // f64 rounding can push a near-`Duration::MAX` product out of range.
+    Duration::try_from_secs_f64(sampled).map_or(delay, |d| d.min(delay))
+}
+
+// Reason: see `apply_jitter_full`.
+#[expect(
+    clippy::suboptimal_flops,
+    reason = "mul_add emits slow fma call on default x86-64 target; explicit multiply+add is faster"
+)]
+#[inline(never)]
+fn apply_jitter_decorrelated(
+    delay: Duration,
+    prev: Option<Duration>,
+    max: Duration,
+    multiplier: f64,
+    seed: Option<u64>,
+    attempt: u32,
+) -> Duration {
+    let multiplier = if multiplier.is_nan() {
+        1.0
+    } else {
+        multiplier.max(1.0)
+    };
+    let lower = delay.min(max);
+    // Overflow (or an infinite multiplier) means "beyond any cap".
+    let upper = Duration::try_from_secs_f64(prev.unwrap_or(delay).as_secs_f64() * multiplier)
+        .map_or(max, |upper| upper.clamp(lower, max));
+    let lower_secs = lower.as_secs_f64();
+    let sampled = lower_secs + (upper.as_secs_f64() - lower_secs) * jitter_sample(seed, attempt);
+    // f64 rounding can push the sample slightly outside `[lower, upper]`.
+    Duration::try_from_secs_f64(sampled).map_or(upper, |d| d.clamp(lower, upper))
+}
+
 // Reason: mul_add compiles to `call fma` (~30 cycles) on default target-cpu=x86-64
 // which lacks hardware FMA. Explicit multiply+add uses mulsd+addsd (~8 cycles).
 #[expect(
@@ -689,9 +1291,7 @@ fn apply_jitter_full(delay: Duration, factor: f64, seed: Option<u64>, attempt: u
 
     let base = delay.as_secs_f64();
     let clamped_factor = factor.min(1.0);
-    let rand_val = seed.map_or_else(fastrand::f64, |s| {
-        fastrand::Rng::with_seed(s.wrapping_add(u64::from(attempt))).f64()
-    });
+    let rand_val = jitter_sample(seed, attempt);
     let total = base + clamped_factor * base * rand_val;
     // total >= 0.0 is guaranteed when base >= 0, factor > 0, rand_val >= 0.
     // Guard against infinity from very large base values.
@@ -935,8 +1535,8 @@ mod tests {
             factor: 0.5,
             seed: Some(42),
         };
-        let d1 = apply_jitter(delay, &jitter, 0);
-        let d2 = apply_jitter(delay, &jitter, 0);
+        let d1 = apply_jitter(delay, &jitter, 0, None);
+        let d2 = apply_jitter(delay, &jitter, 0, None);
 
         assert_eq!(d1, d2, "same seed + same attempt must produce same jitter");
         assert!(d1 > delay, "jitter should add to delay");
@@ -953,9 +1553,9 @@ mod tests {
             factor: 0.5,
             seed: Some(42),
         };
-        let d0 = apply_jitter(delay, &jitter, 0);
-        let d1 = apply_jitter(delay, &jitter, 1);
-        let d2 = apply_jitter(delay, &jitter, 2);
+        let d0 = apply_jitter(delay, &jitter, 0, None);
+        let d1 = apply_jitter(delay, &jitter, 1, None);
+        let d2 = apply_jitter(delay, &jitter, 2, None);
 
         // Different attempts should (almost certainly) produce different jitter
         assert!(
@@ -971,19 +1571,19 @@ mod tests {
             factor: f64::NAN,
             seed: Some(42),
         };
-        assert_eq!(apply_jitter(delay, &nan_jitter, 0), delay);
+        assert_eq!(apply_jitter(delay, &nan_jitter, 0, None), delay);
 
         let neg_jitter = JitterConfig::Full {
             factor: -1.0,
             seed: Some(42),
         };
-        assert_eq!(apply_jitter(delay, &neg_jitter, 0), delay);
+        assert_eq!(apply_jitter(delay, &neg_jitter, 0, None), delay);
 
         let zero_jitter = JitterConfig::Full {
             factor: 0.0,
             seed: Some(42),
         };
-        assert_eq!(apply_jitter(delay, &zero_jitter, 0), delay);
+        assert_eq!(apply_jitter(delay, &zero_jitter, 0, None), delay);
     }
 
     #[test]
@@ -994,10 +1594,126 @@ mod tests {
             seed: Some(42),
         };
         // Infinity is clamped to 1.0 by factor.min(1.0), so jitter is applied
-        let result = apply_jitter(delay, &jitter, 0);
+        let result = apply_jitter(delay, &jitter, 0, None);
         assert!(result >= delay, "clamped infinity factor should add jitter");
     }
 
+    proptest::proptest! {
+        #[test]
+        fn uniform_jitter_stays_within_zero_and_delay(
+            delay_ms in 0u64..=600_000,
+            seed in proptest::prelude::any::<u64>(),
+            attempt in 0u32..=100,
+        ) {
+            let delay = Duration::from_millis(delay_ms);
+            let jittered = apply_jitter(delay, &JitterConfig::Uniform { seed: Some(seed) }, attempt, None);
+            proptest::prop_assert!(jittered <= delay, "jittered={jittered:?} > delay={delay:?}");
+        }
+
+        #[test]
+        fn decorrelated_jitter_stays_between_base_and_scaled_prev(
+            base_ms in 1u64..=5_000,
+            prev_ms in 0u64..=60_000,
+            max_ms in 1u64..=120_000,
+            multiplier in 1.0f64..=10.0,
+            seed in proptest::prelude::any::<u64>(),
+            attempt in 0u32..=100,
+        ) {
+            let base = Duration::from_millis(base_ms);
+            let max = Duration::from_millis(max_ms);
+            let prev = Duration::from_millis(prev_ms);
+            let jitter = JitterConfig::Decorrelated { max, multiplier, seed: Some(seed) };
+            let jittered = apply_jitter(base, &jitter, attempt, Some(prev));
+
+            let lower = base.min(max);
+            let upper = prev.mul_f64(multiplier).clamp(lower, max);
+            proptest::prop_assert!(
+                lower <= jittered && jittered <= upper,
+                "jittered={jittered:?} outside [{lower:?}, {upper:?}]"
+            );
+        }
+    }
+
+    #[test]
+    fn unseeded_uniform_and_decorrelated_jitter_stay_in_bounds() {
+        let delay = Duration::from_millis(100);
+        let max = Duration::from_secs(1);
+        let decorrelated = JitterConfig::Decorrelated {
+            max,
+            multiplier: 3.0,
+            seed: None,
+        };
+        let mut prev = None;
+        for attempt in 0..10_000 {
+            let uniform = apply_jitter(delay, &JitterConfig::Uniform { seed: None }, attempt, None);
+            assert!(uniform <= delay);
+
+            let next = apply_jitter(delay, &decorrelated, attempt, prev);
+            let upper = prev.unwrap_or(delay).mul_f64(3.0).min(max);
+            assert!(
+                delay <= next && next <= upper,
+                "{next:?} outside [{delay:?}, {upper:?}]"
+            );
+            prev = Some(next);
+        }
+    }
+
+    #[test]
+    fn jitter_handles_max_duration_without_panic() {
+        let uniform = JitterConfig::Uniform { seed: Some(7) };
+        assert!(apply_jitter(Duration::MAX, &uniform, 0, None) <= Duration::MAX);
+
+        let decorrelated = JitterConfig::Decorrelated {
+            max: Duration::MAX,
+            multiplier: 3.0,
+            seed: Some(7),
+        };
+        let jittered = apply_jitter(Duration::MAX, &decorrelated, 0, Some(Duration::MAX));
+        assert_eq!(jittered, Duration::MAX);
+    }
+
+    /// One decorrelated step samples `U(base, prev * multiplier)`, so its
+    /// mean is `(base + prev * multiplier) / 2`.
+    #[test]
+    fn decorrelated_jitter_mean_converges_for_each_multiplier() {
+        let base = Duration::from_millis(100);
+        let prev = Duration::from_millis(400);
+        for multiplier in [1.5, 2.0, 3.0, 5.0] {
+            let jitter = JitterConfig::Decorrelated {
+                max: Duration::from_secs(30),
+                multiplier,
+                seed: None,
+            };
+            let samples = 10_000;
+            let mean = (0..samples)
+                .map(|attempt| apply_jitter(base, &jitter, attempt, Some(prev)).as_secs_f64())
+                .sum::<f64>()
+                / f64::from(samples);
+
+            let expected =
+                f64::midpoint(base.as_secs_f64(), prev.mul_f64(multiplier).as_secs_f64());
+            assert!(
+                (mean - expected).abs() <= expected * 0.1,
+                "multiplier {multiplier}: mean {mean} not within 10% of {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn decorrelated_multiplier_below_one_or_nan_acts_as_one() {
+        let base = Duration::from_millis(100);
+        let prev = Duration::from_millis(250);
+        for multiplier in [0.5, -2.0, f64::NAN] {
+            let jitter = JitterConfig::Decorrelated {
+                max: Duration::from_secs(1),
+                multiplier,
+                seed: Some(11),
+            };
+            let jittered = apply_jitter(base, &jitter, 0, Some(prev));
+            assert!(base <= jittered && jittered <= prev, "{jittered:?}");
+        }
+    }
+
     #[tokio::test]
     async fn total_budget_check_handles_large_backoff_without_panic() {
         let config = RetryConfig::new(3)
@@ -1010,7 +1726,128 @@ mod tests {
         let result: Result<(), CallError<TransientErr>> =
             retry_with(config, || Box::pin(async { Err(TransientErr("fail")) })).await;
 
-        assert!(matches!(result, Err(CallError::Timeout(_))));
+        assert!(matches!(
+            result,
+            Err(CallError::BudgetExhausted { attempts: 1, .. })
+        ));
+    }
+
+    #[cfg(feature = "dyn-backoff")]
+    #[test]
+    fn dyn_exponential_policy_matches_enum_sequence() {
+        let config = BackoffConfig::Exponential {
+            base: Duration::from_millis(50),
+            multiplier: 2.0,
+            max: Duration::from_secs(2),
+        };
+        let policy: Arc<dyn DynBackoffPolicy> = Arc::new(config.clone());
+
+        for attempt in 0..10 {
+            assert_eq!(policy.next_delay(attempt), config.delay_for(attempt));
+        }
+    }
+
+    #[cfg(feature = "dyn-backoff")]
+    #[tokio::test]
+    async fn retry_uses_dyn_backoff_policy_delays() {
+        #[derive(Debug)]
+        struct StepPolicy;
+        impl DynBackoffPolicy for StepPolicy {
+            fn next_delay(&self, attempt: u32) -> Duration {
+                Duration::from_millis(u64::from(attempt) + 1)
+            }
+        }
+
+        let delays = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let d = delays.clone();
+        let config = RetryConfig::new(4)
+            .unwrap()
+            .backoff_policy(Arc::new(StepPolicy))
+            .on_retry(move |_: &TransientErr, delay, _| d.lock().unwrap().push(delay));
+
+        let _: Result<(), CallError<TransientErr>> =
+            retry_with(config, || Box::pin(async { Err(TransientErr("fail")) })).await;
+
+        assert_eq!(
+            *delays.lock().unwrap(),
+            [1, 2, 3].map(Duration::from_millis).to_vec()
+        );
+    }
+
+    #[cfg(feature = "dyn-backoff")]
+    #[tokio::test]
+    async fn retry_threads_backoff_state_across_attempts() {
+        /// Doubles the delay it stored on the previous attempt.
+        #[derive(Debug)]
+        struct Doubling;
+        impl DynBackoffPolicy for Doubling {
+            fn next_delay(&self, _attempt: u32) -> Duration {
+                Duration::from_millis(1)
+            }
+
+            fn next_delay_with_state(&self, attempt: u32, state: &mut u64) -> Duration {
+                // The loop seeds `state` randomly; restart from 1 ms.
+                *state = attempt.checked_sub(1).map_or(1, |_| *state * 2);
+                Duration::from_millis(*state)
+            }
+        }
+
+        let delays = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let d = delays.clone();
+        let config = RetryConfig::new(4)
+            .unwrap()
+            .backoff_policy(Arc::new(Doubling))
+            .on_retry(move |_: &TransientErr, delay, _| d.lock().unwrap().push(delay));
+
+        let _: Result<(), CallError<TransientErr>> =
+            retry_with(config, || Box::pin(async { Err(TransientErr("fail")) })).await;
+
+        assert_eq!(
+            *delays.lock().unwrap(),
+            [1, 2, 4].map(Duration::from_millis).to_vec()
+        );
+    }
+
+    #[tokio::test]
+    async fn decorrelated_jitter_builds_on_previous_delay() {
+        let delays = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let d = delays.clone();
+        let base = Duration::from_millis(1);
+        let max = Duration::from_millis(5);
+        let config = RetryConfig::new(5)
+            .unwrap()
+            .backoff(BackoffConfig::Fixed(base))
+            .jitter(JitterConfig::Decorrelated {
+                max,
+                multiplier: 3.0,
+                seed: Some(3),
+            })
+            .on_retry(move |_: &TransientErr, delay, _| d.lock().unwrap().push(delay));
+
+        let _: Result<(), CallError<TransientErr>> =
+            retry_with(config, || Box::pin(async { Err(TransientErr("fail")) })).await;
+
+        let delays = delays.lock().unwrap().clone();
+        assert_eq!(delays.len(), 4);
+        let mut prev = base;
+        for delay in delays {
+            assert!(
+                base <= delay && delay <= prev.mul_f64(3.0).min(max),
+                "{delay:?}"
+            );
+            prev = delay;
+        }
+    }
+
+    #[cfg(feature = "dyn-backoff")]
+    #[test]
+    fn backoff_clears_previous_dyn_policy() {
+        let config = RetryConfig::<TransientErr>::new(2)
+            .unwrap()
+            .backoff_policy(Arc::new(BackoffConfig::Fixed(Duration::from_secs(1))))
+            .backoff(BackoffConfig::Fixed(Duration::from_millis(5)));
+
+        assert_eq!(config.base_delay(0, &mut 0), Duration::from_millis(5));
     }
 
     #[test]
@@ -1146,6 +1983,205 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn deadline_mid_backoff_aborts_without_another_attempt() {
+        let counter = Arc::new(AtomicU32::new(0));
+        let c = counter.clone();
+
+        let config = RetryConfig::new(5)
+            .unwrap()
+            .backoff(BackoffConfig::Fixed(Duration::from_millis(200)));
+        let start = std::time::Instant::now();
+        let deadline = start + Duration::from_millis(50);
+
+        let result: Result<(), CallError<TransientErr>> =
+            retry_with_deadline(config, deadline, async || {
+                c.fetch_add(1, Ordering::SeqCst);
+                Err(TransientErr("fail"))
+            })
+            .await;
+
+        assert!(matches!(result, Err(CallError::Timeout(_))));
+        assert_eq!(counter.load(Ordering::SeqCst), 1);
+        // The 200ms sleep is never started.
+        assert!(
+            start.elapsed() < Duration::from_millis(150),
+            "took too long: {:?}",
+            start.elapsed()
+        );
+    }
+
+    #[tokio::test]
+    async fn cancel_mid_backoff_returns_promptly() {
+        let counter = Arc::new(AtomicU32::new(0));
+        let c = counter.clone();
+        let token = CancellationToken::new();
+
+        let config = RetryConfig::new(5)
+            .unwrap()
+            .backoff(BackoffConfig::Fixed(Duration::from_secs(10)));
+        let canceller = token.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            canceller.cancel();
+        });
+        let start = std::time::Instant::now();
+
+        let result: Result<(), CallError<TransientErr>> =
+            retry_with_cancel(config, &token, async || {
+                c.fetch_add(1, Ordering::SeqCst);
+                Err(TransientErr("fail"))
+            })
+            .await;
+
+        assert!(matches!(result, Err(CallError::Cancelled { .. })));
+        assert_eq!(counter.load(Ordering::SeqCst), 1);
+        // Woken out of the 10s backoff sleep, not waiting it out.
+        assert!(
+            start.elapsed() < Duration::from_millis(200),
+            "took too long: {:?}",
+            start.elapsed()
+        );
+    }
+
+    #[tokio::test]
+    async fn cancel_drops_in_flight_attempt() {
+        struct DropFlag(Arc<AtomicU32>);
+        impl Drop for DropFlag {
+            fn drop(&mut self) {
+                self.0.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        let dropped = Arc::new(AtomicU32::new(0));
+        let d = dropped.clone();
+        let token = CancellationToken::new();
+        let canceller = token.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            canceller.cancel();
+        });
+
+        let result: Result<(), CallError<TransientErr>> =
+            retry_with_cancel(RetryConfig::new(3).unwrap(), &token, || {
+                let flag = DropFlag(d.clone());
+                async move {
+                    let _flag = flag;
+                    std::future::pending::<()>().await;
+                    Ok(())
+                }
+            })
+            .await;
+
+        assert!(matches!(result, Err(CallError::Cancelled { .. })));
+        assert_eq!(dropped.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn cancelled_token_skips_first_attempt() {
+        let counter = Arc::new(AtomicU32::new(0));
+        let c = counter.clone();
+        let token = CancellationToken::new();
+        token.cancel();
+
+        let result: Result<(), CallError<TransientErr>> =
+            retry_with_cancel(RetryConfig::new(3).unwrap(), &token, async || {
+                c.fetch_add(1, Ordering::SeqCst);
+                Ok(())
+            })
+            .await;
+
+        assert!(matches!(result, Err(CallError::Cancelled { .. })));
+        assert_eq!(counter.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn expired_deadline_skips_first_attempt() {
+        let counter = Arc::new(AtomicU32::new(0));
+        let c = counter.clone();
+        let config = RetryConfig::new(3).unwrap();
+
+        let result: Result<(), CallError<TransientErr>> =
+            retry_with_deadline(config, std::time::Instant::now(), async || {
+                c.fetch_add(1, Ordering::SeqCst);
+                Ok(())
+            })
+            .await;
+
+        assert!(matches!(result, Err(CallError::Timeout(_))));
+        assert_eq!(counter.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn deadline_and_total_budget_use_earliest() {
+        let config = RetryConfig::new(100)
+            .unwrap()
+            .backoff(BackoffConfig::Fixed(Duration::from_millis(10)))
+            .total_budget(Duration::from_millis(40));
+        let start = std::time::Instant::now();
+
+        let result: Result<(), CallError<TransientErr>> =
+            retry_with_deadline(config, start + Duration::from_secs(10), async || {
+                Err(TransientErr("fail"))
+            })
+            .await;
+
+        // The 40ms total budget is the earlier deadline.
+        assert!(matches!(result, Err(CallError::BudgetExhausted { .. })));
+        assert!(start.elapsed() < Duration::from_secs(1));
+    }
+
+    #[tokio::test]
+    async fn total_budget_returns_last_error_before_overrunning_sleep() {
+        let counter = Arc::new(AtomicU32::new(0));
+        let c = counter.clone();
+        let config = RetryConfig::new(10)
+            .unwrap()
+            .backoff(BackoffConfig::Fixed(Duration::from_millis(30)))
+            .total_budget(Duration::from_millis(100));
+
+        let (result, stats) = retry_with_stats(config, async || {
+            let n = c.fetch_add(1, Ordering::SeqCst) + 1;
+            Err::<(), _>(TransientErr(if n == 1 { "first" } else { "later" }))
+        })
+        .await;
+
+        // Attempts at ~0, 30 and 60ms; the sleep to 90ms fits, the one to 120ms does not.
+        let Err(CallError::BudgetExhausted {
+            attempts,
+            elapsed,
+            last,
+        }) = result
+        else {
+            panic!("expected BudgetExhausted, got {result:?}");
+        };
+        assert_eq!(attempts, counter.load(Ordering::SeqCst));
+        assert_eq!(attempts, stats.attempts);
+        assert!((3..=4).contains(&attempts), "got {attempts} attempts");
+        assert_eq!(last, TransientErr("later"));
+        assert!(elapsed < Duration::from_millis(100), "elapsed {elapsed:?}");
+        assert!(stats.budget_exhausted);
+        assert!(stats.total_elapsed >= elapsed);
+        assert!(stats.total_elapsed < Duration::from_millis(100));
+    }
+
+    #[tokio::test]
+    async fn stats_report_elapsed_without_budget() {
+        let config = RetryConfig::new(2)
+            .unwrap()
+            .backoff(BackoffConfig::Fixed(Duration::from_millis(10)));
+
+        let (result, stats) =
+            retry_with_stats(config, async || Err::<(), _>(TransientErr("fail"))).await;
+
+        assert!(matches!(
+            result,
+            Err(CallError::RetriesExhausted { attempts: 2, .. })
+        ));
+        assert!(!stats.budget_exhausted);
+        assert!(stats.total_elapsed >= Duration::from_millis(10));
+    }
+
     // ── B3: total_budget works with zero-delay backoff ───────────────────
 
     #[tokio::test]
@@ -1261,4 +2297,148 @@ mod tests {
             .await;
         assert!(matches!(result, Err(CallError::RateLimited { .. })));
     }
+
+    // ── Per-attempt timeout ──────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn attempt_timeout_retries_hung_attempt() {
+        let counter = Arc::new(AtomicU32::new(0));
+        let c = counter.clone();
+        let config = RetryConfig::new(3)
+            .unwrap()
+            .attempt_timeout(Duration::from_millis(20));
+
+        let (result, stats) = retry_with_stats(config, async || {
+            if c.fetch_add(1, Ordering::SeqCst) == 0 {
+                tokio::time::sleep(Duration::from_mins(5)).await;
+            }
+            Ok::<_, TransientErr>(7)
+        })
+        .await;
+
+        assert_eq!(result.unwrap(), 7);
+        assert_eq!((stats.attempts, stats.timed_out, stats.failed), (2, 1, 0));
+        assert!(!stats.budget_exhausted);
+        assert!(stats.total_elapsed >= Duration::from_millis(20));
+    }
+
+    #[tokio::test]
+    async fn attempt_timeout_counts_timeouts_and_errors_separately() {
+        let counter = Arc::new(AtomicU32::new(0));
+        let c = counter.clone();
+        let config = RetryConfig::new(3)
+            .unwrap()
+            .attempt_timeout(Duration::from_millis(20));
+
+        let (result, stats) = retry_with_stats(config, async || {
+            if c.fetch_add(1, Ordering::SeqCst) == 1 {
+                return Err(TransientErr("fail"));
+            }
+            tokio::time::sleep(Duration::from_mins(5)).await;
+            Ok::<u32, _>(1)
+        })
+        .await;
+
+        assert!(matches!(result, Err(CallError::Timeout(d)) if d == Duration::from_millis(20)));
+        assert_eq!(stats.attempts, 3);
+        assert_eq!(stats.timed_out, 2);
+        assert_eq!(stats.failed, 1);
+    }
+
+    #[tokio::test]
+    async fn attempt_timeout_is_capped_by_remaining_budget() {
+        let start = std::time::Instant::now();
+        let config = RetryConfig::new(3)
+            .unwrap()
+            .attempt_timeout(Duration::from_secs(10))
+            .total_budget(Duration::from_millis(30));
+
+        let (result, stats) = retry_with_stats(config, async || {
+            tokio::time::sleep(Duration::from_mins(5)).await;
+            Ok::<u32, TransientErr>(1)
+        })
+        .await;
+
+        assert!(matches!(result, Err(CallError::Timeout(d)) if d == Duration::from_millis(30)));
+        assert_eq!(stats.attempts, 1);
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
+
+    // ── Shared retry budget ──────────────────────────────────────────────
+
+    #[test]
+    fn shared_budget_hands_out_exactly_total_tokens() {
+        let budget = SharedRetryBudget::new(2);
+        assert!(budget.try_acquire());
+        assert!(budget.try_acquire());
+        assert!(!budget.try_acquire());
+        assert_eq!(budget.remaining(), 0);
+        assert_eq!(budget.total(), 2);
+    }
+
+    #[tokio::test]
+    async fn shared_budget_stops_retries_when_spent() {
+        let budget = Arc::new(SharedRetryBudget::new(2));
+        let counter = Arc::new(AtomicU32::new(0));
+        let c = counter.clone();
+        let config = RetryConfig::new(10)
+            .unwrap()
+            .shared_budget(Arc::clone(&budget));
+
+        let (result, stats) = retry_with_stats(config, async || {
+            c.fetch_add(1, Ordering::SeqCst);
+            Err::<(), _>(TransientErr("fail"))
+        })
+        .await;
+
+        // The first attempt is free; the two tokens pay for two retries.
+        assert!(matches!(
+            result,
+            Err(CallError::BudgetExhausted {
+                attempts: 3,
+                last: TransientErr("fail"),
+                ..
+            })
+        ));
+        assert_eq!(counter.load(Ordering::SeqCst), 3);
+        assert!(stats.budget_exhausted);
+        assert_eq!(budget.remaining(), 0);
+    }
+
+    #[tokio::test]
+    async fn shared_budget_is_not_spent_on_success_or_permanent_errors() {
+        let budget = Arc::new(SharedRetryBudget::new(5));
+        let config = RetryConfig::new(3)
+            .unwrap()
+            .shared_budget(Arc::clone(&budget));
+        let value = retry_with(config, async || Ok::<_, TransientErr>(1u32)).await;
+        assert_eq!(value.unwrap(), 1);
+
+        let config = RetryConfig::new(3)
+            .unwrap()
+            .shared_budget(Arc::clone(&budget));
+        let result = retry_with(config, async || Err::<(), _>(TestApiErr::AuthFailed)).await;
+        assert!(matches!(
+            result,
+            Err(CallError::Operation(TestApiErr::AuthFailed))
+        ));
+
+        assert_eq!(budget.remaining(), 5);
+    }
+
+    #[tokio::test]
+    async fn shared_budget_does_not_cover_the_final_attempt() {
+        let budget = Arc::new(SharedRetryBudget::new(5));
+        let config = RetryConfig::new(3)
+            .unwrap()
+            .shared_budget(Arc::clone(&budget));
+
+        let result = retry_with(config, async || Err::<(), _>(TransientErr("fail"))).await;
+
+        assert!(matches!(
+            result,
+            Err(CallError::RetriesExhausted { attempts: 3, .. })
+        ));
+        assert_eq!(budget.remaining(), 3);
+    }
 }
@@ -0,0 +1,413 @@
+//! Per-key sharded circuit breaker for multi-tenant workloads.
+//!
+//! One [`CircuitBreaker`] per key, so a failing tenant (API key, region, host)
+//! opens only its own circuit. Shards are created lazily on first use, capped
+//! by [`ShardedCircuitBreaker::with_max_shards`], and idle closed shards are
+//! dropped by [`evict_idle`](ShardedCircuitBreaker::evict_idle).
+
+use std::{
+    collections::HashMap,
+    fmt,
+    future::Future,
+    hash::Hash,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use parking_lot::{Mutex, RwLock};
+
+use crate::{
+    CallError, ConfigError,
+    circuit_breaker::{CircuitBreaker, CircuitBreakerConfig},
+    clock::{Clock, SystemClock},
+    sink::{CircuitState, MetricsSink, NoopSink},
+};
+
+struct Shard {
+    breaker: Arc<CircuitBreaker>,
+    last_used: Mutex<Instant>,
+}
+
+impl Shard {
+    fn touch(&self, now: Instant) -> Arc<CircuitBreaker> {
+        *self.last_used.lock() = now;
+        Arc::clone(&self.breaker)
+    }
+
+    fn is_closed(&self) -> bool {
+        self.breaker.circuit_state() == CircuitState::Closed
+    }
+}
+
+/// A set of independent circuit breakers keyed by `K`.
+///
+/// Every shard shares the same [`CircuitBreakerConfig`], clock and sink. When the
+/// shard cap is reached, the least recently used *closed* shard is evicted to make
+/// room; if every shard is open or half-open, calls for new keys are rejected with
+/// [`CallError::CircuitOpen`] rather than forgetting a tripped circuit.
+///
+/// The crate never spawns work, so idle eviction runs only when
+/// [`evict_idle`](Self::evict_idle) is called or when the caller spawns
+/// [`eviction_task`](Self::eviction_task).
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use nebula_resilience::{ShardedCircuitBreaker, circuit_breaker::CircuitBreakerConfig};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let breakers = ShardedCircuitBreaker::<String>::new(CircuitBreakerConfig::default())
+///     .expect("valid config")
+///     .with_idle_timeout(Duration::from_mins(10));
+///
+/// let tenant = "tenant-a".to_owned();
+/// let value = breakers
+///     .call_for_key(&tenant, || async { Ok::<_, &str>(42) })
+///     .await
+///     .unwrap();
+/// assert_eq!(value, 42);
+/// assert_eq!(breakers.len(), 1);
+/// # }
+/// ```
+pub struct ShardedCircuitBreaker<K> {
+    config: CircuitBreakerConfig,
+    shards: RwLock<HashMap<K, Shard>>,
+    max_shards: usize,
+    idle_timeout: Duration,
+    clock: Arc<dyn Clock>,
+    sink: Arc<dyn MetricsSink>,
+}
+
+impl<K> fmt::Debug for ShardedCircuitBreaker<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ShardedCircuitBreaker")
+            .field("config", &self.config)
+            .field("shards", &self.shards.read().len())
+            .field("max_shards", &self.max_shards)
+            .field("idle_timeout", &self.idle_timeout)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<K: Eq + Hash + Clone> ShardedCircuitBreaker<K> {
+    /// Default cap on the number of live shards.
+    pub const DEFAULT_MAX_SHARDS: usize = 10_000;
+
+    /// Default time a closed shard may sit unused before [`evict_idle`](Self::evict_idle)
+    /// drops it.
+    pub const DEFAULT_IDLE_TIMEOUT: Duration = Duration::from_mins(10);
+
+    /// Create a sharded breaker; every shard uses `config`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ConfigError)` if `config` is invalid.
+    pub fn new(config: CircuitBreakerConfig) -> Result<Self, ConfigError> {
+        config.validate()?;
+        Ok(Self {
+            config,
+            shards: RwLock::new(HashMap::new()),
+            max_shards: Self::DEFAULT_MAX_SHARDS,
+            idle_timeout: Self::DEFAULT_IDLE_TIMEOUT,
+            clock: Arc::new(SystemClock),
+            sink: Arc::new(NoopSink),
+        })
+    }
+
+    /// Cap the number of live shards. Default: 10 000.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(ConfigError)` if `max_shards` is 0.
+    pub fn with_max_shards(mut self, max_shards: usize) -> Result<Self, ConfigError> {
+        if max_shards == 0 {
+            return Err(ConfigError::new("max_shards", "must be >= 1"));
+        }
+        self.max_shards = max_shards;
+        Ok(self)
+    }
+
+    /// Set how long a closed shard may stay unused before it is evicted. Default: 10 minutes.
+    #[must_use]
+    pub const fn with_idle_timeout(mut self, idle_timeout: Duration) -> Self {
+        self.idle_timeout = idle_timeout;
+        self
+    }
+
+    /// Replace the clock shared by all shards (builder-style, for testing).
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Inject a metrics sink shared by all shards.
+    #[must_use]
+    pub fn with_sink(mut self, sink: impl MetricsSink + 'static) -> Self {
+        self.sink = Arc::new(sink);
+        self
+    }
+
+    /// Return the breaker for `key`, creating it if needed.
+    ///
+    /// Returns `None` when the shard cap is reached and no closed shard can be evicted.
+    pub fn shard(&self, key: &K) -> Option<Arc<CircuitBreaker>> {
+        let now = self.clock.now();
+        if let Some(shard) = self.shards.read().get(key) {
+            return Some(shard.touch(now));
+        }
+
+        let mut shards = self.shards.write();
+        // Another caller may have created the shard between the two locks.
+        if let Some(shard) = shards.get(key) {
+            return Some(shard.touch(now));
+        }
+        if shards.len() >= self.max_shards && !Self::evict_lru_closed(&mut shards) {
+            return None;
+        }
+        // The config was validated in `new`, so this cannot fail.
+        let breaker = Arc::new(
+            CircuitBreaker::new(self.config.clone())
+                .ok()?
+                .with_clock(Arc::clone(&self.clock))
+                .with_shared_sink(Arc::clone(&self.sink)),
+        );
+        shards.insert(
+            key.clone(),
+            Shard {
+                breaker: Arc::clone(&breaker),
+                last_used: Mutex::new(now),
+            },
+        );
+        drop(shards);
+        Some(breaker)
+    }
+
+    /// Execute `f` under the breaker for `key`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(CallError::CircuitOpen)` if the key's circuit is open or no
+    /// shard could be allocated, or `Err(CallError::Operation)` on operation error.
+    ///
+    /// # Cancel safety
+    ///
+    /// Same as [`CircuitBreaker::call`].
+    pub async fn call_for_key<T, E, Fut>(
+        &self,
+        key: &K,
+        f: impl FnOnce() -> Fut,
+    ) -> Result<T, CallError<E>>
+    where
+        K: Sync,
+        Fut: Future<Output = Result<T, E>> + Send,
+    {
+        let Some(breaker) = self.shard(key) else {
+            return Err(CallError::CircuitOpen);
+        };
+        breaker.call(f).await
+    }
+
+    /// Current state of the shard for `key`, without creating it.
+    pub fn circuit_state(&self, key: &K) -> Option<CircuitState> {
+        self.shards
+            .read()
+            .get(key)
+            .map(|shard| shard.breaker.circuit_state())
+    }
+
+    /// Drop the shard for `key`. Returns whether a shard existed.
+    pub fn remove(&self, key: &K) -> bool {
+        self.shards.write().remove(key).is_some()
+    }
+
+    /// Number of live shards.
+    pub fn len(&self) -> usize {
+        self.shards.read().len()
+    }
+
+    /// Whether no shard has been created yet (or all were evicted).
+    pub fn is_empty(&self) -> bool {
+        self.shards.read().is_empty()
+    }
+
+    /// Evict closed shards unused for at least the idle timeout.
+    ///
+    /// Open and half-open shards are kept so a failing tenant stays tripped.
+    /// Returns the number of shards removed.
+    pub fn evict_idle(&self) -> usize {
+        let now = self.clock.now();
+        let mut shards = self.shards.write();
+        let before = shards.len();
+        shards.retain(|_, shard| {
+            !shard.is_closed()
+                || now.saturating_duration_since(*shard.last_used.lock()) < self.idle_timeout
+        });
+        before - shards.len()
+    }
+
+    /// A future that calls [`evict_idle`](Self::evict_idle) every `interval`.
+    ///
+    /// Spawn it on your runtime. It holds only a weak reference and completes once
+    /// the sharded breaker is dropped. A zero `interval` is treated as 1 ms.
+    pub fn eviction_task(self: &Arc<Self>, interval: Duration) -> impl Future<Output = ()> + use<K>
+    where
+        K: Send + Sync + 'static,
+    {
+        let weak = Arc::downgrade(self);
+        let interval = interval.max(Duration::from_millis(1));
+        async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            // The first tick completes immediately.
+            ticker.tick().await;
+            loop {
+                ticker.tick().await;
+                let Some(this) = weak.upgrade() else {
+                    break;
+                };
+                this.evict_idle();
+            }
+        }
+    }
+
+    /// Remove the least recently used closed shard. Returns `false` if none is closed.
+    fn evict_lru_closed(shards: &mut HashMap<K, Shard>) -> bool {
+        let victim = shards
+            .iter()
+            .filter(|(_, shard)| shard.is_closed())
+            .min_by_key(|(_, shard)| *shard.last_used.lock())
+            .map(|(key, _)| key.clone());
+        victim.is_some_and(|key| shards.remove(&key).is_some())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{circuit_breaker::Outcome, clock::MockClock};
+
+    fn config() -> CircuitBreakerConfig {
+        CircuitBreakerConfig {
+            failure_threshold: 3,
+            reset_timeout: Duration::from_mins(1),
+            min_operations: 1,
+            ..CircuitBreakerConfig::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn tenants_do_not_cross_contaminate() {
+        let breakers = ShardedCircuitBreaker::<&str>::new(config()).unwrap();
+
+        for _ in 0..5 {
+            let _ = breakers
+                .call_for_key(&"tenant-a", || async { Err::<(), _>("down") })
+                .await;
+            let ok = breakers
+                .call_for_key(&"tenant-b", || async { Ok::<_, &str>(()) })
+                .await;
+            assert!(ok.is_ok());
+        }
+
+        assert_eq!(
+            breakers.circuit_state(&"tenant-a"),
+            Some(CircuitState::Open)
+        );
+        assert_eq!(
+            breakers.circuit_state(&"tenant-b"),
+            Some(CircuitState::Closed)
+        );
+        let rejected = breakers
+            .call_for_key(&"tenant-a", || async { Ok::<_, &str>(()) })
+            .await;
+        assert!(matches!(rejected, Err(CallError::CircuitOpen)));
+    }
+
+    #[test]
+    fn cap_evicts_least_recently_used_closed_shard() {
+        let clock = Arc::new(MockClock::new());
+        let breakers = ShardedCircuitBreaker::<u32>::new(config())
+            .unwrap()
+            .with_max_shards(2)
+            .unwrap()
+            .with_clock(clock.clone());
+
+        breakers.shard(&1).unwrap();
+        clock.advance(Duration::from_secs(1));
+        breakers.shard(&2).unwrap();
+        clock.advance(Duration::from_secs(1));
+        breakers.shard(&1).unwrap();
+
+        breakers.shard(&3).unwrap();
+        assert_eq!(breakers.len(), 2);
+        assert!(breakers.circuit_state(&2).is_none());
+        assert!(breakers.circuit_state(&1).is_some());
+    }
+
+    #[test]
+    fn cap_rejects_new_keys_when_all_shards_are_open() {
+        let breakers = ShardedCircuitBreaker::<u32>::new(config())
+            .unwrap()
+            .with_max_shards(1)
+            .unwrap();
+        breakers.shard(&1).unwrap().force_open();
+
+        assert!(breakers.shard(&2).is_none());
+        assert_eq!(breakers.circuit_state(&1), Some(CircuitState::Open));
+    }
+
+    #[test]
+    fn evict_idle_keeps_open_and_recent_shards() {
+        let clock = Arc::new(MockClock::new());
+        let breakers = ShardedCircuitBreaker::<u32>::new(config())
+            .unwrap()
+            .with_idle_timeout(Duration::from_secs(10))
+            .with_clock(clock.clone());
+
+        breakers.shard(&1).unwrap();
+        let tripped = breakers.shard(&2).unwrap();
+        for _ in 0..3 {
+            tripped.record_outcome(Outcome::Failure);
+        }
+        clock.advance(Duration::from_secs(11));
+        breakers.shard(&3).unwrap();
+
+        assert_eq!(breakers.evict_idle(), 1);
+        assert!(breakers.circuit_state(&1).is_none());
+        assert_eq!(breakers.circuit_state(&2), Some(CircuitState::Open));
+        assert!(breakers.circuit_state(&3).is_some());
+    }
+
+    #[test]
+    fn rejects_zero_max_shards() {
+        let err = ShardedCircuitBreaker::<u32>::new(config())
+            .unwrap()
+            .with_max_shards(0)
+            .unwrap_err();
+        assert_eq!(err.field, "max_shards");
+    }
+
+    #[tokio::test]
+    async fn eviction_task_stops_when_breaker_is_dropped() {
+        let breakers = Arc::new(
+            ShardedCircuitBreaker::<u32>::new(config())
+                .unwrap()
+                .with_idle_timeout(Duration::ZERO),
+        );
+        breakers.shard(&1).unwrap();
+        let task = tokio::spawn(breakers.eviction_task(Duration::from_millis(5)));
+
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        assert!(breakers.is_empty());
+
+        drop(breakers);
+        tokio::time::timeout(Duration::from_secs(1), task)
+            .await
+            .expect("eviction task should finish")
+            .unwrap();
+    }
+}
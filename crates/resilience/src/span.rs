@@ -0,0 +1,152 @@
+//! `tracing` spans for resilience patterns (`tracing` feature).
+//!
+//! Each [`CircuitBreaker`](crate::CircuitBreaker) call and each retry loop runs
+//! inside an `INFO` span. Field names use the `resilience.*` namespace, and
+//! `otel.status_code` follows the convention `tracing-opentelemetry` maps to
+//! the exported span status:
+//!
+//! | Span | Fields |
+//! |------|--------|
+//! | `resilience.circuit_breaker` | `resilience.pattern`, `resilience.circuit_breaker.state`, `resilience.outcome`, `otel.status_code` |
+//! | `resilience.retry` | `resilience.pattern`, `resilience.retry.max_attempts`, `resilience.retry.attempt`, `resilience.retry.backoff_ms`, `resilience.outcome`, `otel.status_code` |
+//!
+//! `resilience.circuit_breaker.state` is the state the call found the breaker
+//! in. `resilience.retry.attempt` and `resilience.retry.backoff_ms` hold the
+//! latest attempt and backoff. Each backoff also emits a `DEBUG` event inside
+//! the span. `resilience.outcome` is `success` or the snake-case
+//! [`CallErrorKind`](crate::CallErrorKind) of the error.
+//!
+//! Without the feature, [`PatternSpan`] is zero-sized and every method is a
+//! no-op.
+
+// Reason: `unreachable_pub` wants `pub(crate)` here; the crate-level nursery
+// group re-enables the lint that fights it.
+#![allow(clippy::redundant_pub_crate)]
+
+use std::{future::Future, time::Duration};
+
+use crate::{CallError, sink::CircuitState};
+
+/// Span wrapping one pattern call; a no-op unless the `tracing` feature is on.
+pub(crate) struct PatternSpan {
+    #[cfg(feature = "tracing")]
+    span: tracing::Span,
+}
+
+#[cfg(feature = "tracing")]
+impl PatternSpan {
+    pub(crate) fn circuit_breaker(state: CircuitState) -> Self {
+        Self {
+            span: tracing::info_span!(
+                "resilience.circuit_breaker",
+                resilience.pattern = "circuit_breaker",
+                resilience.circuit_breaker.state = state_name(state),
+                resilience.outcome = tracing::field::Empty,
+                otel.status_code = tracing::field::Empty,
+            ),
+        }
+    }
+
+    pub(crate) fn retry(max_attempts: u32) -> Self {
+        Self {
+            span: tracing::info_span!(
+                "resilience.retry",
+                resilience.pattern = "retry",
+                resilience.retry.max_attempts = max_attempts,
+                resilience.retry.attempt = tracing::field::Empty,
+                resilience.retry.backoff_ms = tracing::field::Empty,
+                resilience.outcome = tracing::field::Empty,
+                otel.status_code = tracing::field::Empty,
+            ),
+        }
+    }
+
+    /// Run `fut` inside the span.
+    pub(crate) async fn run<F: Future>(&self, fut: F) -> F::Output {
+        use tracing::Instrument;
+
+        fut.instrument(self.span.clone()).await
+    }
+
+    /// Record the number of attempts the call made.
+    pub(crate) fn record_attempts(&self, attempts: u32) {
+        self.span.record("resilience.retry.attempt", attempts);
+    }
+
+    /// Record the final outcome and the matching OpenTelemetry status.
+    pub(crate) fn record_outcome<T, E>(&self, result: &Result<T, CallError<E>>) {
+        let (outcome, status) = match result {
+            Ok(_) => ("success", "OK"),
+            Err(e) => (kind_name(e), "ERROR"),
+        };
+        self.span.record("resilience.outcome", outcome);
+        self.span.record("otel.status_code", status);
+    }
+}
+
+#[cfg(not(feature = "tracing"))]
+#[expect(clippy::unused_self, reason = "mirrors the `tracing` API")]
+impl PatternSpan {
+    pub(crate) const fn circuit_breaker(_state: CircuitState) -> Self {
+        Self {}
+    }
+
+    pub(crate) const fn retry(_max_attempts: u32) -> Self {
+        Self {}
+    }
+
+    pub(crate) async fn run<F: Future>(&self, fut: F) -> F::Output {
+        fut.await
+    }
+
+    pub(crate) const fn record_attempts(&self, _attempts: u32) {}
+
+    pub(crate) const fn record_outcome<T, E>(&self, _result: &Result<T, CallError<E>>) {}
+}
+
+/// Record a retry backoff on the enclosing retry span.
+///
+/// `attempt` is the 1-based attempt that just failed.
+#[cfg(feature = "tracing")]
+pub(crate) fn record_backoff(attempt: u32, delay: Duration) {
+    let backoff_ms = u64::try_from(delay.as_millis()).unwrap_or(u64::MAX);
+    let span = tracing::Span::current();
+    span.record("resilience.retry.attempt", attempt);
+    span.record("resilience.retry.backoff_ms", backoff_ms);
+    tracing::debug!(
+        resilience.retry.attempt = attempt,
+        resilience.retry.backoff_ms = backoff_ms,
+        "retrying after backoff"
+    );
+}
+
+#[cfg(not(feature = "tracing"))]
+pub(crate) const fn record_backoff(_attempt: u32, _delay: Duration) {}
+
+#[cfg(feature = "tracing")]
+const fn state_name(state: CircuitState) -> &'static str {
+    match state {
+        CircuitState::Closed => "closed",
+        CircuitState::Open => "open",
+        CircuitState::HalfOpen => "half_open",
+    }
+}
+
+#[cfg(feature = "tracing")]
+const fn kind_name<E>(error: &CallError<E>) -> &'static str {
+    use crate::CallErrorKind;
+
+    match error.kind() {
+        CallErrorKind::Operation => "operation",
+        CallErrorKind::CircuitOpen => "circuit_open",
+        CallErrorKind::BulkheadFull => "bulkhead_full",
+        CallErrorKind::BulkheadTimeout => "bulkhead_timeout",
+        CallErrorKind::Timeout => "timeout",
+        CallErrorKind::RetriesExhausted => "retries_exhausted",
+        CallErrorKind::BudgetExhausted => "budget_exhausted",
+        CallErrorKind::Cancelled => "cancelled",
+        CallErrorKind::LoadShed => "load_shed",
+        CallErrorKind::RateLimited => "rate_limited",
+        CallErrorKind::FallbackFailed => "fallback_failed",
+    }
+}
@@ -2,10 +2,13 @@
 
 use std::{fmt, future::Future, sync::Arc, time::Duration};
 
-use tokio::time::timeout as tokio_timeout;
+use parking_lot::RwLock;
+use tokio::time::{Instant, timeout as tokio_timeout};
+use tokio_util::sync::CancellationToken;
 
 use crate::{
     CallError, ConfigError, PolicyContext,
+    hedge::LatencyTracker,
     sink::{MetricsSink, NoopSink, ResilienceEvent},
 };
 
@@ -85,6 +88,90 @@ where
     }
 }
 
+/// Like [`timeout`] but cancels `token` when the deadline fires.
+///
+/// Dropping `future` on timeout only stops work that the future owns. Work it
+/// handed off — spawned tasks, blocking threads, remote calls — keeps running
+/// and keeps whatever it holds (bulkhead permits, connections, locks). Give
+/// that work a clone of `token` and have it stop on
+/// [`CancellationToken::cancelled`]; this function cancels the token exactly
+/// when it returns `CallError::Timeout`, including for a zero `duration`.
+/// On success or operation error the token is left untouched.
+///
+/// # Errors
+///
+/// Returns `Err(CallError::Timeout)` on timeout or `Err(CallError::Operation)` on operation error.
+///
+/// # Cancel safety
+///
+/// Cancel-safe with respect to this crate: dropping the returned future
+/// drops the in-flight operation at its current `.await` and discards the
+/// timeout bookkeeping without cancelling `token`. Whether a *partially
+/// executed* operation is safe to abandon is the supplied operation's own
+/// contract.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+///
+/// use nebula_resilience::{CallError, timeout_with_token};
+/// use tokio_util::sync::CancellationToken;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let token = CancellationToken::new();
+/// let worker = token.clone();
+/// let result: Result<(), CallError<()>> = timeout_with_token(
+///     Duration::from_millis(50),
+///     token,
+///     async move {
+///         // Detached work observes the token and stops when the deadline fires.
+///         tokio::spawn(async move { worker.cancelled().await })
+///             .await
+///             .map_err(|_| ())
+///     },
+/// )
+/// .await;
+/// assert!(matches!(result, Err(CallError::Timeout(_))));
+/// # }
+/// ```
+pub async fn timeout_with_token<T, E, F>(
+    duration: Duration,
+    token: CancellationToken,
+    future: F,
+) -> Result<T, CallError<E>>
+where
+    F: Future<Output = Result<T, E>>,
+{
+    timeout_with_token_and_sink(duration, token, future, &NoopSink).await
+}
+
+/// Like [`timeout_with_token`] but emits [`ResilienceEvent::TimeoutElapsed`] via `sink`.
+///
+/// # Errors
+///
+/// Returns `Err(CallError::Timeout)` on timeout or `Err(CallError::Operation)` on operation error.
+///
+/// # Cancel safety
+///
+/// Same as [`timeout_with_token`].
+pub async fn timeout_with_token_and_sink<T, E, F>(
+    duration: Duration,
+    token: CancellationToken,
+    future: F,
+    sink: &dyn MetricsSink,
+) -> Result<T, CallError<E>>
+where
+    F: Future<Output = Result<T, E>>,
+{
+    let result = timeout_with_sink(duration, future, sink).await;
+    if matches!(result, Err(CallError::Timeout(_))) {
+        token.cancel();
+    }
+    result
+}
+
 /// Like [`timeout`] but also observes a shared [`PolicyContext`].
 ///
 /// The effective deadline is the earlier of `duration` and the context deadline.
@@ -283,6 +370,198 @@ impl TimeoutExecutor {
     }
 }
 
+// ── AdaptiveTimeout ───────────────────────────────────────────────────────────
+
+/// A timeout whose limit follows observed latency.
+///
+/// Keeps the last `window` latencies (default 1000) and derives the limit as
+/// `percentile(p) × safety_factor` (defaults: p99 × 1.5), clamped to
+/// `[min, max]`. Until the first sample arrives, the initial duration is used.
+///
+/// Record latencies with [`update`](Self::update), or let [`call`](Self::call)
+/// do it. A call that times out records the limit it hit, so sustained
+/// slowdowns push the limit upward instead of being invisible to the window.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use nebula_resilience::AdaptiveTimeout;
+///
+/// let timeout = AdaptiveTimeout::new(Duration::from_millis(200))
+///     .with_percentile(0.5)
+///     .unwrap()
+///     .with_safety_factor(2.0)
+///     .unwrap();
+/// assert_eq!(timeout.current(), Duration::from_millis(200));
+///
+/// timeout.update(Duration::from_millis(100));
+/// assert_eq!(timeout.current(), Duration::from_millis(200));
+/// ```
+pub struct AdaptiveTimeout {
+    tracker: RwLock<LatencyTracker>,
+    initial: Duration,
+    percentile: f64,
+    safety_factor: f64,
+    min: Duration,
+    max: Duration,
+    sink: Arc<dyn MetricsSink>,
+}
+
+impl fmt::Debug for AdaptiveTimeout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AdaptiveTimeout")
+            .field("initial", &self.initial)
+            .field("percentile", &self.percentile)
+            .field("safety_factor", &self.safety_factor)
+            .field("min", &self.min)
+            .field("max", &self.max)
+            .finish_non_exhaustive()
+    }
+}
+
+impl AdaptiveTimeout {
+    const DEFAULT_WINDOW: usize = 1000;
+
+    /// Create an adaptive timeout that uses `initial` until latencies are recorded.
+    #[must_use]
+    pub fn new(initial: Duration) -> Self {
+        Self {
+            tracker: RwLock::new(LatencyTracker::new(Self::DEFAULT_WINDOW)),
+            initial,
+            percentile: 0.99,
+            safety_factor: 1.5,
+            min: Duration::from_millis(1),
+            max: Duration::MAX,
+            sink: Arc::new(NoopSink),
+        }
+    }
+
+    /// Set how many recent latencies are kept. Default: 1000.
+    ///
+    /// Replaces the window, discarding samples recorded so far.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError`] if `window` is 0.
+    pub fn with_window(mut self, window: usize) -> Result<Self, ConfigError> {
+        if window == 0 {
+            return Err(ConfigError::new("adaptive_timeout.window", "must be >= 1"));
+        }
+        self.tracker = RwLock::new(LatencyTracker::new(window));
+        Ok(self)
+    }
+
+    /// Set the latency percentile the limit is derived from. Default: 0.99.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError`] if `percentile` is not finite or outside `0.0..=1.0`.
+    pub fn with_percentile(mut self, percentile: f64) -> Result<Self, ConfigError> {
+        if !percentile.is_finite() || !(0.0..=1.0).contains(&percentile) {
+            return Err(ConfigError::new(
+                "adaptive_timeout.percentile",
+                "must be 0.0..=1.0",
+            ));
+        }
+        self.percentile = percentile;
+        Ok(self)
+    }
+
+    /// Set the multiplier applied to the percentile latency. Default: 1.5.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError`] if `factor` is not finite or is below 1.0.
+    pub fn with_safety_factor(mut self, factor: f64) -> Result<Self, ConfigError> {
+        if !factor.is_finite() || factor < 1.0 {
+            return Err(ConfigError::new(
+                "adaptive_timeout.safety_factor",
+                "must be finite and >= 1.0",
+            ));
+        }
+        self.safety_factor = factor;
+        Ok(self)
+    }
+
+    /// Clamp the derived limit to `[min, max]`. Default: `[1ms, Duration::MAX]`.
+    ///
+    /// The initial duration is returned as-is before any sample is recorded.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError`] if `min` is zero or greater than `max`.
+    pub fn with_bounds(mut self, min: Duration, max: Duration) -> Result<Self, ConfigError> {
+        if min.is_zero() || min > max {
+            return Err(ConfigError::new(
+                "adaptive_timeout.bounds",
+                "min must be > 0 and <= max",
+            ));
+        }
+        self.min = min;
+        self.max = max;
+        Ok(self)
+    }
+
+    /// Inject a metrics sink.
+    #[must_use]
+    pub fn with_sink(mut self, sink: impl MetricsSink + 'static) -> Self {
+        self.sink = Arc::new(sink);
+        self
+    }
+
+    /// Record an observed latency.
+    pub fn update(&self, latency: Duration) {
+        self.tracker.write().record(latency);
+    }
+
+    /// The current timeout limit.
+    #[must_use]
+    pub fn current(&self) -> Duration {
+        let Some(observed) = self.tracker.read().percentile(self.percentile) else {
+            return self.initial;
+        };
+        // Saturate instead of panicking when the product overflows `Duration`.
+        let scaled = Duration::try_from_secs_f64(observed.as_secs_f64() * self.safety_factor)
+            .unwrap_or(Duration::MAX);
+        scaled.clamp(self.min, self.max)
+    }
+
+    /// Number of latencies currently in the window.
+    #[must_use]
+    pub fn sample_count(&self) -> usize {
+        self.tracker.read().len()
+    }
+
+    /// Execute `future` within [`current`](Self::current) and record its latency.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(CallError::Timeout)` on timeout or `Err(CallError::Operation)` on operation
+    /// error.
+    ///
+    /// # Cancel safety
+    ///
+    /// Cancel-safe with respect to this crate: dropping the returned future
+    /// drops the in-flight operation and records no latency sample.
+    pub async fn call<T, E, F>(&self, future: F) -> Result<T, CallError<E>>
+    where
+        F: Future<Output = Result<T, E>>,
+    {
+        let limit = self.current();
+        let start = Instant::now();
+        let result = timeout_with_sink(limit, future, self.sink.as_ref()).await;
+        let observed = if matches!(result, Err(CallError::Timeout(_))) {
+            limit
+        } else {
+            start.elapsed()
+        };
+        self.update(observed);
+        result
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::sync::{
@@ -291,7 +570,10 @@ mod tests {
     };
 
     use super::*;
-    use crate::{CallError, CancellationContext, RecordingSink, ResilienceEventKind};
+    use crate::{
+        Bulkhead, BulkheadConfig, CallError, CancellationContext, RecordingSink,
+        ResilienceEventKind,
+    };
 
     #[tokio::test]
     async fn timeout_success() {
@@ -328,6 +610,71 @@ mod tests {
         assert!(!polled.load(Ordering::SeqCst));
     }
 
+    #[tokio::test]
+    async fn timeout_with_token_releases_bulkhead_permit_held_by_detached_work() {
+        let bulkhead = Arc::new(
+            Bulkhead::new(BulkheadConfig {
+                max_concurrency: 1,
+                ..BulkheadConfig::default()
+            })
+            .unwrap(),
+        );
+        let token = CancellationToken::new();
+        let worker_token = token.clone();
+        let guarded = Arc::clone(&bulkhead);
+
+        let result: Result<(), CallError<()>> =
+            timeout_with_token(Duration::from_millis(20), token.clone(), async move {
+                let permit = guarded.acquire::<()>().await.map_err(|_| ())?;
+                // Detached work outlives the dropped future unless it observes the token.
+                tokio::spawn(async move {
+                    worker_token.cancelled().await;
+                    drop(permit);
+                })
+                .await
+                .map_err(|_| ())
+            })
+            .await;
+
+        assert!(matches!(result, Err(CallError::Timeout(d)) if d == Duration::from_millis(20)));
+        assert!(token.is_cancelled());
+        tokio::time::timeout(Duration::from_secs(1), async {
+            while bulkhead.available_permits() < 1 {
+                tokio::task::yield_now().await;
+            }
+        })
+        .await
+        .expect("permit should be released after the token is cancelled");
+    }
+
+    #[tokio::test]
+    async fn timeout_with_token_leaves_token_alone_on_completion() {
+        let token = CancellationToken::new();
+
+        let ok = timeout_with_token(Duration::from_millis(100), token.clone(), async {
+            Ok::<_, &str>(1)
+        })
+        .await;
+        let err = timeout_with_token(Duration::from_millis(100), token.clone(), async {
+            Err::<(), _>("fail")
+        })
+        .await;
+
+        assert_eq!(ok.unwrap(), 1);
+        assert!(matches!(err, Err(CallError::Operation("fail"))));
+        assert!(!token.is_cancelled());
+    }
+
+    #[tokio::test]
+    async fn zero_timeout_with_token_cancels_immediately() {
+        let token = CancellationToken::new();
+        let result: Result<(), CallError<()>> =
+            timeout_with_token(Duration::ZERO, token.clone(), async { Ok(()) }).await;
+
+        assert!(matches!(result, Err(CallError::Timeout(d)) if d.is_zero()));
+        assert!(token.is_cancelled());
+    }
+
     #[test]
     fn try_new_rejects_zero_timeout() {
         let err = TimeoutExecutor::try_new(Duration::ZERO).unwrap_err();
@@ -411,4 +758,125 @@ mod tests {
         assert!(matches!(result, Err(CallError::Timeout(_))));
         assert_eq!(sink.count(ResilienceEventKind::TimeoutElapsed), 0);
     }
+
+    #[test]
+    fn adaptive_uses_initial_without_samples() {
+        let timeout = AdaptiveTimeout::new(Duration::from_millis(250));
+        assert_eq!(timeout.sample_count(), 0);
+        assert_eq!(timeout.current(), Duration::from_millis(250));
+    }
+
+    #[test]
+    fn adaptive_scales_percentile_by_safety_factor() {
+        let timeout = AdaptiveTimeout::new(Duration::from_secs(1))
+            .with_percentile(1.0)
+            .unwrap()
+            .with_safety_factor(2.0)
+            .unwrap();
+        for ms in [10, 20, 40] {
+            timeout.update(Duration::from_millis(ms));
+        }
+        assert_eq!(timeout.current(), Duration::from_millis(80));
+    }
+
+    #[test]
+    fn adaptive_window_evicts_oldest_samples() {
+        let timeout = AdaptiveTimeout::new(Duration::from_secs(1))
+            .with_window(3)
+            .unwrap()
+            .with_percentile(1.0)
+            .unwrap();
+        timeout.update(Duration::from_millis(500));
+        for _ in 0..3 {
+            timeout.update(Duration::from_millis(100));
+        }
+        assert_eq!(timeout.sample_count(), 3);
+        assert_eq!(timeout.current(), Duration::from_millis(150));
+    }
+
+    #[test]
+    fn adaptive_handles_many_distinct_latencies() {
+        // More distinct values than the histogram keeps inline, so it spills to the heap.
+        let timeout = AdaptiveTimeout::new(Duration::from_secs(1))
+            .with_window(100)
+            .unwrap()
+            .with_percentile(0.5)
+            .unwrap()
+            .with_safety_factor(1.0)
+            .unwrap();
+        for ms in 1..=300 {
+            timeout.update(Duration::from_millis(ms));
+        }
+        assert_eq!(timeout.sample_count(), 100);
+        assert_eq!(timeout.current(), Duration::from_millis(250));
+    }
+
+    #[test]
+    fn adaptive_clamps_to_bounds_and_saturates() {
+        let timeout = AdaptiveTimeout::new(Duration::from_secs(1))
+            .with_bounds(Duration::from_millis(50), Duration::from_secs(5))
+            .unwrap();
+        timeout.update(Duration::ZERO);
+        assert_eq!(timeout.current(), Duration::from_millis(50));
+
+        timeout.update(Duration::from_hours(1));
+        timeout.update(Duration::from_hours(1));
+        assert_eq!(timeout.current(), Duration::from_secs(5));
+
+        let unbounded = AdaptiveTimeout::new(Duration::from_secs(1))
+            .with_safety_factor(f64::MAX)
+            .unwrap();
+        unbounded.update(Duration::from_hours(1));
+        assert_eq!(unbounded.current(), Duration::MAX);
+    }
+
+    #[test]
+    fn adaptive_rejects_invalid_config() {
+        let base = || AdaptiveTimeout::new(Duration::from_secs(1));
+        assert!(base().with_window(0).is_err());
+        assert!(base().with_percentile(1.5).is_err());
+        assert!(base().with_percentile(f64::NAN).is_err());
+        assert!(base().with_safety_factor(0.5).is_err());
+        assert!(
+            base()
+                .with_bounds(Duration::ZERO, Duration::from_secs(1))
+                .is_err()
+        );
+        assert!(
+            base()
+                .with_bounds(Duration::from_secs(2), Duration::from_secs(1))
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn adaptive_call_records_latency_and_timeouts() {
+        let sink = RecordingSink::new();
+        let timeout = AdaptiveTimeout::new(Duration::from_millis(100))
+            .with_percentile(1.0)
+            .unwrap()
+            .with_safety_factor(1.0)
+            .unwrap()
+            .with_sink(sink.clone());
+
+        let ok: Result<(), CallError<()>> = timeout
+            .call(async {
+                tokio::time::sleep(Duration::from_millis(40)).await;
+                Ok(())
+            })
+            .await;
+        assert!(ok.is_ok());
+        let learned = timeout.current();
+        assert!(learned >= Duration::from_millis(40) && learned < Duration::from_millis(100));
+
+        let timed_out: Result<(), CallError<()>> = timeout
+            .call(async {
+                tokio::time::sleep(Duration::from_secs(1)).await;
+                Ok(())
+            })
+            .await;
+        assert!(matches!(timed_out, Err(CallError::Timeout(d)) if d == learned));
+        assert_eq!(timeout.sample_count(), 2);
+        assert_eq!(sink.count(ResilienceEventKind::TimeoutElapsed), 1);
+    }
 }
@@ -0,0 +1,102 @@
+//! `ChaosLayer` driving a real `CircuitBreaker`: the breaker must trip at its
+//! configured threshold under injected failures and recover once chaos is off.
+
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicU32, Ordering},
+    },
+    time::Duration,
+};
+
+use nebula_resilience::{
+    CallError,
+    chaos::{ChaosLayer, ChaosPolicy},
+    circuit_breaker::{CircuitBreaker, CircuitBreakerConfig},
+    sink::CircuitState,
+};
+
+fn breaker() -> CircuitBreaker {
+    CircuitBreaker::new(CircuitBreakerConfig {
+        failure_threshold: 3,
+        reset_timeout: Duration::from_millis(50),
+        max_half_open_operations: 1,
+        min_operations: 1,
+        ..Default::default()
+    })
+    .unwrap()
+}
+
+#[tokio::test]
+async fn breaker_trips_at_threshold_under_chaos_and_recovers_when_disabled() {
+    let cb = breaker();
+    let backend_calls = Arc::new(AtomicU32::new(0));
+    let calls = Arc::clone(&backend_calls);
+    let chaos = ChaosLayer::new(ChaosPolicy::new("injected").error_rate(1.0));
+    let op = chaos.wrap(move || {
+        calls.fetch_add(1, Ordering::SeqCst);
+        async { Ok::<u32, &str>(42) }
+    });
+
+    // Two injected failures stay below the threshold.
+    for _ in 0..2 {
+        let result = cb.call(&op).await;
+        assert!(matches!(result, Err(CallError::Operation("injected"))));
+    }
+    assert_eq!(cb.circuit_state(), CircuitState::Closed);
+
+    // The third trips the breaker.
+    let result = cb.call(&op).await;
+    assert!(matches!(result, Err(CallError::Operation("injected"))));
+    assert_eq!(cb.circuit_state(), CircuitState::Open);
+
+    // Open: rejected before the chaos layer is even reached.
+    let result = cb.call(&op).await;
+    assert!(matches!(result, Err(CallError::CircuitOpen)));
+    assert_eq!(chaos.calls(), 3);
+    assert_eq!(backend_calls.load(Ordering::SeqCst), 0);
+
+    // Chaos off: the half-open probe reaches the healthy backend and closes.
+    chaos.set_enabled(false);
+    tokio::time::sleep(Duration::from_millis(60)).await;
+    assert_eq!(cb.call(&op).await.unwrap(), 42);
+    assert_eq!(cb.circuit_state(), CircuitState::Closed);
+    assert_eq!(cb.call(&op).await.unwrap(), 42);
+    assert_eq!(backend_calls.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn sporadic_failures_below_threshold_do_not_trip() {
+    let cb = breaker();
+    // Every third call fails; successes in between reset the failure count.
+    let chaos = ChaosLayer::new(
+        ChaosPolicy::new("injected")
+            .fail_nth(3)
+            .fail_nth(6)
+            .fail_nth(9),
+    );
+    let op = chaos.wrap(|| async { Ok::<u32, &str>(1) });
+
+    for _ in 0..9 {
+        let _ = cb.call(&op).await;
+    }
+    assert_eq!(chaos.injected(), 3);
+    assert_eq!(cb.circuit_state(), CircuitState::Closed);
+}
+
+#[tokio::test]
+async fn half_open_probe_reopens_while_chaos_persists() {
+    let cb = breaker();
+    let chaos = ChaosLayer::new(ChaosPolicy::new("injected").error_rate(1.0));
+    let op = chaos.wrap(|| async { Ok::<u32, &str>(1) });
+
+    for _ in 0..3 {
+        let _ = cb.call(&op).await;
+    }
+    assert_eq!(cb.circuit_state(), CircuitState::Open);
+
+    tokio::time::sleep(Duration::from_millis(60)).await;
+    let result = cb.call(&op).await;
+    assert!(matches!(result, Err(CallError::Operation("injected"))));
+    assert_eq!(cb.circuit_state(), CircuitState::Open);
+}
@@ -6,6 +6,7 @@
 //! - Consistent state transitions with no stuck states.
 //! - All futures resolve (no hangs) regardless of success/failure mix.
 //! - Cooperative shutdown (Gate) is reliable while tasks are in-flight.
+//! - A shared retry budget caps retries across all callers together.
 //!
 //! Individual tests are gated with `#[cfg(not(miri))]` because Miri cannot
 //! drive the Tokio multi-thread runtime.
@@ -24,14 +25,14 @@ use nebula_resilience::{
     circuit_breaker::{CircuitBreaker, CircuitBreakerConfig},
     gate::Gate,
     pipeline::ResiliencePipeline,
-    retry::{BackoffConfig, RetryConfig},
+    retry::{BackoffConfig, RetryConfig, SharedRetryBudget, retry_with_inner},
     sink::CircuitState,
 };
 
 // ── Test 1: Bulkhead under heavy contention ─────────────────────────────────
 
 /// Verifies that 10 000 tasks competing for 100 bulkhead permits all either
-/// complete successfully or get rejected (`BulkheadFull` / `Timeout`), with
+/// complete successfully or get rejected (`BulkheadFull` / `BulkheadTimeout`), with
 /// zero permit leaks after every task has finished.
 #[cfg(not(miri))]
 #[tokio::test(flavor = "multi_thread")]
@@ -69,7 +70,7 @@ async fn stress_bulkhead_heavy_contention() {
                 Ok(_) => {
                     completed.fetch_add(1, Ordering::Relaxed);
                 },
-                Err(CallError::BulkheadFull | CallError::Timeout(_)) => {
+                Err(CallError::BulkheadFull | CallError::BulkheadTimeout(_)) => {
                     rejected.fetch_add(1, Ordering::Relaxed);
                 },
                 Err(other) => panic!("unexpected error: {other:?}"),
@@ -329,3 +330,61 @@ async fn stress_gate_shutdown_under_load() {
     );
     assert!(gate.is_closed(), "gate must report closed after close()");
 }
+
+// ── Test 5: Shared retry budget under a retry storm ──────────────────────────
+
+/// Verifies that 1 000 always-failing tasks sharing one `SharedRetryBudget`
+/// make no more retries between them than the budget holds, even though each
+/// task alone would retry up to its own `max_attempts`.
+#[cfg(not(miri))]
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn stress_shared_retry_budget_caps_total_attempts() {
+    const TASKS: u32 = 1_000;
+    const MAX_ATTEMPTS: u32 = 10;
+    const BUDGET: usize = 500;
+
+    let budget = Arc::new(SharedRetryBudget::new(BUDGET));
+    let attempts = Arc::new(AtomicU32::new(0));
+    let exhausted = Arc::new(AtomicU32::new(0));
+
+    let mut handles = Vec::with_capacity(TASKS as usize);
+    for _ in 0..TASKS {
+        let budget = Arc::clone(&budget);
+        let attempts = Arc::clone(&attempts);
+        let exhausted = Arc::clone(&exhausted);
+        handles.push(tokio::spawn(async move {
+            let config = RetryConfig::<&'static str>::new(MAX_ATTEMPTS)
+                .unwrap()
+                .shared_budget(budget);
+            let result = retry_with_inner(config, || {
+                attempts.fetch_add(1, Ordering::Relaxed);
+                async {
+                    tokio::task::yield_now().await;
+                    Err::<(), _>("reconnect failed")
+                }
+            })
+            .await;
+            match result {
+                Err(CallError::BudgetExhausted { .. }) => {
+                    exhausted.fetch_add(1, Ordering::Relaxed);
+                },
+                Err(CallError::RetriesExhausted { .. }) => {},
+                other => panic!("unexpected result: {other:?}"),
+            }
+        }));
+    }
+
+    for h in handles {
+        h.await.expect("task panicked");
+    }
+
+    // Every task gets its free first attempt; only retries draw on the budget.
+    let total = attempts.load(Ordering::Relaxed);
+    assert_eq!(
+        total,
+        TASKS + BUDGET as u32,
+        "the storm must spend the whole budget and not one retry more"
+    );
+    assert_eq!(budget.remaining(), 0);
+    assert!(exhausted.load(Ordering::Relaxed) > 0);
+}
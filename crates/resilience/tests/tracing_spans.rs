@@ -0,0 +1,192 @@
+//! Integration tests for the `tracing` feature — span names and attributes.
+#![cfg(feature = "tracing")]
+
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use nebula_resilience::{
+    CallError,
+    circuit_breaker::{CircuitBreaker, CircuitBreakerConfig},
+    retry::{BackoffConfig, RetryConfig, retry_with_inner},
+};
+use tracing::{
+    Subscriber,
+    field::{Field, Visit},
+    span::{Attributes, Id, Record},
+};
+use tracing_subscriber::{Layer, layer::Context, prelude::*, registry::LookupSpan};
+
+/// One captured span: its name and every field recorded on it.
+#[derive(Debug, Clone)]
+struct CapturedSpan {
+    name: &'static str,
+    fields: HashMap<String, String>,
+}
+
+impl CapturedSpan {
+    fn field(&self, name: &str) -> Option<&str> {
+        self.fields.get(name).map(String::as_str)
+    }
+}
+
+struct FieldVisitor<'a>(&'a mut HashMap<String, String>);
+
+impl Visit for FieldVisitor<'_> {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.insert(field.name().to_owned(), value.to_owned());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.0.insert(field.name().to_owned(), format!("{value:?}"));
+    }
+}
+
+/// Layer that keeps every span it sees, in creation order.
+#[derive(Clone, Default)]
+struct CaptureLayer {
+    spans: Arc<Mutex<Vec<(Id, CapturedSpan)>>>,
+}
+
+impl CaptureLayer {
+    fn spans(&self, name: &str) -> Vec<CapturedSpan> {
+        self.spans
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(_, span)| span.name == name)
+            .map(|(_, span)| span.clone())
+            .collect()
+    }
+}
+
+impl<S> Layer<S> for CaptureLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, _ctx: Context<'_, S>) {
+        let mut fields = HashMap::new();
+        attrs.record(&mut FieldVisitor(&mut fields));
+        let span = CapturedSpan {
+            name: attrs.metadata().name(),
+            fields,
+        };
+        self.spans.lock().unwrap().push((id.clone(), span));
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, _ctx: Context<'_, S>) {
+        let mut spans = self.spans.lock().unwrap();
+        if let Some((_, span)) = spans.iter_mut().rev().find(|(span_id, _)| span_id == id) {
+            values.record(&mut FieldVisitor(&mut span.fields));
+        }
+    }
+}
+
+fn capture() -> (CaptureLayer, tracing::subscriber::DefaultGuard) {
+    let layer = CaptureLayer::default();
+    let guard = tracing_subscriber::registry()
+        .with(layer.clone())
+        .set_default();
+    (layer, guard)
+}
+
+fn breaker_config() -> CircuitBreakerConfig {
+    CircuitBreakerConfig {
+        failure_threshold: 1,
+        min_operations: 1,
+        reset_timeout: Duration::from_mins(1),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn circuit_breaker_call_records_state_and_outcome() {
+    let (layer, _guard) = capture();
+    let cb = CircuitBreaker::new(breaker_config()).unwrap();
+
+    let result = cb.call(|| async { Ok::<_, &str>(1u32) }).await;
+    assert_eq!(result.unwrap(), 1);
+
+    let spans = layer.spans("resilience.circuit_breaker");
+    assert_eq!(spans.len(), 1);
+    let span = &spans[0];
+    assert_eq!(span.field("resilience.pattern"), Some("circuit_breaker"));
+    assert_eq!(
+        span.field("resilience.circuit_breaker.state"),
+        Some("closed")
+    );
+    assert_eq!(span.field("resilience.outcome"), Some("success"));
+    assert_eq!(span.field("otel.status_code"), Some("OK"));
+}
+
+#[tokio::test]
+async fn open_circuit_is_recorded_as_error() {
+    let (layer, _guard) = capture();
+    let cb = CircuitBreaker::new(breaker_config()).unwrap();
+
+    let _ = cb.call(|| async { Err::<u32, _>("boom") }).await;
+    let result = cb.call(|| async { Ok::<_, &str>(1u32) }).await;
+    assert!(matches!(result, Err(CallError::CircuitOpen)));
+
+    let spans = layer.spans("resilience.circuit_breaker");
+    assert_eq!(spans.len(), 2);
+    assert_eq!(spans[0].field("resilience.outcome"), Some("operation"));
+    assert_eq!(
+        spans[1].field("resilience.circuit_breaker.state"),
+        Some("open")
+    );
+    assert_eq!(spans[1].field("resilience.outcome"), Some("circuit_open"));
+    assert_eq!(spans[1].field("otel.status_code"), Some("ERROR"));
+}
+
+#[tokio::test]
+async fn retry_records_attempts_backoff_and_outcome() {
+    let (layer, _guard) = capture();
+    let config = RetryConfig::<&'static str>::new(3)
+        .unwrap()
+        .backoff(BackoffConfig::Fixed(Duration::from_millis(5)));
+
+    let mut calls = 0u32;
+    let result = retry_with_inner(config, || {
+        calls += 1;
+        let attempt = calls;
+        async move {
+            if attempt < 3 {
+                Err("flaky")
+            } else {
+                Ok(attempt)
+            }
+        }
+    })
+    .await;
+    assert_eq!(result.unwrap(), 3);
+
+    let spans = layer.spans("resilience.retry");
+    assert_eq!(spans.len(), 1);
+    let span = &spans[0];
+    assert_eq!(span.field("resilience.pattern"), Some("retry"));
+    assert_eq!(span.field("resilience.retry.max_attempts"), Some("3"));
+    assert_eq!(span.field("resilience.retry.attempt"), Some("3"));
+    assert_eq!(span.field("resilience.retry.backoff_ms"), Some("5"));
+    assert_eq!(span.field("resilience.outcome"), Some("success"));
+    assert_eq!(span.field("otel.status_code"), Some("OK"));
+}
+
+#[tokio::test]
+async fn exhausted_retry_is_recorded_as_error() {
+    let (layer, _guard) = capture();
+    let config = RetryConfig::<&'static str>::new(2)
+        .unwrap()
+        .backoff(BackoffConfig::Fixed(Duration::from_millis(1)));
+
+    let result = retry_with_inner(config, || async { Err::<u32, _>("down") }).await;
+    assert!(matches!(result, Err(CallError::RetriesExhausted { .. })));
+
+    let span = &layer.spans("resilience.retry")[0];
+    assert_eq!(span.field("resilience.retry.attempt"), Some("2"));
+    assert_eq!(span.field("resilience.outcome"), Some("retries_exhausted"));
+    assert_eq!(span.field("otel.status_code"), Some("ERROR"));
+}
@@ -0,0 +1,387 @@
+//! Config-driven bootstrap: build a [`Manager`]'s registrations from a
+//! declarative resources table.
+//!
+//! A [`ResourceManifest`] is the deserialized form of a `resources` table in
+//! the host's config file (TOML, YAML, JSON — anything serde reads). Each
+//! [`ManifestEntry`] names a resource `type` from the closed
+//! [`ResourceActivatorRegistry`] allowlist, carries its opaque config, an
+//! optional concurrency cap, and the ids of the entries it depends on.
+//!
+//! [`ResourceActivatorRegistry::bootstrap`] runs in two passes:
+//!
+//! 1. **Load** — resolve the dependency order and validate every entry's
+//!    config against its kind's `R::Config` schema (the same check as
+//!    [`ResourceActivatorRegistry::validate`]). Nothing is registered if any
+//!    entry fails, so a bad config file never leaves a half-wired manager.
+//! 2. **Register** — register entries dependencies-first through the
+//!    erased factory, then apply each entry's `max_concurrent` as a
+//!    [per-scope quota](Manager::set_quota).
+//!
+//! ```toml
+//! [[resources]]
+//! id = "primary-db"
+//! type = "postgres"
+//! max_concurrent = 20
+//! config = { host = "db.internal", port = 5432 }
+//!
+//! [[resources]]
+//! id = "cache"
+//! type = "redis"
+//! depends_on = ["primary-db"]
+//! config = { url = "redis://cache.internal" }
+//! ```
+//!
+//! Manifest entries carry no credential slot bindings; a kind that declares
+//! credential slots must still be registered through
+//! [`ResourceActivatorRegistry::register`] with resolved bindings.
+
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::{
+    Manager, ScopeLevel,
+    factory::{
+        RegisterRequest, RegistrarError, ResourceActivatorRegistry, ResourceRegistrationOutcome,
+    },
+};
+
+/// Declarative resources table: the entries a [`Manager`] is bootstrapped
+/// from.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ResourceManifest {
+    /// Resource entries, in file order. Registration order is derived from
+    /// `depends_on`; file order only breaks ties.
+    #[serde(default)]
+    pub resources: Vec<ManifestEntry>,
+}
+
+/// One row of a [`ResourceManifest`].
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ManifestEntry {
+    /// Manifest-local identifier, referenced by other entries' `depends_on`.
+    pub id: String,
+    /// Resource kind, resolved through the [`ResourceActivatorRegistry`]
+    /// allowlist. Spelled `type` in the config file.
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// Opaque resource-specific config, validated against the kind's
+    /// `R::Config` schema on load. `{{ … }}` templates are resolved at
+    /// registration.
+    #[serde(default = "empty_config")]
+    pub config: serde_json::Value,
+    /// Registration scope. Defaults to [`ScopeLevel::Global`].
+    #[serde(default = "global_scope")]
+    pub scope: ScopeLevel,
+    /// Cap on concurrent leases within `scope`, applied through
+    /// [`Manager::set_quota`]. `None` leaves the topology's own capacity as
+    /// the only limit.
+    #[serde(default)]
+    pub max_concurrent: Option<usize>,
+    /// Ids of entries that must be registered before this one.
+    #[serde(default)]
+    pub depends_on: Vec<String>,
+}
+
+fn empty_config() -> serde_json::Value {
+    serde_json::Value::Object(serde_json::Map::new())
+}
+
+const fn global_scope() -> ScopeLevel {
+    ScopeLevel::Global
+}
+
+/// Errors raised while loading or applying a [`ResourceManifest`].
+///
+/// The structural variants (`DuplicateId`, `UnknownDependency`,
+/// `DependencyCycle`) are config-file faults caught before anything is
+/// registered and classify as validation errors. `Entry` wraps the
+/// per-entry [`RegistrarError`] and classifies by delegation.
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum BootstrapError {
+    /// Two entries share the same `id`.
+    #[error("resource manifest declares id `{0}` more than once")]
+    DuplicateId(String),
+
+    /// An entry's `depends_on` names an id absent from the manifest.
+    #[error("resource `{id}` depends on `{dependency}`, which is not in the manifest")]
+    UnknownDependency {
+        /// The entry declaring the dependency.
+        id: String,
+        /// The missing dependency id.
+        dependency: String,
+    },
+
+    /// The `depends_on` graph has a cycle; carries the ids left unordered.
+    #[error("resource manifest has a dependency cycle among: {}", .0.join(", "))]
+    DependencyCycle(Vec<String>),
+
+    /// Validating or registering one entry failed.
+    #[error("resource `{id}`: {source}")]
+    Entry {
+        /// The failing entry's id.
+        id: String,
+        /// The underlying registrar error, boxed to keep the enum small.
+        #[source]
+        source: Box<RegistrarError>,
+    },
+}
+
+impl nebula_error::Classify for BootstrapError {
+    fn category(&self) -> nebula_error::ErrorCategory {
+        match self {
+            Self::Entry { source, .. } => nebula_error::Classify::category(&**source),
+            _ => nebula_error::ErrorCategory::Validation,
+        }
+    }
+
+    fn code(&self) -> nebula_error::ErrorCode {
+        match self {
+            Self::DuplicateId(_) => nebula_error::ErrorCode::new("RESOURCE:MANIFEST_DUPLICATE_ID"),
+            Self::UnknownDependency { .. } => {
+                nebula_error::ErrorCode::new("RESOURCE:MANIFEST_UNKNOWN_DEPENDENCY")
+            },
+            Self::DependencyCycle(_) => {
+                nebula_error::ErrorCode::new("RESOURCE:MANIFEST_DEPENDENCY_CYCLE")
+            },
+            Self::Entry { source, .. } => nebula_error::Classify::code(&**source),
+        }
+    }
+
+    fn is_retryable(&self) -> bool {
+        match self {
+            Self::Entry { source, .. } => nebula_error::Classify::is_retryable(&**source),
+            _ => false,
+        }
+    }
+}
+
+impl ResourceManifest {
+    /// Returns the entries in registration order: every entry after all of
+    /// its `depends_on`, ties broken by file order.
+    ///
+    /// # Errors
+    ///
+    /// - [`BootstrapError::DuplicateId`] — two entries share an id.
+    /// - [`BootstrapError::UnknownDependency`] — `depends_on` names a missing id.
+    /// - [`BootstrapError::DependencyCycle`] — the dependency graph has a cycle.
+    pub fn load_order(&self) -> Result<Vec<&ManifestEntry>, BootstrapError> {
+        let mut index = HashMap::with_capacity(self.resources.len());
+        for (position, entry) in self.resources.iter().enumerate() {
+            if index.insert(entry.id.as_str(), position).is_some() {
+                return Err(BootstrapError::DuplicateId(entry.id.clone()));
+            }
+        }
+
+        // Kahn's algorithm over positions; scanning in file order each round
+        // keeps the result deterministic.
+        let mut pending = vec![0usize; self.resources.len()];
+        let mut dependents = vec![Vec::new(); self.resources.len()];
+        for (position, entry) in self.resources.iter().enumerate() {
+            for dependency in &entry.depends_on {
+                let Some(&target) = index.get(dependency.as_str()) else {
+                    return Err(BootstrapError::UnknownDependency {
+                        id: entry.id.clone(),
+                        dependency: dependency.clone(),
+                    });
+                };
+                pending[position] += 1;
+                dependents[target].push(position);
+            }
+        }
+
+        let mut order = Vec::with_capacity(self.resources.len());
+        let mut placed = vec![false; self.resources.len()];
+        while let Some(next) = (0..self.resources.len()).find(|&p| !placed[p] && pending[p] == 0) {
+            placed[next] = true;
+            order.push(&self.resources[next]);
+            for &dependent in &dependents[next] {
+                pending[dependent] -= 1;
+            }
+        }
+
+        if order.len() < self.resources.len() {
+            let stuck = self
+                .resources
+                .iter()
+                .zip(&placed)
+                .filter(|(_, placed)| !**placed)
+                .map(|(entry, _)| entry.id.clone())
+                .collect();
+            return Err(BootstrapError::DependencyCycle(stuck));
+        }
+        Ok(order)
+    }
+}
+
+impl ResourceActivatorRegistry {
+    /// Resolves the manifest's dependency order and validates every entry's
+    /// config against its kind, without registering anything.
+    ///
+    /// Returns the entries in registration order.
+    ///
+    /// # Errors
+    ///
+    /// The structural errors of [`ResourceManifest::load_order`], or
+    /// [`BootstrapError::Entry`] for the first entry (in registration order)
+    /// whose kind is unknown or whose config fails validation.
+    pub fn validate_manifest<'m>(
+        &self,
+        manifest: &'m ResourceManifest,
+    ) -> Result<Vec<&'m ManifestEntry>, BootstrapError> {
+        let order = manifest.load_order()?;
+        for entry in &order {
+            self.validate(&entry.kind, entry.config.clone())
+                .map_err(|source| BootstrapError::Entry {
+                    id: entry.id.clone(),
+                    source: Box::new(source),
+                })?;
+        }
+        Ok(order)
+    }
+
+    /// Registers every manifest entry against `manager`, dependencies first.
+    ///
+    /// The whole manifest is validated (see
+    /// [`validate_manifest`](Self::validate_manifest)) before the first
+    /// registration. Returns one outcome per entry, in registration order.
+    ///
+    /// # Errors
+    ///
+    /// Any error from [`validate_manifest`](Self::validate_manifest), or
+    /// [`BootstrapError::Entry`] if a registration fails. Entries registered
+    /// before a failing one stay registered; the caller decides whether to
+    /// shut the manager down.
+    pub async fn bootstrap(
+        &self,
+        manager: &Manager,
+        manifest: &ResourceManifest,
+        expr_engine: &nebula_expression::ExpressionEngine,
+    ) -> Result<Vec<ResourceRegistrationOutcome>, BootstrapError> {
+        let order = self.validate_manifest(manifest)?;
+        let mut outcomes = Vec::with_capacity(order.len());
+        for entry in order {
+            let request = RegisterRequest {
+                config_json: entry.config.clone(),
+                expr_engine,
+                slot_bindings: Vec::new(),
+                scope: entry.scope.clone(),
+                recovery_gate: None,
+            };
+            let outcome = self
+                .register(&entry.kind, manager, request)
+                .await
+                .map_err(|source| BootstrapError::Entry {
+                    id: entry.id.clone(),
+                    source: Box::new(source),
+                })?;
+            if let Some(max_concurrent) = entry.max_concurrent {
+                manager.set_quota(
+                    entry.scope.clone(),
+                    outcome.resource_key.clone(),
+                    max_concurrent,
+                );
+            }
+            tracing::debug!(
+                target: "nebula.resource",
+                id = %entry.id,
+                kind = %entry.kind,
+                key = %outcome.resource_key,
+                "bootstrap: registered manifest entry"
+            );
+            outcomes.push(outcome);
+        }
+        Ok(outcomes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nebula_error::{Classify, ErrorCategory};
+
+    use super::*;
+
+    fn manifest(value: serde_json::Value) -> ResourceManifest {
+        serde_json::from_value(value).expect("manifest deserializes")
+    }
+
+    fn ids(order: &[&ManifestEntry]) -> Vec<String> {
+        order.iter().map(|entry| entry.id.clone()).collect()
+    }
+
+    #[test]
+    fn entry_defaults_apply() {
+        let m = manifest(serde_json::json!({
+            "resources": [{ "id": "db", "type": "postgres" }]
+        }));
+        let entry = &m.resources[0];
+        assert_eq!(entry.config, serde_json::json!({}));
+        assert_eq!(entry.scope, ScopeLevel::Global);
+        assert_eq!(entry.max_concurrent, None);
+        assert!(entry.depends_on.is_empty());
+    }
+
+    #[test]
+    fn load_order_puts_dependencies_first() {
+        let m = manifest(serde_json::json!({
+            "resources": [
+                { "id": "api", "type": "http", "depends_on": ["cache", "db"] },
+                { "id": "cache", "type": "redis", "depends_on": ["db"] },
+                { "id": "db", "type": "postgres" },
+                { "id": "metrics", "type": "statsd" },
+            ]
+        }));
+        let order = m.load_order().expect("acyclic manifest");
+        assert_eq!(ids(&order), ["db", "cache", "api", "metrics"]);
+    }
+
+    #[test]
+    fn load_order_rejects_structural_faults() {
+        let duplicate = manifest(serde_json::json!({
+            "resources": [{ "id": "db", "type": "a" }, { "id": "db", "type": "b" }]
+        }));
+        assert!(matches!(
+            duplicate.load_order(),
+            Err(BootstrapError::DuplicateId(id)) if id == "db"
+        ));
+
+        let unknown = manifest(serde_json::json!({
+            "resources": [{ "id": "api", "type": "http", "depends_on": ["db"] }]
+        }));
+        assert!(matches!(
+            unknown.load_order(),
+            Err(BootstrapError::UnknownDependency { ref dependency, .. }) if dependency == "db"
+        ));
+
+        let cycle = manifest(serde_json::json!({
+            "resources": [
+                { "id": "a", "type": "x", "depends_on": ["b"] },
+                { "id": "b", "type": "x", "depends_on": ["a"] },
+                { "id": "c", "type": "x" },
+            ]
+        }));
+        let err = cycle.load_order().expect_err("cycle must be rejected");
+        assert!(matches!(&err, BootstrapError::DependencyCycle(stuck) if stuck == &["a", "b"]));
+        assert_eq!(err.category(), ErrorCategory::Validation);
+        assert!(!err.is_retryable());
+    }
+
+    #[test]
+    fn validate_manifest_rejects_unknown_kind_before_registering() {
+        let registry = ResourceActivatorRegistry::new();
+        let m = manifest(serde_json::json!({
+            "resources": [{ "id": "db", "type": "postgres" }]
+        }));
+        let err = registry
+            .validate_manifest(&m)
+            .expect_err("empty allowlist rejects every kind");
+        assert!(matches!(
+            &err,
+            BootstrapError::Entry { source, .. } if matches!(**source, RegistrarError::UnknownKind(_))
+        ));
+        assert_eq!(err.category(), ErrorCategory::Conflict);
+    }
+}
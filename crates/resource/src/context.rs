@@ -211,6 +211,9 @@ impl Context for ResourceContext {
     fn cancellation(&self) -> &CancellationToken {
         self.base.cancellation()
     }
+    fn cancellation_reason(&self) -> Option<nebula_core::CancellationReason> {
+        self.base.cancellation_reason()
+    }
     fn clock(&self) -> &dyn Clock {
         self.base.clock()
     }
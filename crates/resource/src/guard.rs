@@ -21,8 +21,9 @@ use nebula_eventbus::EventBus;
 use tokio::sync::{Notify, OwnedSemaphorePermit};
 
 use crate::{
-    context::ResourceContext, events::ResourceEvent, metrics::ResourceOpsMetrics,
-    release_queue::ReleaseQueue, resource::Provider, topology_tag::TopologyTag,
+    context::ResourceContext, events::ResourceEvent, manager::QuotaPermit,
+    metrics::ResourceOpsMetrics, release_queue::ReleaseQueue, resource::Provider,
+    topology_tag::TopologyTag,
 };
 
 /// The awaited teardown future a release callback produces.
@@ -148,6 +149,11 @@ pub struct ResourceGuard<R: Provider> {
     // Held only for its `Drop`: the watchdog observes liveness via a `Weak`
     // upgrade, never by reading this field.
     hold_token: Option<Arc<()>>,
+    /// Per-scope quota reservation taken by
+    /// [`Manager::run_acquire`](crate::manager::Manager). Held only for its
+    /// `Drop`, which returns the lease to the scope's quota. `None` for
+    /// guards minted outside the manager funnel.
+    quota_permit: Option<QuotaPermit>,
 }
 
 enum GuardInner<R: Provider> {
@@ -177,6 +183,7 @@ impl<R: Provider> ResourceGuard<R> {
             event_bus: None,
             release_queue: None,
             hold_token: None,
+            quota_permit: None,
         }
     }
 
@@ -241,6 +248,7 @@ impl<R: Provider> ResourceGuard<R> {
             event_bus: None,
             release_queue: Some(release_queue),
             hold_token: None,
+            quota_permit: None,
         }
     }
 
@@ -262,6 +270,13 @@ impl<R: Provider> ResourceGuard<R> {
         self
     }
 
+    /// Attaches the per-scope quota reservation this lease counts against.
+    /// Released with the guard, including on [`detach`](Self::detach).
+    pub(crate) fn with_quota_permit(mut self, permit: QuotaPermit) -> Self {
+        self.quota_permit = Some(permit);
+        self
+    }
+
     /// Attaches the manager's event bus so this guard emits
     /// [`ResourceEvent::Released`] on drop. Wired by
     /// [`Manager::run_acquire`](crate::manager::Manager) right after the
@@ -226,6 +226,7 @@
 #![forbid(unsafe_code)]
 #![cfg_attr(not(test), warn(unused_crate_dependencies))]
 
+pub mod bootstrap;
 pub(crate) mod cell;
 pub mod context;
 #[cfg(feature = "rotation")]
@@ -259,6 +260,7 @@ pub mod topology_tag;
 // `cell` module is crate-internal (`pub(crate) mod`) so consumers reach for
 // the generation-bearing `SlotCell` and are not misled into using the
 // epoch-blind cell at a credential-slot boundary.
+pub use bootstrap::{BootstrapError, ManifestEntry, ResourceManifest};
 pub use context::{
     ResourceContext, minimal_scope_for_level, scope_levels_for_acquire, scope_to_level,
 };
@@ -269,7 +271,8 @@ pub use ext::HasResourcesExt;
 pub use guard::ResourceGuard;
 pub use manager::{
     DrainTimeoutPolicy, Manager, ManagerConfig, RegisterOptions, RegistrationSpec,
-    ResourceHealthSnapshot, RevokeTail, ShutdownConfig, ShutdownError, ShutdownReport, TaintedSlot,
+    ResourceHealthSnapshot, RevokeTail, ScopeQuotaUsage, ShutdownConfig, ShutdownError,
+    ShutdownReport, TaintedSlot,
 };
 pub use metrics::{
     ACQUIRE_WAIT_BUCKET_UPPER_BOUNDS_MICROS, AcquireWaitSnapshot, OutcomeCountersSnapshot,
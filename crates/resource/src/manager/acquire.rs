@@ -4,11 +4,11 @@
 
 use std::{any::Any, future::Future, sync::Arc, time::Instant};
 
-use nebula_core::{Context, ResourceKey, ScopeLevel};
+use nebula_core::{Context, ResourceKey, ScopeLevel, scope::Scope};
 
 use super::{InFlightCounter, Manager, gate::admit_through_gate, gate::settle_gate_admission};
 use crate::{
-    context::ResourceContext,
+    context::{ResourceContext, scope_levels_for_acquire},
     error::Error,
     events::ResourceEvent,
     hook_guard::{DEFAULT_AUTHOR_HOOK_CEILING, HookFault, guard_author_hook},
@@ -26,6 +26,7 @@ impl Manager {
         ctx: &ResourceContext,
     ) -> Result<Arc<ManagedResource<R>>, Error> {
         self.shutdown_guard()?;
+        self.scope_access_guard(&R::key(), ctx.scope())?;
         let managed =
             Self::resolve_typed::<R>(self.registry.get_typed_for_acquire_scope::<R>(ctx.scope()))?;
         Self::taint_gate::<R>(managed)
@@ -40,6 +41,7 @@ impl Manager {
         slot_identity: &crate::dedup::SlotIdentity,
     ) -> Result<Arc<ManagedResource<R>>, Error> {
         self.shutdown_guard()?;
+        self.scope_access_guard(&R::key(), ctx.scope())?;
         let managed = Self::resolve_typed_pinned::<R>(
             self.registry
                 .get_typed_for_acquire::<R>(ctx.scope(), slot_identity),
@@ -47,6 +49,41 @@ impl Manager {
         Self::taint_gate::<R>(managed)
     }
 
+    /// Tenant-isolation check on the caller's scope bag, run by every
+    /// acquire lookup before the scope walk.
+    ///
+    /// With a resolver attached ([`with_scope_resolver`](Self::with_scope_resolver)),
+    /// the bag's most specific level must be strictly contained in each
+    /// non-Global ancestor level it carries. A mismatch is a cross-tenant
+    /// bag and rejects with [`ErrorKind::Permanent`](crate::error::ErrorKind::Permanent):
+    /// retrying with the same context cannot succeed. No resolver, no check.
+    pub(crate) fn scope_access_guard(&self, key: &ResourceKey, scope: &Scope) -> Result<(), Error> {
+        let Some(resolver) = self.scope_resolver.as_deref() else {
+            return Ok(());
+        };
+        let mut levels = scope_levels_for_acquire(scope).into_iter();
+        let Some(leaf) = levels.next() else {
+            return Ok(());
+        };
+        let Some(foreign) = levels
+            .filter(|level| !level.is_global())
+            .find(|ancestor| !leaf.is_contained_in_strict(ancestor, resolver))
+        else {
+            return Ok(());
+        };
+        tracing::warn!(
+            target: "nebula.resource",
+            %key,
+            caller = %leaf,
+            claimed = %foreign,
+            "acquire: cross-tenant scope rejected"
+        );
+        Err(Error::permanent(format!(
+            "{key}: scope {leaf} does not belong to {foreign}; cross-tenant access denied"
+        ))
+        .with_resource_key(key.clone()))
+    }
+
     /// Shared taint check tail for the acquire-side lookups.
     ///
     /// Every `acquire_*` path funnels through here so a single check
@@ -138,7 +175,8 @@ impl Manager {
     /// # Errors
     ///
     /// Same as the typed `acquire_*_for_identity` family: not found,
-    /// ambiguous, shutdown, taint, topology, and acquire-time failures.
+    /// ambiguous, shutdown, taint, cross-tenant scope, topology, and acquire-time
+    /// failures.
     ///
     /// # Cancel safety
     ///
@@ -158,6 +196,7 @@ impl Manager {
         use crate::registry::AcquireLookupOutcome;
 
         manager.shutdown_guard()?;
+        manager.scope_access_guard(key, ctx.scope())?;
         tracing::debug!(
             target: "nebula.resource",
             %key,
@@ -422,6 +461,10 @@ impl Manager {
         // resource). Same `Revoked`/`Cancelled` classifications as the
         // pre-checks. Rationale: see the `manager` module documentation.
         self.reject_if_tainted_or_shutting_down_post_count::<R>(&managed)?;
+        // Scope quotas go before the gate so a denied acquire never claims
+        // the recovery probe. The permit rides on the guard and is returned
+        // when it drops.
+        let quota_permit = self.reserve_quota(&R::key(), ctx.scope())?;
         let gate_admission = admit_through_gate(&managed.recovery_gate)?;
 
         // Publish a `RetryAttempt` event when this acquire is the recovery
@@ -463,6 +506,7 @@ impl Manager {
             // there is nothing to release.
             Ok(h) => Ok(h
                 .with_drain_tracker(in_flight.release_to_guard())
+                .with_quota_permit(quota_permit)
                 .with_event_bus(Arc::clone(&self.event_bus))
                 .with_hold_watchdog(R::max_hold_duration(), ctx, self.metrics.clone())),
             Err(e) => Err(e),
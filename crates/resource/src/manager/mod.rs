@@ -25,6 +25,7 @@
 //! - `gate` — `GateAdmission` + `admit_through_gate` + `settle_gate_admission`
 //! - `execute` — resilience pipeline + register-time pool config validation
 //! - `shutdown` — `graceful_shutdown` + drain helpers + `set_phase_all*`
+//! - `quota` — per-scope concurrency quotas (`set_quota`, `quota_usage`)
 //!
 //! # The two-phase revoke / drain invariant (canonical)
 //!
@@ -327,7 +328,9 @@ use std::{
     time::Instant,
 };
 
-use nebula_core::{LayerLifecycle, ResourceKey, ScopeLevel, context::Context as _};
+use nebula_core::{
+    LayerLifecycle, ResourceKey, ScopeLevel, context::Context as _, scope::ScopeResolver,
+};
 use nebula_eventbus::EventBus;
 use tokio::sync::Notify;
 use tokio_util::sync::CancellationToken;
@@ -346,6 +349,7 @@ use crate::{
 pub(crate) mod acquire;
 mod gate;
 pub(crate) mod options;
+mod quota;
 mod registration;
 mod rotation;
 pub(crate) mod shutdown;
@@ -353,6 +357,8 @@ pub(crate) mod shutdown;
 pub use options::{
     DrainTimeoutPolicy, ManagerConfig, RegisterOptions, RegistrationSpec, ShutdownConfig,
 };
+pub(crate) use quota::QuotaPermit;
+pub use quota::ScopeQuotaUsage;
 pub use rotation::{RevokeTail, TaintedSlot};
 pub use shutdown::{ShutdownError, ShutdownReport};
 
@@ -424,6 +430,11 @@ pub struct Manager {
     /// [`AcquireOptions::acquire_slow_threshold`](crate::options::AcquireOptions::acquire_slow_threshold)
     /// overrides this per call.
     pub(super) acquire_slow_threshold: Option<std::time::Duration>,
+    /// Optional scope-ownership resolver for tenant isolation on acquire.
+    /// See [`with_scope_resolver`](Self::with_scope_resolver).
+    pub(super) scope_resolver: Option<Arc<dyn ScopeResolver + Send + Sync>>,
+    /// Per-scope concurrency quotas. See [`set_quota`](Self::set_quota).
+    pub(super) quotas: quota::ScopeQuotas,
 }
 
 impl std::fmt::Debug for Manager {
@@ -440,6 +451,7 @@ impl std::fmt::Debug for Manager {
             )
             .field("has_metrics", &self.metrics.is_some())
             .field("acquire_slow_threshold", &self.acquire_slow_threshold)
+            .field("has_scope_resolver", &self.scope_resolver.is_some())
             .finish_non_exhaustive()
     }
 }
@@ -480,6 +492,8 @@ impl Manager {
             shutting_down: AtomicBool::new(false),
             lifecycle: None,
             acquire_slow_threshold,
+            scope_resolver: None,
+            quotas: quota::ScopeQuotas::default(),
         }
     }
 
@@ -525,6 +539,25 @@ impl Manager {
         self
     }
 
+    /// Attaches a [`ScopeResolver`] that enforces tenant isolation on acquire.
+    ///
+    /// The acquire scope walk trusts every id in the caller's
+    /// [`Scope`](nebula_core::scope::Scope) bag. With a resolver attached,
+    /// each `acquire_*` call first checks that the bag's most specific level
+    /// is contained — per [`ScopeLevel::is_contained_in_strict`] — in every
+    /// ancestor level the bag carries. A context holding tenant A's execution
+    /// next to tenant B's organization is then refused with
+    /// [`ErrorKind::Permanent`](crate::error::ErrorKind::Permanent) instead
+    /// of reaching B's organization-scoped rows. Ids the resolver cannot
+    /// place are refused too (fail closed).
+    ///
+    /// Without a resolver the check is skipped.
+    #[must_use]
+    pub fn with_scope_resolver(mut self, resolver: Arc<dyn ScopeResolver + Send + Sync>) -> Self {
+        self.scope_resolver = Some(resolver);
+        self
+    }
+
     /// Returns a reference to the attached lifecycle, if any.
     pub fn lifecycle(&self) -> Option<&LayerLifecycle> {
         self.lifecycle.as_ref()
@@ -0,0 +1,182 @@
+//! Per-scope concurrency quotas: the table behind [`Manager::set_quota`],
+//! the RAII [`QuotaPermit`] each admitted acquire carries, and the
+//! [`ScopeQuotaUsage`] snapshot.
+//!
+//! A quota caps how many leases of one resource key a single scope may hold
+//! at once, independently of the topology's own capacity. It is checked in
+//! [`run_acquire`](Manager::run_acquire) after the post-count re-check and
+//! before the recovery gate, so a denied acquire never consumes a probe
+//! ticket or a topology permit.
+
+use std::sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+};
+
+use dashmap::DashMap;
+use nebula_core::{ResourceKey, ScopeLevel, scope::Scope};
+
+use super::Manager;
+use crate::{context::scope_levels_for_acquire, error::Error};
+
+/// Point-in-time view of one `(scope, key)` quota.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ScopeQuotaUsage {
+    /// The configured concurrent-lease limit.
+    pub max_concurrent: usize,
+    /// Leases currently held against the limit.
+    pub in_use: usize,
+}
+
+/// One quota row. `max` is atomic so [`Manager::set_quota`] can retune a
+/// live quota without resetting `in_use` under held leases.
+#[derive(Debug)]
+struct QuotaSlot {
+    max: AtomicUsize,
+    in_use: AtomicUsize,
+}
+
+impl QuotaSlot {
+    /// CAS-increments `in_use` if it is below `max`. Returns the limit on
+    /// rejection for the error message.
+    fn try_take(&self) -> Result<(), usize> {
+        let max = self.max.load(Ordering::Acquire);
+        self.in_use
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |in_use| {
+                (in_use < max).then_some(in_use + 1)
+            })
+            .map(|_| ())
+            .map_err(|_| max)
+    }
+}
+
+/// Quota table keyed by `(scope level, resource key)`.
+#[derive(Debug, Default)]
+pub(crate) struct ScopeQuotas {
+    slots: DashMap<(ScopeLevel, ResourceKey), Arc<QuotaSlot>>,
+}
+
+impl ScopeQuotas {
+    fn set(&self, scope: ScopeLevel, key: ResourceKey, max_concurrent: usize) {
+        self.slots
+            .entry((scope, key))
+            .and_modify(|slot| slot.max.store(max_concurrent, Ordering::Release))
+            .or_insert_with(|| {
+                Arc::new(QuotaSlot {
+                    max: AtomicUsize::new(max_concurrent),
+                    in_use: AtomicUsize::new(0),
+                })
+            });
+    }
+
+    fn remove(&self, scope: &ScopeLevel, key: &ResourceKey) -> bool {
+        self.slots.remove(&(scope.clone(), key.clone())).is_some()
+    }
+
+    fn usage(&self, scope: &ScopeLevel, key: &ResourceKey) -> Option<ScopeQuotaUsage> {
+        self.slots
+            .get(&(scope.clone(), key.clone()))
+            .map(|slot| ScopeQuotaUsage {
+                max_concurrent: slot.max.load(Ordering::Acquire),
+                in_use: slot.in_use.load(Ordering::Acquire),
+            })
+    }
+
+    /// Reserves one lease against every quota on the caller's scope chain.
+    ///
+    /// Walks [`scope_levels_for_acquire`], so an organization quota also
+    /// bounds the workflows and executions inside that organization.
+    /// All-or-nothing: if any level is at its limit, the levels already
+    /// taken are released (by dropping the partial permit) and the acquire
+    /// fails with [`ErrorKind::Exhausted`](crate::error::ErrorKind::Exhausted).
+    fn try_reserve(&self, key: &ResourceKey, scope: &Scope) -> Result<QuotaPermit, Error> {
+        let mut permit = QuotaPermit { slots: Vec::new() };
+        if self.slots.is_empty() {
+            return Ok(permit);
+        }
+        for level in scope_levels_for_acquire(scope) {
+            let Some(slot) = self
+                .slots
+                .get(&(level.clone(), key.clone()))
+                .map(|slot| Arc::clone(&slot))
+            else {
+                continue;
+            };
+            if let Err(max) = slot.try_take() {
+                tracing::debug!(
+                    target: "nebula.resource",
+                    %key,
+                    scope = %level,
+                    max,
+                    "acquire: scope quota exceeded"
+                );
+                return Err(Error::exhausted(
+                    format!("{key}: scope {level} is at its quota of {max} concurrent leases"),
+                    None,
+                )
+                .with_resource_key(key.clone()));
+            }
+            permit.slots.push(slot);
+        }
+        Ok(permit)
+    }
+}
+
+/// Quota reservation held by a [`ResourceGuard`](crate::guard::ResourceGuard)
+/// for its whole lifetime; dropping it returns the lease to every quota it
+/// was counted against.
+#[derive(Debug)]
+pub(crate) struct QuotaPermit {
+    slots: Vec<Arc<QuotaSlot>>,
+}
+
+impl Drop for QuotaPermit {
+    fn drop(&mut self) {
+        for slot in &self.slots {
+            slot.in_use.fetch_sub(1, Ordering::AcqRel);
+        }
+    }
+}
+
+impl Manager {
+    /// Caps how many leases of `key` the given scope may hold at once.
+    ///
+    /// Enforced on every `acquire_*` path in addition to the topology's own
+    /// capacity: once `scope` holds `max_concurrent` guards for `key`,
+    /// further acquires from that scope fail with
+    /// [`ErrorKind::Exhausted`](crate::error::ErrorKind::Exhausted) even if
+    /// the pool has idle capacity, while other scopes keep acquiring.
+    ///
+    /// A quota applies to every caller whose scope bag carries `scope`, so
+    /// a quota on `Organization(org)` bounds all workflows and executions in
+    /// that organization combined. Calling this again for the same
+    /// `(scope, key)` changes the limit in place; leases already held stay
+    /// valid and count against the new limit.
+    pub fn set_quota(&self, scope: ScopeLevel, key: ResourceKey, max_concurrent: usize) {
+        self.quotas.set(scope, key, max_concurrent);
+    }
+
+    /// Removes the quota for `(scope, key)`. Returns `true` if one was set.
+    ///
+    /// Leases held under the removed quota are unaffected.
+    pub fn remove_quota(&self, scope: &ScopeLevel, key: &ResourceKey) -> bool {
+        self.quotas.remove(scope, key)
+    }
+
+    /// Returns the limit and current usage for `(scope, key)`, or `None`
+    /// if no quota is set. Usage is tracked only for scopes with a quota.
+    pub fn quota_usage(&self, scope: &ScopeLevel, key: &ResourceKey) -> Option<ScopeQuotaUsage> {
+        self.quotas.usage(scope, key)
+    }
+
+    /// Reserves a lease against the caller's scope quotas. See
+    /// [`set_quota`](Self::set_quota).
+    pub(crate) fn reserve_quota(
+        &self,
+        key: &ResourceKey,
+        scope: &Scope,
+    ) -> Result<QuotaPermit, Error> {
+        self.quotas.try_reserve(key, scope)
+    }
+}
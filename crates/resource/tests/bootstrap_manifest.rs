@@ -0,0 +1,204 @@
+//! `ResourceActivatorRegistry::bootstrap` builds a wired `Manager` from a
+//! declarative resources table.
+//!
+//! The manifest below is the JSON form of what a host reads from its config
+//! file: two kinds from the closed allowlist, one depending on the other,
+//! with a concurrency cap on the dependent.
+
+use std::sync::Arc;
+
+use nebula_core::{ResourceKey, resource_key, scope::Scope};
+use nebula_expression::ExpressionEngine;
+use nebula_resource::{
+    AcquireOptions, BootstrapError, KindActivator, Manager, RegistrarError, Resident,
+    ResidentConfig, ResourceActivatorRegistry, ResourceContext, ResourceManifest, ScopeLevel,
+    error::{Error, ErrorKind},
+    resource::{Provider, ResourceConfig, ResourceMetadata},
+    topology::resident::ResidentProvider,
+};
+use serde::Deserialize;
+use serde_json::json;
+use tokio_util::sync::CancellationToken;
+
+// ── Test resources ─────────────────────────────────────────────────────────
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct EndpointConfig {
+    url: String,
+}
+
+nebula_schema::impl_empty_has_schema!(EndpointConfig);
+
+impl ResourceConfig for EndpointConfig {
+    fn validate(&self) -> Result<(), Error> {
+        if self.url.is_empty() {
+            return Err(Error::permanent("url must not be empty"));
+        }
+        Ok(())
+    }
+
+    fn fingerprint(&self) -> u64 {
+        use std::hash::{Hash, Hasher};
+        let mut h = std::collections::hash_map::DefaultHasher::new();
+        self.url.hash(&mut h);
+        h.finish()
+    }
+}
+
+/// Defines a resident test resource whose instance is its config URL.
+macro_rules! endpoint_resource {
+    ($name:ident, $key:literal) => {
+        #[derive(Clone)]
+        struct $name;
+
+        #[async_trait::async_trait]
+        impl Provider for $name {
+            type Config = EndpointConfig;
+            type Instance = String;
+            type Topology = Resident<Self>;
+
+            fn key() -> ResourceKey {
+                resource_key!($key)
+            }
+
+            async fn create(
+                &self,
+                config: &EndpointConfig,
+                _ctx: &ResourceContext,
+            ) -> Result<String, Error> {
+                Ok(config.url.clone())
+            }
+
+            fn metadata() -> ResourceMetadata {
+                ResourceMetadata::from_key(&Self::key())
+            }
+        }
+
+        nebula_resource::no_credential_slots!($name);
+
+        impl nebula_core::DeclaresDependencies for $name {}
+
+        #[async_trait::async_trait]
+        impl ResidentProvider for $name {
+            fn is_alive_sync(&self, _runtime: &String) -> bool {
+                true
+            }
+        }
+    };
+}
+
+endpoint_resource!(Database, "bootstrap-db");
+endpoint_resource!(Cache, "bootstrap-cache");
+
+fn registry() -> ResourceActivatorRegistry {
+    let mut registry = ResourceActivatorRegistry::new();
+    registry.insert(
+        "database",
+        Arc::new(KindActivator::<Database, _, _>::new(
+            || Database,
+            || Resident::<Database>::new(ResidentConfig::default()),
+        )),
+    );
+    registry.insert(
+        "cache",
+        Arc::new(KindActivator::<Cache, _, _>::new(
+            || Cache,
+            || Resident::<Cache>::new(ResidentConfig::default()),
+        )),
+    );
+    registry
+}
+
+fn ctx() -> ResourceContext {
+    ResourceContext::minimal(Scope::default(), CancellationToken::new())
+}
+
+// ── Tests ──────────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn bootstrap_registers_manifest_and_serves_acquires() {
+    let manifest: ResourceManifest = serde_json::from_value(json!({
+        "resources": [
+            {
+                "id": "session-cache",
+                "type": "cache",
+                "depends_on": ["primary-db"],
+                "max_concurrent": 1,
+                "config": { "url": "redis://{{ \"cache.internal\" }}" },
+            },
+            {
+                "id": "primary-db",
+                "type": "database",
+                "config": { "url": "postgres://db.internal" },
+            },
+        ]
+    }))
+    .expect("manifest deserializes");
+
+    let manager = Manager::new();
+    let engine = ExpressionEngine::new();
+
+    let outcomes = registry()
+        .bootstrap(&manager, &manifest, &engine)
+        .await
+        .expect("bootstrap succeeds");
+
+    let keys: Vec<ResourceKey> = outcomes.into_iter().map(|o| o.resource_key).collect();
+    assert_eq!(
+        keys,
+        [Database::key(), Cache::key()],
+        "dependencies register first"
+    );
+
+    let db = manager
+        .acquire_resident::<Database>(&ctx(), &AcquireOptions::default())
+        .await
+        .expect("configured database acquires");
+    assert_eq!(*db, "postgres://db.internal");
+
+    let cache = manager
+        .acquire_resident::<Cache>(&ctx(), &AcquireOptions::default())
+        .await
+        .expect("configured cache acquires");
+    assert_eq!(*cache, "redis://cache.internal", "templates resolve");
+
+    let usage = manager
+        .quota_usage(&ScopeLevel::Global, &Cache::key())
+        .expect("max_concurrent installs a quota");
+    assert_eq!((usage.max_concurrent, usage.in_use), (1, 1));
+    let err = manager
+        .acquire_resident::<Cache>(&ctx(), &AcquireOptions::default())
+        .await
+        .expect_err("second lease exceeds max_concurrent");
+    assert!(matches!(err.kind(), ErrorKind::Exhausted { .. }));
+}
+
+#[tokio::test]
+async fn bootstrap_validates_every_entry_before_registering() {
+    let manifest: ResourceManifest = serde_json::from_value(json!({
+        "resources": [
+            { "id": "primary-db", "type": "database", "config": { "url": "postgres://db" } },
+            { "id": "session-cache", "type": "cache", "config": { "url": "redis://c", "tls": true } },
+        ]
+    }))
+    .expect("manifest deserializes");
+
+    let manager = Manager::new();
+    let engine = ExpressionEngine::new();
+
+    let err = registry()
+        .bootstrap(&manager, &manifest, &engine)
+        .await
+        .expect_err("undeclared config field is rejected on load");
+
+    assert!(matches!(
+        &err,
+        BootstrapError::Entry { id, source }
+            if id == "session-cache" && matches!(**source, RegistrarError::Register { .. })
+    ));
+    assert!(
+        !manager.contains(&Database::key()),
+        "a failed load registers nothing"
+    );
+}
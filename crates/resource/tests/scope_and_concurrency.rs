@@ -1,8 +1,9 @@
 //! Scope-aware lookup, multi-resource coexistence, and pool concurrency
 //! integration tests for nebula-resource v2: `ScopeLevel` exact-match vs
 //! global-fallback vs mismatch resolution, two independently registered
-//! resources coexisting on one `Manager`, and pool admission under
-//! concurrent acquire load (max-size enforcement, backpressure).
+//! resources coexisting on one `Manager`, tenant isolation through a
+//! `ScopeResolver`, per-scope quotas, and pool admission under concurrent acquire load
+//! (max-size enforcement, backpressure).
 //!
 //! Split out of the former monolithic `basic_integration.rs` (pure move, no
 //! test-body changes) — shared mocks/helpers live in `tests/common/mod.rs`.
@@ -217,6 +218,281 @@ async fn manager_scope_mismatch_not_found() {
     assert_eq!(*err.kind(), ErrorKind::NotFound);
 }
 
+// ---------------------------------------------------------------------------
+// Tenant isolation (scope resolver)
+// ---------------------------------------------------------------------------
+
+/// One tenant's ownership chain: org → workspace → workflow → execution.
+#[derive(Clone, Copy)]
+struct Tenant {
+    org: nebula_core::OrgId,
+    workspace: nebula_core::WorkspaceId,
+    workflow: nebula_core::WorkflowId,
+    execution: ExecutionId,
+}
+
+impl Tenant {
+    fn new() -> Self {
+        Self {
+            org: nebula_core::OrgId::new(),
+            workspace: nebula_core::WorkspaceId::new(),
+            workflow: nebula_core::WorkflowId::new(),
+            execution: ExecutionId::new(),
+        }
+    }
+}
+
+/// Resolves ownership for a fixed set of tenants.
+struct TenantResolver(Vec<Tenant>);
+
+impl nebula_core::scope::ScopeResolver for TenantResolver {
+    fn workflow_for_execution(&self, id: &ExecutionId) -> Option<nebula_core::WorkflowId> {
+        self.0
+            .iter()
+            .find(|t| t.execution == *id)
+            .map(|t| t.workflow)
+    }
+
+    fn workspace_for_workflow(
+        &self,
+        id: &nebula_core::WorkflowId,
+    ) -> Option<nebula_core::WorkspaceId> {
+        self.0
+            .iter()
+            .find(|t| t.workflow == *id)
+            .map(|t| t.workspace)
+    }
+
+    fn organization_for_workspace(
+        &self,
+        id: &nebula_core::WorkspaceId,
+    ) -> Option<nebula_core::OrgId> {
+        self.0.iter().find(|t| t.workspace == *id).map(|t| t.org)
+    }
+}
+
+/// An action context: the caller's execution plus the org it claims.
+fn action_ctx(execution: ExecutionId, org: nebula_core::OrgId) -> ResourceContext {
+    ResourceContext::minimal(
+        nebula_core::scope::Scope {
+            org_id: Some(org),
+            execution_id: Some(execution),
+            ..Default::default()
+        },
+        tokio_util::sync::CancellationToken::new(),
+    )
+}
+
+fn tenant_manager(a: Tenant, b: Tenant) -> (Manager, ResidentTestResource) {
+    let manager =
+        Manager::new().with_scope_resolver(std::sync::Arc::new(TenantResolver(vec![a, b])));
+    let resource = ResidentTestResource::new();
+    for org in [a.org, b.org] {
+        manager
+            .register(RegistrationSpec {
+                resource: resource.clone(),
+                config: test_config(),
+                scope: ScopeLevel::Organization(org),
+                slot_identity: SlotIdentity::Unbound,
+                topology: Resident::<ResidentTestResource>::new(ResidentConfig::default()),
+                recovery_gate: None,
+            })
+            .expect("registration should succeed");
+    }
+    (manager, resource)
+}
+
+#[tokio::test]
+async fn tenant_acquires_own_org_resource() {
+    let (a, b) = (Tenant::new(), Tenant::new());
+    let (manager, resource) = tenant_manager(a, b);
+
+    let handle: ResourceGuard<ResidentTestResource> = manager
+        .acquire_resident(&action_ctx(a.execution, a.org), &AcquireOptions::default())
+        .await
+        .expect("tenant A must reach its own org-scoped resource");
+
+    assert_eq!(resource.create_counter.load(Ordering::Relaxed), 1);
+    drop(handle);
+}
+
+#[tokio::test]
+async fn tenant_is_denied_other_tenants_org_resource() {
+    let (a, b) = (Tenant::new(), Tenant::new());
+    let (manager, resource) = tenant_manager(a, b);
+
+    // Tenant A's execution claiming tenant B's org.
+    let result = manager
+        .acquire_resident::<ResidentTestResource>(
+            &action_ctx(a.execution, b.org),
+            &AcquireOptions::default(),
+        )
+        .await;
+
+    let err = match result {
+        Err(e) => e,
+        Ok(_) => panic!("cross-tenant acquire must be denied"),
+    };
+    assert_eq!(*err.kind(), ErrorKind::Permanent);
+    assert!(err.to_string().contains("cross-tenant"), "{err}");
+    assert_eq!(resource.create_counter.load(Ordering::Relaxed), 0);
+}
+
+#[tokio::test]
+async fn unknown_execution_is_denied_when_resolver_is_set() {
+    let (a, b) = (Tenant::new(), Tenant::new());
+    let (manager, _resource) = tenant_manager(a, b);
+
+    let result = manager
+        .acquire_resident::<ResidentTestResource>(
+            &action_ctx(ExecutionId::new(), a.org),
+            &AcquireOptions::default(),
+        )
+        .await;
+
+    assert!(
+        matches!(result, Err(ref e) if *e.kind() == ErrorKind::Permanent),
+        "an execution the resolver cannot place must fail closed"
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Per-scope quotas
+// ---------------------------------------------------------------------------
+
+fn workflow_ctx(workflow: nebula_core::WorkflowId, org: nebula_core::OrgId) -> ResourceContext {
+    ResourceContext::minimal(
+        nebula_core::scope::Scope {
+            org_id: Some(org),
+            workflow_id: Some(workflow),
+            execution_id: Some(ExecutionId::new()),
+            ..Default::default()
+        },
+        tokio_util::sync::CancellationToken::new(),
+    )
+}
+
+/// A Global pool with room for 4 leases — capacity is never the limit here.
+fn quota_manager() -> Manager {
+    let config = nebula_resource::topology::pooled::config::Config {
+        max_size: 4,
+        ..Default::default()
+    };
+    let mgr = Manager::new();
+    register_pool(
+        &mgr,
+        PoolTestResource::new(),
+        test_config(),
+        Pooled::<PoolTestResource>::new(config, 1),
+    );
+    mgr
+}
+
+#[tokio::test]
+async fn scope_at_quota_is_denied_while_other_scope_acquires() {
+    let mgr = quota_manager();
+    let org = nebula_core::OrgId::new();
+    let (wf_a, wf_b) = (
+        nebula_core::WorkflowId::new(),
+        nebula_core::WorkflowId::new(),
+    );
+    let key = resource_key!("test-pool");
+    mgr.set_quota(ScopeLevel::Workflow(wf_a), key.clone(), 1);
+
+    let held = mgr
+        .acquire_pooled::<PoolTestResource>(&workflow_ctx(wf_a, org), &AcquireOptions::default())
+        .await
+        .expect("first acquire within quota should succeed");
+
+    let denied = mgr
+        .acquire_pooled::<PoolTestResource>(&workflow_ctx(wf_a, org), &AcquireOptions::default())
+        .await;
+    let err = match denied {
+        Err(e) => e,
+        Ok(_) => panic!("scope at its quota must be denied"),
+    };
+    assert_eq!(*err.kind(), ErrorKind::Exhausted { retry_after: None });
+    assert_eq!(err.resource_key(), Some(&key));
+
+    // The pool still has capacity, and workflow B has no quota.
+    let other = mgr
+        .acquire_pooled::<PoolTestResource>(&workflow_ctx(wf_b, org), &AcquireOptions::default())
+        .await
+        .expect("another scope should still acquire");
+
+    let usage = mgr
+        .quota_usage(&ScopeLevel::Workflow(wf_a), &key)
+        .expect("quota is set");
+    assert_eq!((usage.max_concurrent, usage.in_use), (1, 1));
+
+    // Releasing the lease frees the quota.
+    drop(held);
+    assert_eq!(
+        mgr.quota_usage(&ScopeLevel::Workflow(wf_a), &key)
+            .map(|u| u.in_use),
+        Some(0)
+    );
+    let _again = mgr
+        .acquire_pooled::<PoolTestResource>(&workflow_ctx(wf_a, org), &AcquireOptions::default())
+        .await
+        .expect("acquire after release should succeed");
+    drop(other);
+}
+
+#[tokio::test]
+async fn org_quota_bounds_all_workflows_in_the_org() {
+    let mgr = quota_manager();
+    let (org_a, org_b) = (nebula_core::OrgId::new(), nebula_core::OrgId::new());
+    let key = resource_key!("test-pool");
+    mgr.set_quota(ScopeLevel::Organization(org_a), key.clone(), 2);
+
+    let _first = mgr
+        .acquire_pooled::<PoolTestResource>(
+            &workflow_ctx(nebula_core::WorkflowId::new(), org_a),
+            &AcquireOptions::default(),
+        )
+        .await
+        .expect("within org quota");
+    let _second = mgr
+        .acquire_pooled::<PoolTestResource>(
+            &workflow_ctx(nebula_core::WorkflowId::new(), org_a),
+            &AcquireOptions::default(),
+        )
+        .await
+        .expect("within org quota");
+
+    let third = mgr
+        .acquire_pooled::<PoolTestResource>(
+            &workflow_ctx(nebula_core::WorkflowId::new(), org_a),
+            &AcquireOptions::default(),
+        )
+        .await;
+    assert!(
+        matches!(third, Err(ref e) if matches!(e.kind(), ErrorKind::Exhausted { .. })),
+        "a third workflow in the same org must hit the org quota"
+    );
+
+    let _other_org = mgr
+        .acquire_pooled::<PoolTestResource>(
+            &workflow_ctx(nebula_core::WorkflowId::new(), org_b),
+            &AcquireOptions::default(),
+        )
+        .await
+        .expect("another org is not bound by org A's quota");
+
+    // Raising the limit in place keeps the two held leases counted.
+    mgr.set_quota(ScopeLevel::Organization(org_a), key.clone(), 3);
+    let usage = mgr
+        .quota_usage(&ScopeLevel::Organization(org_a), &key)
+        .expect("quota is set");
+    assert_eq!((usage.max_concurrent, usage.in_use), (3, 2));
+    assert!(mgr.remove_quota(&ScopeLevel::Organization(org_a), &key));
+    assert!(
+        mgr.quota_usage(&ScopeLevel::Organization(org_a), &key)
+            .is_none()
+    );
+}
+
 // ---------------------------------------------------------------------------
 // Multiple resources coexist
 // ---------------------------------------------------------------------------
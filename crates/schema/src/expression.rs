@@ -243,8 +243,9 @@ impl ExpressionContext for EngineExpressionContext {
 }
 
 /// Evaluate a schema expression source, mirroring [`nebula_expression::parse_expression`]
-/// dispatch: mixed templates render to a string; a lone `{{ ... }}` envelope keeps
-/// typed evaluation; raw expression sources go through `ExpressionEngine::evaluate`.
+/// dispatch: mixed templates (and escaped `\{{` / raw blocks) render to a string; a lone
+/// `{{ ... }}` envelope keeps typed evaluation; raw expression sources go through
+/// `ExpressionEngine::evaluate`.
 fn resolve_expression_value(
     engine: &nebula_expression::ExpressionEngine,
     ctx: &nebula_expression::EvaluationContext,
@@ -253,21 +254,17 @@ fn resolve_expression_value(
     use nebula_expression::TemplatePart;
 
     if let Ok(template) = nebula_expression::Template::new(source.to_owned())
-        && template.expression_count() > 0
-    {
-        let has_static = template
+        && template
             .parts()
             .iter()
-            .any(|part| matches!(part, TemplatePart::Static { .. }));
-
-        if has_static || template.expression_count() > 1 {
-            let rendered = engine.render_template(&template, ctx)?;
-            return Ok(serde_json::Value::String(rendered));
+            .any(|part| !matches!(part, TemplatePart::Static { .. }))
+    {
+        if let [TemplatePart::Expression { content, .. }] = template.parts() {
+            return engine.evaluate(content.trim(), ctx);
         }
 
-        if let Some(expr) = template.expressions().into_iter().next() {
-            return engine.evaluate(expr.trim(), ctx);
-        }
+        let rendered = engine.render_template(&template, ctx)?;
+        return Ok(serde_json::Value::String(rendered));
     }
 
     engine.evaluate(source, ctx)